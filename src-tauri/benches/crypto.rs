@@ -0,0 +1,79 @@
+//! Benchmarks del pipeline criptográfico
+//!
+//! - Keystream Argon2id sobre una rejilla de iteraciones y memoria (sin caché)
+//! - Acierto de la caché de keystream
+//! - transform_seed completo para 12 y 24 palabras
+//!
+//! Ejecutar con `cargo bench --bench crypto`.
+
+mod support;
+
+use std::time::Duration;
+use criterion::{black_box, BatchSize, BenchmarkId, Criterion};
+use scypher_gui::bip39::conversion::entropy_to_phrase;
+use scypher_gui::crypto::keystream::{clear_kdf_cache, derive_keystream};
+use scypher_gui::transform_seed;
+
+const PASSWORD: &str = "benchmark_password";
+
+/// Rejilla de parámetros (iteraciones, memoria en KB)
+const KDF_GRID: &[(u32, u32)] = &[
+    (1, 8192),
+    (3, 8192),
+    (1, 65536),
+    (3, 65536),
+    (5, 131072), // Valores por defecto de la aplicación
+];
+
+fn bench_keystream(c: &mut Criterion) {
+    let mut group = c.benchmark_group("keystream");
+    group.sample_size(10).measurement_time(Duration::from_secs(10));
+
+    for &(iterations, memory_cost) in KDF_GRID {
+        let id = BenchmarkId::new("argon2id", format!("t{}_m{}", iterations, memory_cost));
+        group.bench_with_input(id, &(iterations, memory_cost), |b, &(iterations, memory_cost)| {
+            // La caché se vacía fuera de la medición para medir siempre Argon2id
+            b.iter_batched(
+                clear_kdf_cache,
+                |_| derive_keystream(black_box(PASSWORD), 32, iterations, memory_cost, 1).unwrap(),
+                BatchSize::PerIteration,
+            );
+        });
+    }
+
+    derive_keystream(PASSWORD, 32, 1, 8192, 1).unwrap();
+    group.bench_function("cache_hit", |b| {
+        b.iter(|| derive_keystream(black_box(PASSWORD), 32, 1, 8192, 1).unwrap());
+    });
+
+    group.finish();
+}
+
+fn bench_transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transform_seed");
+    group.sample_size(10);
+
+    for entropy_len in [16usize, 32] {
+        let phrase = entropy_to_phrase(&vec![0x42; entropy_len]).unwrap();
+        let words = entropy_len * 3 / 4;
+
+        group.bench_with_input(BenchmarkId::new("words", words), &phrase, |b, phrase| {
+            b.iter_batched(
+                clear_kdf_cache,
+                |_| transform_seed(black_box(phrase), PASSWORD, 1, 8192).unwrap(),
+                BatchSize::PerIteration,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_keystream(&mut criterion);
+    bench_transform(&mut criterion);
+    criterion.final_summary();
+
+    support::export_summary("crypto", &["keystream", "transform_seed"]);
+}
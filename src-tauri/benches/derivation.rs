@@ -0,0 +1,46 @@
+//! Benchmarks de derivación de direcciones, uno por red del registro
+//!
+//! Ejecutar con `cargo bench --bench derivation`.
+
+mod support;
+
+use std::collections::HashMap;
+use criterion::{black_box, BenchmarkId, Criterion};
+use scypher_gui::addresses::{
+    derive_addresses_with_config, EvmPathScheme, NetworkConfig, SolanaScheme, NETWORK_REGISTRY,
+};
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Direcciones derivadas por iteración
+const ADDRESS_COUNT: u32 = 5;
+
+fn bench_networks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("derive_addresses");
+    group.sample_size(20);
+
+    for spec in NETWORK_REGISTRY {
+        let mut config = HashMap::new();
+        config.insert(spec.key.to_string(), NetworkConfig {
+            count: ADDRESS_COUNT,
+            use_passphrase: false,
+            solana_scheme: SolanaScheme::default(),
+            evm_scheme: EvmPathScheme::default(),
+            account: 0,
+        });
+
+        group.bench_with_input(BenchmarkId::new(spec.key, ADDRESS_COUNT), &config, |b, config| {
+            b.iter(|| derive_addresses_with_config(black_box(MNEMONIC), None, config.clone()).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_networks(&mut criterion);
+    criterion.final_summary();
+
+    support::export_summary("derivation", &["derive_addresses"]);
+}
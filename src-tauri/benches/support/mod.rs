@@ -0,0 +1,145 @@
+//! Exportación de resultados de criterion a JSON y detección de regresiones
+//!
+//! Tras cada suite se escribe `<criterion>/<suite>-summary.json` con la media,
+//! mediana y desviación de cada benchmark. Si SCYPHER_BENCH_BASELINE apunta a
+//! un resumen anterior, cualquier benchmark cuya media empeore más del umbral
+//! (SCYPHER_BENCH_THRESHOLD, 10% por defecto) se informa y el proceso termina
+//! con error.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+const BASELINE_ENV: &str = "SCYPHER_BENCH_BASELINE";
+const THRESHOLD_ENV: &str = "SCYPHER_BENCH_THRESHOLD";
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Resultado de un benchmark (nanosegundos)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+/// Resumen de una suite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchSummary {
+    pub suite: String,
+    pub app_version: String,
+    pub results: BTreeMap<String, BenchResult>,
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+    std_dev: Estimate,
+}
+
+/// Directorio de salida de criterion (misma resolución que criterion)
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target"))
+        .join("criterion")
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Recorrer `<grupo>/<benchmark>/new/` buscando los resultados de los grupos de la suite
+fn collect(dir: &Path, groups: &[&str], results: &mut BTreeMap<String, BenchResult>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().is_some_and(|name| name == "new") {
+            let id = read_json::<BenchmarkId>(&path.join("benchmark.json"));
+            let estimates = read_json::<Estimates>(&path.join("estimates.json"));
+            if let (Some(id), Some(estimates)) = (id, estimates) {
+                if groups.contains(&id.group_id.as_str()) {
+                    results.insert(id.full_id, BenchResult {
+                        mean_ns: estimates.mean.point_estimate,
+                        median_ns: estimates.median.point_estimate,
+                        std_dev_ns: estimates.std_dev.point_estimate,
+                    });
+                }
+            }
+        } else if path.file_name().is_some_and(|name| name != "report" && name != "base") {
+            collect(&path, groups, results);
+        }
+    }
+}
+
+/// Benchmarks cuya media empeora más del umbral respecto a la línea base
+fn regressions(baseline: &BenchSummary, current: &BenchSummary, threshold_percent: f64) -> Vec<String> {
+    current
+        .results
+        .iter()
+        .filter_map(|(id, result)| {
+            let base = baseline.results.get(id)?;
+            let change = (result.mean_ns - base.mean_ns) / base.mean_ns * 100.0;
+            (change > threshold_percent).then(|| {
+                format!("{}: {:.0} ns -> {:.0} ns (+{:.1}%)", id, base.mean_ns, result.mean_ns, change)
+            })
+        })
+        .collect()
+}
+
+/// Escribir el resumen JSON de la suite y comparar con la línea base si existe
+pub fn export_summary(suite: &str, groups: &[&str]) {
+    let dir = criterion_dir();
+    let mut results = BTreeMap::new();
+    collect(&dir, groups, &mut results);
+
+    let summary = BenchSummary {
+        suite: suite.to_string(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        results,
+    };
+
+    let output = dir.join(format!("{}-summary.json", suite));
+    match serde_json::to_string_pretty(&summary).map(|json| fs::write(&output, json)) {
+        Ok(Ok(())) => println!("Benchmark summary written to {}", output.display()),
+        _ => eprintln!("Warning: could not write benchmark summary to {}", output.display()),
+    }
+
+    let Some(baseline_path) = std::env::var_os(BASELINE_ENV) else { return };
+    let Some(baseline) = read_json::<BenchSummary>(Path::new(&baseline_path)) else {
+        eprintln!("Warning: could not read benchmark baseline {:?}", baseline_path);
+        return;
+    };
+    let threshold = std::env::var(THRESHOLD_ENV)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_THRESHOLD_PERCENT);
+
+    let regressed = regressions(&baseline, &summary, threshold);
+    if !regressed.is_empty() {
+        eprintln!("Performance regressions over {}%:", threshold);
+        for line in &regressed {
+            eprintln!("  {}", line);
+        }
+        std::process::exit(1);
+    }
+}
@@ -1,3 +1,18 @@
 fn main() {
+  // Información de compilación para get_build_info. SCYPHER_GIT_COMMIT permite
+  // fijar el commit en compilaciones reproducibles sin directorio .git
+  println!("cargo:rerun-if-env-changed=SCYPHER_GIT_COMMIT");
+  println!("cargo:rerun-if-changed=../.git/HEAD");
+  let commit = std::env::var("SCYPHER_GIT_COMMIT").ok().or_else(|| {
+    std::process::Command::new("git")
+      .args(["rev-parse", "--short=12", "HEAD"])
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+  });
+  println!("cargo:rustc-env=SCYPHER_GIT_COMMIT={}", commit.unwrap_or_else(|| "unknown".to_string()));
+  println!("cargo:rustc-env=SCYPHER_TARGET={}", std::env::var("TARGET").unwrap_or_default());
+
   tauri_build::build()
 }
@@ -0,0 +1,19 @@
+//! Entropía -> frase: el checksum recalculado siempre es válido y reversible
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scypher_gui::bip39::conversion::{entropy_to_phrase, phrase_to_entropy, validate_and_fix_checksum};
+
+fuzz_target!(|data: &[u8]| {
+    match entropy_to_phrase(data) {
+        Ok(phrase) => {
+            assert!([16, 20, 24, 28, 32].contains(&data.len()));
+            assert_eq!(phrase.split_whitespace().count(), data.len() * 3 / 4);
+            assert!(scypher_gui::verify_checksum(&phrase).unwrap());
+            assert_eq!(phrase_to_entropy(&phrase).unwrap(), data);
+            assert_eq!(validate_and_fix_checksum(&phrase).unwrap(), phrase);
+        }
+        Err(_) => assert!(![16, 20, 24, 28, 32].contains(&data.len())),
+    }
+});
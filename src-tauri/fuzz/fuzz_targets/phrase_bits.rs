@@ -0,0 +1,28 @@
+//! Frase <-> bits: cualquier entrada debe fallar limpiamente o volver idéntica
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scypher_gui::bip39::conversion::{bits_to_phrase, phrase_to_bits};
+
+fuzz_target!(|data: &[u8]| {
+    // Texto arbitrario (unicode, espacios raros, cadenas enormes)
+    if let Ok(text) = std::str::from_utf8(data) {
+        if let Ok(bits) = phrase_to_bits(text) {
+            let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            assert_eq!(bits.len(), normalized.split(' ').count() * 11);
+            assert_eq!(bits_to_phrase(&bits).unwrap(), normalized);
+        }
+    }
+
+    // Bits arbitrarios recortados a múltiplo de 11
+    let mut bits: Vec<bool> = data
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect();
+    bits.truncate(bits.len() / 11 * 11);
+    if !bits.is_empty() {
+        let phrase = bits_to_phrase(&bits).unwrap();
+        assert_eq!(phrase_to_bits(&phrase).unwrap(), bits);
+    }
+});
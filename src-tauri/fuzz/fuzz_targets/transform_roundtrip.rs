@@ -0,0 +1,34 @@
+//! Transformación XOR: reversible y con checksum válido para todas las longitudes
+//!
+//! Usa parámetros Argon2id mínimos para que cada ejecución sea rápida.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scypher_gui::bip39::conversion::entropy_to_phrase;
+use scypher_gui::transform_seed;
+
+const ENTROPY_LENGTHS: [usize; 5] = [16, 20, 24, 28, 32];
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, rest)) = data.split_first() else {
+        return;
+    };
+    let entropy_len = ENTROPY_LENGTHS[selector as usize % ENTROPY_LENGTHS.len()];
+    if rest.len() < entropy_len {
+        return;
+    }
+
+    let (entropy, password) = rest.split_at(entropy_len);
+    let password = String::from_utf8_lossy(password);
+    if password.is_empty() {
+        return;
+    }
+
+    let phrase = entropy_to_phrase(entropy).unwrap();
+    let transformed = transform_seed(&phrase, &password, 1, 8192).unwrap();
+
+    assert_eq!(transformed.split_whitespace().count(), phrase.split_whitespace().count());
+    assert!(scypher_gui::verify_checksum(&transformed).unwrap());
+    assert_eq!(transform_seed(&transformed, &password, 1, 8192).unwrap(), phrase);
+});
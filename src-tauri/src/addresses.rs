@@ -8,10 +8,12 @@ use serde::{Deserialize, Serialize};
 use crate::error::{SCypherError, Result};
 
 // Importaciones principales
-use bip32::{XPrv, DerivationPath, ChildNumber};
+use bip32::{XPrv, ChildNumber};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 // Importaciones Ergo
+#[cfg(feature = "network-ergo")]
 use ergo_lib::{
     ergotree_ir::chain::address::{Address as ErgoAddress, NetworkPrefix, AddressEncoder},
     wallet::{
@@ -24,8 +26,6 @@ use ergo_lib::{
 // Importaciones criptográficas
 use blake2::{Blake2b, Digest as Blake2Digest};
 use bech32::{ToBase32, Variant};
-use ed25519_dalek::{SigningKey, VerifyingKey};
-use pbkdf2::pbkdf2;
 use hmac::{Hmac, Mac};
 use ed25519_dalek::SigningKey as SolanaSigningKey;
 use sha2::{Sha256, Sha512, Digest};
@@ -33,6 +33,7 @@ use ripemd::Ripemd160;
 use tiny_keccak::{Hasher, Keccak};
 
 // Importaciones Cardano - EMURGO CSL
+#[cfg(feature = "network-cardano")]
 use cardano_serialization_lib::{
     Bip32PrivateKey, Bip32PublicKey,
     Address as CSLAddress, BaseAddress, Credential,
@@ -45,6 +46,55 @@ pub struct Address {
     pub address_type: String,
     pub path: String,
     pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,            // Solo redes EVM
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explorer_url: Option<String>,     // Solo redes EVM
+}
+
+/// Esquema de derivación Solana (cada wallet usa uno distinto)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolanaScheme {
+    #[default]
+    Phantom,    // m/44'/501'/i'/0'
+    Solflare,   // m/44'/501'/0'/0'/i'
+    LedgerLive, // m/44'/501'/i'
+    RootSeed,   // Sin derivación: primeros 32 bytes del seed BIP39 (solana-keygen)
+}
+
+impl SolanaScheme {
+    /// Ruta de derivación para un índice (None si el esquema no deriva)
+    #[cfg_attr(not(feature = "network-solana"), allow(dead_code))]
+    pub fn derivation_path(&self, index: u32) -> Option<String> {
+        match self {
+            SolanaScheme::Phantom => Some(format!("m/44'/501'/{}'/0'", index)),
+            SolanaScheme::Solflare => Some(format!("m/44'/501'/0'/0'/{}'", index)),
+            SolanaScheme::LedgerLive => Some(format!("m/44'/501'/{}'", index)),
+            SolanaScheme::RootSeed => None,
+        }
+    }
+}
+
+/// Esquema de rutas EVM: dónde cae el índice de cada dirección
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvmPathScheme {
+    #[default]
+    Standard,   // m/44'/60'/0'/0/i (MetaMask, Trezor)
+    LedgerLive, // m/44'/60'/i'/0/0 (una cuenta por índice)
+    LegacyMew,  // m/44'/60'/0'/i (MyEtherWallet / Ledger Chrome app antiguos)
+}
+
+impl EvmPathScheme {
+    /// Ruta de derivación para un índice
+    pub fn derivation_path(&self, index: u32) -> String {
+        match self {
+            EvmPathScheme::Standard => format!("m/44'/60'/0'/0/{}", index),
+            EvmPathScheme::LedgerLive => format!("m/44'/60'/{}'/0/0", index),
+            EvmPathScheme::LegacyMew => format!("m/44'/60'/0'/{}", index),
+        }
+    }
 }
 
 /// Configuración para cada red
@@ -52,33 +102,378 @@ pub struct Address {
 pub struct NetworkConfig {
     pub count: u32,           // Cantidad de direcciones a generar
     pub use_passphrase: bool, // Si usar passphrase (solo para redes que lo soporten oficialmente)
+    #[serde(default)]
+    pub solana_scheme: SolanaScheme, // Solo se aplica a Solana
+    #[serde(default)]
+    pub evm_scheme: EvmPathScheme,   // Solo se aplica a Ethereum
+    #[serde(default)]
+    pub account: u32,                // Cuenta (hardened); solo se aplica a Ergo y Cardano
+}
+
+/// Identificador de red (se serializa en minúsculas: "bitcoin", "bsc"...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkId {
+    Bitcoin,
+    Ethereum,
+    Ergo,
+    Bsc,
+    Polygon,
+    Cardano,
+    Dogecoin,
+    Litecoin,
+    Solana,
+    Tron,
+    BitcoinCash,
+    Stellar,
+    Ton,
+    Liquid,
+    Zcash,
+}
+
+impl NetworkId {
+    /// Clave de red usada en la API y en el frontend
+    pub fn as_str(&self) -> &'static str {
+        network_spec(*self).key
+    }
+}
+
+impl FromStr for NetworkId {
+    type Err = SCypherError;
+
+    fn from_str(network: &str) -> Result<Self> {
+        NETWORK_REGISTRY
+            .iter()
+            .find(|info| info.key == network)
+            .map(|info| info.id)
+            .ok_or_else(|| SCypherError::crypto(format!("Unsupported network: {}", network)))
+    }
+}
+
+/// Curva usada por la red
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkCurve {
+    Secp256k1,
+    Ed25519,       // SLIP-0010
+    Ed25519Bip32,  // BIP32-Ed25519 (Icarus)
+}
+
+/// Codificación de las direcciones de la red
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressEncoding {
+    BitcoinMulti,  // P2PKH, P2SH-P2WPKH y P2WPKH
+    Base58Check,   // P2PKH con byte de versión propio
+    Evm,           // Keccak256 + EIP-55
+    Tron,          // Keccak256 + prefijo 0x41 en Base58Check
+    Base58,        // Clave pública Ed25519 en Base58 (Solana)
+    CardanoBech32,
+    Ergo,
+    CashAddr,      // P2PKH en CashAddr (con el formato legacy como alternativa)
+    StellarStrKey, // Clave pública Ed25519 en base32 con CRC16
+    TonWallet,     // Hash del StateInit de un wallet v4R2
+    Liquid,        // P2SH/SegWit con su forma confidencial (SLIP-77, blech32)
+}
+
+/// Datos comunes a todas las derivaciones de una petición
+pub struct DerivationContext<'a> {
+    pub seed_phrase: &'a str,
+    pub master_key: &'a XPrv,
+    pub passphrase: Option<&'a str>, // Ya filtrada según el soporte de la red
+}
+
+/// Función de derivación de una red
+pub type DeriveFn = fn(&DerivationContext, &NetworkConfig) -> Result<Vec<Address>>;
+
+/// Descripción de una red: añadir una cadena es añadir una entrada aquí
+#[derive(Clone, Copy, Serialize)]
+pub struct NetworkSpec {
+    pub id: NetworkId,
+    pub key: &'static str,
+    pub name: &'static str,
+    pub coin_type: u32,          // SLIP-0044
+    pub curve: NetworkCurve,
+    pub encoder: AddressEncoding,
+    pub supports_passphrase: bool,
+    pub reference_tools: &'static [&'static str], // Herramientas de terceros para contrastar
+    #[serde(skip)]
+    pub derive: DeriveFn,
+}
+
+/// Registro de redes soportadas
+pub const NETWORK_REGISTRY: &[NetworkSpec] = &[
+    NetworkSpec {
+        id: NetworkId::Bitcoin,
+        key: "bitcoin",
+        name: "Bitcoin",
+        coin_type: 0,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::BitcoinMulti,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "Sparrow", "Electrum"],
+        derive: |ctx, config| derive_bitcoin_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Ethereum,
+        key: "ethereum",
+        name: "Ethereum",
+        coin_type: 60,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Evm,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "MetaMask"],
+        derive: |ctx, config| derive_ethereum_addresses(ctx.master_key, config.count, config.evm_scheme),
+    },
+    NetworkSpec {
+        // Ergo soporta passphrase (verificado con wallet SATERGO)
+        id: NetworkId::Ergo,
+        key: "ergo",
+        name: "Ergo",
+        coin_type: 429,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Ergo,
+        supports_passphrase: true,
+        reference_tools: &["SATERGO", "Nautilus"],
+        derive: |ctx, config| derive_ergo_addresses(ctx.seed_phrase, ctx.passphrase, config.account, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Bsc,
+        key: "bsc",
+        name: "BNB Smart Chain",
+        coin_type: 60,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Evm,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "MetaMask"],
+        derive: |ctx, config| derive_bsc_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Polygon,
+        key: "polygon",
+        name: "Polygon",
+        coin_type: 60,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Evm,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "MetaMask"],
+        derive: |ctx, config| derive_polygon_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        // Cardano siempre usa None para passphrase (Yoroi/Daedalus no lo soportan)
+        id: NetworkId::Cardano,
+        key: "cardano",
+        name: "Cardano",
+        coin_type: 1815,
+        curve: NetworkCurve::Ed25519Bip32,
+        encoder: AddressEncoding::CardanoBech32,
+        supports_passphrase: false,
+        reference_tools: &["cardano-address CLI", "Eternl", "Yoroi"],
+        derive: |ctx, config| derive_cardano_addresses_official(ctx.seed_phrase, None, config.account, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Dogecoin,
+        key: "dogecoin",
+        name: "Dogecoin",
+        coin_type: 3,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Base58Check,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "Dogecoin Core"],
+        derive: |ctx, config| derive_dogecoin_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Litecoin,
+        key: "litecoin",
+        name: "Litecoin",
+        coin_type: 2,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Base58Check,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "Electrum-LTC"],
+        derive: |ctx, config| derive_litecoin_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        // Solana siempre usa None para passphrase (Phantom no lo soporta)
+        id: NetworkId::Solana,
+        key: "solana",
+        name: "Solana",
+        coin_type: 501,
+        curve: NetworkCurve::Ed25519,
+        encoder: AddressEncoding::Base58,
+        supports_passphrase: false,
+        reference_tools: &["solana-keygen", "Phantom"],
+        derive: |ctx, config| {
+            derive_solana_from_mnemonic_direct(ctx.seed_phrase, None, config.count, config.solana_scheme)
+        },
+    },
+    NetworkSpec {
+        id: NetworkId::Tron,
+        key: "tron",
+        name: "TRON",
+        coin_type: 195,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Tron,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "TronLink"],
+        derive: |ctx, config| derive_tron_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        // Bitcoin Cash comparte seeds con Bitcoin desde el fork (2017)
+        id: NetworkId::BitcoinCash,
+        key: "bitcoincash",
+        name: "Bitcoin Cash",
+        coin_type: 145,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::CashAddr,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "Electron Cash"],
+        derive: |ctx, config| derive_bitcoin_cash_addresses(ctx.master_key, config.count),
+    },
+    NetworkSpec {
+        id: NetworkId::Stellar,
+        key: "stellar",
+        name: "Stellar",
+        coin_type: 148,
+        curve: NetworkCurve::Ed25519,
+        encoder: AddressEncoding::StellarStrKey,
+        supports_passphrase: true,
+        reference_tools: &["Stellar Laboratory", "LOBSTR"],
+        derive: |ctx, config| derive_stellar_addresses(ctx.seed_phrase, ctx.passphrase, config.count),
+    },
+    NetworkSpec {
+        // TON siempre usa None para passphrase (Trust Wallet no lo soporta)
+        id: NetworkId::Ton,
+        key: "ton",
+        name: "TON",
+        coin_type: 607,
+        curve: NetworkCurve::Ed25519,
+        encoder: AddressEncoding::TonWallet,
+        supports_passphrase: false,
+        reference_tools: &["Trust Wallet"],
+        derive: |ctx, config| derive_ton_addresses(ctx.seed_phrase, config.count),
+    },
+    NetworkSpec {
+        // Liquid soporta passphrase (Blockstream Green/Jade)
+        id: NetworkId::Liquid,
+        key: "liquid",
+        name: "Liquid",
+        coin_type: 1776,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Liquid,
+        supports_passphrase: true,
+        reference_tools: &["Blockstream Green", "Jade"],
+        derive: |ctx, config| derive_liquid_addresses(ctx.seed_phrase, ctx.passphrase, config.count),
+    },
+    NetworkSpec {
+        // Solo direcciones transparentes (t1...): las blindadas no usan BIP32 secp256k1
+        id: NetworkId::Zcash,
+        key: "zcash",
+        name: "Zcash (transparent)",
+        coin_type: 133,
+        curve: NetworkCurve::Secp256k1,
+        encoder: AddressEncoding::Base58Check,
+        supports_passphrase: true,
+        reference_tools: &["Ian Coleman BIP39 (offline)", "Zashi (transparent)"],
+        derive: |ctx, config| derive_zcash_addresses(ctx.master_key, config.count),
+    },
+];
+
+/// Descripción de una red del registro
+pub fn network_spec(id: NetworkId) -> &'static NetworkSpec {
+    NETWORK_REGISTRY
+        .iter()
+        .find(|info| info.id == id)
+        .expect("every NetworkId has a registry entry")
+}
+
+/// Indica si la red está compilada en este binario
+///
+/// Ergo, Cardano y Solana dependen de sus features `network-*`; el resto
+/// está siempre. Las redes no compiladas conservan su entrada del registro
+/// (nombre, coin type) pero no se pueden derivar.
+// Sin ninguna feature `network-*` todos los brazos son `false` y clippy propone matches!
+#[allow(clippy::match_like_matches_macro)]
+pub fn network_compiled(id: NetworkId) -> bool {
+    match id {
+        NetworkId::Ergo => cfg!(feature = "network-ergo"),
+        NetworkId::Cardano => cfg!(feature = "network-cardano"),
+        NetworkId::Solana => cfg!(feature = "network-solana"),
+        _ => true,
+    }
+}
+
+/// Redes del registro compiladas en este binario
+pub fn compiled_networks() -> impl Iterator<Item = &'static NetworkSpec> {
+    NETWORK_REGISTRY.iter().filter(|spec| network_compiled(spec.id))
+}
+
+/// Error al pedir una red que no se compiló
+fn network_not_compiled(id: NetworkId) -> SCypherError {
+    SCypherError::crypto(format!("Network {} is not available in this build", network_spec(id).name))
+}
+
+/// Conjunto de direcciones agrupadas por red
+/// Se serializa como objeto JSON: { "bitcoin": [...], "ethereum": [...] }
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AddressSet(pub HashMap<NetworkId, Vec<Address>>);
+
+/// Lista vacía para redes no solicitadas
+static NO_ADDRESSES: Vec<Address> = Vec::new();
+
+impl AddressSet {
+    /// Lista mutable de una red, creándola si no existe
+    pub fn list_mut(&mut self, network: NetworkId) -> &mut Vec<Address> {
+        self.0.entry(network).or_default()
+    }
 }
 
-/// Conjunto completo de direcciones para todas las redes
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AddressSet {
-    pub bitcoin: Vec<Address>,
-    pub ethereum: Vec<Address>,
-    pub ergo: Vec<Address>,
-    pub bsc: Vec<Address>,
-    pub polygon: Vec<Address>,
-    pub cardano: Vec<Address>,
-    pub dogecoin: Vec<Address>,
-    pub litecoin: Vec<Address>,
-    pub solana: Vec<Address>,
-    pub tron: Vec<Address>,
+/// `set[NetworkId::Bitcoin]` devuelve una lista vacía si la red no se derivó
+impl std::ops::Index<NetworkId> for AddressSet {
+    type Output = Vec<Address>;
+
+    fn index(&self, network: NetworkId) -> &Vec<Address> {
+        self.0.get(&network).unwrap_or(&NO_ADDRESSES)
+    }
 }
 
 /// Información sobre soporte de passphrase por red
 pub fn network_supports_passphrase(network: &str) -> bool {
-    match network {
-        // Redes que oficialmente soportan BIP39 passphrase
-        "bitcoin" | "ethereum" | "tron" | "litecoin" | "dogecoin" | "bsc" | "polygon" => true,
-        // Ergo soporta passphrase (verificado con wallet SATERGO)
-        "ergo" => true,
-        // Redes que NO soportan passphrase consistentemente
-        "cardano" | "solana" => false,
-        _ => false,
+    NetworkId::from_str(network)
+        .map(|id| network_spec(id).supports_passphrase)
+        .unwrap_or(false)
+}
+
+/// Parsear una frase BIP39 de 12, 15, 18, 21 o 24 palabras
+///
+/// Ergo y Cardano derivan desde la frase o su entropía con sus propias
+/// bibliotecas; validar aquí el número de palabras da el mismo error en
+/// todas las redes y la forma canónica (espacios simples) para el seed.
+fn parse_mnemonic(seed_phrase: &str) -> Result<bip39_crate::Mnemonic> {
+    use bip39_crate::{Mnemonic, Language};
+
+    crate::bip39::validate_word_count(seed_phrase.split_whitespace().count())?;
+    Mnemonic::parse_in_normalized(Language::English, seed_phrase)
+        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))
+}
+
+/// Las cuentas son hardened: el índice tiene que caber en 31 bits
+#[cfg(any(feature = "network-ergo", feature = "network-cardano"))]
+fn check_account_index(account: u32) -> Result<()> {
+    if account >= 0x80_00_00_00 {
+        return Err(SCypherError::crypto(format!("Invalid account index {} (must be below 2^31)", account)));
+    }
+    Ok(())
+}
+
+/// Etiqueta de una dirección: "Ergo #3" en la cuenta 0, "Ergo (account 1) #3" en las demás
+#[cfg(any(feature = "network-ergo", feature = "network-cardano"))]
+fn account_label(network: &str, account: u32, index: u32) -> String {
+    if account == 0 {
+        format!("{} #{}", network, index)
+    } else {
+        format!("{} (account {}) #{}", network, account, index)
     }
 }
 
@@ -87,13 +482,10 @@ pub fn network_supports_passphrase(network: &str) -> bool {
 pub fn derive_addresses_with_config(
     seed_phrase: &str,
     passphrase: Option<&str>,
-    network_configs: std::collections::HashMap<String, NetworkConfig>,
+    network_configs: HashMap<String, NetworkConfig>,
 ) -> Result<AddressSet> {
-    use bip39_crate::{Mnemonic, Language};
-
     // Parsear mnemonic BIP39
-    let mnemonic = Mnemonic::parse_in_normalized(Language::English, seed_phrase)
-        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
+    let mnemonic = parse_mnemonic(seed_phrase)?;
 
     // Generar seed con passphrase opcional
     let seed = mnemonic.to_seed(passphrase.unwrap_or(""));
@@ -102,69 +494,46 @@ pub fn derive_addresses_with_config(
     let master_key = XPrv::new(&seed)
         .map_err(|e| SCypherError::crypto(format!("Master key derivation failed: {}", e)))?;
 
-    let mut address_set = AddressSet {
-        bitcoin: Vec::new(),
-        ethereum: Vec::new(),
-        ergo: Vec::new(),
-        bsc: Vec::new(),
-        polygon: Vec::new(),
-        cardano: Vec::new(),
-        dogecoin: Vec::new(),
-        litecoin: Vec::new(),
-        solana: Vec::new(),
-        tron: Vec::new(),
-    };
+    let mut address_set = AddressSet::default();
 
     // Derivar direcciones para cada red solicitada
     for (network, config) in network_configs {
+        let info = network_spec(NetworkId::from_str(&network)?);
+        if !network_compiled(info.id) {
+            return Err(network_not_compiled(info.id));
+        }
+
         // Determinar si usar passphrase según soporte oficial
-        let effective_passphrase = if config.use_passphrase && network_supports_passphrase(&network) {
-            passphrase
-        } else {
-            None // No usar passphrase si la red no lo soporta oficialmente
+        let context = DerivationContext {
+            seed_phrase,
+            master_key: &master_key,
+            passphrase: if config.use_passphrase && info.supports_passphrase {
+                passphrase
+            } else {
+                None // No usar passphrase si la red no lo soporta oficialmente
+            },
         };
 
-        match network.as_str() {
-            "bitcoin" => {
-                address_set.bitcoin = derive_bitcoin_addresses(&master_key, config.count)?;
-            }
-            "ethereum" => {
-                address_set.ethereum = derive_ethereum_addresses(&master_key, config.count)?;
-            }
-            "ergo" => {
-                // Ergo soporta passphrase (verificado con wallet SATERGO)
-                address_set.ergo = derive_ergo_addresses(seed_phrase, effective_passphrase, config.count)?;
-            }
-            "bsc" => {
-                address_set.bsc = derive_bsc_addresses(&master_key, config.count)?;
-            }
-            "polygon" => {
-                address_set.polygon = derive_polygon_addresses(&master_key, config.count)?;
-            }
-            "cardano" => {
-                // Cardano siempre usa None para passphrase (Yoroi/Daedalus no lo soportan)
-                address_set.cardano = derive_cardano_addresses_official(seed_phrase, None, config.count)?;
-            }
-            "dogecoin" => {
-                address_set.dogecoin = derive_dogecoin_addresses(&master_key, config.count)?;
-            }
-            "litecoin" => {
-                address_set.litecoin = derive_litecoin_addresses(&master_key, config.count)?;
-            }
-            "solana" => {
-                // Solana siempre usa None para passphrase (Phantom no lo soporta)
-                address_set.solana = derive_solana_from_mnemonic_direct(seed_phrase, None, config.count)?;
-            }
-            "tron" => {
-                address_set.tron = derive_tron_addresses(&master_key, config.count)?;
-            }
-            _ => return Err(SCypherError::crypto(format!("Unsupported network: {}", network))),
-        }
+        address_set.0.insert(info.id, (info.derive)(&context, &config)?);
     }
 
+    annotate_evm_addresses(&mut address_set);
     Ok(address_set)
 }
 
+/// Añadir chain ID y enlace al explorador a las direcciones EVM
+fn annotate_evm_addresses(address_set: &mut AddressSet) {
+    for (network, addresses) in address_set.0.iter_mut() {
+        if network_spec(*network).encoder != AddressEncoding::Evm {
+            continue;
+        }
+        for address in addresses.iter_mut() {
+            address.chain_id = crate::evm_registry::chain_id(network.as_str());
+            address.explorer_url = crate::evm_registry::explorer_url(network.as_str(), &address.address);
+        }
+    }
+}
+
 /// Función legacy para compatibilidad hacia atrás
 pub fn derive_addresses(
     seed_phrase: &str,
@@ -172,50 +541,105 @@ pub fn derive_addresses(
     networks: &[String],
 ) -> Result<AddressSet> {
     // Crear configuración por defecto (3 direcciones cada red)
-    let mut network_configs = std::collections::HashMap::new();
+    let mut network_configs = HashMap::new();
     for network in networks {
         network_configs.insert(network.clone(), NetworkConfig {
             count: 3,
             use_passphrase: true, // Será aplicado solo a redes que lo soporten
+            solana_scheme: SolanaScheme::default(),
+            evm_scheme: EvmPathScheme::default(),
+            account: 0,
         });
     }
 
     derive_addresses_with_config(seed_phrase, passphrase, network_configs)
 }
 
+/// Entrada de la lista de verificación cruzada: los datos que pide una
+/// herramienta de terceros y la dirección que debe obtener con ellos
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossCheckEntry {
+    pub network: NetworkId,
+    pub name: &'static str,
+    pub address_type: String,
+    pub path: String,
+    pub curve: NetworkCurve,
+    pub encoding: AddressEncoding,
+    pub passphrase_applied: bool,
+    pub address: String,
+    pub reference_tools: &'static [&'static str],
+}
+
+/// Primera dirección de cada red del registro, con ruta, curva y codificación
+///
+/// Pensada para contrastar cada cadena con una herramienta independiente.
+pub fn cross_check_entries(seed_phrase: &str, passphrase: Option<&str>) -> Result<Vec<CrossCheckEntry>> {
+    let network_configs = compiled_networks()
+        .map(|spec| {
+            (spec.key.to_string(), NetworkConfig {
+                count: 1,
+                use_passphrase: true,
+                solana_scheme: SolanaScheme::default(),
+                evm_scheme: EvmPathScheme::default(),
+                account: 0,
+            })
+        })
+        .collect();
+    let address_set = derive_addresses_with_config(seed_phrase, passphrase, network_configs)?;
+
+    let mut entries = Vec::new();
+    for spec in compiled_networks() {
+        for address in &address_set[spec.id] {
+            entries.push(CrossCheckEntry {
+                network: spec.id,
+                name: spec.name,
+                address_type: address.address_type.clone(),
+                path: address.path.clone(),
+                curve: spec.curve,
+                encoding: spec.encoder,
+                passphrase_applied: passphrase.is_some_and(|p| !p.is_empty()) && spec.supports_passphrase,
+                address: address.address.clone(),
+                reference_tools: spec.reference_tools,
+            });
+        }
+    }
+    Ok(entries)
+}
+
 // =============================================================================
 // IMPLEMENTACIÓN CARDANO OFICIAL - EMURGO CSL
 // =============================================================================
 
 /// Derivar direcciones Cardano usando EMURGO CSL (biblioteca oficial)
 /// NOTA: Cardano (Yoroi/Daedalus) no soporta BIP39 passphrase oficialmente
+///
+/// Cada cuenta tiene su propia staking key (m/1852'/1815'/a'/2/0), como en
+/// las cuentas adicionales de Eternl o Yoroi.
+#[cfg(feature = "network-cardano")]
 fn derive_cardano_addresses_official(
     mnemonic_phrase: &str,
     _passphrase: Option<&str>, // Ignorado intencionalmente
+    account: u32,
     count: u32,
 ) -> Result<Vec<Address>> {
-    use bip39_crate::{Mnemonic, Language};
-
     let mut addresses = Vec::new();
+    check_account_index(account)?;
 
-    println!("🔧 CARDANO OFICIAL - EMURGO CSL Implementation (sin passphrase)");
-
-    // Conversión correcta de mnemonic a entropy
-    let mnemonic = Mnemonic::parse_in_normalized(Language::English, mnemonic_phrase)
-        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
+    tracing::debug!(account, count, "Derivando direcciones Cardano con EMURGO CSL (sin passphrase)");
 
+    // Conversión correcta de mnemonic a entropy (16 a 32 bytes, Icarus acepta todas)
+    let mnemonic = parse_mnemonic(mnemonic_phrase)?;
     let entropy = mnemonic.to_entropy();
-    println!("🔍 Entropy correcta: {}", hex::encode(&entropy));
 
     // Generar master key usando EMURGO CSL (sin passphrase para compatibilidad Yoroi/Daedalus)
     let master_key = Bip32PrivateKey::from_bip39_entropy(&entropy, &[]);
-    println!("🔍 Master key generada con EMURGO CSL");
+    tracing::trace!("Master key generada con EMURGO CSL");
 
-    // Derivar staking key: m/1852'/1815'/0'/2/0
+    // Derivar staking key de la cuenta: m/1852'/1815'/account'/2/0
     let staking_key = master_key
         .derive(harden(1852))  // purpose
         .derive(harden(1815))  // coin_type
-        .derive(harden(0))     // account
+        .derive(harden(account))  // account
         .derive(2)             // role (staking)
         .derive(0);            // index
 
@@ -228,7 +652,7 @@ fn derive_cardano_addresses_official(
         let payment_key = master_key
             .derive(harden(1852))  // purpose
             .derive(harden(1815))  // coin_type
-            .derive(harden(0))     // account
+            .derive(harden(account))  // account
             .derive(0)             // role (external)
             .derive(index);        // index
 
@@ -246,19 +670,28 @@ fn derive_cardano_addresses_official(
         let address_str = base_addr.to_address().to_bech32(None)
             .map_err(|e| SCypherError::crypto(format!("Address encoding failed: {:?}", e)))?;
 
-        println!("🔍 Index {} address: {}", index, address_str);
+        tracing::debug!(account, index, address = %address_str, "Dirección Cardano derivada");
 
         addresses.push(Address {
-            address_type: format!("Cardano #{}", index),
-            path: format!("m/1852'/1815'/0'/0/{}", index),
+            address_type: account_label("Cardano", account, index),
+            path: format!("m/1852'/1815'/{}'/0/{}", account, index),
             address: address_str,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
     Ok(addresses)
 }
 
+/// Cardano sin compilar (feature "network-cardano" desactivada)
+#[cfg(not(feature = "network-cardano"))]
+fn derive_cardano_addresses_official(_mnemonic_phrase: &str, _passphrase: Option<&str>, _account: u32, _count: u32) -> Result<Vec<Address>> {
+    Err(network_not_compiled(NetworkId::Cardano))
+}
+
 /// Helper para hardened derivation
+#[cfg(feature = "network-cardano")]
 fn harden(index: u32) -> u32 {
     index | 0x80_00_00_00
 }
@@ -267,57 +700,84 @@ fn harden(index: u32) -> u32 {
 // IMPLEMENTACIÓN SOLANA OFICIAL - PHANTOM COMPATIBLE
 // =============================================================================
 
-/// Derivar direcciones Solana compatible con Phantom Wallet
+/// Derivar direcciones Solana con el esquema de la wallet elegida (Phantom por defecto)
 /// NOTA: Phantom no soporta BIP39 passphrase oficialmente
+#[cfg(feature = "network-solana")]
 fn derive_solana_from_mnemonic_direct(
     mnemonic_phrase: &str,
     _passphrase: Option<&str>, // Ignorado intencionalmente
     count: u32,
+    scheme: SolanaScheme,
 ) -> Result<Vec<Address>> {
     use bip39_crate::{Mnemonic, Language};
+    use crate::logging::Redacted;
 
     let mut addresses = Vec::new();
 
-    println!("🚀 SOLANA PHANTOM COMPATIBLE - BIP32-Ed25519 (sin passphrase)");
+    tracing::debug!(count, ?scheme, "Derivando direcciones Solana (sin passphrase)");
 
     // Generar seed BIP39 (exactamente como Phantom, sin passphrase)
     let mnemonic = Mnemonic::parse_in_normalized(Language::English, mnemonic_phrase)
         .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
 
     let seed = mnemonic.to_seed("");
-    println!("🔍 Seed: {} bytes", seed.len());
+    tracing::trace!(seed = %Redacted(&seed[..]), "Seed BIP39 generada");
 
-    for index in 0u32..count {
-        let derivation_path = if index == 0 {
-            "m/44'/501'/0'/0'".to_string()
-        } else {
-            format!("m/44'/501'/{}'/0'", index)
-        };
+    // El esquema sin derivación produce una única clave
+    let count = if scheme == SolanaScheme::RootSeed { count.min(1) } else { count };
 
-        println!("🔍 Derivando path: {}", derivation_path);
+    for index in 0u32..count {
+        let (derivation_path, derived_key) = match scheme.derivation_path(index) {
+            Some(path) => {
+                tracing::trace!(%path, "Derivando path");
 
-        // Implementar derivePath(path, seed) manualmente
-        let derived_key = manual_derive_path(&derivation_path, &seed)?;
+                // Implementar derivePath(path, seed) manualmente
+                let key = manual_derive_path(&path, &seed)?;
+                (path, key)
+            }
+            None => {
+                let mut key = [0u8; 32];
+                key.copy_from_slice(&seed[..32]);
+                ("m".to_string(), key)
+            }
+        };
 
         // Crear keypair Ed25519
         let signing_key = SolanaSigningKey::from_bytes(&derived_key);
         let verifying_key = signing_key.verifying_key();
         let address_str = bs58::encode(verifying_key.as_bytes()).into_string();
 
-        println!("🔍 Index {} address: {}", index, address_str);
+        tracing::debug!(index, address = %address_str, "Dirección Solana derivada");
 
         addresses.push(Address {
             address_type: format!("Solana #{}", index),
             path: derivation_path,
             address: address_str,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
     Ok(addresses)
 }
 
+/// Solana sin compilar (feature "network-solana" desactivada)
+#[cfg(not(feature = "network-solana"))]
+fn derive_solana_from_mnemonic_direct(
+    _mnemonic_phrase: &str,
+    _passphrase: Option<&str>,
+    _count: u32,
+    _scheme: SolanaScheme,
+) -> Result<Vec<Address>> {
+    Err(network_not_compiled(NetworkId::Solana))
+}
+
 /// Implementación manual de derivePath - Compatible con ed25519-hd-key JavaScript
 fn manual_derive_path(path: &str, seed: &[u8]) -> Result<[u8; 32]> {
+    // SLIP-0010 con Ed25519 solo admite componentes endurecidas
+    let path = crate::derivation::Path::parse(path)?;
+    path.ensure_fully_hardened()?;
+
     // Crear master key usando "ed25519 seed" como en BIP32-Ed25519
     let mut mac = Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
         .map_err(|e| SCypherError::crypto(format!("Master key HMAC failed: {}", e)))?;
@@ -331,14 +791,12 @@ fn manual_derive_path(path: &str, seed: &[u8]) -> Result<[u8; 32]> {
     master_private_key.copy_from_slice(&master_key_data[0..32]);
     master_chain_code.copy_from_slice(&master_key_data[32..64]);
 
-    // Parsear path y derivar jerárquicamente
-    let path_components = parse_derivation_path_simple(path)?;
-
+    // Derivar jerárquicamente
     let mut current_private_key = master_private_key;
     let mut current_chain_code = master_chain_code;
 
-    for (i, &component) in path_components.iter().enumerate() {
-        println!("🔍 Derivando componente {}: 0x{:08x}", i, component);
+    for (i, component) in path.raw_components().into_iter().enumerate() {
+        tracing::trace!(position = i, component = format_args!("0x{:08x}", component), "Derivando componente");
 
         // Crear HMAC para derivación del componente
         let mut child_mac = Hmac::<Sha512>::new_from_slice(&current_chain_code)
@@ -359,37 +817,6 @@ fn manual_derive_path(path: &str, seed: &[u8]) -> Result<[u8; 32]> {
     Ok(current_private_key)
 }
 
-/// Parsear derivation path: "m/44'/501'/0'/0'" -> [0x8000002C, 0x800001F5, 0x80000000, 0x80000000]
-fn parse_derivation_path_simple(path: &str) -> Result<Vec<u32>> {
-    let mut components = Vec::new();
-
-    let path_clean = path.strip_prefix("m/")
-        .ok_or_else(|| SCypherError::crypto("Invalid path format".to_string()))?;
-
-    for component in path_clean.split('/') {
-        if component.is_empty() {
-            continue;
-        }
-
-        let (num_str, is_hardened) = if component.ends_with('\'') {
-            (component.trim_end_matches('\''), true)
-        } else {
-            (component, false)
-        };
-
-        let mut num: u32 = num_str.parse()
-            .map_err(|e| SCypherError::crypto(format!("Invalid path component: {}", e)))?;
-
-        if is_hardened {
-            num |= 0x80000000;
-        }
-
-        components.push(num);
-    }
-
-    Ok(components)
-}
-
 // =============================================================================
 // IMPLEMENTACIONES BITCOIN (SOPORTA PASSPHRASE OFICIALMENTE)
 // =============================================================================
@@ -405,14 +832,7 @@ fn derive_bitcoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
     for index in 0u32..count {
         // 1. LEGACY P2PKH - BIP44
         let legacy_path = format!("m/44'/0'/0'/0/{}", index);
-        let legacy_derivation_path = DerivationPath::from_str(&legacy_path)
-            .map_err(|e| SCypherError::crypto(format!("Invalid Bitcoin Legacy path: {}", e)))?;
-
-        let mut legacy_key = master_key.clone();
-        for child_number in legacy_derivation_path.as_ref() {
-            legacy_key = legacy_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Bitcoin Legacy derivation failed: {}", e)))?;
-        }
+        let legacy_key = derive_key_at_path(master_key, &legacy_path)?;
 
         let legacy_private_key = bitcoin::PrivateKey::new(
             bitcoin::secp256k1::SecretKey::from_slice(legacy_key.private_key().to_bytes().as_slice())
@@ -427,18 +847,13 @@ fn derive_bitcoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
             address_type: format!("Legacy P2PKH #{}", index),
             path: legacy_path,
             address: legacy_address.to_string(),
+            chain_id: None,
+            explorer_url: None,
         });
 
         // 2. NESTED SEGWIT P2SH-P2WPKH - BIP49
         let nested_path = format!("m/49'/0'/0'/0/{}", index);
-        let nested_derivation_path = DerivationPath::from_str(&nested_path)
-            .map_err(|e| SCypherError::crypto(format!("Invalid Bitcoin Nested SegWit path: {}", e)))?;
-
-        let mut nested_key = master_key.clone();
-        for child_number in nested_derivation_path.as_ref() {
-            nested_key = nested_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Bitcoin Nested SegWit derivation failed: {}", e)))?;
-        }
+        let nested_key = derive_key_at_path(master_key, &nested_path)?;
 
         let nested_private_key = bitcoin::PrivateKey::new(
             bitcoin::secp256k1::SecretKey::from_slice(nested_key.private_key().to_bytes().as_slice())
@@ -454,18 +869,13 @@ fn derive_bitcoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
             address_type: format!("Nested SegWit #{}", index),
             path: nested_path,
             address: nested_address.to_string(),
+            chain_id: None,
+            explorer_url: None,
         });
 
         // 3. NATIVE SEGWIT P2WPKH - BIP84 (el código original)
         let native_path = format!("m/84'/0'/0'/0/{}", index);
-        let native_derivation_path = DerivationPath::from_str(&native_path)
-            .map_err(|e| SCypherError::crypto(format!("Invalid Bitcoin Native SegWit path: {}", e)))?;
-
-        let mut native_key = master_key.clone();
-        for child_number in native_derivation_path.as_ref() {
-            native_key = native_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Bitcoin Native SegWit derivation failed: {}", e)))?;
-        }
+        let native_key = derive_key_at_path(master_key, &native_path)?;
 
         let native_private_key = bitcoin::PrivateKey::new(
             bitcoin::secp256k1::SecretKey::from_slice(native_key.private_key().to_bytes().as_slice())
@@ -474,13 +884,14 @@ fn derive_bitcoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
         );
 
         let native_public_key = native_private_key.public_key(&secp);
-        let native_address = bitcoin::Address::p2wpkh(&native_public_key, Network::Bitcoin)
-            .map_err(|e| SCypherError::crypto(format!("P2WPKH address creation failed: {}", e)))?;
+        let native_address = p2wpkh_address(&native_public_key.to_bytes(), "bc")?;
 
         addresses.push(Address {
             address_type: format!("Native SegWit #{}", index),
             path: native_path,
-            address: native_address.to_string(),
+            address: native_address,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -493,19 +904,13 @@ fn derive_bitcoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
 
 /// Derivar direcciones Ethereum
 /// Ethereum soporta BIP39 passphrase oficialmente en hardware wallets
-fn derive_ethereum_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
+fn derive_ethereum_addresses(master_key: &XPrv, count: u32, scheme: EvmPathScheme) -> Result<Vec<Address>> {
     let mut addresses = Vec::new();
 
     for index in 0u32..count {
-        // Ethereum standard - m/44'/60'/0'/0/index
-        let path = DerivationPath::from_str(&format!("m/44'/60'/0'/0/{}", index))
-            .map_err(|e| SCypherError::crypto(format!("Invalid Ethereum path: {}", e)))?;
-
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Ethereum derivation failed: {}", e)))?;
-        }
+        // Ethereum standard - m/44'/60'/0'/0/index (o el esquema elegido)
+        let path_str = scheme.derivation_path(index);
+        let current_key = derive_key_at_path(master_key, &path_str)?;
 
         let public_key_point = current_key.public_key();
         let public_key_compressed = public_key_point.to_bytes();
@@ -533,8 +938,10 @@ fn derive_ethereum_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Addres
 
         addresses.push(Address {
             address_type: format!("Ethereum #{}", index),
-            path: format!("m/44'/60'/0'/0/{}", index),
+            path: path_str,
             address,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -543,7 +950,7 @@ fn derive_ethereum_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Addres
 
 /// Implementar EIP-55 checksum encoding para direcciones Ethereum
 /// Este es el formato estándar usado por MetaMask, Phantom, Ledger, etc.
-fn to_eip55_checksum_address(address_bytes: &[u8]) -> String {
+pub(crate) fn to_eip55_checksum_address(address_bytes: &[u8]) -> String {
     let address_hex = hex::encode(address_bytes);
 
     // Hash de la dirección en minúsculas (sin 0x) usando Keccak256
@@ -581,16 +988,9 @@ fn to_eip55_checksum_address(address_bytes: &[u8]) -> String {
 fn derive_bsc_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
     let mut addresses = Vec::new();
 
-    for index in 0u32..count {
+    for (index, path) in address_paths("m/44'/60'/0'/0", count)?.iter().enumerate() {
         // Usar mismo path que Ethereum para BSC
-        let path = DerivationPath::from_str(&format!("m/44'/60'/0'/0/{}", index))
-            .map_err(|e| SCypherError::crypto(format!("Invalid BSC path: {}", e)))?;
-
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("BSC derivation failed: {}", e)))?;
-        }
+        let current_key = derive_key_at(master_key, path)?;
 
         let public_key_point = current_key.public_key();
         let public_key_compressed = public_key_point.to_bytes();
@@ -612,8 +1012,10 @@ fn derive_bsc_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
 
         addresses.push(Address {
             address_type: format!("BSC #{}", index),
-            path: format!("m/44'/60'/0'/0/{}", index),
+            path: path.to_string(),
             address,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -625,16 +1027,9 @@ fn derive_bsc_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
 fn derive_polygon_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
     let mut addresses = Vec::new();
 
-    for index in 0u32..count {
+    for (index, path) in address_paths("m/44'/60'/0'/0", count)?.iter().enumerate() {
         // Usar mismo path que Ethereum para Polygon
-        let path = DerivationPath::from_str(&format!("m/44'/60'/0'/0/{}", index))
-            .map_err(|e| SCypherError::crypto(format!("Invalid Polygon path: {}", e)))?;
-
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Polygon derivation failed: {}", e)))?;
-        }
+        let current_key = derive_key_at(master_key, path)?;
 
         let public_key_point = current_key.public_key();
         let public_key_compressed = public_key_point.to_bytes();
@@ -656,8 +1051,10 @@ fn derive_polygon_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
 
         addresses.push(Address {
             address_type: format!("Polygon #{}", index),
-            path: format!("m/44'/60'/0'/0/{}", index),
+            path: path.to_string(),
             address,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -670,29 +1067,36 @@ fn derive_polygon_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address
 
 /// Derivar direcciones Ergo usando ergo-lib
 /// NOTA: Ergo soporta passphrase (verificado con wallet SATERGO)
+#[cfg(feature = "network-ergo")]
 fn derive_ergo_addresses(
     seed_phrase: &str,
     passphrase: Option<&str>, // Ahora SÍ usamos passphrase
+    account: u32,
     count: u32,
 ) -> Result<Vec<Address>> {
     let mut addresses = Vec::new();
+    check_account_index(account)?;
+
+    // ergo-lib no valida la frase: se parsea antes y se usa la forma canónica,
+    // porque cualquier diferencia de espacios cambiaría el seed PBKDF2
+    let mnemonic = parse_mnemonic(seed_phrase)?;
 
     // Crear seed usando ergo-lib (con passphrase para compatibilidad SATERGO)
-    let seed = ErgoMnemonic::to_seed(seed_phrase, passphrase.unwrap_or(""));
+    let seed = ErgoMnemonic::to_seed(&mnemonic.to_string(), passphrase.unwrap_or(""));
 
     // Derivar master key usando ergo-lib
     let master_key = ExtSecretKey::derive_master(seed)
         .map_err(|e| SCypherError::crypto(format!("Ergo master key derivation failed: {}", e)))?;
 
-    // Account index 0 (hardened) - m/44'/429'/0'
-    let account = ChildIndexHardened::from_31_bit(0)
+    // Cuenta (hardened) - m/44'/429'/account'
+    let account_index = ChildIndexHardened::from_31_bit(account)
         .map_err(|e| SCypherError::crypto(format!("Invalid Ergo account index: {}", e)))?;
 
     // Derivar direcciones para el número solicitado
     for index in 0u32..count {
-        // Construir path de derivación: m/44'/429'/0'/0/index
+        // Construir path de derivación: m/44'/429'/account'/0/index
         let path = ErgoDerivationPath::new(
-            account,
+            account_index,
             vec![ChildIndexNormal::normal(index)
                 .map_err(|e| SCypherError::crypto(format!("Invalid Ergo address index {}: {}", index, e)))?],
         );
@@ -714,15 +1118,23 @@ fn derive_ergo_addresses(
         );
 
         addresses.push(Address {
-            address_type: format!("Ergo #{}", index),
-            path: format!("m/44'/429'/0'/0/{}", index),
+            address_type: account_label("Ergo", account, index),
+            path: format!("m/44'/429'/{}'/0/{}", account, index),
             address: encoded_address, // CAMBIO: usar encoded_address en lugar de address_string
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
     Ok(addresses)
 }
 
+/// Ergo sin compilar (feature "network-ergo" desactivada)
+#[cfg(not(feature = "network-ergo"))]
+fn derive_ergo_addresses(_seed_phrase: &str, _passphrase: Option<&str>, _account: u32, _count: u32) -> Result<Vec<Address>> {
+    Err(network_not_compiled(NetworkId::Ergo))
+}
+
 // =============================================================================
 // IMPLEMENTACIÓN TRON (SOPORTA PASSPHRASE OFICIALMENTE)
 // =============================================================================
@@ -733,21 +1145,12 @@ fn derive_ergo_addresses(
 fn derive_tron_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
     let mut addresses = Vec::new();
 
-    println!("🔶 TRON Address Derivation - BIP44 m/44'/195'/0'/0/index");
+    tracing::debug!(count, "Derivando direcciones TRON (m/44'/195'/0'/0/index)");
 
     // Generar direcciones para el número solicitado
-    for index in 0u32..count {
-        // TRON BIP44 derivation path oficial
-        let path_str = format!("m/44'/195'/0'/0/{}", index);
-        let path = DerivationPath::from_str(&path_str)
-            .map_err(|e| SCypherError::crypto(format!("Invalid TRON path {}: {}", path_str, e)))?;
-
-        // Derivar la clave privada siguiendo el path BIP44
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("TRON derivation failed at {}: {}", path_str, e)))?;
-        }
+    for (index, path) in address_paths("m/44'/195'/0'/0", count)?.iter().enumerate() {
+        // TRON BIP44 derivation path oficial; derivar la clave privada siguiendo el path
+        let current_key = derive_key_at(master_key, path)?;
 
         // Extraer public key en formato secp256k1
         let public_key_point = current_key.public_key();
@@ -764,7 +1167,7 @@ fn derive_tron_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>>
         // TRON usa solo las coordenadas X,Y (64 bytes), sin el prefijo 0x04
         let xy_coords = &uncompressed[1..]; // 64 bytes
 
-        println!("🔍 Index {} - Public key coords: {} bytes", index, xy_coords.len());
+        tracing::trace!(index, bytes = xy_coords.len(), "Coordenadas de la clave pública");
 
         // Aplicar Keccak256 hash (SHA3) a las coordenadas públicas
         let mut hasher = Keccak::v256();
@@ -779,12 +1182,10 @@ fn derive_tron_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>>
         let mut tron_address = vec![0x41];
         tron_address.extend_from_slice(address_bytes);
 
-        println!("🔍 Index {} - Address with prefix: {}", index, hex::encode(&tron_address));
-
         // Aplicar TRON Base58Check encoding
         let tron_address_base58 = tron_base58_encode(&tron_address)?;
 
-        println!("🔍 Index {} - Final TRON address: {}", index, tron_address_base58);
+        tracing::debug!(index, address = %tron_address_base58, "Dirección TRON derivada");
 
         // Verificar que la dirección comience con 'T'
         if !tron_address_base58.starts_with('T') {
@@ -793,8 +1194,10 @@ fn derive_tron_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>>
 
         addresses.push(Address {
             address_type: format!("TRON #{}", index),
-            path: path_str,
+            path: path.to_string(),
             address: tron_address_base58,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -827,99 +1230,247 @@ fn tron_base58_encode(input: &[u8]) -> Result<String> {
 // IMPLEMENTACIONES OTRAS REDES (SOPORTAN PASSPHRASE OFICIALMENTE)
 // =============================================================================
 
-/// Derivar direcciones Dogecoin
-/// Dogecoin soporta BIP39 passphrase por herencia de Bitcoin
-fn derive_dogecoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
-    use bitcoin::Network;
+/// Bytes de versión de las redes tipo Bitcoin (Base58Check, WIF y bech32)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtxoParams {
+    pub coin_type: u32,
+    pub p2pkh: u8,
+    pub p2sh: u8,
+    pub wif: u8,
+    pub bech32_hrp: Option<&'static str>, // None si la red no tiene SegWit
+}
 
-    let mut addresses = Vec::new();
+/// Parámetros de codificación de una red tipo Bitcoin
+pub fn utxo_params(network: NetworkId) -> Option<UtxoParams> {
+    match network {
+        NetworkId::Bitcoin => Some(UtxoParams { coin_type: 0, p2pkh: 0x00, p2sh: 0x05, wif: 0x80, bech32_hrp: Some("bc") }),
+        NetworkId::Litecoin => Some(UtxoParams { coin_type: 2, p2pkh: 0x30, p2sh: 0x32, wif: 0xB0, bech32_hrp: Some("ltc") }),
+        NetworkId::Dogecoin => Some(UtxoParams { coin_type: 3, p2pkh: 0x1e, p2sh: 0x16, wif: 0x9e, bech32_hrp: None }),
+        NetworkId::BitcoinCash => Some(UtxoParams { coin_type: 145, p2pkh: 0x00, p2sh: 0x05, wif: 0x80, bech32_hrp: None }),
+        _ => None,
+    }
+}
 
-    for index in 0u32..count {
-        // Dogecoin coin type: 3' - m/44'/3'/0'/0/index
-        let path = DerivationPath::from_str(&format!("m/44'/3'/0'/0/{}", index))
-            .map_err(|e| SCypherError::crypto(format!("Invalid Dogecoin path: {}", e)))?;
-
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Dogecoin derivation failed: {}", e)))?;
+/// Clave privada WIF decodificada
+pub struct WifKey {
+    pub network: NetworkId,
+    pub secret: [u8; 32],
+    pub compressed: bool,
+}
+
+impl Drop for WifKey {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.secret.zeroize();
+    }
+}
+
+/// Codificar una clave secp256k1 como WIF comprimido con el byte de versión de la red
+pub fn encode_wif(secret: &[u8; 32], network: NetworkId) -> Result<String> {
+    use zeroize::Zeroize;
+
+    let params = utxo_params(network)
+        .ok_or_else(|| SCypherError::crypto(format!("WIF is not defined for network: {}", network.as_str())))?;
+
+    let mut payload = Vec::with_capacity(34);
+    payload.push(params.wif);
+    payload.extend_from_slice(secret);
+    payload.push(0x01); // clave pública comprimida
+
+    let wif = bitcoin::base58::encode_check(&payload);
+    payload.zeroize();
+    Ok(wif)
+}
+
+/// Decodificar una clave WIF y reconocer la red por su byte de versión
+pub fn decode_wif(wif: &str) -> Result<WifKey> {
+    use zeroize::Zeroize;
+
+    let mut payload = bitcoin::base58::decode_check(wif.trim())
+        .map_err(|_| SCypherError::crypto("Invalid WIF: Base58Check decoding failed".to_string()))?;
+
+    let compressed = match payload.len() {
+        33 => false,
+        34 if payload[33] == 0x01 => true,
+        _ => {
+            payload.zeroize();
+            return Err(SCypherError::crypto("Invalid WIF: unexpected payload length".to_string()));
         }
+    };
 
-        let secp = bitcoin::secp256k1::Secp256k1::new();
-        let private_key = bitcoin::PrivateKey::new(
-            bitcoin::secp256k1::SecretKey::from_slice(current_key.private_key().to_bytes().as_slice())
-                .map_err(|e| SCypherError::crypto(format!("Invalid Dogecoin private key: {}", e)))?,
-            Network::Bitcoin
-        );
+    let version = payload[0];
+    let network = [NetworkId::Bitcoin, NetworkId::Litecoin, NetworkId::Dogecoin]
+        .into_iter()
+        .find(|id| utxo_params(*id).map(|p| p.wif) == Some(version));
+
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&payload[1..33]);
+    payload.zeroize();
+
+    match network {
+        Some(network) => Ok(WifKey { network, secret, compressed }),
+        None => {
+            secret.zeroize();
+            Err(SCypherError::crypto(format!("Unknown WIF version byte 0x{:02x}", version)))
+        }
+    }
+}
+
+/// Datos públicos de una clave WIF importada (la clave no sale de aquí)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WifKeyInfo {
+    pub network: String,
+    pub compressed: bool,
+    pub public_key: String,  // hex
+    pub address: String,     // P2PKH de la red del byte de versión
+}
+
+/// Importar una clave WIF: red según su byte de versión y dirección P2PKH
+pub fn inspect_wif(wif: &str) -> Result<WifKeyInfo> {
+    let key = decode_wif(wif)?;
+    let params = utxo_params(key.network)
+        .ok_or_else(|| SCypherError::crypto(format!("WIF is not defined for network: {}", key.network.as_str())))?;
+
+    let secret_key = bitcoin::secp256k1::SecretKey::from_slice(&key.secret)
+        .map_err(|e| SCypherError::crypto(format!("Invalid WIF private key: {}", e)))?;
+    let public_key = bitcoin::secp256k1::PublicKey::from_secret_key(&bitcoin::secp256k1::Secp256k1::new(), &secret_key);
+    let public_key = if key.compressed {
+        public_key.serialize().to_vec()
+    } else {
+        public_key.serialize_uncompressed().to_vec()
+    };
+
+    Ok(WifKeyInfo {
+        network: key.network.as_str().to_string(),
+        compressed: key.compressed,
+        public_key: hex::encode(&public_key),
+        address: p2pkh_address(&public_key, params.p2pkh),
+    })
+}
+
+/// Variante de checksum de un programa de testigo: bech32 para la versión 0 y
+/// bech32m para las versiones 1 a 16 (BIP173/BIP350)
+pub fn segwit_variant(witness_version: u8) -> Variant {
+    if witness_version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    }
+}
+
+/// Codificar un programa de testigo con la variante que corresponde a su versión
+pub fn segwit_address(hrp: &str, witness_version: u8, program: &[u8]) -> Result<String> {
+    let valid_length = match witness_version {
+        0 => matches!(program.len(), 20 | 32),
+        1..=16 => (2..=40).contains(&program.len()),
+        _ => false,
+    };
+    if !valid_length {
+        return Err(SCypherError::crypto(format!(
+            "Invalid witness program: version {}, {} bytes",
+            witness_version,
+            program.len()
+        )));
+    }
+
+    let mut data = vec![bech32::u5::try_from_u8(witness_version)
+        .map_err(|e| SCypherError::crypto(format!("Invalid witness version: {}", e)))?];
+    data.extend(program.to_base32());
+    bech32::encode(hrp, data, segwit_variant(witness_version))
+        .map_err(|e| SCypherError::crypto(format!("Bech32 encoding failed: {}", e)))
+}
+
+/// Codificar una dirección P2WPKH (SegWit v0, bech32) con el HRP de la red
+pub fn p2wpkh_address(compressed_pubkey: &[u8], hrp: &str) -> Result<String> {
+    let program = Ripemd160::digest(Sha256::digest(compressed_pubkey));
+    segwit_address(hrp, 0, &program)
+}
+
+/// Codificar una dirección P2TR (Taproot, bech32m) de solo ruta de clave (BIP86):
+/// la clave de salida es la interna ajustada sin árbol de scripts
+pub fn p2tr_address(compressed_pubkey: &[u8], hrp: &str) -> Result<String> {
+    use bitcoin::key::TapTweak;
+
+    let public_key = bitcoin::secp256k1::PublicKey::from_slice(compressed_pubkey)
+        .map_err(|e| SCypherError::crypto(format!("Invalid public key: {}", e)))?;
+    let (internal_key, _) = public_key.x_only_public_key();
+    let secp = bitcoin::secp256k1::Secp256k1::verification_only();
+    let (output_key, _) = internal_key.tap_tweak(&secp, None);
+    segwit_address(hrp, 1, &output_key.to_inner().serialize())
+}
 
-        let public_key = private_key.public_key(&secp);
-        let compressed_pubkey = public_key.to_bytes();
-        let sha256_hash = Sha256::digest(&compressed_pubkey);
-        let ripemd_hash = Ripemd160::digest(&sha256_hash);
+/// Codificar una dirección P2SH-P2WPKH (SegWit anidado) con el byte P2SH de la red
+pub fn p2sh_p2wpkh_address(compressed_pubkey: &[u8], p2sh_version: u8) -> String {
+    // redeemScript = OP_0 PUSH20 <hash160(pubkey)>
+    let mut redeem_script = vec![0x00, 0x14];
+    redeem_script.extend_from_slice(&Ripemd160::digest(Sha256::digest(compressed_pubkey)));
 
-        // Dogecoin version byte is 0x1e (30)
-        let mut address_bytes = vec![0x1e];
-        address_bytes.extend_from_slice(&ripemd_hash);
+    let mut address_bytes = vec![p2sh_version];
+    address_bytes.extend_from_slice(&Ripemd160::digest(Sha256::digest(&redeem_script)));
+    bitcoin::base58::encode_check(&address_bytes)
+}
 
-        // Checksum
-        let checksum_hash = Sha256::digest(&Sha256::digest(&address_bytes));
-        address_bytes.extend_from_slice(&checksum_hash[0..4]);
+/// Derivar direcciones Dogecoin
+/// Dogecoin soporta BIP39 passphrase por herencia de Bitcoin (sin SegWit)
+fn derive_dogecoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
+    let params = utxo_params(NetworkId::Dogecoin).expect("Dogecoin has UTXO params");
+    let mut addresses = Vec::new();
 
-        let dogecoin_address = bs58::encode(address_bytes).into_string();
+    // Dogecoin coin type: 3' - m/44'/3'/0'/0/index
+    for (index, path) in address_paths(&format!("m/44'/{}'/0'/0", params.coin_type), count)?.iter().enumerate() {
+        let key = derive_key_at(master_key, path)?;
 
         addresses.push(Address {
             address_type: format!("Dogecoin #{}", index),
-            path: format!("m/44'/3'/0'/0/{}", index),
-            address: dogecoin_address, // CAMBIO: usar dogecoin_address en lugar de address
+            address: p2pkh_address(&key.public_key().to_bytes(), params.p2pkh),
+            path: path.to_string(),
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
     Ok(addresses)
 }
 
-/// Derivar direcciones Litecoin
+/// Derivar direcciones Litecoin: Legacy (BIP44), Nested SegWit (BIP49) y Native SegWit (BIP84)
 /// Litecoin soporta BIP39 passphrase por herencia de Bitcoin
 fn derive_litecoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
-    use bitcoin::Network;
-
+    let params = utxo_params(NetworkId::Litecoin).expect("Litecoin has UTXO params");
+    let hrp = params.bech32_hrp.expect("Litecoin has a bech32 HRP");
     let mut addresses = Vec::new();
 
     for index in 0u32..count {
-        // Litecoin coin type: 2' - m/44'/2'/0'/0/index
-        let path = DerivationPath::from_str(&format!("m/44'/2'/0'/0/{}", index))
-            .map_err(|e| SCypherError::crypto(format!("Invalid Litecoin path: {}", e)))?;
-
-        let mut current_key = master_key.clone();
-        for child_number in path.as_ref() {
-            current_key = current_key.derive_child(*child_number)
-                .map_err(|e| SCypherError::crypto(format!("Litecoin derivation failed: {}", e)))?;
-        }
-
-        let secp = bitcoin::secp256k1::Secp256k1::new();
-        let private_key = bitcoin::PrivateKey::new(
-            bitcoin::secp256k1::SecretKey::from_slice(current_key.private_key().to_bytes().as_slice())
-                .map_err(|e| SCypherError::crypto(format!("Invalid Litecoin private key: {}", e)))?,
-            Network::Bitcoin
-        );
-
-        let public_key = private_key.public_key(&secp);
-        let compressed_pubkey = public_key.to_bytes();
-        let sha256_hash = Sha256::digest(&compressed_pubkey);
-        let ripemd_hash = Ripemd160::digest(&sha256_hash);
-
-        // Litecoin P2PKH version byte is 0x30 (48)
-        let mut address_bytes = vec![0x30];
-        address_bytes.extend_from_slice(&ripemd_hash);
-
-        let checksum_hash = Sha256::digest(&Sha256::digest(&address_bytes));
-        address_bytes.extend_from_slice(&checksum_hash[0..4]);
+        // 1. LEGACY P2PKH (L...) - m/44'/2'/0'/0/index
+        let legacy_path = format!("m/44'/{}'/0'/0/{}", params.coin_type, index);
+        let legacy_key = derive_key_at_path(master_key, &legacy_path)?;
+        addresses.push(Address {
+            address_type: format!("Legacy P2PKH #{}", index),
+            address: p2pkh_address(&legacy_key.public_key().to_bytes(), params.p2pkh),
+            path: legacy_path,
+            chain_id: None,
+            explorer_url: None,
+        });
 
-        let litecoin_address = bs58::encode(address_bytes).into_string();
+        // 2. NESTED SEGWIT P2SH-P2WPKH (M...) - m/49'/2'/0'/0/index
+        let nested_path = format!("m/49'/{}'/0'/0/{}", params.coin_type, index);
+        let nested_key = derive_key_at_path(master_key, &nested_path)?;
+        addresses.push(Address {
+            address_type: format!("Nested SegWit #{}", index),
+            address: p2sh_p2wpkh_address(&nested_key.public_key().to_bytes(), params.p2sh),
+            path: nested_path,
+            chain_id: None,
+            explorer_url: None,
+        });
 
+        // 3. NATIVE SEGWIT P2WPKH (ltc1...) - m/84'/2'/0'/0/index
+        let native_path = format!("m/84'/{}'/0'/0/{}", params.coin_type, index);
+        let native_key = derive_key_at_path(master_key, &native_path)?;
         addresses.push(Address {
-            address_type: format!("Litecoin #{}", index),
-            path: format!("m/44'/2'/0'/0/{}", index),
-            address: litecoin_address,
+            address_type: format!("Native SegWit #{}", index),
+            address: p2wpkh_address(&native_key.public_key().to_bytes(), hrp)?,
+            path: native_path,
+            chain_id: None,
+            explorer_url: None,
         });
     }
 
@@ -927,25 +1478,1478 @@ fn derive_litecoin_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Addres
 }
 
 // =============================================================================
-// TESTING Y VALIDACIÓN CON TEST VECTORS OFICIALES
+// IMPLEMENTACIÓN BITCOIN CASH (CASHADDR)
 // =============================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use bip39_crate::{Mnemonic, Language};
+/// Prefijo CashAddr de mainnet
+const CASHADDR_PREFIX: &str = "bitcoincash";
 
-    // =============================================================================
-    // TEST VECTORS OFICIALES BIP39 - Mnemonic estándar de prueba
-    // =============================================================================
+/// Alfabeto base32 de CashAddr (el mismo que bech32)
+const CASHADDR_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
 
-    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// Checksum BCH de 40 bits de la especificación CashAddr
+fn cashaddr_polymod(values: &[u8]) -> u64 {
+    const GENERATORS: [u64; 5] = [0x98f2bc8e61, 0x79b76d99e2, 0xf33e5fb3c4, 0xae2eabe2a8, 0x1e4f43e470];
 
-    // =============================================================================
-    // TEST VECTORS BITCOIN - Ian Coleman BIP39 Tool
-    // =============================================================================
+    let mut c: u64 = 1;
+    for &value in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ value as u64;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        }
+    }
+    c ^ 1
+}
 
-    #[test]
+/// Reagrupar bits (8 -> 5 con relleno, 5 -> 8 sin relleno)
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut out = Vec::new();
+    let max = (1u32 << to) - 1;
+
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+fn cashaddr_checksum_input(prefix: &str, payload: &[u8]) -> Vec<u8> {
+    let mut input: Vec<u8> = prefix.bytes().map(|b| b & 0x1f).collect();
+    input.push(0);
+    input.extend_from_slice(payload);
+    input
+}
+
+/// Codificar un hash160 en CashAddr (tipo 0 = P2PKH, 1 = P2SH)
+pub fn cashaddr_encode(hash160: &[u8; 20], script_type: u8) -> String {
+    // Byte de versión: tipo en los bits 3-6, tamaño 0 = 160 bits
+    let mut versioned = vec![script_type << 3];
+    versioned.extend_from_slice(hash160);
+    let payload = convert_bits(&versioned, 8, 5, true).expect("padding never fails");
+
+    let mut checksum_input = cashaddr_checksum_input(CASHADDR_PREFIX, &payload);
+    checksum_input.extend_from_slice(&[0u8; 8]);
+    let checksum = cashaddr_polymod(&checksum_input);
+
+    let mut address = format!("{}:", CASHADDR_PREFIX);
+    for &value in &payload {
+        address.push(CASHADDR_CHARSET[value as usize] as char);
+    }
+    for i in 0..8 {
+        address.push(CASHADDR_CHARSET[((checksum >> (5 * (7 - i))) & 0x1f) as usize] as char);
+    }
+    address
+}
+
+/// Decodificar una dirección CashAddr de mainnet (con o sin prefijo): (tipo, hash160)
+pub fn cashaddr_decode(address: &str) -> std::result::Result<(u8, [u8; 20]), String> {
+    let has_upper = address.chars().any(|c| c.is_ascii_uppercase());
+    let has_lower = address.chars().any(|c| c.is_ascii_lowercase());
+    if has_upper && has_lower {
+        return Err("Mixed-case CashAddr".to_string());
+    }
+
+    let address = address.to_lowercase();
+    let (prefix, body) = match address.split_once(':') {
+        Some((prefix, body)) => (prefix, body),
+        None => (CASHADDR_PREFIX, address.as_str()),
+    };
+    if prefix != CASHADDR_PREFIX {
+        return Err(format!("Expected prefix '{}:', found '{}:'", CASHADDR_PREFIX, prefix));
+    }
+
+    let values = body
+        .bytes()
+        .map(|b| CASHADDR_CHARSET.iter().position(|&c| c == b).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| "Invalid CashAddr character".to_string())?;
+    if values.len() != 42 {
+        return Err(format!("Unexpected CashAddr length {} (expected 42)", values.len()));
+    }
+    if cashaddr_polymod(&cashaddr_checksum_input(prefix, &values)) != 0 {
+        return Err("CashAddr checksum mismatch".to_string());
+    }
+
+    let bytes = convert_bits(&values[..values.len() - 8], 5, 8, false)
+        .ok_or_else(|| "Invalid CashAddr padding".to_string())?;
+    let (version, hash) = bytes.split_first().ok_or_else(|| "Empty CashAddr payload".to_string())?;
+    if version & 0x07 != 0 || hash.len() != 20 {
+        return Err("Only 160-bit CashAddr hashes are supported".to_string());
+    }
+
+    let mut hash160 = [0u8; 20];
+    hash160.copy_from_slice(hash);
+    Ok((version >> 3, hash160))
+}
+
+/// Derivar direcciones Bitcoin Cash: CashAddr y su equivalente legacy
+/// Path: m/44'/145'/0'/0/index (145 = coin type BCH, Electron Cash / Ian Coleman)
+fn derive_bitcoin_cash_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
+    let params = utxo_params(NetworkId::BitcoinCash).expect("Bitcoin Cash has UTXO params");
+    let mut addresses = Vec::new();
+
+    for (index, path) in address_paths(&format!("m/44'/{}'/0'/0", params.coin_type), count)?.iter().enumerate() {
+        let key = derive_key_at(master_key, path)?;
+        let public_key = key.public_key().to_bytes();
+
+        let hash160: [u8; 20] = Ripemd160::digest(Sha256::digest(public_key)).into();
+        addresses.push(Address {
+            address_type: format!("CashAddr #{}", index),
+            path: path.to_string(),
+            address: cashaddr_encode(&hash160, 0),
+            chain_id: None,
+            explorer_url: None,
+        });
+
+        // Formato legacy (1...) para wallets y exchanges que no aceptan CashAddr
+        addresses.push(Address {
+            address_type: format!("Legacy P2PKH #{}", index),
+            path: path.to_string(),
+            address: p2pkh_address(&public_key, params.p2pkh),
+            chain_id: None,
+            explorer_url: None,
+        });
+    }
+
+    Ok(addresses)
+}
+
+/// Prefijos Base58 de dos bytes de las direcciones transparentes de Zcash (mainnet)
+const ZCASH_P2PKH_PREFIX: [u8; 2] = [0x1c, 0xb8];
+const ZCASH_P2SH_PREFIX: [u8; 2] = [0x1c, 0xbd];
+
+/// Derivar direcciones transparentes de Zcash (t1...)
+/// Path: m/44'/133'/0'/0/index; las direcciones blindadas (Sapling/Orchard) no se derivan
+fn derive_zcash_addresses(master_key: &XPrv, count: u32) -> Result<Vec<Address>> {
+    let mut addresses = Vec::new();
+
+    for (index, path) in address_paths("m/44'/133'/0'/0", count)?.iter().enumerate() {
+        let public_key = derive_key_at(master_key, path)?.public_key().to_bytes();
+
+        let mut payload = ZCASH_P2PKH_PREFIX.to_vec();
+        payload.extend_from_slice(&Ripemd160::digest(Sha256::digest(public_key)));
+        addresses.push(Address {
+            address_type: format!("Transparent P2PKH #{}", index),
+            path: path.to_string(),
+            address: bitcoin::base58::encode_check(&payload),
+            chain_id: None,
+            explorer_url: None,
+        });
+    }
+
+    Ok(addresses)
+}
+
+// =============================================================================
+// IMPLEMENTACIÓN LIQUID (L-BTC) CON DIRECCIONES CONFIDENCIALES (SLIP-0077)
+// La dirección confidencial es la normal más la clave pública de cegado:
+// Base58Check con prefijo 0x0c para P2SH y blech32 ("lq1") para SegWit nativo.
+// La clave de cegado sale del seed BIP39 (SLIP-77) y del scriptPubKey, no de
+// la ruta BIP32, igual que en Blockstream Green y Jade.
+// =============================================================================
+
+/// Bytes de versión Base58 de Liquid (mainnet)
+const LIQUID_P2PKH_VERSION: u8 = 0x39;
+const LIQUID_P2SH_VERSION: u8 = 0x27;
+const LIQUID_CONFIDENTIAL_PREFIX: u8 = 0x0c;
+
+/// HRP de las direcciones SegWit sin cegar y confidenciales
+const LIQUID_SEGWIT_HRP: &str = "ex";
+const LIQUID_BLECH32_HRP: &str = "lq";
+
+/// Longitud del checksum blech32 (caracteres)
+const BLECH32_CHECKSUM_LEN: usize = 12;
+
+/// Checksum BCH de 60 bits de blech32 (Elements)
+fn blech32_polymod(values: &[u8]) -> u64 {
+    const GENERATORS: [u64; 5] = [0x7d52fba40bd886, 0x5e8dbf1a03950c, 0x1c3a3c74072a18, 0x385d72fa0e5139, 0x7093e5a608865b];
+
+    let mut c: u64 = 1;
+    for &value in values {
+        let c0 = (c >> 55) as u8;
+        c = ((c & 0x7f_ffff_ffff_ffff) << 5) ^ value as u64;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        }
+    }
+    c
+}
+
+fn blech32_hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut values: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|b| b & 31));
+    values
+}
+
+/// Codificar un programa SegWit v0 en blech32
+pub fn blech32_encode(hrp: &str, program: &[u8]) -> Result<String> {
+    let mut data = vec![0u8];
+    data.extend(convert_bits(program, 8, 5, true)
+        .ok_or_else(|| SCypherError::crypto("Blech32 encoding failed".to_string()))?);
+
+    let mut values = blech32_hrp_expand(hrp);
+    values.extend(&data);
+    values.extend([0u8; BLECH32_CHECKSUM_LEN]);
+    let polymod = blech32_polymod(&values) ^ 1;
+
+    let mut address = format!("{}1", hrp);
+    address.extend(data.iter().map(|&d| CASHADDR_CHARSET[d as usize] as char));
+    address.extend((0..BLECH32_CHECKSUM_LEN)
+        .map(|i| CASHADDR_CHARSET[((polymod >> (5 * (BLECH32_CHECKSUM_LEN - 1 - i))) & 31) as usize] as char));
+    Ok(address)
+}
+
+/// Decodificar una dirección blech32 v0: (hrp, programa)
+fn blech32_decode(address: &str) -> std::result::Result<(String, Vec<u8>), String> {
+    let address = address.to_lowercase();
+    let (hrp, data) = address.rsplit_once('1').ok_or_else(|| "Missing separator".to_string())?;
+    if data.len() < BLECH32_CHECKSUM_LEN + 1 {
+        return Err("Address too short".to_string());
+    }
+
+    let values = data
+        .bytes()
+        .map(|c| CASHADDR_CHARSET.iter().position(|&x| x == c).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| "Invalid blech32 character".to_string())?;
+
+    let mut check = blech32_hrp_expand(hrp);
+    check.extend(&values);
+    if blech32_polymod(&check) != 1 {
+        return Err("Blech32 checksum mismatch".to_string());
+    }
+
+    let (version, program) = values[..values.len() - BLECH32_CHECKSUM_LEN]
+        .split_first()
+        .ok_or_else(|| "Missing witness version".to_string())?;
+    if *version != 0 {
+        return Err(format!("Unsupported witness version {}", version));
+    }
+    let program = convert_bits(program, 5, 8, false).ok_or_else(|| "Invalid witness program".to_string())?;
+    Ok((hrp.to_string(), program))
+}
+
+/// Clave maestra de cegado SLIP-77 a partir del seed BIP39
+pub fn slip77_master_blinding_key(seed: &[u8]) -> Result<[u8; 32]> {
+    let mut root = Hmac::<Sha512>::new_from_slice(b"Symmetric key seed")
+        .map_err(|e| SCypherError::crypto(format!("HMAC error: {}", e)))?;
+    root.update(seed);
+    let root = root.finalize().into_bytes();
+
+    let mut node = Hmac::<Sha512>::new_from_slice(&root[..32])
+        .map_err(|e| SCypherError::crypto(format!("HMAC error: {}", e)))?;
+    node.update(b"\x00SLIP-0077");
+    let node = node.finalize().into_bytes();
+
+    let mut master_blinding_key = [0u8; 32];
+    master_blinding_key.copy_from_slice(&node[32..]);
+    Ok(master_blinding_key)
+}
+
+/// Clave pública de cegado de un scriptPubKey (SLIP-77)
+pub fn slip77_blinding_public_key(master_blinding_key: &[u8; 32], script_pubkey: &[u8]) -> Result<[u8; 33]> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(master_blinding_key)
+        .map_err(|e| SCypherError::crypto(format!("HMAC error: {}", e)))?;
+    mac.update(script_pubkey);
+
+    let secret = bitcoin::secp256k1::SecretKey::from_slice(&mac.finalize().into_bytes())
+        .map_err(|e| SCypherError::crypto(format!("Invalid blinding key: {}", e)))?;
+    let secp = bitcoin::secp256k1::Secp256k1::signing_only();
+    Ok(bitcoin::secp256k1::PublicKey::from_secret_key(&secp, &secret).serialize())
+}
+
+/// Derivar direcciones Liquid: SegWit anidado (BIP49) y nativo (BIP84), sin cegar y confidenciales
+/// Paths: m/49'/1776'/0'/0/index y m/84'/1776'/0'/0/index (Blockstream Green singlesig)
+fn derive_liquid_addresses(seed_phrase: &str, passphrase: Option<&str>, count: u32) -> Result<Vec<Address>> {
+    use zeroize::Zeroize;
+
+    // El seed se usa para la master key y para la clave de cegado: ambas con la misma passphrase
+    let mut seed = parse_mnemonic(seed_phrase)?.to_seed(passphrase.unwrap_or(""));
+    let master_key = XPrv::new(seed)
+        .map_err(|e| SCypherError::crypto(format!("Master key derivation failed: {}", e)));
+    let master_blinding_key = slip77_master_blinding_key(&seed);
+    seed.zeroize();
+    let (master_key, mut master_blinding_key) = (master_key?, master_blinding_key?);
+
+    let mut addresses = Vec::new();
+    for index in 0u32..count {
+        // 1. SEGWIT ANIDADO P2SH-P2WPKH
+        let nested_path = format!("m/49'/1776'/0'/0/{}", index);
+        let public_key = derive_key_at_path(&master_key, &nested_path)?.public_key().to_bytes();
+        let mut redeem_script = vec![0x00, 0x14];
+        redeem_script.extend_from_slice(&Ripemd160::digest(Sha256::digest(public_key)));
+        let script_hash = Ripemd160::digest(Sha256::digest(&redeem_script));
+        let mut script_pubkey = vec![0xa9, 0x14];
+        script_pubkey.extend_from_slice(&script_hash);
+        script_pubkey.push(0x87);
+
+        let mut confidential = vec![LIQUID_CONFIDENTIAL_PREFIX, LIQUID_P2SH_VERSION];
+        confidential.extend_from_slice(&slip77_blinding_public_key(&master_blinding_key, &script_pubkey)?);
+        confidential.extend_from_slice(&script_hash);
+
+        addresses.push(Address {
+            address_type: format!("Confidential Nested SegWit #{}", index),
+            path: nested_path.clone(),
+            address: bitcoin::base58::encode_check(&confidential),
+            chain_id: None,
+            explorer_url: None,
+        });
+        addresses.push(Address {
+            address_type: format!("Unconfidential Nested SegWit #{}", index),
+            path: nested_path,
+            address: p2sh_p2wpkh_address(&public_key, LIQUID_P2SH_VERSION),
+            chain_id: None,
+            explorer_url: None,
+        });
+
+        // 2. SEGWIT NATIVO P2WPKH
+        let native_path = format!("m/84'/1776'/0'/0/{}", index);
+        let public_key = derive_key_at_path(&master_key, &native_path)?.public_key().to_bytes();
+        let mut script_pubkey = vec![0x00, 0x14];
+        script_pubkey.extend_from_slice(&Ripemd160::digest(Sha256::digest(public_key)));
+
+        let mut program = slip77_blinding_public_key(&master_blinding_key, &script_pubkey)?.to_vec();
+        program.extend_from_slice(&script_pubkey[2..]);
+
+        addresses.push(Address {
+            address_type: format!("Confidential Native SegWit #{}", index),
+            path: native_path.clone(),
+            address: blech32_encode(LIQUID_BLECH32_HRP, &program)?,
+            chain_id: None,
+            explorer_url: None,
+        });
+        addresses.push(Address {
+            address_type: format!("Unconfidential Native SegWit #{}", index),
+            path: native_path,
+            address: p2wpkh_address(&public_key, LIQUID_SEGWIT_HRP)?,
+            chain_id: None,
+            explorer_url: None,
+        });
+    }
+
+    master_blinding_key.zeroize();
+    Ok(addresses)
+}
+
+// =============================================================================
+// IMPLEMENTACIONES STELLAR Y TON (ED25519 SLIP-0010)
+// =============================================================================
+
+/// CRC16-XModem (polinomio 0x1021, inicial 0), usado por StrKey y las direcciones TON
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Clave Ed25519 SLIP-0010 en una ruta (todos los niveles endurecidos)
+pub fn ed25519_signing_key_at_path(seed_phrase: &str, passphrase: Option<&str>, path: &str) -> Result<SolanaSigningKey> {
+    use bip39_crate::{Mnemonic, Language};
+    use zeroize::Zeroize;
+
+    let mnemonic = Mnemonic::parse_in_normalized(Language::English, seed_phrase)
+        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
+    let mut seed = mnemonic.to_seed(passphrase.unwrap_or(""));
+
+    let derived_key = manual_derive_path(path, &seed);
+    seed.zeroize();
+    let mut derived_key = derived_key?;
+
+    let signing_key = SolanaSigningKey::from_bytes(&derived_key);
+    derived_key.zeroize();
+    Ok(signing_key)
+}
+
+/// Codificar una clave pública Ed25519 como cuenta Stellar (StrKey "G...")
+pub fn stellar_strkey(public_key: &[u8; 32]) -> String {
+    const VERSION_ACCOUNT_ID: u8 = 6 << 3;
+
+    let mut payload = vec![VERSION_ACCOUNT_ID];
+    payload.extend_from_slice(public_key);
+    payload.extend_from_slice(&crc16_xmodem(&payload).to_le_bytes());
+    base32::encode(base32::Alphabet::RFC4648 { padding: false }, &payload)
+}
+
+/// Derivar cuentas Stellar según SEP-0005
+/// Stellar soporta BIP39 passphrase oficialmente (SEP-0005)
+/// Path: m/44'/148'/index'
+fn derive_stellar_addresses(seed_phrase: &str, passphrase: Option<&str>, count: u32) -> Result<Vec<Address>> {
+    let mut addresses = Vec::new();
+
+    for index in 0u32..count {
+        let path = format!("m/44'/148'/{}'", index);
+        let signing_key = ed25519_signing_key_at_path(seed_phrase, passphrase, &path)?;
+
+        addresses.push(Address {
+            address_type: format!("Stellar #{}", index),
+            path,
+            address: stellar_strkey(signing_key.verifying_key().as_bytes()),
+            chain_id: None,
+            explorer_url: None,
+        });
+    }
+
+    Ok(addresses)
+}
+
+/// Código del contrato wallet v4R2 (BOC oficial de ton-blockchain/wallet-contract)
+const TON_WALLET_V4R2_CODE: &str = "te6cckECFAEAAtQAART/APSkE/S88sgLAQIBIAIDAgFIBAUE+PKDCNcYINMf0x/THwL4I7vyZO1E0NMf0x/T//QE0VFDuvKhUVG68qIF+QFUEGT5EPKj+AAkpMjLH1JAyx9SMMv/UhD0AMntVPgPAdMHIcAAn2xRkyDXSpbTB9QC+wDoMOAhwAHjACHAAuMAAcADkTDjDQOkyMsfEssfy/8QERITAubQAdDTAyFxsJJfBOAi10nBIJJfBOAC0x8hghBwbHVnvSKCEGRzdHK9sJJfBeAD+kAwIPpEAcjKB8v/ydDtRNCBAUDXIfQEMFyBAQj0Cm+hMbOSXwfgBdM/yCWCEHBsdWe6kjgw4w0DghBkc3RyupJfBuMNBgcCASAICQB4AfoA9AQw+CdvIjBQCqEhvvLgUIIQcGx1Z4MesXCAGFAEywUmzxZY+gIZ9ADLaRfLH1Jgyz8gyYBA+wAGAIpQBIEBCPRZMO1E0IEBQNcgyAHPFvQAye1UAXKwjiOCEGRzdHKDHrFwgBhQBcsFUAPPFiP6AhPLassfyz/JgED7AJJfA+ICASAKCwBZvSQrb2omhAgKBrkPoCGEcNQICEekk30pkQzmkD6f+YN4EoAbeBAUiYcVnzGEAgFYDA0AEbjJftRNDXCx+AA9sp37UTQgQFA1yH0BDACyMoHy//J0AGBAQj0Cm+hMYAIBIA4PABmtznaiaEAga5Drhf/AABmvHfaiaEAQa5DrhY/AAG7SB/oA1NQi+QAFyMoHFcv/ydB3dIAYyMsFywIizxZQBfoCFMtrEszMyXP7AMhAFIEBCPRR8qcCAHCBAQjXGPoA0z/IVCBHgQEI9FHyp4IQbm90ZXB0gBjIywXLAlAGzxZQBPoCFMtqEssfyz/Jc/sAAgBsgQEI1xj6ANM/MFIkgQEI9Fnyp4IQZHN0cnB0gBjIywXLAlAFzxZQA/oCE8tqyx8Syz/Jc/sAAAr0AMntVGliJeU=";
+
+/// subwallet_id por defecto de los wallets v4 en la workchain 0
+const TON_DEFAULT_SUBWALLET_ID: u32 = 698_983_191;
+
+/// Hash de representación y profundidad de una celda ordinaria (nivel 0)
+#[derive(Clone, Copy)]
+struct TonCellHash {
+    hash: [u8; 32],
+    depth: u16,
+}
+
+/// Hash de una celda a partir de sus bits (con la marca de fin ya aplicada) y sus referencias
+fn ton_cell_hash(bit_len: usize, data: &[u8], refs: &[TonCellHash]) -> TonCellHash {
+    let mut hasher = Sha256::new();
+    Digest::update(&mut hasher, [refs.len() as u8, ((bit_len / 8) + bit_len.div_ceil(8)) as u8]);
+    Digest::update(&mut hasher, data);
+    for reference in refs {
+        Digest::update(&mut hasher, reference.depth.to_be_bytes());
+    }
+    for reference in refs {
+        Digest::update(&mut hasher, reference.hash);
+    }
+
+    TonCellHash {
+        hash: hasher.finalize().into(),
+        depth: refs.iter().map(|r| r.depth + 1).max().unwrap_or(0),
+    }
+}
+
+/// Hash de la celda raíz de un BOC (bag of cells) sin celdas exóticas
+fn ton_boc_root_hash(boc: &[u8]) -> Result<TonCellHash> {
+    let malformed = || SCypherError::crypto("Malformed TON bag of cells".to_string());
+
+    if boc.len() < 6 || boc[..4] != [0xb5, 0xee, 0x9c, 0x72] {
+        return Err(malformed());
+    }
+    let flags = boc[4];
+    let (has_index, ref_size, offset_size) = (flags & 0x80 != 0, (flags & 0x07) as usize, boc[5] as usize);
+
+    let mut position = 6;
+    let mut read = |size: usize| -> Result<usize> {
+        let bytes = boc.get(position..position + size).ok_or_else(malformed)?;
+        position += size;
+        Ok(bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize))
+    };
+
+    let cell_count = read(ref_size)?;
+    let root_count = read(ref_size)?;
+    let _absent = read(ref_size)?;
+    let _total_size = read(offset_size)?;
+    let root = read(ref_size)?;
+    for _ in 1..root_count {
+        read(ref_size)?;
+    }
+    if has_index {
+        for _ in 0..cell_count {
+            read(offset_size)?;
+        }
+    }
+
+    // Leer las celdas: (bits, datos, índices de referencias)
+    let mut cells = Vec::with_capacity(cell_count);
+    for _ in 0..cell_count {
+        let d1 = read(1)?;
+        let d2 = read(1)?;
+        if d1 & 0x08 != 0 {
+            return Err(SCypherError::crypto("Exotic TON cells are not supported".to_string()));
+        }
+        let data_len = d2.div_ceil(2);
+        let data: Vec<u8> = (0..data_len).map(|_| read(1).map(|b| b as u8)).collect::<Result<_>>()?;
+        let refs: Vec<usize> = (0..d1 & 0x07).map(|_| read(ref_size)).collect::<Result<_>>()?;
+
+        // Longitud en bits: con d2 impar el último byte lleva la marca de fin
+        let bit_len = if d2 % 2 == 0 {
+            data_len * 8
+        } else {
+            let last = *data.last().ok_or_else(malformed)?;
+            if last == 0 {
+                return Err(malformed());
+            }
+            data_len * 8 - 1 - last.trailing_zeros() as usize
+        };
+        cells.push((bit_len, data, refs));
+    }
+
+    // Las referencias siempre apuntan a celdas posteriores: calcular de atrás hacia delante
+    let mut hashes: Vec<Option<TonCellHash>> = vec![None; cell_count];
+    for index in (0..cell_count).rev() {
+        let (bit_len, data, refs) = &cells[index];
+        let refs = refs
+            .iter()
+            .map(|&r| hashes.get(r).copied().flatten().filter(|_| r > index).ok_or_else(malformed))
+            .collect::<Result<Vec<_>>>()?;
+        hashes[index] = Some(ton_cell_hash(*bit_len, data, &refs));
+    }
+
+    hashes.get(root).copied().flatten().ok_or_else(malformed)
+}
+
+/// Dirección de un wallet v4R2 (workchain 0) para una clave pública Ed25519
+/// `bounceable`: "EQ..." (contratos) o "UQ..." (formato recomendado para wallets)
+pub fn ton_wallet_v4r2_address(public_key: &[u8; 32], bounceable: bool) -> Result<String> {
+    use base64::Engine;
+
+    let code_boc = base64::engine::general_purpose::STANDARD
+        .decode(TON_WALLET_V4R2_CODE)
+        .map_err(|e| SCypherError::crypto(format!("Invalid wallet code: {}", e)))?;
+    let code = ton_boc_root_hash(&code_boc)?;
+
+    // Datos iniciales: seqno (32) + subwallet_id (32) + clave pública (256) + plugins vacío (1)
+    let mut data_bits = Vec::with_capacity(41);
+    data_bits.extend_from_slice(&0u32.to_be_bytes());
+    data_bits.extend_from_slice(&TON_DEFAULT_SUBWALLET_ID.to_be_bytes());
+    data_bits.extend_from_slice(public_key);
+    data_bits.push(0b0100_0000); // bit 0 (diccionario vacío) + marca de fin
+    let data = ton_cell_hash(321, &data_bits, &[]);
+
+    // StateInit: split_depth 0, special 0, code 1, data 1, library 0 + marca de fin
+    let state_init = ton_cell_hash(5, &[0b0011_0100], &[code, data]);
+
+    let mut address = vec![if bounceable { 0x11 } else { 0x51 }, 0x00];
+    address.extend_from_slice(&state_init.hash);
+    address.extend_from_slice(&crc16_xmodem(&address).to_be_bytes());
+    Ok(base64::engine::general_purpose::URL_SAFE.encode(address))
+}
+
+/// Derivar direcciones TON (wallet v4R2)
+/// TON no usa passphrase (igual que Trust Wallet)
+/// Path: m/44'/607'/index' (SLIP-0044 607)
+fn derive_ton_addresses(seed_phrase: &str, count: u32) -> Result<Vec<Address>> {
+    let mut addresses = Vec::new();
+
+    for index in 0u32..count {
+        let path = format!("m/44'/607'/{}'", index);
+        let signing_key = ed25519_signing_key_at_path(seed_phrase, None, &path)?;
+
+        addresses.push(Address {
+            address_type: format!("TON v4R2 #{}", index),
+            path,
+            address: ton_wallet_v4r2_address(signing_key.verifying_key().as_bytes(), false)?,
+            chain_id: None,
+            explorer_url: None,
+        });
+    }
+
+    Ok(addresses)
+}
+
+// =============================================================================
+// PERFILES DE WALLETS (LEDGER LIVE, TREZOR SUITE, METAMASK, PHANTOM, EXODUS)
+// =============================================================================
+
+/// Tipo de dirección que genera una entrada de perfil
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfileAddressKind {
+    BitcoinLegacy,
+    BitcoinNestedSegwit,
+    BitcoinNativeSegwit,
+    BitcoinTaproot,
+    LitecoinLegacy,
+    LitecoinNativeSegwit,
+    Dogecoin,
+    Evm,
+    Tron,
+    Solana,
+}
+
+/// Entrada de un perfil: red, etiqueta y plantilla de ruta (`{i}` = índice)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileEntry {
+    pub network: NetworkId,
+    pub label: String,
+    pub path_template: String,
+    pub kind: ProfileAddressKind,
+}
+
+/// Perfil de wallet con las rutas exactas que usa
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletProfile {
+    pub name: String,
+    pub description: String,
+    pub entries: Vec<ProfileEntry>,
+}
+
+/// Tabla de perfiles: (red, etiqueta, plantilla, tipo)
+type ProfileTable = &'static [(NetworkId, &'static str, &'static str, ProfileAddressKind)];
+
+const LEDGER_LIVE_ENTRIES: ProfileTable = &[
+    (NetworkId::Bitcoin, "Native SegWit", "m/84'/0'/{i}'/0/0", ProfileAddressKind::BitcoinNativeSegwit),
+    (NetworkId::Bitcoin, "Taproot", "m/86'/0'/{i}'/0/0", ProfileAddressKind::BitcoinTaproot),
+    (NetworkId::Bitcoin, "Nested SegWit", "m/49'/0'/{i}'/0/0", ProfileAddressKind::BitcoinNestedSegwit),
+    (NetworkId::Bitcoin, "Legacy", "m/44'/0'/{i}'/0/0", ProfileAddressKind::BitcoinLegacy),
+    (NetworkId::Ethereum, "Ethereum", "m/44'/60'/{i}'/0/0", ProfileAddressKind::Evm),
+    (NetworkId::Bsc, "BSC", "m/44'/60'/{i}'/0/0", ProfileAddressKind::Evm),
+    (NetworkId::Polygon, "Polygon", "m/44'/60'/{i}'/0/0", ProfileAddressKind::Evm),
+    (NetworkId::Litecoin, "Native SegWit", "m/84'/2'/{i}'/0/0", ProfileAddressKind::LitecoinNativeSegwit),
+    (NetworkId::Dogecoin, "Dogecoin", "m/44'/3'/{i}'/0/0", ProfileAddressKind::Dogecoin),
+    (NetworkId::Tron, "TRON", "m/44'/195'/{i}'/0/0", ProfileAddressKind::Tron),
+    (NetworkId::Solana, "Solana", "m/44'/501'/{i}'", ProfileAddressKind::Solana),
+];
+
+const TREZOR_SUITE_ENTRIES: ProfileTable = &[
+    (NetworkId::Bitcoin, "Native SegWit", "m/84'/0'/0'/0/{i}", ProfileAddressKind::BitcoinNativeSegwit),
+    (NetworkId::Bitcoin, "Taproot", "m/86'/0'/0'/0/{i}", ProfileAddressKind::BitcoinTaproot),
+    (NetworkId::Bitcoin, "Nested SegWit", "m/49'/0'/0'/0/{i}", ProfileAddressKind::BitcoinNestedSegwit),
+    (NetworkId::Bitcoin, "Legacy", "m/44'/0'/0'/0/{i}", ProfileAddressKind::BitcoinLegacy),
+    (NetworkId::Ethereum, "Ethereum", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Litecoin, "Native SegWit", "m/84'/2'/0'/0/{i}", ProfileAddressKind::LitecoinNativeSegwit),
+    (NetworkId::Dogecoin, "Dogecoin", "m/44'/3'/0'/0/{i}", ProfileAddressKind::Dogecoin),
+    (NetworkId::Solana, "Solana", "m/44'/501'/{i}'/0'", ProfileAddressKind::Solana),
+];
+
+const METAMASK_ENTRIES: ProfileTable = &[
+    (NetworkId::Ethereum, "Ethereum", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Bsc, "BSC", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Polygon, "Polygon", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+];
+
+const PHANTOM_ENTRIES: ProfileTable = &[
+    (NetworkId::Solana, "Solana", "m/44'/501'/{i}'/0'", ProfileAddressKind::Solana),
+    (NetworkId::Ethereum, "Ethereum", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Polygon, "Polygon", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Bitcoin, "Native SegWit", "m/84'/0'/{i}'/0/0", ProfileAddressKind::BitcoinNativeSegwit),
+];
+
+const EXODUS_ENTRIES: ProfileTable = &[
+    (NetworkId::Bitcoin, "Native SegWit", "m/84'/0'/0'/0/{i}", ProfileAddressKind::BitcoinNativeSegwit),
+    (NetworkId::Bitcoin, "Legacy", "m/44'/0'/0'/0/{i}", ProfileAddressKind::BitcoinLegacy),
+    (NetworkId::Ethereum, "Ethereum", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Bsc, "BSC", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Polygon, "Polygon", "m/44'/60'/0'/0/{i}", ProfileAddressKind::Evm),
+    (NetworkId::Litecoin, "Legacy", "m/44'/2'/0'/0/{i}", ProfileAddressKind::LitecoinLegacy),
+    (NetworkId::Dogecoin, "Dogecoin", "m/44'/3'/0'/0/{i}", ProfileAddressKind::Dogecoin),
+    (NetworkId::Tron, "TRON", "m/44'/195'/0'/0/{i}", ProfileAddressKind::Tron),
+];
+
+/// Perfiles disponibles (nombre, descripción, entradas)
+const WALLET_PROFILES: &[(&str, &str, ProfileTable)] = &[
+    ("Ledger Live", "One account per index: the index is the hardened account level", LEDGER_LIVE_ENTRIES),
+    ("Trezor Suite", "First account, consecutive receive addresses", TREZOR_SUITE_ENTRIES),
+    ("MetaMask", "Single EVM account tree, consecutive addresses", METAMASK_ENTRIES),
+    ("Phantom", "Solana accounts m/44'/501'/i'/0', EVM and Bitcoin per account", PHANTOM_ENTRIES),
+    ("Exodus", "First account, consecutive addresses (Solana not included)", EXODUS_ENTRIES),
+];
+
+/// Listar los perfiles de wallets disponibles
+pub fn wallet_profiles() -> Vec<WalletProfile> {
+    WALLET_PROFILES
+        .iter()
+        .map(|(name, description, entries)| WalletProfile {
+            name: name.to_string(),
+            description: description.to_string(),
+            entries: entries
+                .iter()
+                .map(|(network, label, path_template, kind)| ProfileEntry {
+                    network: *network,
+                    label: label.to_string(),
+                    path_template: path_template.to_string(),
+                    kind: *kind,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Buscar un perfil por nombre (sin distinguir mayúsculas, espacios opcionales)
+pub fn find_wallet_profile(profile_name: &str) -> Result<WalletProfile> {
+    let normalize = |s: &str| s.to_lowercase().replace([' ', '-', '_'], "");
+    let wanted = normalize(profile_name);
+
+    wallet_profiles()
+        .into_iter()
+        .find(|profile| normalize(&profile.name) == wanted)
+        .ok_or_else(|| SCypherError::crypto(format!("Unknown wallet profile: {}", profile_name)))
+}
+
+/// Calcular la dirección de una entrada de perfil en una ruta concreta
+pub(crate) fn profile_address(
+    master_key: &XPrv,
+    seed_phrase: &str,
+    kind: ProfileAddressKind,
+    path: &str,
+) -> Result<String> {
+    use bitcoin::Network;
+
+    if kind == ProfileAddressKind::Solana {
+        if !network_compiled(NetworkId::Solana) {
+            return Err(network_not_compiled(NetworkId::Solana));
+        }
+        // Solana no usa passphrase (igual que Phantom)
+        let signing_key = solana_signing_key_at_path(seed_phrase, path)?;
+        return Ok(bs58::encode(signing_key.verifying_key().as_bytes()).into_string());
+    }
+
+    let key = derive_key_at_path(master_key, path)?;
+    let public_key = bitcoin::PublicKey::new(
+        bitcoin::secp256k1::PublicKey::from_slice(&key.public_key().to_bytes())
+            .map_err(|e| SCypherError::crypto(format!("Invalid public key: {}", e)))?,
+    );
+
+    let address = match kind {
+        ProfileAddressKind::BitcoinLegacy => bitcoin::Address::p2pkh(&public_key, Network::Bitcoin).to_string(),
+        ProfileAddressKind::BitcoinNestedSegwit => bitcoin::Address::p2shwpkh(&public_key, Network::Bitcoin)
+            .map_err(|e| SCypherError::crypto(format!("P2SH-P2WPKH address creation failed: {}", e)))?
+            .to_string(),
+        // SegWit nativo: la variante (bech32 o bech32m) sale de la versión de testigo
+        ProfileAddressKind::BitcoinNativeSegwit => p2wpkh_address(&public_key.to_bytes(), "bc")?,
+        ProfileAddressKind::BitcoinTaproot => p2tr_address(&public_key.to_bytes(), "bc")?,
+        ProfileAddressKind::LitecoinLegacy => p2pkh_address(&public_key.to_bytes(), 0x30),
+        ProfileAddressKind::LitecoinNativeSegwit => p2wpkh_address(&public_key.to_bytes(), "ltc")?,
+        ProfileAddressKind::Dogecoin => p2pkh_address(&public_key.to_bytes(), 0x1e),
+        ProfileAddressKind::Evm => evm_address_from_key(&key)?,
+        ProfileAddressKind::Tron => {
+            let evm_address = evm_address_from_key(&key)?;
+            let mut tron_address = vec![0x41];
+            tron_address.extend(hex::decode(evm_address.trim_start_matches("0x"))
+                .map_err(|e| SCypherError::crypto(format!("Invalid EVM address: {}", e)))?);
+            tron_base58_encode(&tron_address)?
+        }
+        ProfileAddressKind::Solana => unreachable!(),
+    };
+
+    Ok(address)
+}
+
+/// Derivar direcciones con las rutas exactas de un perfil de wallet
+pub fn derive_addresses_with_profile(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    profile_name: &str,
+    count: u32,
+) -> Result<AddressSet> {
+    let profile = find_wallet_profile(profile_name)?;
+    let master_key = master_key_from_phrase(seed_phrase, passphrase)?;
+
+    let mut address_set = AddressSet::default();
+
+    for index in 0..count {
+        // Las entradas de redes no compiladas se omiten
+        for entry in profile.entries.iter().filter(|entry| network_compiled(entry.network)) {
+            let path = entry.path_template.replace("{i}", &index.to_string());
+            let address = profile_address(&master_key, seed_phrase, entry.kind, &path)?;
+
+            address_set.list_mut(entry.network).push(Address {
+                address_type: format!("{} {} #{}", profile.name, entry.label, index),
+                path,
+                address,
+                chain_id: None,
+                explorer_url: None,
+            });
+        }
+    }
+
+    annotate_evm_addresses(&mut address_set);
+    Ok(address_set)
+}
+
+// =============================================================================
+// ACCESO A CLAVES DERIVADAS (EXPORTACIÓN Y FIRMA)
+// =============================================================================
+
+/// Derivar la master key BIP32 desde una seed phrase y passphrase opcional
+pub fn master_key_from_phrase(seed_phrase: &str, passphrase: Option<&str>) -> Result<XPrv> {
+    use bip39_crate::{Mnemonic, Language};
+
+    let mnemonic = Mnemonic::parse_in_normalized(Language::English, seed_phrase)
+        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
+
+    let seed = mnemonic.to_seed(passphrase.unwrap_or(""));
+
+    XPrv::new(seed)
+        .map_err(|e| SCypherError::crypto(format!("Master key derivation failed: {}", e)))
+}
+
+/// Huella de la master key (4 bytes en hex, la que muestran las wallets y los PSBT)
+pub fn master_fingerprint(seed_phrase: &str, passphrase: Option<&str>) -> Result<String> {
+    let master_key = master_key_from_phrase(seed_phrase, passphrase)?;
+    Ok(hex::encode(master_key.public_key().fingerprint()))
+}
+
+/// Derivar la clave extendida en una ruta BIP32 arbitraria
+pub fn derive_key_at_path(master_key: &XPrv, path: &str) -> Result<XPrv> {
+    derive_key_at(master_key, &crate::derivation::Path::parse(path)?)
+}
+
+/// Derivar la clave de una ruta ya leída
+pub fn derive_key_at(master_key: &XPrv, path: &crate::derivation::Path) -> Result<XPrv> {
+    let mut key = master_key.clone();
+    for component in path.components() {
+        key = key.derive_child(ChildNumber(component.raw()))
+            .map_err(|e| SCypherError::crypto(format!("Key derivation failed at {}: {}", path, e)))?;
+    }
+
+    Ok(key)
+}
+
+/// Rutas de las `count` primeras direcciones de una cadena: el rango `base/0-(count-1)`
+fn address_paths(base: &str, count: u32) -> Result<Vec<crate::derivation::Path>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let range = crate::derivation::PathRange::parse(&format!("{}/0-{}", base, count - 1))?;
+    Ok(range.iter().collect())
+}
+
+/// Calcular la dirección EVM (EIP-55) de una clave derivada
+pub fn evm_address_from_key(key: &XPrv) -> Result<String> {
+    let pk = secp256k1::PublicKey::from_slice(&key.public_key().to_bytes())
+        .map_err(|e| SCypherError::crypto(format!("Invalid public key: {}", e)))?;
+    let uncompressed = pk.serialize_uncompressed();
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&uncompressed[1..]);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    Ok(to_eip55_checksum_address(&hash[12..]))
+}
+
+/// Derivar la clave Ed25519 de Solana en una ruta (compatible con Phantom, sin passphrase)
+pub fn solana_signing_key_at_path(seed_phrase: &str, path: &str) -> Result<SolanaSigningKey> {
+    ed25519_signing_key_at_path(seed_phrase, None, path)
+}
+
+/// Derivar la clave Ed25519 de Solana para un índice
+pub fn solana_signing_key_at_index(seed_phrase: &str, index: u32) -> Result<SolanaSigningKey> {
+    solana_signing_key_at_path(seed_phrase, &solana_derivation_path(index))
+}
+
+/// Ruta de derivación Solana usada por Phantom para un índice
+pub fn solana_derivation_path(index: u32) -> String {
+    format!("m/44'/501'/{}'/0'", index)
+}
+
+/// Codificar una dirección P2PKH con el byte de versión de la red (Base58Check)
+pub fn p2pkh_address(compressed_pubkey: &[u8], version: u8) -> String {
+    let ripemd_hash = Ripemd160::digest(Sha256::digest(compressed_pubkey));
+
+    let mut address_bytes = vec![version];
+    address_bytes.extend_from_slice(&ripemd_hash);
+
+    let checksum_hash = Sha256::digest(Sha256::digest(&address_bytes));
+    address_bytes.extend_from_slice(&checksum_hash[0..4]);
+
+    bs58::encode(address_bytes).into_string()
+}
+
+// =============================================================================
+// VALIDACIÓN DE DIRECCIONES (SIN DERIVAR)
+// Comprueba formato y checksum de una dirección pegada por el usuario o
+// mostrada por una hardware wallet, para compararla con las derivadas.
+// =============================================================================
+
+/// Resultado de validar una dirección
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddressValidation {
+    pub valid: bool,
+    pub network: String,
+    pub format: Option<String>,     // "p2pkh", "p2wpkh", "eip55", "cardano_base"...
+    pub normalized: Option<String>, // Forma canónica (EIP-55, bech32 en minúsculas)
+    pub message: String,
+}
+
+/// Formato reconocido y forma canónica, o motivo del rechazo
+type ValidationOutcome = std::result::Result<(&'static str, String), String>;
+
+/// Decodificar Base58Check (payload sin los 4 bytes de checksum)
+fn decode_base58check(address: &str) -> std::result::Result<Vec<u8>, String> {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| "Not valid Base58".to_string())?;
+    if bytes.len() < 5 {
+        return Err("Address is too short".to_string());
+    }
+
+    let (payload, checksum) = bytes.split_at(bytes.len() - 4);
+    if Sha256::digest(Sha256::digest(payload))[..4] != *checksum {
+        return Err("Base58Check checksum mismatch".to_string());
+    }
+    Ok(payload.to_vec())
+}
+
+/// P2PKH/P2SH en Base58Check con los bytes de versión de la red
+fn validate_base58_hash160(address: &str, p2pkh: u8, p2sh: &[u8]) -> ValidationOutcome {
+    let payload = decode_base58check(address)?;
+    if payload.len() != 21 {
+        return Err(format!("Unexpected payload length {} (expected 21)", payload.len()));
+    }
+
+    match payload[0] {
+        version if version == p2pkh => Ok(("p2pkh", address.to_string())),
+        version if p2sh.contains(&version) => Ok(("p2sh", address.to_string())),
+        version => Err(format!("Version byte 0x{:02x} does not belong to this network", version)),
+    }
+}
+
+/// SegWit: bech32 para la versión 0 y bech32m para las demás (BIP173/BIP350)
+fn validate_segwit(address: &str, hrp: &str) -> ValidationOutcome {
+    use bech32::FromBase32;
+
+    let (decoded_hrp, data, variant) = bech32::decode(address).map_err(|e| format!("Invalid bech32: {}", e))?;
+    if decoded_hrp != hrp {
+        return Err(format!("Expected prefix '{}1', found '{}1'", hrp, decoded_hrp));
+    }
+
+    let (version, program) = data.split_first().ok_or_else(|| "Missing witness version".to_string())?;
+    let version = version.to_u8();
+    let program = Vec::<u8>::from_base32(program).map_err(|e| format!("Invalid witness program: {}", e))?;
+
+    if version > 16 {
+        return Err(format!("Invalid witness version {}", version));
+    }
+    if variant != segwit_variant(version) {
+        return Err(match version {
+            0 => "SegWit v0 addresses use bech32, not bech32m".to_string(),
+            1 => "Taproot (SegWit v1) addresses use bech32m, not bech32".to_string(),
+            _ => format!("SegWit v{} addresses use bech32m, not bech32", version),
+        });
+    }
+
+    let format = match (version, program.len()) {
+        (0, 20) => "p2wpkh",
+        (0, 32) => "p2wsh",
+        (1, 32) => "p2tr",
+        (1, _) => return Err(format!("Taproot (SegWit v1) programs are 32 bytes, found {}", program.len())),
+        (2..=16, 2..=40) => "segwit",
+        _ => return Err(format!("Invalid witness program: version {}, {} bytes", version, program.len())),
+    };
+    Ok((format, address.to_lowercase()))
+}
+
+fn validate_evm(address: &str) -> ValidationOutcome {
+    let hex_part = address
+        .strip_prefix("0x")
+        .or_else(|| address.strip_prefix("0X"))
+        .ok_or_else(|| "EVM addresses start with 0x".to_string())?;
+    if hex_part.len() != 40 {
+        return Err(format!("Expected 40 hex characters, found {}", hex_part.len()));
+    }
+    let bytes = hex::decode(hex_part).map_err(|_| "Not a hexadecimal address".to_string())?;
+    let checksummed = to_eip55_checksum_address(&bytes);
+
+    let mixed_case = hex_part.chars().any(|c| c.is_ascii_lowercase()) && hex_part.chars().any(|c| c.is_ascii_uppercase());
+    if !mixed_case {
+        return Ok(("hex", checksummed));
+    }
+    if checksummed[2..] != *hex_part {
+        return Err("EIP-55 checksum mismatch (check letter case)".to_string());
+    }
+    Ok(("eip55", checksummed))
+}
+
+fn validate_tron(address: &str) -> ValidationOutcome {
+    let payload = decode_base58check(address)?;
+    match payload.as_slice() {
+        [0x41, rest @ ..] if rest.len() == 20 => Ok(("base58check", address.to_string())),
+        [0x41, ..] => Err(format!("Unexpected payload length {} (expected 21)", payload.len())),
+        _ => Err("TRON mainnet addresses use version byte 0x41 (T...)".to_string()),
+    }
+}
+
+fn validate_solana(address: &str) -> ValidationOutcome {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| "Not valid Base58".to_string())?;
+    if bytes.len() != 32 {
+        return Err(format!("Expected a 32-byte public key, found {} bytes", bytes.len()));
+    }
+    Ok(("base58", address.to_string()))
+}
+
+/// Shelley (CIP-19): cabecera = tipo (4 bits altos) + red (4 bits bajos)
+fn validate_cardano(address: &str) -> ValidationOutcome {
+    use bech32::FromBase32;
+
+    let (hrp, data, variant) = bech32::decode(address).map_err(|e| format!("Invalid bech32: {}", e))?;
+    if variant != Variant::Bech32 {
+        return Err("Cardano addresses use bech32, not bech32m".to_string());
+    }
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| format!("Invalid address payload: {}", e))?;
+    let header = *bytes.first().ok_or_else(|| "Empty address payload".to_string())?;
+    let (kind, network) = (header >> 4, header & 0x0f);
+
+    let expected_hrp = if kind >= 14 { "stake" } else { "addr" };
+    if hrp != expected_hrp || network != 1 {
+        return Err(format!("Not a Cardano mainnet address (prefix '{}', network id {})", hrp, network));
+    }
+
+    let format = match (kind, bytes.len()) {
+        (0..=3, 57) => "cardano_base",
+        (4 | 5, len) if len > 29 => "cardano_pointer",
+        (6 | 7, 29) => "cardano_enterprise",
+        (14 | 15, 29) => "cardano_reward",
+        _ => return Err(format!("Invalid length {} for address type {}", bytes.len(), kind)),
+    };
+    Ok((format, address.to_lowercase()))
+}
+
+/// Ergo: cabecera (red + tipo) + contenido + 4 bytes de Blake2b-256
+fn validate_ergo(address: &str) -> ValidationOutcome {
+    use blake2::digest::consts::U32;
+
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| "Not valid Base58".to_string())?;
+    if bytes.len() < 6 {
+        return Err("Address is too short".to_string());
+    }
+
+    let (body, checksum) = bytes.split_at(bytes.len() - 4);
+    if Blake2b::<U32>::digest(body)[..4] != *checksum {
+        return Err("Blake2b checksum mismatch".to_string());
+    }
+
+    let (prefix, kind) = (body[0] & 0xf0, body[0] & 0x0f);
+    if prefix != 0x00 {
+        return Err(format!("Not an Ergo mainnet address (network prefix 0x{:02x})", prefix));
+    }
+    match (kind, body.len() - 1) {
+        (1, 33) => Ok(("p2pk", address.to_string())),
+        (2, 24) => Ok(("p2sh", address.to_string())),
+        (3, _) => Ok(("p2s", address.to_string())),
+        (kind, len) => Err(format!("Invalid content length {} for address type {}", len, kind)),
+    }
+}
+
+fn validate_stellar(address: &str) -> ValidationOutcome {
+    let bytes = base32::decode(base32::Alphabet::RFC4648 { padding: false }, address)
+        .ok_or_else(|| "Not valid base32".to_string())?;
+    if bytes.len() != 35 || !address.starts_with('G') {
+        return Err("Stellar account IDs are 56 characters starting with G".to_string());
+    }
+
+    let (payload, checksum) = bytes.split_at(33);
+    if crc16_xmodem(payload).to_le_bytes() != checksum {
+        return Err("StrKey checksum mismatch".to_string());
+    }
+    Ok(("strkey", address.to_string()))
+}
+
+/// TON: forma "amigable" (base64, 36 bytes con CRC16) o cruda "workchain:hash"
+fn validate_ton(address: &str) -> ValidationOutcome {
+    use base64::Engine;
+
+    if let Some((workchain, hash)) = address.split_once(':') {
+        let valid_workchain = matches!(workchain, "0" | "-1");
+        if !valid_workchain || hash.len() != 64 || hex::decode(hash).is_err() {
+            return Err("Raw TON addresses are <workchain>:<64 hex characters>".to_string());
+        }
+        return Ok(("raw", address.to_lowercase()));
+    }
+
+    let bytes = base64::engine::general_purpose::URL_SAFE
+        .decode(address)
+        .or_else(|_| base64::engine::general_purpose::STANDARD.decode(address))
+        .map_err(|_| "Not valid base64".to_string())?;
+    if bytes.len() != 36 {
+        return Err(format!("Expected 36 bytes, found {}", bytes.len()));
+    }
+
+    let (body, checksum) = bytes.split_at(34);
+    if crc16_xmodem(body).to_be_bytes() != checksum {
+        return Err("TON address checksum mismatch".to_string());
+    }
+    if body[1] != 0x00 && body[1] != 0xff {
+        return Err(format!("Unknown workchain {}", body[1] as i8));
+    }
+    match body[0] {
+        0x11 => Ok(("bounceable", address.to_string())),
+        0x51 => Ok(("non_bounceable", address.to_string())),
+        tag if tag & 0x80 != 0 => Err("Testnet TON address".to_string()),
+        tag => Err(format!("Unknown address tag 0x{:02x}", tag)),
+    }
+}
+
+/// Liquid: Base58 (Q/G), confidencial Base58 (prefijo 0x0c), SegWit "ex1" o blech32 "lq1"
+fn validate_liquid(address: &str) -> ValidationOutcome {
+    let lower = address.to_lowercase();
+    if lower.starts_with("ex1") {
+        return validate_segwit(address, LIQUID_SEGWIT_HRP);
+    }
+    if lower.starts_with("lq1") {
+        let (hrp, program) = blech32_decode(address)?;
+        if hrp != LIQUID_BLECH32_HRP {
+            return Err(format!("Expected prefix '{}1', found '{}1'", LIQUID_BLECH32_HRP, hrp));
+        }
+        return match program.len() {
+            53 => Ok(("confidential_p2wpkh", lower)),
+            65 => Ok(("confidential_p2wsh", lower)),
+            len => Err(format!("Invalid confidential witness program: {} bytes", len)),
+        };
+    }
+
+    let payload = decode_base58check(address)?;
+    match (payload.len(), payload.first()) {
+        (55, Some(&LIQUID_CONFIDENTIAL_PREFIX)) => match payload[1] {
+            LIQUID_P2PKH_VERSION => Ok(("confidential_p2pkh", address.to_string())),
+            LIQUID_P2SH_VERSION => Ok(("confidential_p2sh", address.to_string())),
+            version => Err(format!("Version byte 0x{:02x} does not belong to this network", version)),
+        },
+        (21, _) => validate_base58_hash160(address, LIQUID_P2PKH_VERSION, &[LIQUID_P2SH_VERSION]),
+        (len, _) => Err(format!("Unexpected payload length {}", len)),
+    }
+}
+
+/// Zcash: solo direcciones transparentes (prefijo de dos bytes)
+fn validate_zcash(address: &str) -> ValidationOutcome {
+    let lower = address.to_lowercase();
+    if lower.starts_with("zs1") || lower.starts_with("u1") || lower.starts_with("zc") {
+        return Err("Shielded and unified addresses are not supported (transparent only)".to_string());
+    }
+
+    let payload = decode_base58check(address)?;
+    if payload.len() != 22 {
+        return Err(format!("Unexpected payload length {} (expected 22)", payload.len()));
+    }
+    match [payload[0], payload[1]] {
+        ZCASH_P2PKH_PREFIX => Ok(("p2pkh", address.to_string())),
+        ZCASH_P2SH_PREFIX => Ok(("p2sh", address.to_string())),
+        prefix => Err(format!("Prefix 0x{} does not belong to this network", hex::encode(prefix))),
+    }
+}
+
+/// Validar formato y checksum de una dirección de una red soportada
+pub fn validate_address(network: &str, address: &str) -> Result<AddressValidation> {
+    let id = NetworkId::from_str(network)?;
+    let address = address.trim();
+
+    let outcome = match id {
+        NetworkId::Bitcoin if address.to_lowercase().starts_with("bc1") => validate_segwit(address, "bc"),
+        NetworkId::Bitcoin => validate_base58_hash160(address, 0x00, &[0x05]),
+        NetworkId::Litecoin if address.to_lowercase().starts_with("ltc1") => validate_segwit(address, "ltc"),
+        NetworkId::Litecoin => validate_base58_hash160(address, 0x30, &[0x32, 0x05]),
+        NetworkId::Dogecoin => validate_base58_hash160(address, 0x1e, &[0x16]),
+        NetworkId::Ethereum | NetworkId::Bsc | NetworkId::Polygon => validate_evm(address),
+        NetworkId::Tron => validate_tron(address),
+        NetworkId::BitcoinCash if address.starts_with(['1', '3']) => validate_base58_hash160(address, 0x00, &[0x05]),
+        NetworkId::BitcoinCash => cashaddr_decode(address).and_then(|(script_type, hash160)| match script_type {
+            0 => Ok(("cashaddr_p2pkh", cashaddr_encode(&hash160, 0))),
+            1 => Ok(("cashaddr_p2sh", cashaddr_encode(&hash160, 1))),
+            other => Err(format!("Unknown CashAddr type {}", other)),
+        }),
+        NetworkId::Solana => validate_solana(address),
+        NetworkId::Cardano => validate_cardano(address),
+        NetworkId::Ergo => validate_ergo(address),
+        NetworkId::Stellar => validate_stellar(address),
+        NetworkId::Ton => validate_ton(address),
+        NetworkId::Liquid => validate_liquid(address),
+        NetworkId::Zcash => validate_zcash(address),
+    };
+
+    let name = network_spec(id).name;
+    Ok(match outcome {
+        Ok((format, normalized)) => AddressValidation {
+            valid: true,
+            network: id.as_str().to_string(),
+            message: if format == "hex" {
+                format!("Valid {} address (no EIP-55 checksum to verify)", name)
+            } else {
+                format!("Valid {} address", name)
+            },
+            format: Some(format.to_string()),
+            normalized: Some(normalized),
+        },
+        Err(reason) => AddressValidation {
+            valid: false,
+            network: id.as_str().to_string(),
+            format: None,
+            normalized: None,
+            message: format!("Invalid {} address: {}", name, reason),
+        },
+    })
+}
+
+// =============================================================================
+// PREDICCIÓN DE DIRECCIONES DE CONTRATO (CREATE / CREATE2)
+// Para comprobar qué contratos desplegaría una cuenta derivada de una frase
+// protegida sin conectarse a la red:
+// - EVM CREATE:  keccak256(rlp([desplegador, nonce]))[12..]
+// - EVM CREATE2: keccak256(0xff ++ desplegador ++ salt ++ keccak256(init_code))[12..]
+// - TRON: los despliegues por transacción no usan nonce sino el id de la
+//   transacción firmada, keccak256(txid ++ 0x41 ++ desplegador)[12..]; CREATE2
+//   en la TVM es igual que en EVM con 0x41 en lugar de 0xff.
+// =============================================================================
+
+/// Datos del despliegue; cada campo opcional activa un cálculo
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContractDeployment {
+    pub nonce: Option<u64>,              // CREATE en redes EVM
+    pub transaction_id: Option<String>,  // Despliegue en TRON (hex, 32 bytes)
+    pub salt: Option<String>,            // CREATE2 (hex, 32 bytes)
+    pub init_code: Option<String>,       // CREATE2 (hex)
+}
+
+/// Direcciones de contrato previstas para un desplegador
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractAddressPrediction {
+    pub network: String,
+    pub deployer: String,          // Normalizada (EIP-55 o Base58Check)
+    pub create: Option<String>,    // Por nonce (EVM) o por id de transacción (TRON)
+    pub create2: Option<String>,
+}
+
+fn keccak256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Decodificar un campo hexadecimal (con o sin 0x), con longitud fija si se indica
+fn decode_hex_field(field: &str, value: &str, expected_len: Option<usize>) -> Result<Vec<u8>> {
+    let value = value.trim();
+    let hex_part = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).unwrap_or(value);
+    let bytes = hex::decode(hex_part)
+        .map_err(|e| SCypherError::crypto(format!("Invalid {} (expected hexadecimal): {}", field, e)))?;
+    match expected_len {
+        Some(len) if bytes.len() != len => Err(SCypherError::crypto(format!(
+            "Invalid {}: expected {} bytes, found {}",
+            field, len, bytes.len()
+        ))),
+        _ => Ok(bytes),
+    }
+}
+
+/// RLP de [desplegador, nonce] (la entrada de CREATE)
+fn rlp_create_preimage(deployer: &[u8; 20], nonce: u64) -> Vec<u8> {
+    let nonce_bytes = nonce.to_be_bytes();
+    let start = nonce_bytes.iter().position(|&b| b != 0).unwrap_or(nonce_bytes.len());
+
+    let mut items = vec![0x80 + 20];
+    items.extend_from_slice(deployer);
+    match &nonce_bytes[start..] {
+        [] => items.push(0x80),
+        [byte] if *byte < 0x80 => items.push(*byte),
+        bytes => {
+            items.push(0x80 + bytes.len() as u8);
+            items.extend_from_slice(bytes);
+        }
+    }
+
+    // La lista siempre mide menos de 56 bytes: prefijo corto
+    let mut encoded = vec![0xc0 + items.len() as u8];
+    encoded.extend_from_slice(&items);
+    encoded
+}
+
+/// Dirección de contrato en el formato de la red
+fn encode_contract_address(network: NetworkId, hash: &[u8; 32]) -> Result<String> {
+    if network == NetworkId::Tron {
+        let mut payload = vec![0x41];
+        payload.extend_from_slice(&hash[12..]);
+        tron_base58_encode(&payload)
+    } else {
+        Ok(to_eip55_checksum_address(&hash[12..]))
+    }
+}
+
+/// Calcular las direcciones CREATE/CREATE2 que desplegaría `deployer`
+///
+/// Solo redes EVM (Ethereum, BSC, Polygon) y TRON.
+pub fn predict_contract_addresses(network: &str, deployer: &str, deployment: &ContractDeployment) -> Result<ContractAddressPrediction> {
+    let id = NetworkId::from_str(network)?;
+    let name = network_spec(id).name;
+    let deployer = deployer.trim();
+
+    let outcome = match id {
+        NetworkId::Ethereum | NetworkId::Bsc | NetworkId::Polygon => validate_evm(deployer),
+        NetworkId::Tron => validate_tron(deployer),
+        _ => return Err(SCypherError::crypto(format!("Contract address prediction is not available for {}", name))),
+    };
+    let (_, normalized) = outcome
+        .map_err(|reason| SCypherError::crypto(format!("Invalid {} deployer address: {}", name, reason)))?;
+
+    let mut deployer_bytes = [0u8; 20];
+    if id == NetworkId::Tron {
+        let payload = decode_base58check(&normalized).map_err(SCypherError::crypto)?;
+        deployer_bytes.copy_from_slice(&payload[1..]);
+    } else {
+        deployer_bytes.copy_from_slice(&decode_hex_field("deployer address", &normalized, Some(20))?);
+    }
+
+    let create = match (id, deployment.nonce, &deployment.transaction_id) {
+        (NetworkId::Tron, Some(_), _) => {
+            return Err(SCypherError::crypto("TRON deployments do not use a nonce: provide the signed transaction id"));
+        }
+        (NetworkId::Tron, None, Some(txid)) => {
+            let txid = decode_hex_field("transaction id", txid, Some(32))?;
+            Some(encode_contract_address(id, &keccak256(&[&txid, &[0x41], &deployer_bytes]))?)
+        }
+        (_, _, Some(_)) => {
+            return Err(SCypherError::crypto(format!("{} deployments use the account nonce, not a transaction id", name)));
+        }
+        (_, Some(nonce), None) => Some(encode_contract_address(id, &keccak256(&[&rlp_create_preimage(&deployer_bytes, nonce)]))?),
+        (_, None, None) => None,
+    };
+
+    let create2 = match (&deployment.salt, &deployment.init_code) {
+        (Some(salt), Some(init_code)) => {
+            let salt = decode_hex_field("salt", salt, Some(32))?;
+            let init_code_hash = keccak256(&[&decode_hex_field("init code", init_code, None)?]);
+            let prefix = if id == NetworkId::Tron { 0x41 } else { 0xff };
+            Some(encode_contract_address(id, &keccak256(&[&[prefix], &deployer_bytes, &salt, &init_code_hash]))?)
+        }
+        (None, None) => None,
+        _ => return Err(SCypherError::crypto("CREATE2 prediction needs both a salt and the init code")),
+    };
+
+    if create.is_none() && create2.is_none() {
+        return Err(SCypherError::crypto("Provide a nonce (or TRON transaction id) and/or a CREATE2 salt and init code"));
+    }
+
+    Ok(ContractAddressPrediction {
+        network: id.as_str().to_string(),
+        deployer: normalized,
+        create,
+        create2,
+    })
+}
+
+// =============================================================================
+// EXPORTACIÓN DE DIRECCIONES (CSV / JSON)
+// Una fila por dirección derivada (red, tipo, ruta, dirección), ordenadas por
+// red y en el orden de derivación. Solo contiene datos públicos, pero el
+// archivo se crea igualmente con permisos 0600 como el resto de exportaciones.
+// =============================================================================
+
+/// Fila de la exportación
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddressExportRow {
+    pub network: String,
+    pub address_type: String,
+    pub path: String,
+    pub address: String,
+}
+
+/// Filas de un conjunto de direcciones, ordenadas por red
+pub fn address_export_rows(addresses: &AddressSet) -> Vec<AddressExportRow> {
+    let mut networks: Vec<&NetworkId> = addresses.0.keys().collect();
+    networks.sort();
+
+    networks
+        .into_iter()
+        .flat_map(|network| {
+            addresses[*network].iter().map(move |address| AddressExportRow {
+                network: network.as_str().to_string(),
+                address_type: address.address_type.clone(),
+                path: address.path.clone(),
+                address: address.address.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Campo CSV (RFC 4180): entre comillas si contiene separador, comillas o saltos de línea
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Contenido de la exportación en el formato pedido ("csv" o "json")
+pub fn render_address_export(addresses: &AddressSet, format: &str) -> Result<String> {
+    let rows = address_export_rows(addresses);
+
+    match format.trim().to_lowercase().as_str() {
+        "csv" => {
+            let mut csv = String::from("network,address_type,path,address\n");
+            for row in &rows {
+                let fields = [&row.network, &row.address_type, &row.path, &row.address];
+                let line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv.push_str(&line.join(","));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+        "json" => serde_json::to_string_pretty(&rows)
+            .map_err(|e| SCypherError::file(format!("Cannot encode addresses: {}", e))),
+        other => Err(SCypherError::file(format!(
+            "Unsupported export format '{}'. Use csv or json",
+            other
+        ))),
+    }
+}
+
+/// Escribir las direcciones en un archivo CSV o JSON (permisos 0600 en Unix)
+///
+/// Devuelve el número de direcciones exportadas.
+pub fn export_addresses(addresses: &AddressSet, format: &str, path: &str) -> Result<usize> {
+    use std::fs;
+
+    let content = render_address_export(addresses, format)?;
+
+    fs::write(path, content)
+        .map_err(|e| SCypherError::file(format!("Cannot write file: {}", e)))?;
+
+    // Establecer permisos seguros en sistemas Unix
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| SCypherError::file(format!("Cannot set permissions: {}", e)))?;
+    }
+
+    Ok(addresses.0.values().map(Vec::len).sum())
+}
+
+// =============================================================================
+// TESTING Y VALIDACIÓN CON TEST VECTORS OFICIALES
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39_crate::{Mnemonic, Language};
+
+    // =============================================================================
+    // TEST VECTORS OFICIALES BIP39 - Mnemonic estándar de prueba
+    // =============================================================================
+
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // =============================================================================
+    // TEST VECTORS BITCOIN - Ian Coleman BIP39 Tool
+    // =============================================================================
+
+    #[test]
     fn test_bitcoin_official_test_vectors() {
         let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
         let seed = mnemonic.to_seed("");
@@ -1021,7 +3025,7 @@ mod tests {
         let seed = mnemonic.to_seed("");
         let master_key = XPrv::new(&seed).unwrap();
 
-        let addresses = derive_ethereum_addresses(&master_key, 1).unwrap();
+        let addresses = derive_ethereum_addresses(&master_key, 1, EvmPathScheme::Standard).unwrap();
 
         // Dirección verificada con MetaMask y Phantom (formato EIP-55)
         let expected_address = "0x9858EfFD232B4033E47d90003D41EC34EcaEda94";
@@ -1031,13 +3035,30 @@ mod tests {
         println!("✅ Ethereum official test vector passed: {}", addresses[0].address);
     }
 
+    #[test]
+    fn test_ethereum_path_schemes() {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
+        let master_key = XPrv::new(mnemonic.to_seed("")).unwrap();
+
+        // Ledger Live: la cuenta 0 coincide con la ruta estándar
+        let ledger = derive_ethereum_addresses(&master_key, 2, EvmPathScheme::LedgerLive).unwrap();
+        assert_eq!(ledger[0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(ledger[1].path, "m/44'/60'/1'/0/0");
+        assert_eq!(ledger[1].address, "0x78839F6054d7ed13918bAe0473BA31b1Ca9D7265");
+
+        let legacy = derive_ethereum_addresses(&master_key, 2, EvmPathScheme::LegacyMew).unwrap();
+        assert_eq!(legacy[0].path, "m/44'/60'/0'/0");
+        assert_eq!(legacy[0].address, "0xB8Fd42000d00202DCbCF5e18d6640d656345FD6A");
+        assert_eq!(legacy[1].address, "0x94381955F4028159A477a107510618aDb6B79Eb7");
+    }
+
     #[test]
     fn test_ethereum_with_bip39_passphrase() {
         let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
         let seed = mnemonic.to_seed("test");
         let master_key = XPrv::new(&seed).unwrap();
 
-        let addresses = derive_ethereum_addresses(&master_key, 1).unwrap();
+        let addresses = derive_ethereum_addresses(&master_key, 1, EvmPathScheme::Standard).unwrap();
 
         // Dirección verificada con Ian Coleman BIP39 tool usando passphrase "test"
         // Formato EIP-55 estándar compatible con todas las wallets
@@ -1048,6 +3069,44 @@ mod tests {
         println!("✅ Ethereum BIP39 passphrase test vector passed: {}", addresses[0].address);
     }
 
+    #[test]
+    fn test_wallet_profiles() {
+        let metamask = derive_addresses_with_profile(TEST_MNEMONIC, None, "metamask", 2).unwrap();
+        assert_eq!(metamask[NetworkId::Ethereum][0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(metamask[NetworkId::Bsc][0].chain_id, Some(56));
+        assert_eq!(
+            metamask[NetworkId::Ethereum][0].explorer_url.as_deref(),
+            Some("https://etherscan.io/address/0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+        );
+        assert_eq!(metamask[NetworkId::Ethereum][1].path, "m/44'/60'/0'/0/1");
+        assert!(metamask[NetworkId::Bitcoin].is_empty());
+
+        // Ledger Live: el índice es la cuenta; la cuenta 0 coincide con la ruta estándar
+        let ledger = derive_addresses_with_profile(TEST_MNEMONIC, None, "Ledger Live", 2).unwrap();
+        assert_eq!(ledger[NetworkId::Ethereum][0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(ledger[NetworkId::Ethereum][1].path, "m/44'/60'/1'/0/0");
+        assert_eq!(ledger[NetworkId::Tron][0].address, "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+
+        // Vector oficial BIP86 (Taproot) y BIP84
+        let trezor = derive_addresses_with_profile(TEST_MNEMONIC, None, "trezor-suite", 1).unwrap();
+        let taproot = trezor[NetworkId::Bitcoin].iter().find(|a| a.path == "m/86'/0'/0'/0/0").unwrap();
+        assert_eq!(taproot.address, "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+        assert_eq!(trezor[NetworkId::Bitcoin][0].address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert!(trezor[NetworkId::Litecoin][0].address.starts_with("ltc1q"));
+
+        assert!(derive_addresses_with_profile(TEST_MNEMONIC, None, "Unknown Wallet", 1).is_err());
+    }
+
+    #[test]
+    fn test_key_access_helpers_match_derivation() {
+        let master_key = master_key_from_phrase(TEST_MNEMONIC, None).unwrap();
+        let key = derive_key_at_path(&master_key, "m/44'/60'/0'/0/0").unwrap();
+
+        // Debe coincidir con la dirección del test vector oficial de Ethereum
+        assert_eq!(evm_address_from_key(&key).unwrap(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert!(derive_key_at_path(&master_key, "m/invalid").is_err());
+    }
+
     // =============================================================================
     // TEST VECTORS TRON - Ian Coleman BIP39 Tool
     // =============================================================================
@@ -1156,6 +3215,216 @@ mod tests {
         println!("✅ Litecoin BIP39 passphrase test vector passed: {}", addresses[0].address);
     }
 
+    #[test]
+    fn test_litecoin_segwit_test_vectors() {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
+        let seed = mnemonic.to_seed("");
+        let master_key = XPrv::new(seed).unwrap();
+
+        let addresses = derive_litecoin_addresses(&master_key, 2).unwrap();
+        assert_eq!(addresses.len(), 6);
+
+        // Direcciones verificadas con Ian Coleman BIP39 tool (pestañas BIP49 y BIP84)
+        assert_eq!(addresses[1].path, "m/49'/2'/0'/0/0");
+        assert_eq!(addresses[1].address, "M7wtsL7wSHDBJVMWWhtQfTMSYYkyooAAXM");
+        assert_eq!(addresses[2].path, "m/84'/2'/0'/0/0");
+        assert_eq!(addresses[2].address, "ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh");
+        assert_eq!(addresses[5].address, "ltc1qwlezpr3890hcp6vva9twqh27mr6edadreqvhnn");
+
+        for address in &addresses {
+            assert!(validate_address("litecoin", &address.address).unwrap().valid);
+        }
+    }
+
+    // =============================================================================
+    // TEST VECTORS BITCOIN CASH - Especificación CashAddr e Ian Coleman BIP39 Tool
+    // =============================================================================
+
+    #[test]
+    fn test_cashaddr_spec_vectors() {
+        // Vectores de la especificación CashAddr (mismo hash160 en P2PKH y P2SH)
+        let legacy = bs58::decode("1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu").into_vec().unwrap();
+        let hash160: [u8; 20] = legacy[1..21].try_into().unwrap();
+        assert_eq!(cashaddr_encode(&hash160, 0), "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a");
+        assert_eq!(cashaddr_encode(&hash160, 1), "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq");
+
+        assert_eq!(cashaddr_decode("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap(), (0, hash160));
+        assert_eq!(cashaddr_decode("BITCOINCASH:PPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVN0H829PQ").unwrap(), (1, hash160));
+        assert!(cashaddr_decode("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b").is_err());
+        assert!(cashaddr_decode("bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_cash_test_vector() {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
+        let seed = mnemonic.to_seed("");
+        let master_key = XPrv::new(seed).unwrap();
+
+        let addresses = derive_bitcoin_cash_addresses(&master_key, 1).unwrap();
+        // Dirección verificada con Ian Coleman BIP39 tool (BCH, formato CashAddr)
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].path, "m/44'/145'/0'/0/0");
+        assert_eq!(addresses[0].address, "bitcoincash:qqyx49mu0kkn9ftfj6hje6g2wfer34yfnq5tahq3q6");
+        assert_eq!(addresses[1].address, "1mW6fDEMjKrDHvLvoEsaeLxSCzZBf3Bfg");
+
+        for address in &addresses {
+            let validation = validate_address("bitcoincash", &address.address).unwrap();
+            assert!(validation.valid, "{}", validation.message);
+        }
+    }
+
+    // =============================================================================
+    // TEST VECTORS STELLAR (SEP-0005) Y TON (wallet v4R2)
+    // =============================================================================
+
+    #[test]
+    fn test_stellar_sep0005_vectors() {
+        // SEP-0005, vector 1 (12 palabras, sin passphrase)
+        let mnemonic = "illness spike retreat truth genius clock brain pass fit cave bargain toe";
+        let addresses = derive_stellar_addresses(mnemonic, None, 2).unwrap();
+
+        assert_eq!(addresses[0].path, "m/44'/148'/0'");
+        assert_eq!(addresses[0].address, "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6");
+        assert_eq!(addresses[1].address, "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX");
+
+        // La passphrase cambia la cuenta
+        let with_pass = derive_stellar_addresses(mnemonic, Some("test"), 1).unwrap();
+        assert_ne!(with_pass[0].address, addresses[0].address);
+
+        assert!(validate_address("stellar", &addresses[0].address).unwrap().valid);
+        assert!(!validate_address("stellar", "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ7").unwrap().valid);
+    }
+
+    #[test]
+    fn test_liquid_slip77_vectors() {
+        // Vector de SLIP-0077 ("all all ..."), dirección confidencial P2PKH de Elements regtest
+        let seed = bip39_crate::Mnemonic::parse("all all all all all all all all all all all all").unwrap().to_seed("");
+        let master_blinding_key = slip77_master_blinding_key(&seed).unwrap();
+        assert_eq!(hex::encode(master_blinding_key), "6c2de18eabeff3f7822bc724ad482bef0557f3e1c1e1c75b7a393a5ced4de616");
+
+        let script_pubkey = hex::decode("76a914a579388225827d9f2fe9014add644487808c695d88ac").unwrap();
+        let mut confidential = vec![0x04, 0xeb];
+        confidential.extend_from_slice(&slip77_blinding_public_key(&master_blinding_key, &script_pubkey).unwrap());
+        confidential.extend_from_slice(&script_pubkey[3..23]);
+        assert_eq!(
+            bitcoin::base58::encode_check(&confidential),
+            "CTEkf75DFff5ReB7juTg2oehrj41aMj21kvvJaQdWsEAQohz1EDhu7Ayh6goxpz3GZRVKidTtaXaXYEJ"
+        );
+    }
+
+    #[test]
+    fn test_liquid_addresses() {
+        // Vectores de una implementación independiente (BIP32 + SLIP-77 + blech32) comprobada con el vector de SLIP-0077
+        let addresses = derive_liquid_addresses(TEST_MNEMONIC, None, 1).unwrap();
+        let expected = [
+            ("m/49'/1776'/0'/0/0", "VJLCQwwG8s7qUGhpJkQpkf7wLoK785TcK2cPqka8675FeJB7NEHLto5MUJyhJURGJCbFHA6sb6rgTwbh"),
+            ("m/49'/1776'/0'/0/0", "Gyzr4nj1BLpKaEQdnpRazFP7tCefPmnK5M"),
+            ("m/84'/1776'/0'/0/0", "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv"),
+            ("m/84'/1776'/0'/0/0", "ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a"),
+        ];
+        assert_eq!(addresses.len(), expected.len());
+        for (address, (path, value)) in addresses.iter().zip(expected) {
+            assert_eq!(address.path, path);
+            assert_eq!(address.address, value);
+            assert!(validate_address("liquid", &address.address).unwrap().valid, "{}", value);
+        }
+
+        // La passphrase cambia también la clave de cegado
+        let with_pass = derive_liquid_addresses(TEST_MNEMONIC, Some("TREZOR"), 1).unwrap();
+        assert_eq!(with_pass[0].address, "VJLJSuiDERnUVTBA4niS2LCKzEtm7hWqx7NQ7jJf8PbeyLSm4FDagpihNQvf8s2bjzr8aqpvs2cH4Q3o");
+        assert_eq!(with_pass[2].address, "lq1qqd59jmp0k8t7u7uzw4feafhgjlyrf9yr6pv0aac9xj4xzd8sw558fp6pq68u394qjw9l9ysa60nkkz45up2akutjnqh8zfwzx");
+
+        let mut corrupted = expected[2].1.to_string();
+        corrupted.replace_range(corrupted.len() - 1.., "w");
+        assert!(!validate_address("liquid", &corrupted).unwrap().valid);
+        assert!(!validate_address("liquid", "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").unwrap().valid);
+    }
+
+    #[test]
+    fn test_zcash_transparent_addresses() {
+        // Vectores de una implementación independiente (BIP32 + Base58Check con prefijo 0x1cb8)
+        let master_key = XPrv::new(bip39_crate::Mnemonic::parse(TEST_MNEMONIC).unwrap().to_seed("")).unwrap();
+        let addresses = derive_zcash_addresses(&master_key, 2).unwrap();
+        assert_eq!(addresses[0].path, "m/44'/133'/0'/0/0");
+        assert_eq!(addresses[0].address, "t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F");
+        assert_eq!(addresses[1].address, "t1aQ2b1XszNVo15BguYLbQGqETBL9QZA8Jq");
+        assert!(addresses[0].address_type.starts_with("Transparent"));
+
+        let validation = validate_address("zcash", &addresses[0].address).unwrap();
+        assert_eq!(validation.format.as_deref(), Some("p2pkh"));
+        assert!(validate_address("zcash", "t3JZcvsuaXE6ygokL4XUiZSTrQBUoPYFnXJ").unwrap().valid);
+        assert!(!validate_address("zcash", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA").unwrap().valid);
+        assert!(!validate_address("zcash", "zs1z7rejlpsa98s2rrrfkwmaxu53e4ue0ulcrw0h4x5g8jl04tak0d3mm47vdtahatqrlkngh9sly").unwrap().valid);
+    }
+
+    #[test]
+    fn test_ton_wallet_v4r2_vectors() {
+        // Hash del código oficial del wallet v4R2
+        let code = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, TON_WALLET_V4R2_CODE).unwrap();
+        assert_eq!(
+            hex::encode(ton_boc_root_hash(&code).unwrap().hash),
+            "feb5ff6820e2ff0d9483e7e0d62c817d846789fb4ae580c878866d959dabd5c0"
+        );
+
+        // Vector de Trust Wallet Core (TheOpenNetwork, wallet v4R2)
+        let public_key: [u8; 32] = hex::decode("f42c77f931bea20ec5d0150731276bbb2e2860947661245b2319ef8133ee8d41")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            ton_wallet_v4r2_address(&public_key, true).unwrap(),
+            "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q"
+        );
+        assert_eq!(
+            ton_wallet_v4r2_address(&public_key, false).unwrap(),
+            "UQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts4DV"
+        );
+
+        let addresses = derive_ton_addresses(TEST_MNEMONIC, 2).unwrap();
+        assert_eq!(addresses[0].path, "m/44'/607'/0'");
+        for address in &addresses {
+            let validation = validate_address("ton", &address.address).unwrap();
+            assert_eq!(validation.format.as_deref(), Some("non_bounceable"));
+        }
+        assert!(!validate_address("ton", "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90R").unwrap().valid);
+    }
+
+    #[test]
+    fn test_network_wif_version_bytes() {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, TEST_MNEMONIC).unwrap();
+        let master_key = XPrv::new(mnemonic.to_seed("")).unwrap();
+
+        // WIF verificado con Ian Coleman BIP39 tool (DOGE, m/44'/3'/0'/0/0)
+        let key = derive_key_at_path(&master_key, "m/44'/3'/0'/0/0").unwrap();
+        let secret: [u8; 32] = key.private_key().to_bytes().into();
+        let wif = encode_wif(&secret, NetworkId::Dogecoin).unwrap();
+        assert_eq!(wif, "QPkeC1ZfHx3c9g7WTj9cQ8gnvk2iSAfAcbq1aVAWjNTwDAKfZUzx");
+
+        let decoded = decode_wif(&wif).unwrap();
+        assert_eq!(decoded.network, NetworkId::Dogecoin);
+        assert_eq!(decoded.secret, secret);
+        assert!(decoded.compressed);
+
+        // Vector clásico de Bitcoin (clave sin comprimir)
+        let uncompressed = decode_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap();
+        assert_eq!(uncompressed.network, NetworkId::Bitcoin);
+        assert!(!uncompressed.compressed);
+        assert_eq!(
+            hex::encode(uncompressed.secret),
+            "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d"
+        );
+
+        // Importación: la dirección coincide con la derivada
+        let info = inspect_wif(&wif).unwrap();
+        assert_eq!(info.network, "dogecoin");
+        assert_eq!(info.address, p2pkh_address(&key.public_key().to_bytes(), 0x1e));
+        assert_eq!(inspect_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap().address, "1GAehh7TsJAHuUAeKZcXf5CnwuGuGgyX2S");
+
+        assert_eq!(decode_wif(&encode_wif(&secret, NetworkId::Litecoin).unwrap()).unwrap().network, NetworkId::Litecoin);
+        assert!(encode_wif(&secret, NetworkId::Ethereum).is_err());
+        assert!(decode_wif("QPkeC1ZfHx3c9g7WTj9cQ8gnvk2iSAfAcbq1aVAWjNTwDAKfZUzy").is_err());
+    }
+
     // =============================================================================
     // TEST VECTORS BSC/POLYGON - Ian Coleman BIP39 Tool (same as Ethereum)
     // =============================================================================
@@ -1203,16 +3472,17 @@ mod tests {
     // =============================================================================
 
     #[test]
+    #[cfg(feature = "network-ergo")]
     fn test_ergo_satergo_test_vectors() {
         // Test sin passphrase
-        let addresses_no_pass = derive_ergo_addresses(TEST_MNEMONIC, None, 1).unwrap();
+        let addresses_no_pass = derive_ergo_addresses(TEST_MNEMONIC, None, 0, 1).unwrap();
         let expected_no_pass = "9fv2n41gttbUx8oqqhexi68qPfoETFPxnLEEbTfaTk4SmY2knYC";
 
         assert_eq!(addresses_no_pass[0].address, expected_no_pass);
         println!("✅ Ergo SATERGO test vector (no passphrase) passed: {}", addresses_no_pass[0].address);
 
         // Test con passphrase "test"
-        let addresses_with_pass = derive_ergo_addresses(TEST_MNEMONIC, Some("test"), 1).unwrap();
+        let addresses_with_pass = derive_ergo_addresses(TEST_MNEMONIC, Some("test"), 0, 1).unwrap();
         let expected_with_pass = "9hqHAeSrCtq8p5WP8tPokBBeiC1uh6Vp42eRwvoNfaQYT1kaa6X";
 
         assert_eq!(addresses_with_pass[0].address, expected_with_pass);
@@ -1224,8 +3494,9 @@ mod tests {
     // =============================================================================
 
     #[test]
+    #[cfg(feature = "network-cardano")]
     fn test_cardano_eternl_test_vector() {
-        let addresses = derive_cardano_addresses_official(TEST_MNEMONIC, None, 1).unwrap();
+        let addresses = derive_cardano_addresses_official(TEST_MNEMONIC, None, 0, 1).unwrap();
 
         // Dirección verificada con Eternl wallet
         let expected_address = "addr1qy8ac7qqy0vtulyl7wntmsxc6wex80gvcyjy33qffrhm7sh927ysx5sftuw0dlft05dz3c7revpf7jx0xnlcjz3g69mq4afdhv";
@@ -1235,13 +3506,33 @@ mod tests {
         println!("✅ Cardano Eternl test vector passed: {}", addresses[0].address);
     }
 
+    #[test]
+    #[cfg(all(feature = "network-ergo", feature = "network-cardano"))]
+    fn test_ergo_cardano_account_index() {
+        let ergo = derive_ergo_addresses(TEST_MNEMONIC, None, 1, 2).unwrap();
+        assert_eq!(ergo[1].path, "m/44'/429'/1'/0/1");
+        assert_eq!(ergo[1].address_type, "Ergo (account 1) #1");
+        assert_ne!(ergo[0].address, derive_ergo_addresses(TEST_MNEMONIC, None, 0, 1).unwrap()[0].address);
+
+        // Cada cuenta de Cardano tiene su propia staking key: cambia también la parte de staking
+        let account_0 = derive_cardano_addresses_official(TEST_MNEMONIC, None, 0, 1).unwrap();
+        let account_1 = derive_cardano_addresses_official(TEST_MNEMONIC, None, 1, 1).unwrap();
+        assert_eq!(account_1[0].path, "m/1852'/1815'/1'/0/0");
+        let staking_part = |address: &str| address[address.len() - 60..].to_string();
+        assert_ne!(staking_part(&account_0[0].address), staking_part(&account_1[0].address));
+
+        assert!(derive_ergo_addresses(TEST_MNEMONIC, None, 0x80_00_00_00, 1).is_err());
+        assert!(derive_cardano_addresses_official(TEST_MNEMONIC, None, 0x80_00_00_00, 1).is_err());
+    }
+
     // =============================================================================
     // TEST VECTORS SOLANA - Phantom Wallet
     // =============================================================================
 
     #[test]
+    #[cfg(feature = "network-solana")]
     fn test_solana_phantom_test_vector() {
-        let addresses = derive_solana_from_mnemonic_direct(TEST_MNEMONIC, None, 1).unwrap();
+        let addresses = derive_solana_from_mnemonic_direct(TEST_MNEMONIC, None, 1, SolanaScheme::Phantom).unwrap();
 
         // Dirección verificada con Phantom wallet
         let expected_address = "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk";
@@ -1251,6 +3542,32 @@ mod tests {
         println!("✅ Solana Phantom test vector passed: {}", addresses[0].address);
     }
 
+    #[test]
+    #[cfg(feature = "network-solana")]
+    fn test_solana_scheme_vectors() {
+        let derive = |scheme| derive_solana_from_mnemonic_direct(TEST_MNEMONIC, None, 2, scheme).unwrap();
+
+        let phantom = derive(SolanaScheme::Phantom);
+        assert_eq!(phantom[1].path, "m/44'/501'/1'/0'");
+        assert_eq!(phantom[1].address, "Hh8QwFUA6MtVu1qAoq12ucvFHNwCcVTV7hpWjeY1Hztb");
+
+        let solflare = derive(SolanaScheme::Solflare);
+        assert_eq!(solflare[0].path, "m/44'/501'/0'/0'/0'");
+        assert_eq!(solflare[0].address, "B9sVeu4rJU12oUrUtzjc6BSNuEXdfvurZkdcaTVkP2LY");
+        assert_eq!(solflare[1].address, "634j9U9kjxbM8TmPzNCRQhjeENowxtAYC86Pwy2eGcje");
+
+        let ledger = derive(SolanaScheme::LedgerLive);
+        assert_eq!(ledger[0].path, "m/44'/501'/0'");
+        assert_eq!(ledger[0].address, "GjJyeC1r2RgkuoCWMyPYkCWSGSGLcz266EaAkLA27AhL");
+        assert_eq!(ledger[1].address, "ANf3TEKFL6jPWjzkndo4CbnNdUNkBk4KHPggJs2nu8Xi");
+
+        // Sin derivación: una sola dirección aunque se pidan más
+        let root = derive(SolanaScheme::RootSeed);
+        assert_eq!(root.len(), 1);
+        assert_eq!(root[0].path, "m");
+        assert_eq!(root[0].address, "EHqmfkN89RJ7Y33CXM6uCzhVeuywHoJXZZLszBHHZy7o");
+    }
+
     // =============================================================================
     // TESTS DE FUNCIONALIDAD GENERAL
     // =============================================================================
@@ -1266,17 +3583,21 @@ mod tests {
         assert!(network_supports_passphrase("bsc"));
         assert!(network_supports_passphrase("polygon"));
         assert!(network_supports_passphrase("ergo"));
+        assert!(network_supports_passphrase("bitcoincash"));
 
         assert!(!network_supports_passphrase("cardano"));
         assert!(!network_supports_passphrase("solana"));
+        assert!(network_supports_passphrase("stellar"));
+        assert!(!network_supports_passphrase("ton"));
 
         println!("✅ Passphrase support detection test passed");
     }
 
     #[test]
+    #[cfg(all(feature = "network-ergo", feature = "network-cardano", feature = "network-solana"))]
     fn test_all_networks_standard_seed() {
         // Test integral que verifica que todas las redes generen direcciones válidas
-        let mut network_configs = std::collections::HashMap::new();
+        let mut network_configs = HashMap::new();
 
         let networks = ["bitcoin", "ethereum", "tron", "litecoin", "dogecoin",
                        "bsc", "polygon", "cardano", "solana", "ergo"];
@@ -1285,34 +3606,37 @@ mod tests {
             network_configs.insert(network.to_string(), NetworkConfig {
                 count: 1,
                 use_passphrase: false,
+                solana_scheme: SolanaScheme::default(),
+                evm_scheme: EvmPathScheme::default(),
+                account: 0,
             });
         }
 
         let result = derive_addresses_with_config(TEST_MNEMONIC, None, network_configs).unwrap();
 
         // Verificar que todas las redes generaron direcciones
-        assert!(!result.bitcoin.is_empty());
-        assert!(!result.ethereum.is_empty());
-        assert!(!result.tron.is_empty());
-        assert!(!result.litecoin.is_empty());
-        assert!(!result.dogecoin.is_empty());
-        assert!(!result.bsc.is_empty());
-        assert!(!result.polygon.is_empty());
-        assert!(!result.cardano.is_empty());
-        assert!(!result.solana.is_empty());
-        assert!(!result.ergo.is_empty());
+        assert!(!result[NetworkId::Bitcoin].is_empty());
+        assert!(!result[NetworkId::Ethereum].is_empty());
+        assert!(!result[NetworkId::Tron].is_empty());
+        assert!(!result[NetworkId::Litecoin].is_empty());
+        assert!(!result[NetworkId::Dogecoin].is_empty());
+        assert!(!result[NetworkId::Bsc].is_empty());
+        assert!(!result[NetworkId::Polygon].is_empty());
+        assert!(!result[NetworkId::Cardano].is_empty());
+        assert!(!result[NetworkId::Solana].is_empty());
+        assert!(!result[NetworkId::Ergo].is_empty());
 
         println!("✅ All networks standard seed test passed");
-        println!("   Bitcoin:  {}", result.bitcoin[0].address);
-        println!("   Ethereum: {}", result.ethereum[0].address);
-        println!("   TRON:     {}", result.tron[0].address);
-        println!("   Litecoin: {}", result.litecoin[0].address);
-        println!("   Dogecoin: {}", result.dogecoin[0].address);
-        println!("   BSC:      {}", result.bsc[0].address);
-        println!("   Polygon:  {}", result.polygon[0].address);
-        println!("   Cardano:  {}...", &result.cardano[0].address[..20]);
-        println!("   Solana:   {}", result.solana[0].address);
-        println!("   Ergo:     {}", result.ergo[0].address);
+        println!("   Bitcoin:  {}", result[NetworkId::Bitcoin][0].address);
+        println!("   Ethereum: {}", result[NetworkId::Ethereum][0].address);
+        println!("   TRON:     {}", result[NetworkId::Tron][0].address);
+        println!("   Litecoin: {}", result[NetworkId::Litecoin][0].address);
+        println!("   Dogecoin: {}", result[NetworkId::Dogecoin][0].address);
+        println!("   BSC:      {}", result[NetworkId::Bsc][0].address);
+        println!("   Polygon:  {}", result[NetworkId::Polygon][0].address);
+        println!("   Cardano:  {}...", &result[NetworkId::Cardano][0].address[..20]);
+        println!("   Solana:   {}", result[NetworkId::Solana][0].address);
+        println!("   Ergo:     {}", result[NetworkId::Ergo][0].address);
     }
 
     #[test]
@@ -1322,7 +3646,7 @@ mod tests {
         let master_key = XPrv::new(&seed).unwrap();
 
         // Test con múltiples direcciones
-        let ethereum_addresses = derive_ethereum_addresses(&master_key, 5).unwrap();
+        let ethereum_addresses = derive_ethereum_addresses(&master_key, 5, EvmPathScheme::Standard).unwrap();
         assert_eq!(ethereum_addresses.len(), 5);
 
         let tron_addresses = derive_tron_addresses(&master_key, 3).unwrap();
@@ -1340,27 +3664,29 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "network-ergo")]
     fn test_passphrase_differences() {
         // Test para redes que soportan passphrase
         let mut config = std::collections::HashMap::new();
-        config.insert("ethereum".to_string(), NetworkConfig { count: 1, use_passphrase: true });
-        config.insert("ergo".to_string(), NetworkConfig { count: 1, use_passphrase: true });
+        config.insert("ethereum".to_string(), NetworkConfig { count: 1, use_passphrase: true, solana_scheme: SolanaScheme::default(), evm_scheme: EvmPathScheme::default(), account: 0 });
+        config.insert("ergo".to_string(), NetworkConfig { count: 1, use_passphrase: true, solana_scheme: SolanaScheme::default(), evm_scheme: EvmPathScheme::default(), account: 0 });
 
         let result_no_pass = derive_addresses_with_config(TEST_MNEMONIC, None, config.clone()).unwrap();
         let result_with_pass = derive_addresses_with_config(TEST_MNEMONIC, Some("test"), config).unwrap();
 
         // Las direcciones deben ser diferentes
-        assert_ne!(result_no_pass.ethereum[0].address, result_with_pass.ethereum[0].address);
-        assert_ne!(result_no_pass.ergo[0].address, result_with_pass.ergo[0].address);
+        assert_ne!(result_no_pass[NetworkId::Ethereum][0].address, result_with_pass[NetworkId::Ethereum][0].address);
+        assert_ne!(result_no_pass[NetworkId::Ergo][0].address, result_with_pass[NetworkId::Ergo][0].address);
 
         println!("✅ Passphrase differences test passed");
-        println!("   Ethereum without passphrase: {}", result_no_pass.ethereum[0].address);
-        println!("   Ethereum with passphrase:    {}", result_with_pass.ethereum[0].address);
-        println!("   Ergo without passphrase:     {}", result_no_pass.ergo[0].address);
-        println!("   Ergo with passphrase:        {}", result_with_pass.ergo[0].address);
+        println!("   Ethereum without passphrase: {}", result_no_pass[NetworkId::Ethereum][0].address);
+        println!("   Ethereum with passphrase:    {}", result_with_pass[NetworkId::Ethereum][0].address);
+        println!("   Ergo without passphrase:     {}", result_no_pass[NetworkId::Ergo][0].address);
+        println!("   Ergo with passphrase:        {}", result_with_pass[NetworkId::Ergo][0].address);
     }
 
     #[test]
+    #[cfg(feature = "network-ergo")]
     fn test_bip39_passphrase_comprehensive_validation() {
         // Test completo que valida todas las redes que soportan passphrase
         println!("🔐 BIP39 Passphrase Comprehensive Validation");
@@ -1372,7 +3698,10 @@ mod tests {
             let mut config = std::collections::HashMap::new();
             config.insert(network.to_string(), NetworkConfig {
                 count: 1,
-                use_passphrase: true
+                use_passphrase: true,
+                solana_scheme: SolanaScheme::default(),
+                evm_scheme: EvmPathScheme::default(),
+                account: 0,
             });
 
             let result_no_pass = derive_addresses_with_config(TEST_MNEMONIC, None, config.clone()).unwrap();
@@ -1380,26 +3709,26 @@ mod tests {
 
             // Verificar que las direcciones sean diferentes con passphrase
             let addr_no_pass = match network {
-                &"bitcoin" => &result_no_pass.bitcoin[0].address,
-                &"ethereum" => &result_no_pass.ethereum[0].address,
-                &"tron" => &result_no_pass.tron[0].address,
-                &"litecoin" => &result_no_pass.litecoin[0].address,
-                &"dogecoin" => &result_no_pass.dogecoin[0].address,
-                &"bsc" => &result_no_pass.bsc[0].address,
-                &"polygon" => &result_no_pass.polygon[0].address,
-                &"ergo" => &result_no_pass.ergo[0].address,
+                &"bitcoin" => &result_no_pass[NetworkId::Bitcoin][0].address,
+                &"ethereum" => &result_no_pass[NetworkId::Ethereum][0].address,
+                &"tron" => &result_no_pass[NetworkId::Tron][0].address,
+                &"litecoin" => &result_no_pass[NetworkId::Litecoin][0].address,
+                &"dogecoin" => &result_no_pass[NetworkId::Dogecoin][0].address,
+                &"bsc" => &result_no_pass[NetworkId::Bsc][0].address,
+                &"polygon" => &result_no_pass[NetworkId::Polygon][0].address,
+                &"ergo" => &result_no_pass[NetworkId::Ergo][0].address,
                 _ => panic!("Network not supported"),
             };
 
             let addr_with_pass = match network {
-                &"bitcoin" => &result_with_pass.bitcoin[0].address,
-                &"ethereum" => &result_with_pass.ethereum[0].address,
-                &"tron" => &result_with_pass.tron[0].address,
-                &"litecoin" => &result_with_pass.litecoin[0].address,
-                &"dogecoin" => &result_with_pass.dogecoin[0].address,
-                &"bsc" => &result_with_pass.bsc[0].address,
-                &"polygon" => &result_with_pass.polygon[0].address,
-                &"ergo" => &result_with_pass.ergo[0].address,
+                &"bitcoin" => &result_with_pass[NetworkId::Bitcoin][0].address,
+                &"ethereum" => &result_with_pass[NetworkId::Ethereum][0].address,
+                &"tron" => &result_with_pass[NetworkId::Tron][0].address,
+                &"litecoin" => &result_with_pass[NetworkId::Litecoin][0].address,
+                &"dogecoin" => &result_with_pass[NetworkId::Dogecoin][0].address,
+                &"bsc" => &result_with_pass[NetworkId::Bsc][0].address,
+                &"polygon" => &result_with_pass[NetworkId::Polygon][0].address,
+                &"ergo" => &result_with_pass[NetworkId::Ergo][0].address,
                 _ => panic!("Network not supported"),
             };
 
@@ -1411,4 +3740,386 @@ mod tests {
 
         println!("✅ BIP39 Passphrase comprehensive validation passed");
     }
+
+    // =============================================================================
+    // CONTRATO "SIN FUGAS" - NINGUNA DERIVACIÓN ESCRIBE MATERIAL DE CLAVE
+    // =============================================================================
+
+    /// Variable que convierte `leak_contract_child` en el proceso observado
+    const LEAK_CHILD_ENV: &str = "SCYPHER_LEAK_CONTRACT_CHILD";
+
+    /// Secuencias hexadecimales de longitud de clave (16 bytes o más)
+    ///
+    /// Las direcciones EVM (0x + 40 hex) son públicas y se excluyen.
+    fn key_length_hex(output: &str) -> Vec<String> {
+        let mut found = Vec::new();
+        let mut run = String::new();
+        let mut previous = String::new();
+
+        for c in output.chars().chain(std::iter::once(' ')) {
+            if c.is_ascii_hexdigit() {
+                run.push(c);
+                continue;
+            }
+            let evm_address = run.len() == 40 && previous.ends_with("0x");
+            if run.len() >= 32 && !evm_address {
+                found.push(run.clone());
+            }
+            previous.push_str(&run);
+            previous.push(c);
+            run.clear();
+        }
+        found
+    }
+
+    /// Proceso hijo: deriva todas las redes con registro a nivel TRACE sin redacción
+    #[test]
+    fn leak_contract_child() {
+        if std::env::var(LEAK_CHILD_ENV).is_err() {
+            return;
+        }
+
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(tracing_subscriber::filter::LevelFilter::TRACE)
+            .with_writer(std::io::stderr)
+            .try_init();
+
+        for spec in NETWORK_REGISTRY {
+            for passphrase in [None, Some("test")] {
+                // Los errores no importan aquí; solo la salida producida
+                let _ = derive_addresses(TEST_MNEMONIC, passphrase, &[spec.key.to_string()]);
+            }
+        }
+        for scheme in [SolanaScheme::Phantom, SolanaScheme::Solflare, SolanaScheme::LedgerLive, SolanaScheme::RootSeed] {
+            let _ = derive_solana_from_mnemonic_direct(TEST_MNEMONIC, None, 2, scheme);
+        }
+    }
+
+    #[test]
+    fn test_no_leak_output_contract() {
+        assert_eq!(key_length_hex("entropy 00000000000000000000000000000000"), vec!["0".repeat(32)]);
+        assert!(key_length_hex("0x9858EfFD232B4033E47d90003D41EC34EcaEda94 index 0x8000002c").is_empty());
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["addresses::tests::leak_contract_child", "--exact", "--nocapture", "--test-threads=1"])
+            .env(LEAK_CHILD_ENV, "1")
+            .output()
+            .expect("cannot run leak contract child");
+        assert!(output.status.success(), "leak contract child failed");
+
+        let captured = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(captured.contains("leak_contract_child"), "child test did not run");
+
+        // La entropía de TEST_MNEMONIC es todo ceros: no puede aparecer en ningún formato
+        let leaks = key_length_hex(&captured);
+        assert!(leaks.is_empty(), "derivation output leaked key-length hex: {:?}", leaks);
+    }
+
+    // =============================================================================
+    // VALIDACIÓN DE DIRECCIONES
+    // =============================================================================
+
+    #[test]
+    fn test_validate_address_formats() {
+        let valid = [
+            ("bitcoin", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA", "p2pkh"),
+            ("bitcoin", "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf", "p2sh"),
+            ("bitcoin", "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", "p2wpkh"),
+            ("bitcoin", "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr", "p2tr"),
+            ("ethereum", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94", "eip55"),
+            ("polygon", "0x9858effd232b4033e47d90003d41ec34ecaeda94", "hex"),
+            ("tron", "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH", "base58check"),
+            ("dogecoin", "DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC", "p2pkh"),
+            ("litecoin", "LUWPbpM43E2p7ZSh8cyTBEkvpHmr3cB8Ez", "p2pkh"),
+            ("solana", "B9sVeu4rJU12oUrUtzjc6BSNuEXdfvurZkdcaTVkP2LY", "base58"),
+            ("ergo", "9fv2n41gttbUx8oqqhexi68qPfoETFPxnLEEbTfaTk4SmY2knYC", "p2pk"),
+            (
+                "cardano",
+                "addr1qy8ac7qqy0vtulyl7wntmsxc6wex80gvcyjy33qffrhm7sh927ysx5sftuw0dlft05dz3c7revpf7jx0xnlcjz3g69mq4afdhv",
+                "cardano_base",
+            ),
+        ];
+        for (network, address, format) in valid {
+            let result = validate_address(network, address).unwrap();
+            assert!(result.valid, "{} {}: {}", network, address, result.message);
+            assert_eq!(result.format.as_deref(), Some(format), "{}", address);
+        }
+
+        // La forma canónica de una dirección EVM en minúsculas es EIP-55
+        let lower = validate_address("bsc", "0x9858effd232b4033e47d90003d41ec34ecaeda94").unwrap();
+        assert_eq!(lower.normalized.as_deref(), Some("0x9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+    }
+
+    #[test]
+    fn test_validate_address_rejections() {
+        let invalid = [
+            ("bitcoin", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabB"),             // Checksum
+            ("bitcoin", "DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC"),             // Otra red
+            ("bitcoin", "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyv"),     // Checksum bech32
+            ("bitcoin", "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"),     // Testnet
+            ("ethereum", "0x9858EfFD232B4033E47d90003D41EC34EcaEdA94"),    // EIP-55
+            ("ethereum", "0x9858EfFD232B4033E47d90003D41EC34EcaEda9"),     // Longitud
+            ("tron", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"),
+            ("solana", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"),
+            ("ergo", "9fv2n41gttbUx8oqqhexi68qPfoETFPxnLEEbTfaTk4SmY2knYD"),
+            (
+                "cardano",
+                "addr1qy8ac7qqy0vtulyl7wntmsxc6wex80gvcyjy33qffrhm7sh927ysx5sftuw0dlft05dz3c7revpf7jx0xnlcjz3g69mq4afdhw",
+            ),
+        ];
+        for (network, address) in invalid {
+            let result = validate_address(network, address).unwrap();
+            assert!(!result.valid, "{} {} should be rejected", network, address);
+            assert!(result.format.is_none());
+        }
+
+        assert!(validate_address("dogecoin", "DMjZienrvG6ygQ64oDUemeaaKw3NHHjcZb").unwrap().valid);
+        assert!(validate_address("monero", "4AdUndXHHZ6cfufTMvppY6JwXNouMBzSkbLYfpAV5Usx").is_err());
+    }
+
+    #[test]
+    fn test_segwit_variant_follows_witness_version() {
+        // Vectores de BIP173/BIP350 y BIP86
+        let v0 = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(segwit_address("bc", 0, &v0).unwrap(), "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+        let output_key = hex::decode("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c").unwrap();
+        let taproot = "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr";
+        assert_eq!(segwit_address("bc", 1, &output_key).unwrap(), taproot);
+        let internal_key = hex::decode("02cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115").unwrap();
+        assert_eq!(p2tr_address(&internal_key, "bc").unwrap(), taproot);
+        assert!(segwit_address("bc", 0, &[0u8; 21]).is_err());
+        assert!(segwit_address("bc", 17, &[0u8; 32]).is_err());
+
+        assert_eq!(validate_address("bitcoin", "BC1SW50QGDZ25J").unwrap().format.as_deref(), Some("segwit"));
+        let bech32_taproot = validate_address("bitcoin", "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd").unwrap();
+        assert!(!bech32_taproot.valid);
+        assert!(bech32_taproot.message.contains("bech32m"), "{}", bech32_taproot.message);
+        assert!(!validate_address("bitcoin", "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P").unwrap().valid);
+    }
+
+    #[test]
+    fn test_predict_evm_contract_addresses() {
+        // Vectores conocidos de CREATE (nonces 0, 1 y 3) y de EIP-1014 (CREATE2)
+        let deployer = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+        let expected = [
+            (0, "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (1, "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (3, "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        ];
+        for (nonce, address) in expected {
+            let deployment = ContractDeployment { nonce: Some(nonce), ..Default::default() };
+            let prediction = predict_contract_addresses("ethereum", deployer, &deployment).unwrap();
+            assert_eq!(prediction.create.unwrap().to_lowercase(), address);
+            assert!(prediction.create2.is_none());
+        }
+
+        let deployment = ContractDeployment {
+            salt: Some(format!("0x{}cafebabe", "0".repeat(56))),
+            init_code: Some("0xdeadbeef".to_string()),
+            ..Default::default()
+        };
+        let prediction = predict_contract_addresses("bsc", "0x00000000000000000000000000000000deadbeef", &deployment).unwrap();
+        assert_eq!(prediction.create2.as_deref(), Some("0x60f3f640a8508fC6a86d45DF051962668E1e8AC7"));
+
+        // Nonces grandes: RLP con prefijo de longitud
+        let deployment = ContractDeployment { nonce: Some(0x1234), ..Default::default() };
+        assert_eq!(rlp_create_preimage(&[0u8; 20], 0x1234)[22..], [0x82, 0x12, 0x34]);
+        assert!(predict_contract_addresses("polygon", deployer, &deployment).is_ok());
+    }
+
+    #[test]
+    fn test_predict_tron_contract_addresses() {
+        let deployer = "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH";
+        let txid = "0x".to_string() + &"11".repeat(32);
+
+        let deployment = ContractDeployment {
+            transaction_id: Some(txid.clone()),
+            salt: Some("22".repeat(32)),
+            init_code: Some("6080".to_string()),
+            ..Default::default()
+        };
+        let prediction = predict_contract_addresses("tron", deployer, &deployment).unwrap();
+        for address in [prediction.create.unwrap(), prediction.create2.unwrap()] {
+            assert!(validate_address("tron", &address).unwrap().valid, "{}", address);
+        }
+
+        // TRON no usa nonce; EVM no usa id de transacción; CREATE2 necesita ambos datos
+        let with_nonce = ContractDeployment { nonce: Some(0), ..Default::default() };
+        assert!(predict_contract_addresses("tron", deployer, &with_nonce).is_err());
+        let with_txid = ContractDeployment { transaction_id: Some(txid), ..Default::default() };
+        assert!(predict_contract_addresses("ethereum", "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0", &with_txid).is_err());
+        let salt_only = ContractDeployment { salt: Some("00".repeat(32)), ..Default::default() };
+        assert!(predict_contract_addresses("tron", deployer, &salt_only).is_err());
+        assert!(predict_contract_addresses("tron", deployer, &ContractDeployment::default()).is_err());
+        assert!(predict_contract_addresses("bitcoin", deployer, &with_nonce).is_err());
+    }
+
+    fn sample_address(address_type: &str, path: &str, address: &str) -> Address {
+        Address {
+            address_type: address_type.to_string(),
+            path: path.to_string(),
+            address: address.to_string(),
+            chain_id: None,
+            explorer_url: None,
+        }
+    }
+
+    #[test]
+    fn test_address_export_formats() {
+        let mut set = AddressSet::default();
+        set.list_mut(NetworkId::Solana).push(sample_address(
+            "Phantom, Solflare",
+            "m/44'/501'/0'/0'",
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk",
+        ));
+        set.list_mut(NetworkId::Bitcoin).push(sample_address(
+            "Native SegWit #0",
+            "m/84'/0'/0'/0/0",
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+        ));
+
+        // CSV: cabecera, filas ordenadas por red y campos con coma entre comillas
+        let csv = render_address_export(&set, "CSV").unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "network,address_type,path,address");
+        assert_eq!(lines[1], "bitcoin,Native SegWit #0,m/84'/0'/0'/0/0,bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert!(lines[2].starts_with("solana,\"Phantom, Solflare\","));
+        assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+
+        let rows: Vec<AddressExportRow> =
+            serde_json::from_str(&render_address_export(&set, "json").unwrap()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].network, "bitcoin");
+        assert_eq!(rows[1].address, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+
+        assert!(render_address_export(&set, "xlsx").is_err());
+    }
+
+    #[test]
+    fn test_export_addresses_file() {
+        let mut set = AddressSet::default();
+        set.list_mut(NetworkId::Ethereum).push(sample_address(
+            "Ethereum #0",
+            "m/44'/60'/0'/0/0",
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94",
+        ));
+
+        let file = std::env::temp_dir().join("scypher_address_export_test.csv");
+        let path = file.to_str().unwrap();
+        assert_eq!(export_addresses(&set, "csv", path).unwrap(), 1);
+
+        let content = std::fs::read_to_string(&file).unwrap();
+        assert!(content.contains("ethereum,Ethereum #0,m/44'/60'/0'/0/0,0x9858"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&file).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_master_fingerprint() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(master_fingerprint(phrase, None).unwrap(), "73c5da0a");
+        assert_ne!(master_fingerprint(phrase, Some("TREZOR")).unwrap(), "73c5da0a");
+    }
+
+    #[test]
+    fn test_compiled_networks() {
+        assert!(compiled_networks().any(|spec| spec.id == NetworkId::Bitcoin));
+        assert_eq!(network_compiled(NetworkId::Ergo), cfg!(feature = "network-ergo"));
+
+        // Las redes no compiladas se reconocen pero no se derivan
+        if !network_compiled(NetworkId::Cardano) {
+            let err = derive_addresses(TEST_MNEMONIC, None, &["cardano".to_string()]).unwrap_err();
+            assert!(err.to_string().contains("not available"));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "network-solana")]
+    fn test_cross_check_entries() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let entries = cross_check_entries(phrase, None).unwrap();
+
+        let segwit = entries.iter().find(|e| e.path == "m/84'/0'/0'/0/0").unwrap();
+        assert_eq!(segwit.network, NetworkId::Bitcoin);
+        assert_eq!(segwit.address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(segwit.curve, NetworkCurve::Secp256k1);
+        assert!(!segwit.reference_tools.is_empty());
+
+        // La passphrase solo consta como aplicada en las redes que la soportan
+        let entries = cross_check_entries(phrase, Some("TREZOR")).unwrap();
+        for entry in &entries {
+            assert_eq!(entry.passphrase_applied, network_spec(entry.network).supports_passphrase);
+        }
+        assert!(entries.iter().any(|e| e.network == NetworkId::Solana && !e.passphrase_applied));
+    }
+
+    // =============================================================================
+    // FRASES DE 15, 18 Y 21 PALABRAS (ERGO Y CARDANO)
+    // Entropía 0x7f repetida; direcciones calculadas con una implementación
+    // independiente (BIP32 secp256k1 / Icarus BIP32-Ed25519) que reproduce los
+    // vectores SATERGO y Eternl de 12 palabras de este módulo
+    // =============================================================================
+
+    #[test]
+    #[cfg(all(feature = "network-ergo", feature = "network-cardano"))]
+    fn test_ergo_cardano_all_word_counts() {
+        let vectors = [
+            (20usize, "9fzBcAQofre3upQ21XKRQnE22K53H8VuQoLJvs6ZytJAQWV5Ujd",
+             "addr1qx03x2lewqwjlycngp723m6ungaj644n05th4r6t58dne753zes3lwrssq7fd86y7y0evp2dfhkvwjc4gddlj8x0wxfqmt8yjz"),
+            (24, "9hZXugyHPGhdAsdDAgGEC89SnFy6PcgozmkMFTe1UZ1KkfXfiuC",
+             "addr1qx09zsxnfesmjj7ht6jcc3wfwj5lf083vz0ap9ja59k42mkrmvflpv7qtfxwmcnp97ml7e6q4y8429kcdau83n0jykfqnz8r5n"),
+            (28, "9goCfVZsyQjH8CZqhNUviiw2YcZvLCBj17eCudd3TZ7mrFRHDsC",
+             "addr1qyrc4u4rdmfllelcc3dpukqfvqr90xc6gv37e533ck9psd4q5huu5a6tsz5ux09ygadjx6hk0shgjl8ctaz6ycmfc3uqum2g4q"),
+        ];
+
+        for (len, ergo, cardano) in vectors {
+            let phrase = crate::bip39::entropy_to_phrase(&vec![0x7f; len]).unwrap();
+            assert_eq!(derive_ergo_addresses(&phrase, None, 0, 1).unwrap()[0].address, ergo, "{} bytes", len);
+            assert_eq!(derive_cardano_addresses_official(&phrase, None, 0, 1).unwrap()[0].address, cardano, "{} bytes", len);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "network-ergo", feature = "network-cardano"))]
+    fn test_all_word_counts_pipeline() {
+        for len in [16usize, 20, 24, 28, 32] {
+            // Validación -> transformación -> derivación con la frase descifrada
+            let phrase = crate::bip39::entropy_to_phrase(&vec![0x7f; len]).unwrap();
+            assert!(crate::bip39::validate_seed_phrase_complete(&phrase).is_ok());
+
+            let encrypted = crate::crypto::transform_seed(&phrase, "pipeline_password", 1, 8192).unwrap();
+            assert_eq!(encrypted.split_whitespace().count(), len * 3 / 4);
+            let decrypted = crate::crypto::transform_seed(&encrypted, "pipeline_password", 1, 8192).unwrap();
+            assert_eq!(decrypted, phrase);
+
+            let mut configs = HashMap::new();
+            for network in ["ergo", "cardano", "bitcoin"] {
+                configs.insert(network.to_string(), NetworkConfig {
+                    count: 1,
+                    use_passphrase: true,
+                    solana_scheme: SolanaScheme::default(),
+                    evm_scheme: EvmPathScheme::default(),
+                    account: 0,
+                });
+            }
+            let set = derive_addresses_with_config(&decrypted, None, configs).unwrap();
+            assert!(set[NetworkId::Ergo][0].address.starts_with('9'));
+            assert!(set[NetworkId::Cardano][0].address.starts_with("addr1"));
+        }
+
+        // Número de palabras inválido: mismo error en todas las redes
+        let eleven = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(matches!(derive_ergo_addresses(eleven, None, 0, 1), Err(SCypherError::InvalidWordCount(11))));
+        assert!(matches!(derive_cardano_addresses_official(eleven, None, 0, 1), Err(SCypherError::InvalidWordCount(11))));
+    }
 }
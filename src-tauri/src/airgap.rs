@@ -0,0 +1,746 @@
+// =============================================================================
+// TRANSFERENCIA AIR-GAP POR QR ANIMADO (BC-UR Y BBQr)
+// Codifica PSBTs, descriptores o exportaciones de direcciones en secuencias
+// de QR para moverlas entre la herramienta offline y un dispositivo online:
+// - BC-UR (BCR-2020-005): bytewords mínimos y fragmentos fountain con CRC32.
+//   El decodificador acepta también los fragmentos mezclados (XOR) que
+//   emiten Sparrow, Keystone o Passport tras la primera vuelta.
+// - BBQr (Coinkite): cabecera B$ + codificación + tipo + total + índice.
+// =============================================================================
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use crate::error::{SCypherError, Result};
+
+/// Longitud máxima de fragmento UR por defecto (bytes)
+pub const DEFAULT_UR_FRAGMENT_LEN: usize = 150;
+
+/// Caracteres máximos por QR BBQr por defecto
+pub const DEFAULT_BBQR_PART_CHARS: usize = 400;
+
+/// Datos reensamblados a partir de los QR recibidos
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirgapPayload {
+    pub format: String,            // "ur" o "bbqr"
+    pub data_type: String,         // Tipo UR ("crypto-psbt") o tipo BBQr ("P")
+    pub payload_hex: String,
+    pub payload_base64: String,
+    pub payload_text: Option<String>,  // Solo si es UTF-8 válido
+}
+
+impl AirgapPayload {
+    fn new(format: &str, data_type: String, payload: Vec<u8>) -> Self {
+        Self {
+            format: format.to_string(),
+            data_type,
+            payload_hex: hex::encode(&payload),
+            payload_base64: base64::engine::general_purpose::STANDARD.encode(&payload),
+            payload_text: String::from_utf8(payload).ok(),
+        }
+    }
+}
+
+fn invalid(message: &str) -> SCypherError {
+    SCypherError::crypto(message.to_string())
+}
+
+/// CRC32 (IEEE 802.3), usado por bytewords y por los fragmentos fountain
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+// =============================================================================
+// BYTEWORDS (BCR-2020-012)
+// =============================================================================
+
+const BYTEWORDS: [&str; 256] = [
+    "able", "acid", "also", "apex", "aqua", "arch", "atom", "aunt", "away", "axis", "back", "bald", "barn", "belt", "beta", "bias",
+    "blue", "body", "brag", "brew", "bulb", "buzz", "calm", "cash", "cats", "chef", "city", "claw", "code", "cola", "cook", "cost",
+    "crux", "curl", "cusp", "cyan", "dark", "data", "days", "deli", "dice", "diet", "door", "down", "draw", "drop", "drum", "dull",
+    "duty", "each", "easy", "echo", "edge", "epic", "even", "exam", "exit", "eyes", "fact", "fair", "fern", "figs", "film", "fish",
+    "fizz", "flap", "flew", "flux", "foxy", "free", "frog", "fuel", "fund", "gala", "game", "gear", "gems", "gift", "girl", "glow",
+    "good", "gray", "grim", "guru", "gush", "gyro", "half", "hang", "hard", "hawk", "heat", "help", "high", "hill", "holy", "hope",
+    "horn", "huts", "iced", "idea", "idle", "inch", "inky", "into", "iris", "iron", "item", "jade", "jazz", "join", "jolt", "jowl",
+    "judo", "jugs", "jump", "junk", "jury", "keep", "keno", "kept", "keys", "kick", "kiln", "king", "kite", "kiwi", "knob", "lamb",
+    "lava", "lazy", "leaf", "legs", "liar", "limp", "lion", "list", "logo", "loud", "love", "luau", "luck", "lung", "main", "many",
+    "math", "maze", "memo", "menu", "meow", "mild", "mint", "miss", "monk", "nail", "navy", "need", "news", "next", "noon", "note",
+    "numb", "obey", "oboe", "omit", "onyx", "open", "oval", "owls", "paid", "part", "peck", "play", "plus", "poem", "pool", "pose",
+    "puff", "puma", "purr", "quad", "quiz", "race", "ramp", "real", "redo", "rich", "road", "rock", "roof", "ruby", "ruin", "runs",
+    "rust", "safe", "saga", "scar", "sets", "silk", "skew", "slot", "soap", "solo", "song", "stub", "surf", "swan", "taco", "task",
+    "taxi", "tent", "tied", "time", "tiny", "toil", "tomb", "toys", "trip", "tuna", "twin", "ugly", "undo", "unit", "urge", "user",
+    "vast", "very", "veto", "vial", "vibe", "view", "visa", "void", "vows", "wall", "wand", "warm", "wasp", "wave", "waxy", "webs",
+    "what", "when", "whiz", "wolf", "work", "yank", "yawn", "yell", "yoga", "yurt", "zaps", "zero", "zest", "zinc", "zone", "zoom",
+];
+
+fn byteword(index: u8) -> &'static str {
+    BYTEWORDS[index as usize]
+}
+
+/// Forma mínima: primera y última letra de cada palabra, con CRC32 al final
+fn bytewords_minimal_encode(data: &[u8]) -> String {
+    let mut bytes = data.to_vec();
+    bytes.extend_from_slice(&crc32(data).to_be_bytes());
+    bytes
+        .iter()
+        .map(|&b| {
+            let word = byteword(b).as_bytes();
+            format!("{}{}", word[0] as char, word[3] as char)
+        })
+        .collect()
+}
+
+fn bytewords_minimal_decode(text: &str) -> Result<Vec<u8>> {
+    let text = text.to_ascii_lowercase();
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return Err(invalid("Invalid bytewords length"));
+    }
+
+    let mut lookup = HashMap::with_capacity(256);
+    for i in 0..=255u8 {
+        let word = byteword(i).as_bytes();
+        lookup.insert([word[0], word[3]], i);
+    }
+
+    let bytes: Vec<u8> = text
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| lookup.get(&[pair[0], pair[1]]).copied())
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| invalid("Invalid byteword in UR"))?;
+
+    if bytes.len() < 5 {
+        return Err(invalid("UR body too short"));
+    }
+    let (data, checksum) = bytes.split_at(bytes.len() - 4);
+    if crc32(data).to_be_bytes() != checksum {
+        return Err(invalid("UR bytewords checksum mismatch"));
+    }
+    Ok(data.to_vec())
+}
+
+// =============================================================================
+// CBOR MÍNIMO (ENTEROS, BYTE STRINGS Y ARRAYS)
+// =============================================================================
+
+fn cbor_head(major: u8, value: u64) -> Vec<u8> {
+    let major = major << 5;
+    match value {
+        0..=23 => vec![major | value as u8],
+        24..=0xFF => vec![major | 24, value as u8],
+        0x100..=0xFFFF => [vec![major | 25], (value as u16).to_be_bytes().to_vec()].concat(),
+        0x1_0000..=0xFFFF_FFFF => [vec![major | 26], (value as u32).to_be_bytes().to_vec()].concat(),
+        _ => [vec![major | 27], value.to_be_bytes().to_vec()].concat(),
+    }
+}
+
+fn cbor_bytes(data: &[u8]) -> Vec<u8> {
+    let mut out = cbor_head(2, data.len() as u64);
+    out.extend_from_slice(data);
+    out
+}
+
+/// Lector CBOR secuencial
+struct CborReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> CborReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or_else(|| invalid("Invalid CBOR length"))?;
+        let slice = self.data.get(self.pos..end).ok_or_else(|| invalid("Truncated CBOR data"))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn head(&mut self, expected_major: u8) -> Result<u64> {
+        let first = self.take(1)?[0];
+        if first >> 5 != expected_major {
+            return Err(invalid("Unexpected CBOR item"));
+        }
+        let value = match first & 0x1F {
+            info @ 0..=23 => info as u64,
+            24 => self.take(1)?[0] as u64,
+            25 => u16::from_be_bytes(self.take(2)?.try_into().unwrap()) as u64,
+            26 => u32::from_be_bytes(self.take(4)?.try_into().unwrap()) as u64,
+            27 => u64::from_be_bytes(self.take(8)?.try_into().unwrap()),
+            _ => return Err(invalid("Unsupported CBOR encoding")),
+        };
+        Ok(value)
+    }
+
+    fn uint(&mut self) -> Result<u64> {
+        self.head(0)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.head(2)? as usize;
+        self.take(len)
+    }
+
+    fn finish(&self) -> Result<()> {
+        if self.pos == self.data.len() {
+            Ok(())
+        } else {
+            Err(invalid("Trailing data after CBOR item"))
+        }
+    }
+}
+
+// =============================================================================
+// FOUNTAIN CODES (ELECCIÓN DE FRAGMENTOS COMPATIBLE CON BC-UR)
+// =============================================================================
+
+/// Generador Xoshiro256** sembrado con SHA256, como en la referencia BC-UR
+struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    fn from_seed_data(data: &[u8]) -> Self {
+        let digest = Sha256::digest(data);
+        let mut s = [0u64; 4];
+        for (i, word) in s.iter_mut().enumerate() {
+            *word = u64::from_be_bytes(digest[i * 8..i * 8 + 8].try_into().unwrap());
+        }
+        Self { s }
+    }
+
+    fn next(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    fn next_double(&mut self) -> f64 {
+        self.next() as f64 / (u64::MAX as f64 + 1.0)
+    }
+
+    fn next_int(&mut self, low: u64, high: u64) -> u64 {
+        (self.next_double() * (high - low + 1) as f64) as u64 + low
+    }
+}
+
+/// Muestreo por alias (Vose) de los grados con pesos 1/i
+fn choose_degree(seq_len: usize, rng: &mut Xoshiro256) -> usize {
+    let weights: Vec<f64> = (1..=seq_len).map(|i| 1.0 / i as f64).collect();
+    let total: f64 = weights.iter().sum();
+    let mut scaled: Vec<f64> = weights.iter().map(|w| w * seq_len as f64 / total).collect();
+
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    for i in (0..seq_len).rev() {
+        if scaled[i] < 1.0 { small.push(i) } else { large.push(i) }
+    }
+
+    let mut probs = vec![0.0; seq_len];
+    let mut aliases = vec![0usize; seq_len];
+    while let (Some(&a), Some(&g)) = (small.last(), large.last()) {
+        small.pop();
+        large.pop();
+        probs[a] = scaled[a];
+        aliases[a] = g;
+        scaled[g] += scaled[a] - 1.0;
+        if scaled[g] < 1.0 { small.push(g) } else { large.push(g) }
+    }
+    for i in large.into_iter().chain(small) {
+        probs[i] = 1.0;
+    }
+
+    let r1 = rng.next_double();
+    let r2 = rng.next_double();
+    let i = (seq_len as f64 * r1) as usize;
+    (if r2 < probs[i] { i } else { aliases[i] }) + 1
+}
+
+/// Índices de fragmentos combinados en la parte `seq_num`
+fn choose_fragments(seq_num: u32, seq_len: usize, checksum: u32) -> BTreeSet<usize> {
+    if seq_num as usize <= seq_len {
+        return BTreeSet::from([seq_num as usize - 1]);
+    }
+
+    let seed = [seq_num.to_be_bytes(), checksum.to_be_bytes()].concat();
+    let mut rng = Xoshiro256::from_seed_data(&seed);
+    let degree = choose_degree(seq_len, &mut rng);
+
+    let mut remaining: Vec<usize> = (0..seq_len).collect();
+    let mut shuffled = Vec::with_capacity(seq_len);
+    while !remaining.is_empty() {
+        let index = rng.next_int(0, remaining.len() as u64 - 1) as usize;
+        shuffled.push(remaining.remove(index));
+    }
+    shuffled.into_iter().take(degree).collect()
+}
+
+// =============================================================================
+// BC-UR
+// =============================================================================
+
+fn validate_ur_type(ur_type: &str) -> Result<String> {
+    let ur_type = ur_type.trim().to_ascii_lowercase();
+    if ur_type.is_empty() || !ur_type.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+        return Err(SCypherError::crypto(format!("Invalid UR type: {}", ur_type)));
+    }
+    Ok(ur_type)
+}
+
+/// Codificar datos como UR. El mensaje es un byte string CBOR (formato de
+/// `bytes` y `crypto-psbt`). Los QR multiparte contienen los fragmentos
+/// puros, que se muestran en bucle; en mayúsculas para el modo alfanumérico.
+pub fn encode_ur(payload: &[u8], ur_type: &str, max_fragment_len: usize) -> Result<Vec<String>> {
+    let ur_type = validate_ur_type(ur_type)?;
+    if payload.is_empty() {
+        return Err(invalid("Payload cannot be empty"));
+    }
+    if max_fragment_len < 10 {
+        return Err(invalid("Fragment length must be at least 10 bytes"));
+    }
+
+    let message = cbor_bytes(payload);
+    if message.len() <= max_fragment_len {
+        return Ok(vec![format!("ur:{}/{}", ur_type, bytewords_minimal_encode(&message)).to_uppercase()]);
+    }
+
+    let fragment_count = message.len().div_ceil(max_fragment_len);
+    let fragment_len = message.len().div_ceil(fragment_count);
+    let checksum = crc32(&message);
+
+    let frames = message
+        .chunks(fragment_len)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut fragment = chunk.to_vec();
+            fragment.resize(fragment_len, 0);
+
+            let mut part = cbor_head(4, 5);
+            part.extend(cbor_head(0, i as u64 + 1));
+            part.extend(cbor_head(0, fragment_count as u64));
+            part.extend(cbor_head(0, message.len() as u64));
+            part.extend(cbor_head(0, checksum as u64));
+            part.extend(cbor_bytes(&fragment));
+
+            format!(
+                "ur:{}/{}-{}/{}",
+                ur_type,
+                i + 1,
+                fragment_count,
+                bytewords_minimal_encode(&part)
+            )
+            .to_uppercase()
+        })
+        .collect();
+
+    Ok(frames)
+}
+
+/// Parte fountain decodificada
+struct UrPart {
+    seq_num: u32,
+    seq_len: usize,
+    message_len: usize,
+    checksum: u32,
+    fragment: Vec<u8>,
+}
+
+fn parse_ur_part(body: &[u8]) -> Result<UrPart> {
+    let mut reader = CborReader::new(body);
+    if reader.head(4)? != 5 {
+        return Err(invalid("Invalid UR part structure"));
+    }
+    let part = UrPart {
+        seq_num: u32::try_from(reader.uint()?).map_err(|_| invalid("Invalid UR sequence number"))?,
+        seq_len: reader.uint()? as usize,
+        message_len: reader.uint()? as usize,
+        checksum: u32::try_from(reader.uint()?).map_err(|_| invalid("Invalid UR checksum"))?,
+        fragment: reader.bytes()?.to_vec(),
+    };
+    reader.finish()?;
+
+    if part.seq_num == 0 || part.seq_len == 0 || part.seq_len > 10_000 || part.fragment.is_empty() {
+        return Err(invalid("Invalid UR part header"));
+    }
+    Ok(part)
+}
+
+fn xor_into(target: &mut [u8], other: &[u8]) {
+    for (a, b) in target.iter_mut().zip(other) {
+        *a ^= b;
+    }
+}
+
+/// Reensamblar un UR a partir de sus QR (en cualquier orden, con repeticiones)
+pub fn decode_ur(frames: &[String]) -> Result<AirgapPayload> {
+    let mut ur_type: Option<String> = None;
+    let mut parts = Vec::new();
+    let mut single: Option<Vec<u8>> = None;
+
+    for frame in frames {
+        let frame = frame.trim().to_ascii_lowercase();
+        if frame.is_empty() {
+            continue;
+        }
+        let rest = frame.strip_prefix("ur:").ok_or_else(|| invalid("Frame is not a UR (missing 'ur:' prefix)"))?;
+        let components: Vec<&str> = rest.split('/').collect();
+
+        let frame_type = validate_ur_type(components[0])?;
+        if ur_type.get_or_insert_with(|| frame_type.clone()) != &frame_type {
+            return Err(invalid("Frames belong to different UR types"));
+        }
+
+        match components.len() {
+            2 => single = Some(bytewords_minimal_decode(components[1])?),
+            3 => parts.push(parse_ur_part(&bytewords_minimal_decode(components[2])?)?),
+            _ => return Err(invalid("Invalid UR frame format")),
+        }
+    }
+
+    let ur_type = ur_type.ok_or_else(|| invalid("No UR frames provided"))?;
+    let message = match single {
+        Some(message) => message,
+        None => assemble_fountain_message(&parts)?,
+    };
+
+    let mut reader = CborReader::new(&message);
+    let payload = reader.bytes()?.to_vec();
+    reader.finish()?;
+
+    Ok(AirgapPayload::new("ur", ur_type, payload))
+}
+
+fn assemble_fountain_message(parts: &[UrPart]) -> Result<Vec<u8>> {
+    let first = parts.first().ok_or_else(|| invalid("No UR frames provided"))?;
+    let (seq_len, message_len, checksum, fragment_len) =
+        (first.seq_len, first.message_len, first.checksum, first.fragment.len());
+
+    if parts.iter().any(|p| {
+        p.seq_len != seq_len || p.message_len != message_len || p.checksum != checksum || p.fragment.len() != fragment_len
+    }) {
+        return Err(invalid("Frames belong to different UR messages"));
+    }
+
+    let mut simple: HashMap<usize, Vec<u8>> = HashMap::new();
+    let mut mixed: Vec<(BTreeSet<usize>, Vec<u8>)> = Vec::new();
+    let mut queue: Vec<(BTreeSet<usize>, Vec<u8>)> = parts
+        .iter()
+        .map(|p| (choose_fragments(p.seq_num, seq_len, checksum), p.fragment.clone()))
+        .collect();
+
+    // Reducir por XOR hasta que no haya más información nueva
+    while let Some((mut indexes, mut data)) = queue.pop() {
+        for index in indexes.clone() {
+            if let Some(known) = simple.get(&index) {
+                xor_into(&mut data, known);
+                indexes.remove(&index);
+            }
+        }
+
+        match indexes.len() {
+            0 => {}
+            1 => {
+                let index = *indexes.iter().next().unwrap();
+                // Los mezclados que contienen este fragmento vuelven a la cola
+                let (affected, kept): (Vec<_>, Vec<_>) = mixed.drain(..).partition(|(set, _)| set.contains(&index));
+                mixed = kept;
+                simple.insert(index, data);
+                queue.extend(affected);
+            }
+            _ => mixed.push((indexes, data)),
+        }
+    }
+
+    if simple.len() < seq_len {
+        return Err(SCypherError::crypto(format!(
+            "Incomplete UR: {} of {} fragments recovered, scan more frames",
+            simple.len(),
+            seq_len
+        )));
+    }
+
+    let mut message: Vec<u8> = (0..seq_len).flat_map(|i| simple[&i].clone()).collect();
+    message.truncate(message_len);
+    if crc32(&message) != checksum {
+        return Err(invalid("UR message checksum mismatch"));
+    }
+    Ok(message)
+}
+
+// =============================================================================
+// BBQr
+// =============================================================================
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE36_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Tipos de archivo BBQr soportados
+const BBQR_FILE_TYPES: &str = "PTJUCB";
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(5) * 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 0x1F) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+    }
+    out
+}
+
+fn base32_decode(text: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| invalid("Invalid base32 character in BBQr"))? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+fn base36_pair(value: usize) -> String {
+    format!("{}{}", BASE36_ALPHABET[value / 36] as char, BASE36_ALPHABET[value % 36] as char)
+}
+
+fn parse_base36_pair(text: &str) -> Result<usize> {
+    usize::from_str_radix(text, 36).map_err(|_| invalid("Invalid BBQr header"))
+}
+
+/// Codificar datos como BBQr (base32, sin compresión)
+pub fn encode_bbqr(payload: &[u8], file_type: char, max_part_chars: usize) -> Result<Vec<String>> {
+    let file_type = file_type.to_ascii_uppercase();
+    if !BBQR_FILE_TYPES.contains(file_type) {
+        return Err(SCypherError::crypto(format!("Unsupported BBQr file type: {}", file_type)));
+    }
+    if payload.is_empty() {
+        return Err(invalid("Payload cannot be empty"));
+    }
+    if max_part_chars < 16 {
+        return Err(invalid("BBQr part size too small"));
+    }
+
+    // Cada parte lleva un múltiplo de 8 caracteres base32 (5 bytes completos)
+    let encoded = base32_encode(payload);
+    let chars_per_part = (max_part_chars - 8) / 8 * 8;
+    let total = encoded.len().div_ceil(chars_per_part);
+    if total > 1295 {
+        return Err(invalid("Payload too large for BBQr (max 1295 parts)"));
+    }
+
+    Ok(encoded
+        .as_bytes()
+        .chunks(chars_per_part)
+        .enumerate()
+        .map(|(i, chunk)| {
+            format!(
+                "B$2{}{}{}{}",
+                file_type,
+                base36_pair(total),
+                base36_pair(i),
+                std::str::from_utf8(chunk).unwrap_or_default()
+            )
+        })
+        .collect())
+}
+
+/// Reensamblar un BBQr a partir de sus partes (en cualquier orden)
+pub fn decode_bbqr(frames: &[String]) -> Result<AirgapPayload> {
+    let mut header: Option<(char, char, usize)> = None;
+    let mut parts: HashMap<usize, String> = HashMap::new();
+
+    for frame in frames {
+        let frame = frame.trim();
+        if frame.is_empty() {
+            continue;
+        }
+        if frame.len() < 8 || !frame.starts_with("B$") || !frame.is_ascii() {
+            return Err(invalid("Frame is not a BBQr part"));
+        }
+
+        let encoding = frame.as_bytes()[2] as char;
+        let file_type = frame.as_bytes()[3] as char;
+        let total = parse_base36_pair(&frame[4..6])?;
+        let index = parse_base36_pair(&frame[6..8])?;
+
+        if header.get_or_insert((encoding, file_type, total)) != &(encoding, file_type, total) {
+            return Err(invalid("Frames belong to different BBQr files"));
+        }
+        if index >= total {
+            return Err(invalid("BBQr part index out of range"));
+        }
+        parts.insert(index, frame[8..].to_string());
+    }
+
+    let (encoding, file_type, total) = header.ok_or_else(|| invalid("No BBQr frames provided"))?;
+    if parts.len() < total {
+        return Err(SCypherError::crypto(format!(
+            "Incomplete BBQr: {} of {} parts scanned",
+            parts.len(),
+            total
+        )));
+    }
+
+    let joined: String = (0..total).map(|i| parts[&i].as_str()).collect();
+    let payload = match encoding {
+        'H' => hex::decode(&joined).map_err(|_| invalid("Invalid hex in BBQr"))?,
+        '2' => base32_decode(&joined)?,
+        'Z' => miniz_oxide::inflate::decompress_to_vec(&base32_decode(&joined)?)
+            .map_err(|_| invalid("Invalid compressed BBQr data"))?,
+        other => return Err(SCypherError::crypto(format!("Unsupported BBQr encoding: {}", other))),
+    };
+
+    Ok(AirgapPayload::new("bbqr", file_type.to_string(), payload))
+}
+
+/// Decodificar payload de texto según su representación ("utf8", "hex" o "base64")
+pub fn payload_from_text(payload: &str, encoding: &str) -> Result<Vec<u8>> {
+    match encoding {
+        "utf8" | "text" => Ok(payload.as_bytes().to_vec()),
+        "hex" => hex::decode(payload.trim().trim_start_matches("0x")).map_err(|_| invalid("Payload is not valid hex")),
+        "base64" => base64::engine::general_purpose::STANDARD
+            .decode(payload.trim())
+            .map_err(|_| invalid("Payload is not valid base64")),
+        other => Err(SCypherError::crypto(format!("Unknown payload encoding: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytewords_spec_vector() {
+        // BCR-2020-012: 00 01 02 80 ff -> "able acid also lava zoom jade need echo taxi"
+        assert_eq!(bytewords_minimal_encode(&[0x00, 0x01, 0x02, 0x80, 0xff]), "aeadaolazmjendeoti");
+        assert_eq!(bytewords_minimal_decode("AEADAOLAZMJENDEOTI").unwrap(), vec![0x00, 0x01, 0x02, 0x80, 0xff]);
+        assert!(bytewords_minimal_decode("aeadaolazmjendeota").is_err());
+    }
+
+    #[test]
+    fn test_ur_single_and_multipart_roundtrip() {
+        let small = encode_ur(b"hello", "bytes", DEFAULT_UR_FRAGMENT_LEN).unwrap();
+        assert_eq!(small.len(), 1);
+        assert!(small[0].starts_with("UR:BYTES/"));
+        assert_eq!(decode_ur(&small).unwrap().payload_text.as_deref(), Some("hello"));
+
+        let payload: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 256) as u8).collect();
+        let mut frames = encode_ur(&payload, "crypto-psbt", 100).unwrap();
+        assert_eq!(frames.len(), 11);
+        assert!(frames[0].starts_with("UR:CRYPTO-PSBT/1-11/"));
+
+        frames.reverse();
+        let decoded = decode_ur(&frames).unwrap();
+        assert_eq!(decoded.data_type, "crypto-psbt");
+        assert_eq!(hex::decode(decoded.payload_hex).unwrap(), payload);
+
+        assert!(decode_ur(&frames[..5]).is_err());
+    }
+
+    #[test]
+    fn test_fountain_mixed_fragments_recover_missing() {
+        let payload: Vec<u8> = (0..500u32).map(|i| (i % 251) as u8).collect();
+        let frames = encode_ur(&payload, "bytes", 60).unwrap();
+        let seq_len = frames.len();
+
+        // Recuperar el mensaje y fabricar partes mezcladas como las de un emisor fountain
+        let parts: Vec<UrPart> = frames
+            .iter()
+            .map(|f| parse_ur_part(&bytewords_minimal_decode(f.to_ascii_lowercase().rsplit('/').next().unwrap()).unwrap()).unwrap())
+            .collect();
+        let checksum = parts[0].checksum;
+
+        let mut available: Vec<UrPart> = parts.into_iter().skip(1).collect();
+        let mut seq_num = seq_len as u32 + 1;
+        loop {
+            let indexes = choose_fragments(seq_num, seq_len, checksum);
+            if indexes.contains(&0) {
+                let mut fragment = vec![0u8; available[0].fragment.len()];
+                let full_message = cbor_bytes(&payload);
+                for &i in &indexes {
+                    let start = i * fragment.len();
+                    let mut piece = full_message[start.min(full_message.len())..(start + fragment.len()).min(full_message.len())].to_vec();
+                    piece.resize(fragment.len(), 0);
+                    xor_into(&mut fragment, &piece);
+                }
+                available.push(UrPart { seq_num, seq_len, message_len: available[0].message_len, checksum, fragment });
+                break;
+            }
+            seq_num += 1;
+        }
+
+        let message = assemble_fountain_message(&available).unwrap();
+        assert_eq!(message, cbor_bytes(&payload));
+    }
+
+    #[test]
+    fn test_fragment_chooser_reference_vector() {
+        // Vector de la implementación de referencia BC-UR: mensaje de 1024 bytes
+        // generado con la semilla "Wolf", 11 fragmentos
+        let mut rng = Xoshiro256::from_seed_data(b"Wolf");
+        let message: Vec<u8> = (0..1024).map(|_| rng.next_int(0, 255) as u8).collect();
+        let checksum = crc32(&message);
+
+        let expected: Vec<Vec<usize>> = vec![
+            vec![0], vec![1], vec![2], vec![3], vec![4], vec![5], vec![6], vec![7], vec![8], vec![9], vec![10],
+            vec![9], vec![2, 5, 6, 8, 9, 10], vec![8], vec![1, 5], vec![1], vec![0, 2, 4, 5, 8, 10], vec![5],
+            vec![2], vec![2], vec![0, 1, 3, 4, 5, 7, 9, 10], vec![0, 1, 2, 3, 5, 6, 8, 9, 10],
+        ];
+        for (i, indexes) in expected.iter().enumerate() {
+            let chosen: Vec<usize> = choose_fragments(i as u32 + 1, 11, checksum).into_iter().collect();
+            assert_eq!(&chosen, indexes, "seq_num {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_bbqr_roundtrip() {
+        assert_eq!(base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(base32_decode("MZXW6YTBOI").unwrap(), b"foobar");
+
+        let payload: Vec<u8> = (0..700u32).map(|i| (i % 256) as u8).collect();
+        let frames = encode_bbqr(&payload, 'P', 200).unwrap();
+        assert!(frames.len() > 1);
+        assert!(frames.iter().all(|f| f.len() <= 200));
+        assert!(frames[0].starts_with(&format!("B$2P{}00", base36_pair(frames.len()))));
+
+        let mut shuffled = frames.clone();
+        shuffled.rotate_left(2);
+        let decoded = decode_bbqr(&shuffled).unwrap();
+        assert_eq!(decoded.data_type, "P");
+        assert_eq!(hex::decode(decoded.payload_hex).unwrap(), payload);
+
+        assert!(decode_bbqr(&frames[1..]).is_err());
+        assert!(decode_bbqr(&["B$HP0100414243".to_string()]).unwrap().payload_text.as_deref() == Some("ABC"));
+    }
+}
@@ -0,0 +1,441 @@
+// =============================================================================
+// REGISTRO DE AUDITORÍA A PRUEBA DE MANIPULACIÓN
+// Registro opcional (para instituciones) de cuándo se hicieron operaciones en
+// este equipo. Solo guarda el nombre de la operación, el resultado (código de
+// error) y la fecha: nunca frases, contraseñas ni direcciones.
+// - Cada entrada incluye el SHA256 de la anterior (cadena de hashes)
+// - Cada línea de `<config>/scypher/audit.log` va cifrada con AES-256-CTR y
+//   autenticada con HMAC-SHA256 (claves derivadas con HKDF)
+// - La clave y la cabeza de la cadena (último número y hash) se guardan en el
+//   llavero del sistema, así que también se detecta el borrado de entradas
+//   finales o la sustitución del archivo
+// Se activa con `enable_audit_log`; mientras el archivo no exista no se
+// consulta el llavero ni se registra nada. En modo incógnito tampoco.
+// =============================================================================
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use base64::Engine;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use crate::security::SecureString;
+
+/// Nombre del archivo dentro del directorio de configuración
+const AUDIT_LOG_FILE: &str = "audit.log";
+
+/// Hash anterior de la primera entrada
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Información de HKDF para cada subclave
+const ENCRYPTION_INFO: &[u8] = b"SCYPHER_AUDIT_LOG_ENCRYPTION_V1";
+const AUTHENTICATION_INFO: &[u8] = b"SCYPHER_AUDIT_LOG_MAC_V1";
+
+/// Longitudes del IV de AES-CTR y de la etiqueta HMAC
+const IV_LEN: usize = 16;
+const TAG_LEN: usize = 32;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Una entrada del registro (en claro solo dentro de la aplicación)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub at: u64,                    // Segundos Unix
+    pub operation: String,
+    pub success: bool,
+    pub error_code: Option<String>,
+    pub app_version: String,
+    pub prev_hash: String,
+}
+
+/// Estado del registro para la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogStatus {
+    pub enabled: bool,
+    pub path: Option<String>,
+    pub entries: u64,
+}
+
+/// Resultado de la verificación de la cadena
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogVerification {
+    pub valid: bool,
+    pub entries: u64,
+    pub first_at: Option<u64>,
+    pub last_at: Option<u64>,
+    pub broken_at_line: Option<u64>, // Primera línea que no encaja (1-based)
+    pub message: Option<String>,
+}
+
+/// Clave maestra y cabeza de la cadena (lo que se guarda en el llavero)
+#[derive(Serialize, Deserialize)]
+struct AuditState {
+    key: String,       // Hex, 32 bytes
+    head_seq: u64,
+    head_hash: String,
+}
+
+impl Drop for AuditState {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Subclaves de cifrado y autenticación
+struct AuditKeys {
+    encryption: [u8; 32],
+    authentication: [u8; 32],
+}
+
+impl Drop for AuditKeys {
+    fn drop(&mut self) {
+        self.encryption.zeroize();
+        self.authentication.zeroize();
+    }
+}
+
+impl AuditKeys {
+    fn derive(master: &[u8]) -> Result<Self> {
+        let hkdf = Hkdf::<Sha256>::new(None, master);
+        let mut keys = AuditKeys { encryption: [0u8; 32], authentication: [0u8; 32] };
+        hkdf.expand(ENCRYPTION_INFO, &mut keys.encryption)
+            .and_then(|()| hkdf.expand(AUTHENTICATION_INFO, &mut keys.authentication))
+            .map_err(|_| SCypherError::crypto("Cannot derive audit log keys".to_string()))?;
+        Ok(keys)
+    }
+
+    fn from_state(state: &AuditState) -> Result<Self> {
+        let mut master = hex::decode(&state.key)
+            .map_err(|_| SCypherError::KeychainError("Corrupted audit log key".to_string()))?;
+        let keys = Self::derive(&master);
+        master.zeroize();
+        keys
+    }
+
+    fn mac(&self, data: &[u8]) -> HmacSha256 {
+        let mut mac = <HmacSha256 as Mac>::new_from_slice(&self.authentication).expect("HMAC accepts any key length");
+        mac.update(data);
+        mac
+    }
+}
+
+static STATE: OnceLock<Mutex<Option<AuditState>>> = OnceLock::new();
+
+fn state_slot() -> &'static Mutex<Option<AuditState>> {
+    STATE.get_or_init(|| Mutex::new(None))
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Directorio de configuración de la aplicación según la plataforma
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    base.map(|dir| dir.join("scypher"))
+}
+
+fn log_path() -> Result<PathBuf> {
+    config_dir()
+        .map(|dir| dir.join(AUDIT_LOG_FILE))
+        .ok_or_else(|| SCypherError::file("Cannot determine the configuration directory".to_string()))
+}
+
+/// Hash de una entrada (cubre también el hash anterior)
+fn entry_hash(entry: &AuditEntry) -> Result<String> {
+    let json = serde_json::to_vec(entry).map_err(|e| SCypherError::crypto(format!("Cannot encode audit entry: {}", e)))?;
+    Ok(hex::encode(Sha256::digest(&json)))
+}
+
+/// Cifrar y autenticar una entrada: base64(IV || texto cifrado || etiqueta)
+fn seal(keys: &AuditKeys, plaintext: &[u8]) -> Result<String> {
+    let iv = crate::security::utils::secure_random_bytes(IV_LEN);
+    let mut data = plaintext.to_vec();
+    ctr::Ctr128BE::<aes::Aes256>::new_from_slices(&keys.encryption, &iv)
+        .map_err(|e| SCypherError::crypto(format!("Invalid AES-CTR parameters: {}", e)))?
+        .apply_keystream(&mut data);
+
+    let mut sealed = iv;
+    sealed.extend_from_slice(&data);
+    let tag = keys.mac(&sealed).finalize().into_bytes();
+    sealed.extend_from_slice(&tag);
+    Ok(base64::engine::general_purpose::STANDARD.encode(sealed))
+}
+
+/// Comprobar la etiqueta y descifrar una línea
+fn open(keys: &AuditKeys, line: &str) -> Result<Vec<u8>> {
+    let invalid = || SCypherError::crypto("Audit log entry failed authentication".to_string());
+    let sealed = base64::engine::general_purpose::STANDARD.decode(line.trim()).map_err(|_| invalid())?;
+    if sealed.len() < IV_LEN + TAG_LEN {
+        return Err(invalid());
+    }
+
+    let (body, tag) = sealed.split_at(sealed.len() - TAG_LEN);
+    keys.mac(body).verify_slice(tag).map_err(|_| invalid())?;
+
+    let (iv, ciphertext) = body.split_at(IV_LEN);
+    let mut plaintext = ciphertext.to_vec();
+    ctr::Ctr128BE::<aes::Aes256>::new_from_slices(&keys.encryption, iv)
+        .map_err(|e| SCypherError::crypto(format!("Invalid AES-CTR parameters: {}", e)))?
+        .apply_keystream(&mut plaintext);
+    Ok(plaintext)
+}
+
+/// Añadir una entrada al final del archivo y avanzar la cabeza
+fn append_entry(path: &Path, state: &mut AuditState, operation: &str, error_code: Option<&str>) -> Result<()> {
+    let keys = AuditKeys::from_state(state)?;
+    let entry = AuditEntry {
+        seq: state.head_seq + 1,
+        at: unix_now(),
+        operation: operation.to_string(),
+        success: error_code.is_none(),
+        error_code: error_code.map(str::to_string),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        prev_hash: state.head_hash.clone(),
+    };
+    let plaintext = serde_json::to_vec(&entry).map_err(|e| SCypherError::crypto(format!("Cannot encode audit entry: {}", e)))?;
+    let line = seal(&keys, &plaintext)?;
+
+    let mut file = OpenOptions::new().append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+    file.sync_all()?;
+
+    state.head_seq = entry.seq;
+    state.head_hash = entry_hash(&entry)?;
+    Ok(())
+}
+
+/// Recorrer el archivo comprobando autenticación, orden y cadena de hashes
+fn verify_file(path: &Path, state: &AuditState) -> Result<AuditLogVerification> {
+    let keys = AuditKeys::from_state(state)?;
+    let content = fs::read_to_string(path)?;
+
+    let mut report = AuditLogVerification {
+        valid: false,
+        entries: 0,
+        first_at: None,
+        last_at: None,
+        broken_at_line: None,
+        message: None,
+    };
+    let mut prev_hash = GENESIS_HASH.to_string();
+
+    for (index, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let entry = open(&keys, line).and_then(|plaintext| {
+            serde_json::from_slice::<AuditEntry>(&plaintext)
+                .map_err(|_| SCypherError::crypto("Audit log entry is malformed".to_string()))
+        });
+        let problem = match &entry {
+            Err(e) => Some(e.to_string()),
+            Ok(entry) if entry.seq != report.entries + 1 => Some(format!("Expected entry {} but found {}", report.entries + 1, entry.seq)),
+            Ok(entry) if entry.prev_hash != prev_hash => Some("Hash chain is broken".to_string()),
+            Ok(_) => None,
+        };
+        if let Some(message) = problem {
+            report.broken_at_line = Some(index as u64 + 1);
+            report.message = Some(message);
+            return Ok(report);
+        }
+
+        let entry = entry?;
+        prev_hash = entry_hash(&entry)?;
+        report.entries = entry.seq;
+        report.first_at.get_or_insert(entry.at);
+        report.last_at = Some(entry.at);
+    }
+
+    // La cabeza guardada en el llavero detecta entradas finales eliminadas
+    if report.entries != state.head_seq || prev_hash != state.head_hash {
+        report.message = Some(format!(
+            "Log ends at entry {} but the keychain records {} entries (entries were removed)",
+            report.entries, state.head_seq
+        ));
+        return Ok(report);
+    }
+
+    report.valid = true;
+    Ok(report)
+}
+
+/// Cargar el estado del llavero (solo si el registro está activado)
+fn load_state(slot: &mut Option<AuditState>) -> Result<Option<&mut AuditState>> {
+    if slot.is_none() {
+        let Some(secret) = crate::security::keychain::retrieve_audit_log_secret()? else {
+            return Ok(None);
+        };
+        let state = serde_json::from_str(secret.as_str())
+            .map_err(|_| SCypherError::KeychainError("Corrupted audit log state".to_string()))?;
+        *slot = Some(state);
+    }
+    Ok(slot.as_mut())
+}
+
+fn store_state(state: &AuditState) -> Result<()> {
+    let json = serde_json::to_string(state).map_err(|e| SCypherError::crypto(format!("Cannot encode audit log state: {}", e)))?;
+    crate::security::keychain::store_audit_log_secret(&SecureString::from(json))
+}
+
+/// Activar el registro: crea la clave en el llavero y el archivo vacío
+pub fn enable_audit_log() -> Result<AuditLogStatus> {
+    crate::security::incognito::ensure_persistence_allowed("enabling the audit log")?;
+    let path = log_path()?;
+    let mut slot = state_slot().lock().unwrap_or_else(|e| e.into_inner());
+
+    if load_state(&mut slot)?.is_none() {
+        if path.exists() {
+            return Err(SCypherError::file(format!(
+                "An audit log already exists at {} but its key is not in the keychain",
+                path.display()
+            )));
+        }
+
+        let mut key = crate::security::utils::secure_random_bytes(32);
+        let state = AuditState { key: hex::encode(&key), head_seq: 0, head_hash: GENESIS_HASH.to_string() };
+        key.zeroize();
+        store_state(&state)?;
+        *slot = Some(state);
+    }
+
+    if !path.exists() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options.open(&path)?;
+    }
+    drop(slot);
+
+    tracing::info!("Registro de auditoría activado");
+    audit_log_status()
+}
+
+/// Estado actual (sin consultar el llavero si el registro no existe)
+pub fn audit_log_status() -> Result<AuditLogStatus> {
+    let path = log_path()?;
+    if !path.exists() {
+        return Ok(AuditLogStatus { enabled: false, path: None, entries: 0 });
+    }
+    let mut slot = state_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let entries = load_state(&mut slot)?.map(|state| state.head_seq);
+    Ok(AuditLogStatus {
+        enabled: entries.is_some(),
+        path: Some(path.display().to_string()),
+        entries: entries.unwrap_or(0),
+    })
+}
+
+/// Registrar el resultado de una operación (nunca hace fallar la operación)
+pub fn record(operation: &str, error_code: Option<&str>) {
+    if crate::security::incognito::is_incognito() {
+        return;
+    }
+    let Ok(path) = log_path() else { return };
+    if !path.exists() {
+        return;
+    }
+
+    let mut slot = state_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let result = load_state(&mut slot).and_then(|state| match state {
+        Some(state) => append_entry(&path, state, operation, error_code).and_then(|()| store_state(state)),
+        None => Ok(()),
+    });
+    if let Err(e) = result {
+        tracing::warn!(error_code = e.code(), "No se pudo escribir en el registro de auditoría");
+    }
+}
+
+/// Verificar la integridad completa del registro
+pub fn verify_log_integrity() -> Result<AuditLogVerification> {
+    let path = log_path()?;
+    if !path.exists() {
+        return Err(SCypherError::file("The audit log is not enabled on this machine".to_string()));
+    }
+    let mut slot = state_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let state = load_state(&mut slot)?
+        .ok_or_else(|| SCypherError::KeychainError("The audit log key is missing from the keychain".to_string()))?;
+    verify_file(&path, state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_state() -> AuditState {
+        AuditState { key: hex::encode([3u8; 32]), head_seq: 0, head_hash: GENESIS_HASH.to_string() }
+    }
+
+    fn test_log(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, "").unwrap();
+        path
+    }
+
+    #[test]
+    fn test_append_and_verify_chain() {
+        let path = test_log("scypher_audit_chain_test.log");
+        let mut state = test_state();
+        append_entry(&path, &mut state, "transform_seed_phrase", None).unwrap();
+        append_entry(&path, &mut state, "transform_seed_phrase", Some("invalid_checksum")).unwrap();
+
+        let report = verify_file(&path, &state).unwrap();
+        assert!(report.valid, "{:?}", report.message);
+        assert_eq!(report.entries, 2);
+
+        // Las entradas van cifradas: ni la operación ni el código aparecen en claro
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("transform_seed_phrase") && !content.contains("invalid_checksum"));
+
+        // Quitar la última línea se detecta con la cabeza del llavero
+        let first_line = content.lines().next().unwrap().to_string();
+        fs::write(&path, format!("{}\n", first_line)).unwrap();
+        let report = verify_file(&path, &state).unwrap();
+        assert!(!report.valid);
+        assert!(report.message.unwrap().contains("removed"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_entries_are_detected() {
+        let path = test_log("scypher_audit_tamper_test.log");
+        let mut state = test_state();
+        for _ in 0..3 {
+            append_entry(&path, &mut state, "transform_entropy", None).unwrap();
+        }
+        let lines: Vec<String> = fs::read_to_string(&path).unwrap().lines().map(String::from).collect();
+
+        // Reordenar entradas rompe la secuencia
+        fs::write(&path, format!("{}\n{}\n{}\n", lines[0], lines[2], lines[1])).unwrap();
+        assert_eq!(verify_file(&path, &state).unwrap().broken_at_line, Some(2));
+
+        // Modificar un byte rompe la autenticación
+        let mut bytes = base64::engine::general_purpose::STANDARD.decode(&lines[1]).unwrap();
+        bytes[IV_LEN] ^= 1;
+        let forged = base64::engine::general_purpose::STANDARD.encode(bytes);
+        fs::write(&path, format!("{}\n{}\n{}\n", lines[0], forged, lines[2])).unwrap();
+        let report = verify_file(&path, &state).unwrap();
+        assert_eq!(report.broken_at_line, Some(2));
+        assert!(report.message.unwrap().contains("authentication"));
+        fs::remove_file(&path).unwrap();
+    }
+}
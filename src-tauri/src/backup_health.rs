@@ -0,0 +1,325 @@
+// =============================================================================
+// RECORDATORIO DE VERIFICACIÓN DE COPIAS CIFRADAS
+// Diario opcional (`<config>/scypher/backups.json`) con las copias cifradas
+// que el usuario quiere vigilar y cuándo se verificó cada una por última vez.
+// Una verificación es un descifrado de ida y vuelta (el resultado vuelve a
+// cifrarse y debe dar el contenido del archivo) más la comprobación de la
+// huella BIP32 que el usuario tiene anotada. El diario solo guarda rutas,
+// etiquetas y fechas: nunca la frase, la contraseña ni la huella. Mientras no
+// se registre ninguna copia no se crea el archivo; en modo incógnito no se
+// escribe nada.
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Nombre del archivo dentro del directorio de configuración
+const BACKUP_JOURNAL_FILE: &str = "backups.json";
+
+/// Intervalo de recordatorio por defecto (días)
+pub const DEFAULT_REMINDER_INTERVAL_DAYS: u32 = 90;
+
+/// Intervalo máximo configurable (días, unos diez años)
+const MAX_REMINDER_INTERVAL_DAYS: u32 = 3650;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Una copia vigilada
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupRecord {
+    pub path: String,
+    pub label: Option<String>,
+    pub registered_at: u64,            // Segundos Unix
+    pub last_verified_at: Option<u64>,
+}
+
+/// Contenido del diario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupJournal {
+    #[serde(default = "default_reminder_interval")]
+    reminder_interval_days: u32,
+    #[serde(default)]
+    backups: Vec<BackupRecord>,
+}
+
+fn default_reminder_interval() -> u32 {
+    DEFAULT_REMINDER_INTERVAL_DAYS
+}
+
+impl Default for BackupJournal {
+    fn default() -> Self {
+        Self { reminder_interval_days: DEFAULT_REMINDER_INTERVAL_DAYS, backups: Vec::new() }
+    }
+}
+
+/// Estado de una copia respecto al recordatorio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupState {
+    Verified,       // Dentro del intervalo
+    Due,            // Verificada hace más del intervalo
+    NeverVerified,
+    Missing,        // El archivo ya no está en esa ruta
+}
+
+/// Una copia con su estado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupHealthEntry {
+    pub path: String,
+    pub label: Option<String>,
+    pub last_verified_at: Option<u64>,
+    pub days_since_verification: Option<u64>,
+    pub state: BackupState,
+}
+
+/// Resumen para la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupHealth {
+    pub reminder_interval_days: u32,
+    pub needs_attention: usize,        // Copias que no están en estado Verified
+    pub backups: Vec<BackupHealthEntry>,
+}
+
+/// Resultado de verificar una copia
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupVerification {
+    pub roundtrip_ok: bool,
+    pub fingerprint_matches: bool,
+    pub verified_at: Option<u64>,      // Solo si pasó las dos comprobaciones
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn journal_path() -> Result<PathBuf> {
+    crate::audit_log::config_dir()
+        .map(|dir| dir.join(BACKUP_JOURNAL_FILE))
+        .ok_or_else(|| SCypherError::file("Cannot determine the configuration directory".to_string()))
+}
+
+fn load_journal_from(path: &Path) -> Result<BackupJournal> {
+    if !path.exists() {
+        return Ok(BackupJournal::default());
+    }
+    let content = fs::read_to_string(path)
+        .map_err(|e| SCypherError::file(format!("Cannot read backup journal: {}", e)))?;
+    serde_json::from_str(&content).map_err(|e| SCypherError::file(format!("Invalid backup journal: {}", e)))
+}
+
+fn save_journal_to(path: &Path, journal: &BackupJournal) -> Result<()> {
+    crate::security::incognito::ensure_persistence_allowed("writing the backup journal")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(journal)
+        .map_err(|e| SCypherError::file(format!("Cannot encode backup journal: {}", e)))?;
+    fs::write(path, json).map_err(|e| SCypherError::file(format!("Cannot write backup journal: {}", e)))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| SCypherError::file(format!("Cannot set permissions: {}", e)))?;
+    }
+    Ok(())
+}
+
+/// Ruta absoluta si el archivo existe, para no registrar dos veces la misma copia
+fn normalize_backup_path(path: &str) -> Result<String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(SCypherError::file("Backup path is empty".to_string()));
+    }
+    Ok(fs::canonicalize(path)
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| path.to_string()))
+}
+
+/// Registrar o actualizar una copia; con `verified_at` anota la verificación
+fn upsert(journal: &mut BackupJournal, path: String, label: Option<String>, verified_at: Option<u64>, now: u64) {
+    let label = label.map(|l| l.trim().to_string()).filter(|l| !l.is_empty());
+    match journal.backups.iter_mut().find(|record| record.path == path) {
+        Some(record) => {
+            if label.is_some() {
+                record.label = label;
+            }
+            if verified_at.is_some() {
+                record.last_verified_at = verified_at;
+            }
+        }
+        None => journal.backups.push(BackupRecord { path, label, registered_at: now, last_verified_at: verified_at }),
+    }
+}
+
+/// Estado de cada copia en el instante `now`
+fn health_at(journal: &BackupJournal, now: u64, exists: impl Fn(&str) -> bool) -> BackupHealth {
+    let interval = journal.reminder_interval_days as u64 * SECONDS_PER_DAY;
+    let backups: Vec<BackupHealthEntry> = journal
+        .backups
+        .iter()
+        .map(|record| {
+            let age = record.last_verified_at.map(|at| now.saturating_sub(at));
+            let state = match age {
+                _ if !exists(&record.path) => BackupState::Missing,
+                None => BackupState::NeverVerified,
+                Some(age) if age > interval => BackupState::Due,
+                Some(_) => BackupState::Verified,
+            };
+            BackupHealthEntry {
+                path: record.path.clone(),
+                label: record.label.clone(),
+                last_verified_at: record.last_verified_at,
+                days_since_verification: age.map(|age| age / SECONDS_PER_DAY),
+                state,
+            }
+        })
+        .collect();
+
+    BackupHealth {
+        reminder_interval_days: journal.reminder_interval_days,
+        needs_attention: backups.iter().filter(|b| b.state != BackupState::Verified).count(),
+        backups,
+    }
+}
+
+/// Estado de las copias vigiladas
+pub fn backup_health() -> Result<BackupHealth> {
+    let journal = load_journal_from(&journal_path()?)?;
+    Ok(health_at(&journal, unix_now(), |path| Path::new(path).exists()))
+}
+
+/// Empezar a vigilar una copia (sin verificarla)
+pub fn track_backup(path: &str, label: Option<String>) -> Result<BackupHealth> {
+    let journal_path = journal_path()?;
+    let mut journal = load_journal_from(&journal_path)?;
+    upsert(&mut journal, normalize_backup_path(path)?, label, None, unix_now());
+    save_journal_to(&journal_path, &journal)?;
+    backup_health()
+}
+
+/// Dejar de vigilar una copia
+pub fn untrack_backup(path: &str) -> Result<BackupHealth> {
+    let journal_path = journal_path()?;
+    let mut journal = load_journal_from(&journal_path)?;
+    let path = normalize_backup_path(path)?;
+    journal.backups.retain(|record| record.path != path);
+    save_journal_to(&journal_path, &journal)?;
+    backup_health()
+}
+
+/// Cambiar el intervalo tras el que se pide volver a verificar
+pub fn set_reminder_interval(days: u32) -> Result<BackupHealth> {
+    if !(1..=MAX_REMINDER_INTERVAL_DAYS).contains(&days) {
+        return Err(SCypherError::file(format!(
+            "Reminder interval must be between 1 and {} days",
+            MAX_REMINDER_INTERVAL_DAYS
+        )));
+    }
+    let journal_path = journal_path()?;
+    let mut journal = load_journal_from(&journal_path)?;
+    journal.reminder_interval_days = days;
+    save_journal_to(&journal_path, &journal)?;
+    backup_health()
+}
+
+/// Verificar una copia cifrada y, si pasa, anotar la fecha en el diario
+///
+/// Descifra el archivo con ida y vuelta y compara la huella BIP32 del
+/// resultado (con `passphrase`) con `expected_fingerprint`. Los fallos
+/// cuentan para el límite de intentos. La frase descifrada no sale de aquí.
+pub fn verify_backup(
+    path: &str,
+    password: String,
+    second_password: Option<String>,
+    mut params: crate::pipeline::TransformParams,
+    passphrase: Option<&str>,
+    expected_fingerprint: &str,
+) -> Result<BackupVerification> {
+    let content = fs::read_to_string(path.trim())
+        .map_err(|e| SCypherError::file(format!("Cannot read backup: {}", e)))?;
+    params.mode = crate::crypto::sanity::TransformMode::Decrypt;
+
+    let request = crate::crypto_worker::PhraseRequest {
+        phrase: crate::cli::output::format::parse_formatted_phrase(&content),
+        password,
+        second_password,
+        params,
+        verify: true,
+    };
+    let (mut output, verified) = crate::crypto_worker::transform(request)?;
+
+    let fingerprint = crate::addresses::master_fingerprint(&output.phrase, passphrase);
+    output.phrase.zeroize();
+    let fingerprint = fingerprint?;
+    let expected = expected_fingerprint.trim().trim_start_matches("0x").to_lowercase();
+    let fingerprint_matches = crate::security::utils::constant_time_eq(fingerprint.as_bytes(), expected.as_bytes());
+    crate::security::attempt_guard::record_attempt(fingerprint_matches);
+
+    let roundtrip_ok = verified == Some(true);
+    let verified_at = (roundtrip_ok && fingerprint_matches).then(unix_now);
+    if let Some(at) = verified_at {
+        let journal_path = journal_path()?;
+        let mut journal = load_journal_from(&journal_path)?;
+        upsert(&mut journal, normalize_backup_path(path)?, None, Some(at), at);
+        save_journal_to(&journal_path, &journal)?;
+        tracing::info!("Copia cifrada verificada");
+    }
+
+    Ok(BackupVerification { roundtrip_ok, fingerprint_matches, verified_at })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = SECONDS_PER_DAY;
+
+    #[test]
+    fn test_health_states() {
+        let now = 1_000 * DAY;
+        let mut journal = BackupJournal::default();
+        upsert(&mut journal, "/backups/fresh.txt".into(), Some("Safe".into()), Some(now - 10 * DAY), now);
+        upsert(&mut journal, "/backups/old.txt".into(), None, Some(now - 200 * DAY), now);
+        upsert(&mut journal, "/backups/new.txt".into(), None, None, now);
+        upsert(&mut journal, "/backups/gone.txt".into(), None, Some(now), now);
+
+        let health = health_at(&journal, now, |path| path != "/backups/gone.txt");
+        let states: Vec<BackupState> = health.backups.iter().map(|b| b.state).collect();
+        assert_eq!(states, vec![BackupState::Verified, BackupState::Due, BackupState::NeverVerified, BackupState::Missing]);
+        assert_eq!(health.backups[1].days_since_verification, Some(200));
+        assert_eq!(health.needs_attention, 3);
+
+        // Con un intervalo más largo la copia antigua deja de estar pendiente
+        journal.reminder_interval_days = 365;
+        assert_eq!(health_at(&journal, now, |_| true).needs_attention, 1);
+    }
+
+    #[test]
+    fn test_journal_roundtrip_and_upsert() {
+        let dir = std::env::temp_dir().join(format!("scypher-backups-{}", std::process::id()));
+        let path = dir.join(BACKUP_JOURNAL_FILE);
+        assert!(load_journal_from(&path).unwrap().backups.is_empty());
+
+        let mut journal = BackupJournal::default();
+        upsert(&mut journal, "/backups/a.txt".into(), Some("Bank box".into()), None, 5);
+        upsert(&mut journal, "/backups/a.txt".into(), None, Some(50), 60);
+        assert_eq!(journal.backups.len(), 1);
+        assert_eq!(journal.backups[0].label.as_deref(), Some("Bank box"));
+        assert_eq!(journal.backups[0].registered_at, 5);
+
+        save_journal_to(&path, &journal).unwrap();
+        let loaded = load_journal_from(&path).unwrap();
+        assert_eq!(loaded.backups[0].last_verified_at, Some(50));
+        assert_eq!(loaded.reminder_interval_days, DEFAULT_REMINDER_INTERVAL_DAYS);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(set_reminder_interval(0).is_err());
+    }
+}
@@ -67,28 +67,7 @@ pub fn bits_to_phrase(bits: &[bool]) -> Result<String> {
 
 /// Convierte entropía pura a seed phrase BIP39 válida (con checksum)
 pub fn entropy_to_phrase(entropy: &[u8]) -> Result<String> {
-    let entropy_bits = entropy.len() * 8;
-
-    // Validar longitud de entropía
-    checksum::validate_entropy_length(entropy_bits)?;
-
-    // Convertir entropía a bits
-    let mut entropy_bit_vec = Vec::new();
-    for byte in entropy {
-        for i in (0..8).rev() {
-            entropy_bit_vec.push((byte >> i) & 1 == 1);
-        }
-    }
-
-    // Calcular checksum
-    let checksum_bits = checksum::recalculate_bip39_checksum(&entropy_bit_vec)?;
-
-    // Combinar entropía + checksum
-    let mut full_bits = entropy_bit_vec;
-    full_bits.extend(checksum_bits);
-
-    // Convertir a frase
-    bits_to_phrase(&full_bits)
+    super::entropy::AnyBip39Entropy::from_slice(entropy)?.to_phrase()
 }
 
 /// Convierte seed phrase BIP39 a entropía pura (sin checksum)
@@ -143,8 +122,7 @@ pub fn generate_seed_phrase(entropy_bits: usize) -> Result<String> {
     let mut entropy = vec![0u8; entropy_bytes];
 
     // Generar entropía aleatoria
-    use rand::RngCore;
-    rand::thread_rng().fill_bytes(&mut entropy);
+    crate::security::rng::fill_random(&mut entropy);
 
     entropy_to_phrase(&entropy)
 }
@@ -375,4 +353,63 @@ mod tests {
         let invalid_entropy = vec![0u8; 15]; // 120 bits, no válido para BIP39
         assert!(entropy_to_phrase(&invalid_entropy).is_err());
     }
+
+    // Propiedades (proptest): ida y vuelta bits <-> frase y entradas hostiles
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Entropía de cualquier longitud BIP39 válida (12 a 24 palabras)
+        fn valid_entropy() -> impl Strategy<Value = Vec<u8>> {
+            prop::sample::select(vec![16usize, 20, 24, 28, 32])
+                .prop_flat_map(|len| prop::collection::vec(any::<u8>(), len))
+        }
+
+        proptest! {
+            #[test]
+            fn prop_bits_phrase_roundtrip(indices in prop::collection::vec(0usize..2048, 1..48)) {
+                let bits: Vec<bool> = indices
+                    .iter()
+                    .flat_map(|&index| (0..11).rev().map(move |i| (index >> i) & 1 == 1))
+                    .collect();
+
+                let phrase = bits_to_phrase(&bits).unwrap();
+                prop_assert_eq!(phrase.split_whitespace().count(), indices.len());
+                prop_assert_eq!(phrase_to_bits(&phrase).unwrap(), bits);
+            }
+
+            #[test]
+            fn prop_entropy_phrase_roundtrip(entropy in valid_entropy()) {
+                let phrase = entropy_to_phrase(&entropy).unwrap();
+                prop_assert_eq!(phrase.split_whitespace().count(), entropy.len() * 3 / 4);
+                prop_assert_eq!(phrase_to_entropy(&phrase).unwrap(), entropy);
+                prop_assert_eq!(validate_and_fix_checksum(&phrase).unwrap(), phrase);
+            }
+
+            #[test]
+            fn prop_hostile_input_never_panics(input in "\\PC{0,256}") {
+                let _ = phrase_to_bits(&input);
+                let _ = phrase_to_entropy(&input);
+                let _ = validate_and_fix_checksum(&input);
+                let _ = analyze_phrase(&input);
+            }
+
+            #[test]
+            fn prop_unicode_words_rejected(word in "[a-z]{1,4}[\\u{00e0}-\\u{00ff}\\u{0400}-\\u{04ff}\\u{1f600}-\\u{1f64f}][a-z]{0,4}") {
+                let phrase = format!("abandon {} about", word);
+                prop_assert!(matches!(phrase_to_bits(&phrase), Err(SCypherError::InvalidBip39Word(_))));
+            }
+        }
+
+        #[test]
+        fn test_huge_inputs() {
+            // 100.000 palabras válidas: longitud BIP39 inválida, sin pánico
+            let huge_phrase = "abandon ".repeat(100_000);
+            assert!(phrase_to_entropy(&huge_phrase).is_err());
+            assert!(validate_and_fix_checksum(&huge_phrase).is_err());
+
+            // 1 MiB sin espacios
+            assert!(phrase_to_bits(&"x".repeat(1 << 20)).is_err());
+        }
+    }
 }
@@ -0,0 +1,212 @@
+//! Lista de palabras propia para mostrar la frase transformada
+//!
+//! Un usuario avanzado puede registrar una lista de 2048 palabras con la que
+//! se escribe (y se vuelve a leer) solo el resultado de la transformación,
+//! para que lo guardado ni siquiera parezca una frase BIP39. Es un cambio de
+//! representación palabra a palabra por índice: la entropía, el checksum y la
+//! transformación no cambian. El SHA256 de la lista se guarda en los
+//! metadatos para saber con qué lista se escribió un resultado.
+//!
+//! Reglas de la lista: exactamente 2048 palabras distintas, solo letras, al
+//! menos 3 letras y las 4 primeras letras únicas (como en BIP39, para poder
+//! escribir solo el prefijo).
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+use super::wordlist::{normalize_word, BIP39_WORDLIST, UNIQUE_PREFIX_LENGTH};
+
+/// Longitud mínima de cada palabra
+const MIN_WORD_LENGTH: usize = 3;
+
+/// Lista propia validada
+#[derive(Debug)]
+pub struct CustomWordlist {
+    words: Vec<String>,
+    index: HashMap<String, usize>,
+    prefixes: HashMap<String, usize>,
+    hash: String,
+}
+
+/// Datos de la lista registrada para la GUI y los metadatos
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomWordlistInfo {
+    pub hash: String,  // SHA256 en hex de las palabras normalizadas, una por línea
+    pub word_count: usize,
+    pub sample: Vec<String>,  // Primeras palabras, para reconocer la lista
+}
+
+fn prefix(word: &str) -> String {
+    word.chars().take(UNIQUE_PREFIX_LENGTH).collect()
+}
+
+impl CustomWordlist {
+    /// Validar una lista (una palabra por línea; se ignoran las líneas vacías)
+    pub fn parse(text: &str) -> Result<Self> {
+        let words: Vec<String> = text
+            .lines()
+            .map(|line| normalize_word(line).into_owned())
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        if words.len() != BIP39_WORDLIST.len() {
+            return Err(SCypherError::file(format!(
+                "A custom wordlist needs exactly {} words, found {}",
+                BIP39_WORDLIST.len(),
+                words.len()
+            )));
+        }
+
+        let mut index = HashMap::with_capacity(words.len());
+        let mut prefixes = HashMap::with_capacity(words.len());
+        for (position, word) in words.iter().enumerate() {
+            if word.chars().count() < MIN_WORD_LENGTH || !word.chars().all(char::is_alphabetic) {
+                return Err(SCypherError::file(format!(
+                    "Invalid word '{}' at line {}: use letters only, at least {} of them",
+                    word, position + 1, MIN_WORD_LENGTH
+                )));
+            }
+            if let Some(previous) = index.insert(word.clone(), position) {
+                return Err(SCypherError::file(format!(
+                    "Duplicate word '{}' at lines {} and {}",
+                    word, previous + 1, position + 1
+                )));
+            }
+            if let Some(previous) = prefixes.insert(prefix(word), position) {
+                return Err(SCypherError::file(format!(
+                    "Words '{}' and '{}' share the first {} letters",
+                    words[previous], word, UNIQUE_PREFIX_LENGTH
+                )));
+            }
+        }
+
+        let hash = hex::encode(Sha256::digest(words.join("\n").as_bytes()));
+        Ok(CustomWordlist { words, index, prefixes, hash })
+    }
+
+    /// SHA256 de la lista (hex)
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Índice de una palabra propia (completa o por su prefijo de 4 letras)
+    fn lookup(&self, word: &str) -> Option<usize> {
+        let word = normalize_word(word);
+        self.index.get(word.as_ref()).copied().or_else(|| {
+            (word.chars().count() == UNIQUE_PREFIX_LENGTH)
+                .then(|| self.prefixes.get(word.as_ref()).copied())
+                .flatten()
+        })
+    }
+
+    /// Escribir una frase BIP39 con las palabras de esta lista
+    pub fn encode_phrase(&self, phrase: &str) -> Result<String> {
+        let phrase = super::wordlist::expand_abbreviations(phrase)?;
+        let words = phrase
+            .split_whitespace()
+            .map(|word| {
+                super::word_to_index(word)
+                    .map(|index| self.words[index].as_str())
+                    .ok_or_else(|| SCypherError::InvalidBip39Word(word.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(words.join(" "))
+    }
+
+    /// Leer una frase escrita con esta lista y devolverla en BIP39
+    pub fn decode_phrase(&self, phrase: &str) -> Result<String> {
+        let words = phrase
+            .split_whitespace()
+            .map(|word| {
+                self.lookup(word)
+                    .map(|index| BIP39_WORDLIST[index])
+                    .ok_or_else(|| SCypherError::file(format!("'{}' is not in the custom wordlist", word)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(words.join(" "))
+    }
+
+    /// Resumen para la GUI
+    pub fn info(&self) -> CustomWordlistInfo {
+        CustomWordlistInfo {
+            hash: self.hash().to_string(),
+            word_count: self.words.len(),
+            sample: self.words.iter().take(4).cloned().collect(),
+        }
+    }
+}
+
+/// Lista registrada en la sesión (solo en memoria)
+static ACTIVE_WORDLIST: OnceLock<Mutex<Option<CustomWordlist>>> = OnceLock::new();
+
+fn active_slot() -> &'static Mutex<Option<CustomWordlist>> {
+    ACTIVE_WORDLIST.get_or_init(|| Mutex::new(None))
+}
+
+/// Registrar la lista propia de la sesión (sustituye a la anterior)
+pub fn register_custom_wordlist(text: &str) -> Result<CustomWordlistInfo> {
+    let wordlist = CustomWordlist::parse(text)?;
+    let info = wordlist.info();
+    *active_slot().lock().unwrap_or_else(|e| e.into_inner()) = Some(wordlist);
+    Ok(info)
+}
+
+/// Olvidar la lista propia registrada
+pub fn clear_custom_wordlist() {
+    *active_slot().lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Datos de la lista registrada, si hay una
+pub fn active_custom_wordlist() -> Option<CustomWordlistInfo> {
+    active_slot().lock().unwrap_or_else(|e| e.into_inner()).as_ref().map(CustomWordlist::info)
+}
+
+/// Aplicar una operación con la lista registrada
+pub fn with_active_wordlist<T>(operation: impl FnOnce(&CustomWordlist) -> Result<T>) -> Result<T> {
+    let slot = active_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let wordlist = slot
+        .as_ref()
+        .ok_or_else(|| SCypherError::file("No custom wordlist is registered".to_string()))?;
+    operation(wordlist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    /// Lista de prueba: las palabras BIP39 con un sufijo (prefijos de 4 letras únicos)
+    fn test_list() -> String {
+        BIP39_WORDLIST.iter().map(|word| format!("{}x", word)).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let wordlist = CustomWordlist::parse(&test_list()).unwrap();
+        let encoded = wordlist.encode_phrase(PHRASE).unwrap();
+        assert!(encoded.starts_with("legalx winnerx"));
+        assert!(crate::bip39::validate_seed_phrase(&encoded).is_err());
+        assert_eq!(wordlist.decode_phrase(&encoded).unwrap(), PHRASE);
+
+        // Prefijos de 4 letras y mayúsculas
+        assert_eq!(wordlist.decode_phrase("LEGA winnerx").unwrap(), "legal winner");
+        assert!(wordlist.decode_phrase("legal").is_err());
+        assert_eq!(wordlist.hash().len(), 64);
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let list = test_list();
+        assert!(CustomWordlist::parse(&list.replacen("abandonx", "", 1)).is_err());
+        assert!(CustomWordlist::parse(&list.replacen("abilityx", "abandonx", 1)).is_err());
+        assert!(CustomWordlist::parse(&list.replacen("abilityx", "abanx", 1)).unwrap_err().to_string().contains("first 4 letters"));
+        assert!(CustomWordlist::parse(&list.replacen("abilityx", "ab1lity", 1)).is_err());
+
+        // Las líneas vacías y los espacios no cuentan; el hash no depende de ellos
+        let padded = format!("\n{}\n\n", list.replace('\n', "  \n"));
+        assert_eq!(CustomWordlist::parse(&padded).unwrap().hash(), CustomWordlist::parse(&list).unwrap().hash());
+    }
+}
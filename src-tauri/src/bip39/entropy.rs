@@ -0,0 +1,251 @@
+//! Entropía BIP39 con la longitud fijada en el tipo
+//!
+//! `Bip39Entropy<N>` contiene exactamente N bytes de entropía (16, 20, 24, 28
+//! o 32, es decir 12 a 24 palabras). El XOR con un keystream y el cálculo del
+//! checksum solo aceptan la misma N, así que no se pueden mezclar longitudes
+//! por error. `AnyBip39Entropy` es la entrada desde una frase de longitud
+//! desconocida: cada variante conserva su longitud a través de `transform`.
+
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use crate::crypto::checksum;
+
+/// Entropía BIP39 de N bytes (sin checksum)
+pub struct Bip39Entropy<const N: usize> {
+    bytes: [u8; N],
+}
+
+impl<const N: usize> Bip39Entropy<N> {
+    /// Solo existen longitudes BIP39 válidas (error de compilación en otro caso)
+    const VALID_LENGTH: () = assert!(
+        N == 16 || N == 20 || N == 24 || N == 28 || N == 32,
+        "BIP39 entropy must be 16, 20, 24, 28 or 32 bytes"
+    );
+
+    /// Palabras de la frase
+    pub const WORD_COUNT: usize = N * 3 / 4;
+
+    /// Bits de checksum (ENT / 32)
+    pub const CHECKSUM_BITS: usize = N / 4;
+
+    /// Crear desde bytes de entropía
+    pub fn from_bytes(bytes: [u8; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_LENGTH;
+        Self { bytes }
+    }
+
+    /// Número de palabras de la frase
+    pub fn word_count(&self) -> usize {
+        Self::WORD_COUNT
+    }
+
+    /// Bytes de entropía
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Checksum BIP39 de esta entropía (primeros ENT/32 bits de SHA256)
+    pub fn checksum_bits(&self) -> Result<Vec<bool>> {
+        let hash = checksum::calculate_checksum(&self.bytes)?;
+        checksum::extract_bits(&hash, 0, Self::CHECKSUM_BITS)
+    }
+
+    /// Bits completos: entropía seguida del checksum recalculado
+    pub fn to_bits(&self) -> Result<Vec<bool>> {
+        let mut bits: Vec<bool> = self
+            .bytes
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect();
+        bits.extend(self.checksum_bits()?);
+        Ok(bits)
+    }
+
+    /// Frase BIP39 con checksum válido
+    pub fn to_phrase(&self) -> Result<String> {
+        super::conversion::bits_to_phrase(&self.to_bits()?)
+    }
+
+    /// XOR con un keystream de la misma longitud
+    pub fn xor(&self, keystream: &[u8; N]) -> Self {
+        let mut bytes = [0u8; N];
+        for (out, (a, b)) in bytes.iter_mut().zip(self.bytes.iter().zip(keystream.iter())) {
+            *out = a ^ b;
+        }
+        Self::from_bytes(bytes)
+    }
+
+    /// Transformación XOR con keystream Argon2id (la misma operación cifra y descifra)
+    pub fn transform(&self, password: &str, iterations: u32, memory_cost: u32, parallelism: u32) -> Result<Self> {
+        let mut keystream =
+            crate::crypto::keystream::derive_keystream(password, N, iterations, memory_cost, parallelism)?;
+        let result = self.xor_slice(&keystream);
+        keystream.zeroize();
+        result
+    }
+
+    /// XOR con un keystream cuya longitud se comprueba en ejecución
+    pub fn xor_slice(&self, keystream: &[u8]) -> Result<Self> {
+        <&[u8; N]>::try_from(keystream)
+            .map(|keystream| self.xor(keystream))
+            .map_err(|_| SCypherError::crypto("Keystream length mismatch".to_string()))
+    }
+}
+
+impl<const N: usize> Drop for Bip39Entropy<N> {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// Entropía de cualquier longitud BIP39, una variante por número de palabras
+pub enum AnyBip39Entropy {
+    Words12(Bip39Entropy<16>),
+    Words15(Bip39Entropy<20>),
+    Words18(Bip39Entropy<24>),
+    Words21(Bip39Entropy<28>),
+    Words24(Bip39Entropy<32>),
+}
+
+/// Aplicar una operación que conserva la longitud (misma variante de salida)
+macro_rules! map_entropy {
+    ($value:expr, |$entropy:ident| $body:expr) => {
+        match $value {
+            AnyBip39Entropy::Words12($entropy) => AnyBip39Entropy::Words12($body),
+            AnyBip39Entropy::Words15($entropy) => AnyBip39Entropy::Words15($body),
+            AnyBip39Entropy::Words18($entropy) => AnyBip39Entropy::Words18($body),
+            AnyBip39Entropy::Words21($entropy) => AnyBip39Entropy::Words21($body),
+            AnyBip39Entropy::Words24($entropy) => AnyBip39Entropy::Words24($body),
+        }
+    };
+}
+
+/// Aplicar una operación que devuelve otro tipo
+macro_rules! with_entropy {
+    ($value:expr, |$entropy:ident| $body:expr) => {
+        match $value {
+            AnyBip39Entropy::Words12($entropy) => $body,
+            AnyBip39Entropy::Words15($entropy) => $body,
+            AnyBip39Entropy::Words18($entropy) => $body,
+            AnyBip39Entropy::Words21($entropy) => $body,
+            AnyBip39Entropy::Words24($entropy) => $body,
+        }
+    };
+}
+
+/// Números de palabras admitidos, en el orden de las variantes
+const VALID_WORD_COUNTS: [usize; 5] = [
+    Bip39Entropy::<16>::WORD_COUNT,
+    Bip39Entropy::<20>::WORD_COUNT,
+    Bip39Entropy::<24>::WORD_COUNT,
+    Bip39Entropy::<28>::WORD_COUNT,
+    Bip39Entropy::<32>::WORD_COUNT,
+];
+
+fn to_array<const N: usize>(bytes: &[u8]) -> Result<Bip39Entropy<N>> {
+    let array: [u8; N] = bytes
+        .try_into()
+        .map_err(|_| SCypherError::crypto(format!("Expected {} bytes of entropy", N)))?;
+    Ok(Bip39Entropy::from_bytes(array))
+}
+
+impl AnyBip39Entropy {
+    /// Crear desde bytes de entropía (16, 20, 24, 28 o 32)
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        checksum::validate_entropy_length(bytes.len() * 8)?;
+
+        Ok(match bytes.len() {
+            16 => Self::Words12(to_array(bytes)?),
+            20 => Self::Words15(to_array(bytes)?),
+            24 => Self::Words18(to_array(bytes)?),
+            28 => Self::Words21(to_array(bytes)?),
+            _ => Self::Words24(to_array(bytes)?),
+        })
+    }
+
+    /// Extraer la entropía de una frase (el checksum actual se ignora)
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        let word_count = phrase.split_whitespace().count();
+        if !VALID_WORD_COUNTS.contains(&word_count) {
+            return Err(SCypherError::InvalidWordCount(word_count));
+        }
+
+        let bits = super::conversion::phrase_to_bits(phrase)?;
+        let entropy_bits = word_count * 32 / 3;
+        let mut bytes = checksum::bits_to_bytes_padded(&bits[..entropy_bits]);
+
+        let entropy = Self::from_slice(&bytes);
+        bytes.zeroize();
+        entropy
+    }
+
+    /// Número de palabras de la frase
+    pub fn word_count(&self) -> usize {
+        with_entropy!(self, |entropy| entropy.word_count())
+    }
+
+    /// Bytes de entropía
+    pub fn as_bytes(&self) -> &[u8] {
+        with_entropy!(self, |entropy| &entropy.as_bytes()[..])
+    }
+
+    /// Frase BIP39 con checksum válido
+    pub fn to_phrase(&self) -> Result<String> {
+        with_entropy!(self, |entropy| entropy.to_phrase())
+    }
+
+    /// Transformación XOR que conserva el número de palabras
+    pub fn transform(&self, password: &str, iterations: u32, memory_cost: u32, parallelism: u32) -> Result<Self> {
+        Ok(map_entropy!(self, |entropy| entropy.transform(password, iterations, memory_cost, parallelism)?))
+    }
+
+    /// XOR con un keystream ya derivado (de la misma longitud que la entropía)
+    pub fn xor_slice(&self, keystream: &[u8]) -> Result<Self> {
+        Ok(map_entropy!(self, |entropy| entropy.xor_slice(keystream)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_typed_entropy_roundtrip() {
+        let entropy = AnyBip39Entropy::from_phrase(PHRASE).unwrap();
+        assert!(matches!(entropy, AnyBip39Entropy::Words12(_)));
+        assert_eq!(entropy.word_count(), 12);
+        assert_eq!(entropy.as_bytes(), &[0u8; 16]);
+        assert_eq!(entropy.to_phrase().unwrap(), PHRASE);
+
+        // Vector oficial BIP39 (24 palabras, entropía 0x7f...)
+        let typed = Bip39Entropy::<32>::from_bytes([0x7f; 32]);
+        assert_eq!(Bip39Entropy::<32>::WORD_COUNT, 24);
+        assert_eq!(
+            typed.to_phrase().unwrap(),
+            "legal winner thank year wave sausage worth useful legal winner thank year \
+             wave sausage worth useful legal winner thank year wave sausage worth title"
+        );
+    }
+
+    #[test]
+    fn test_transform_preserves_length() {
+        for len in [16usize, 20, 24, 28, 32] {
+            let entropy = AnyBip39Entropy::from_slice(&vec![0x5a; len]).unwrap();
+            let transformed = entropy.transform("typed_password", 1, 8192, 1).unwrap();
+            assert_eq!(transformed.word_count(), entropy.word_count());
+            assert_eq!(
+                transformed.transform("typed_password", 1, 8192, 1).unwrap().as_bytes(),
+                entropy.as_bytes()
+            );
+        }
+
+        assert!(AnyBip39Entropy::from_slice(&[0u8; 15]).is_err());
+        assert!(matches!(
+            AnyBip39Entropy::from_phrase("abandon abandon about"),
+            Err(SCypherError::InvalidWordCount(3))
+        ));
+    }
+}
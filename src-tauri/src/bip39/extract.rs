@@ -0,0 +1,163 @@
+//! Extracción de una frase BIP39 de un texto arbitrario
+//!
+//! Los usuarios pegan exportaciones de wallets o correos enteros. El texto se
+//! parte en palabras (la numeración y la puntuación separan, no cortan) y se
+//! buscan tramos seguidos de palabras BIP39. Dentro de cada tramo, cada
+//! ventana de 12, 15, 18, 21 o 24 palabras con checksum válido es candidata.
+//!
+//! El checksum de 4 a 8 bits deja pasar por azar una ventana de cada 16-256,
+//! así que las candidatas se ordenan por confianza: un tramo que es
+//! exactamente una frase vale más que una ventana dentro de un tramo más
+//! largo, y una ventana dentro de otra candidata mejor es casi siempre ruido.
+
+use serde::{Deserialize, Serialize};
+use super::validation::validate_checksum;
+use super::wordlist::word_to_index;
+
+/// Longitudes de frase buscadas, de mayor a menor
+const PHRASE_LENGTHS: [usize; 5] = [24, 21, 18, 15, 12];
+
+/// Candidatas devueltas como máximo
+pub const MAX_CANDIDATES: usize = 20;
+
+/// Confianza de una candidata (de menor a mayor)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Confidence {
+    Low,    // Dentro de otra candidata con más confianza
+    Medium, // Ventana dentro de un tramo más largo de palabras BIP39
+    High,   // El tramo completo es la frase
+}
+
+/// Frase encontrada en el texto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MnemonicCandidate {
+    pub phrase: String,
+    pub word_count: usize,
+    pub start: usize,        // Desplazamiento en bytes de la primera palabra en el texto
+    pub end: usize,          // Fin (exclusivo) de la última palabra
+    pub confidence: Confidence,
+}
+
+/// Palabra del texto ya normalizada, con su posición
+struct Token {
+    word: String,
+    start: usize,
+    end: usize,
+}
+
+/// Partir el texto en palabras (letras seguidas) recordando su posición
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut current: Option<usize> = None;
+
+    for (offset, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (current, c.is_alphabetic()) {
+            (None, true) => current = Some(offset),
+            (Some(start), false) => {
+                tokens.push(Token {
+                    word: super::normalize_seed_input(&text[start..offset]),
+                    start,
+                    end: offset,
+                });
+                current = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Buscar frases BIP39 válidas en el texto, ordenadas por confianza
+pub fn extract_mnemonics(text: &str) -> Vec<MnemonicCandidate> {
+    let tokens = tokenize(text);
+
+    // Tramos seguidos de palabras de la lista
+    let mut runs: Vec<&[Token]> = Vec::new();
+    let mut run_start = None;
+    for (i, token) in tokens.iter().enumerate() {
+        match (run_start, word_to_index(&token.word).is_some()) {
+            (None, true) => run_start = Some(i),
+            (Some(start), false) => {
+                runs.push(&tokens[start..i]);
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = run_start {
+        runs.push(&tokens[start..]);
+    }
+
+    let mut candidates: Vec<MnemonicCandidate> = Vec::new();
+    for run in runs {
+        for length in PHRASE_LENGTHS.iter().copied().filter(|&length| length <= run.len()) {
+            for window in run.windows(length) {
+                let phrase = window.iter().map(|token| token.word.as_str()).collect::<Vec<_>>().join(" ");
+                if validate_checksum(&phrase).is_err() {
+                    continue;
+                }
+
+                let (start, end) = (window[0].start, window[length - 1].end);
+                let contained = candidates.iter().any(|c| c.start <= start && end <= c.end);
+                let confidence = if contained {
+                    Confidence::Low
+                } else if length == run.len() {
+                    Confidence::High
+                } else {
+                    Confidence::Medium
+                };
+
+                candidates.push(MnemonicCandidate { phrase, word_count: length, start, end, confidence });
+            }
+        }
+    }
+
+    // Más confianza primero; a igual confianza, más palabras y antes en el texto
+    candidates.sort_by(|a, b| {
+        b.confidence
+            .cmp(&a.confidence)
+            .then(b.word_count.cmp(&a.word_count))
+            .then(a.start.cmp(&b.start))
+    });
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_extract_from_email() {
+        let text = "Hi,\n\nhere is the backup you asked for:\n\n1. Abandon 2. abandon 3. abandon 4. abandon\n\
+                    5. abandon 6. abandon 7. abandon 8. abandon\n9. abandon 10. abandon 11. abandon 12. about\n\nCheers";
+        let candidates = extract_mnemonics(text);
+        assert_eq!(candidates[0].phrase, PHRASE);
+        assert_eq!(candidates[0].confidence, Confidence::High);
+        assert_eq!(&text[candidates[0].start..candidates[0].start + 7], "Abandon");
+        assert!(text[..candidates[0].end].ends_with("about"));
+
+        // Sin frase válida no hay candidatas
+        assert!(extract_mnemonics("abandon ability able about above absent").is_empty());
+        assert!(extract_mnemonics("").is_empty());
+    }
+
+    #[test]
+    fn test_extract_ranks_embedded_windows_lower() {
+        let phrase_24 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                         abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+        let text = format!("mnemonic: {}\nthanks", phrase_24);
+        let candidates = extract_mnemonics(&text);
+        assert_eq!(candidates[0].phrase, phrase_24);
+        assert_eq!(candidates[0].confidence, Confidence::High);
+        assert!(candidates[1..].iter().all(|c| c.confidence == Confidence::Low));
+
+        // Una frase dentro de un tramo más largo de palabras BIP39
+        let text = format!("zoo {} zoo", PHRASE);
+        let candidates = extract_mnemonics(&text);
+        assert!(candidates.iter().any(|c| c.phrase == PHRASE && c.confidence == Confidence::Medium));
+    }
+}
@@ -0,0 +1,179 @@
+//! Generación de seed phrases a partir de entropía manual (dados o monedas)
+//!
+//! Mapeo exacto, verificable con herramientas estándar:
+//! - Dados: cada tirada es un dígito `1`-`6`. Se ignoran espacios, comas y
+//!   saltos de línea. Cada tirada aporta log2(6) ≈ 2.585 bits.
+//! - Monedas: `H`/`1` = cara, `T`/`0` = cruz. Se normalizan a `1` y `0`.
+//!   Cada lanzamiento aporta 1 bit.
+//!
+//! La entropía es `SHA256(cadena_normalizada)` truncada a la longitud de la
+//! frase (16 bytes para 12 palabras, 32 bytes para 24), igual que Coldcard.
+//! Se puede comprobar con `printf '%s' 16253... | sha256sum`.
+//!
+//! Opcionalmente se combina (XOR) con el RNG del sistema: el resultado es al
+//! menos tan fuerte como la mejor de las dos fuentes, pero deja de ser
+//! reproducible a partir de las tiradas.
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use crate::bip39::conversion::entropy_to_phrase;
+
+/// Fuente de entropía manual
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntropySource {
+    Dice,
+    Coin,
+}
+
+impl EntropySource {
+    /// Interpretar el nombre de la fuente ("dice" o "coin")
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dice" | "die" => Ok(EntropySource::Dice),
+            "coin" | "coins" => Ok(EntropySource::Coin),
+            other => Err(SCypherError::crypto(format!(
+                "Unknown entropy source '{}' (expected 'dice' or 'coin')",
+                other
+            ))),
+        }
+    }
+
+    /// Bits de entropía aportados por cada evento
+    fn bits_per_event(self) -> f64 {
+        match self {
+            EntropySource::Dice => 6f64.log2(),
+            EntropySource::Coin => 1.0,
+        }
+    }
+}
+
+/// Número mínimo de tiradas/lanzamientos para una longitud de frase
+pub fn required_events(source: EntropySource, word_count: usize) -> Result<usize> {
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        return Err(SCypherError::InvalidWordCount(word_count));
+    }
+    let entropy_bits = (word_count * 32 / 3) as f64;
+    Ok((entropy_bits / source.bits_per_event()).ceil() as usize)
+}
+
+/// Normalizar la entrada a la cadena que se hashea
+pub fn normalize_input(input: &str, source: EntropySource) -> Result<String> {
+    let mut normalized = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        if c.is_whitespace() || c == ',' {
+            continue;
+        }
+        let symbol = match (source, c.to_ascii_uppercase()) {
+            (EntropySource::Dice, '1'..='6') => c,
+            (EntropySource::Coin, 'H' | '1') => '1',
+            (EntropySource::Coin, 'T' | '0') => '0',
+            _ => {
+                return Err(SCypherError::crypto(format!(
+                    "Invalid character '{}' for {} input",
+                    c,
+                    if source == EntropySource::Dice { "dice (use 1-6)" } else { "coin (use H/T or 1/0)" }
+                )))
+            }
+        };
+        normalized.push(symbol);
+    }
+
+    Ok(normalized)
+}
+
+/// Generar una seed phrase a partir de tiradas de dados o lanzamientos de moneda
+pub fn phrase_from_manual_entropy(
+    input: &str,
+    source: EntropySource,
+    word_count: usize,
+    mix_with_os_rng: bool,
+) -> Result<String> {
+    let required = required_events(source, word_count)?;
+    let mut normalized = normalize_input(input, source)?;
+
+    if normalized.len() < required {
+        let provided = normalized.len();
+        normalized.zeroize();
+        return Err(SCypherError::crypto(format!(
+            "Not enough entropy: {} {} provided, at least {} required for {} words",
+            provided,
+            if source == EntropySource::Dice { "dice rolls" } else { "coin flips" },
+            required,
+            word_count
+        )));
+    }
+
+    let mut hash: [u8; 32] = Sha256::digest(normalized.as_bytes()).into();
+    normalized.zeroize();
+
+    let entropy_bytes = word_count * 32 / 3 / 8;
+    let mut entropy = hash[..entropy_bytes].to_vec();
+    hash.zeroize();
+
+    if mix_with_os_rng {
+        let mut os_entropy = vec![0u8; entropy_bytes];
+        crate::security::rng::fill_random(&mut os_entropy);
+        for (byte, os_byte) in entropy.iter_mut().zip(os_entropy.iter()) {
+            *byte ^= os_byte;
+        }
+        os_entropy.zeroize();
+    }
+
+    let phrase = entropy_to_phrase(&entropy);
+    entropy.zeroize();
+    phrase
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_events() {
+        assert_eq!(required_events(EntropySource::Dice, 12).unwrap(), 50);
+        assert_eq!(required_events(EntropySource::Dice, 24).unwrap(), 100);
+        assert_eq!(required_events(EntropySource::Coin, 12).unwrap(), 128);
+        assert!(required_events(EntropySource::Coin, 13).is_err());
+    }
+
+    #[test]
+    fn test_dice_mapping_is_sha256_of_rolls() {
+        let rolls = "1".repeat(50);
+        let phrase = phrase_from_manual_entropy(&rolls, EntropySource::Dice, 12, false).unwrap();
+
+        let expected = entropy_to_phrase(&Sha256::digest(rolls.as_bytes())[..16]).unwrap();
+        assert_eq!(phrase, expected);
+
+        // Separadores ignorados: misma frase
+        let spaced = rolls.chars().map(|c| format!("{}, ", c)).collect::<String>();
+        assert_eq!(phrase_from_manual_entropy(&spaced, EntropySource::Dice, 12, false).unwrap(), phrase);
+    }
+
+    #[test]
+    fn test_coin_normalization() {
+        let flips = "HT".repeat(64);
+        let binary = "10".repeat(64);
+        assert_eq!(
+            phrase_from_manual_entropy(&flips, EntropySource::Coin, 12, false).unwrap(),
+            phrase_from_manual_entropy(&binary, EntropySource::Coin, 12, false).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_or_insufficient_input() {
+        assert!(phrase_from_manual_entropy(&"1".repeat(49), EntropySource::Dice, 12, false).is_err());
+        assert!(phrase_from_manual_entropy(&"7".repeat(50), EntropySource::Dice, 12, false).is_err());
+        assert!(phrase_from_manual_entropy(&"HTX".repeat(60), EntropySource::Coin, 12, false).is_err());
+    }
+
+    #[test]
+    fn test_mixing_with_os_rng() {
+        let rolls = "3".repeat(100);
+        let plain = phrase_from_manual_entropy(&rolls, EntropySource::Dice, 24, false).unwrap();
+        let mixed = phrase_from_manual_entropy(&rolls, EntropySource::Dice, 24, true).unwrap();
+        assert_ne!(plain, mixed);
+        assert_eq!(mixed.split_whitespace().count(), 24);
+    }
+}
@@ -3,13 +3,23 @@
 pub mod wordlist;
 pub mod validation;
 pub mod conversion;
+pub mod entropy;
+pub mod manual_entropy;
+pub mod worksheet;
+pub mod transcription;
+pub mod extract;
+pub mod normalize;
+pub mod custom_wordlist;
+pub mod redaction;
+pub mod phonetic;
 
 use crate::error::Result;
 
 // Re-exportar funciones principales para fácil acceso
-pub use wordlist::{BIP39_WORDLIST, word_to_index, index_to_word, is_valid_word};
+pub use wordlist::{BIP39_WORDLIST, word_to_index, index_to_word, lookup_word};
 pub use validation::{validate_seed_phrase, validate_word_count, validate_words, analyze_seed_phrase, is_valid_seed_phrase};
 pub use conversion::{phrase_to_bits, bits_to_phrase, entropy_to_phrase, phrase_to_entropy, phrase_to_hex, hex_to_phrase};
+pub use normalize::normalize_seed_input;
 
 /// Validar formato de seed phrase BIP39 (función principal)
 pub fn validate_seed_phrase_complete(seed_phrase: &str) -> Result<()> {
@@ -24,3 +34,60 @@ pub fn verify_checksum(seed_phrase: &str) -> Result<bool> {
         Err(e) => Err(e),
     }
 }
+
+/// Comparar dos frases sin revelar en qué se diferencian
+///
+/// Ambas se normalizan (también las abreviaturas de 4 letras) y se reducen a su entropía; la comparación es en
+/// tiempo constante (solo depende de la longitud, es decir, del número de
+/// palabras). Una frase inválida o con checksum incorrecto nunca coincide, y
+/// las dos se procesan igual aunque la primera falle.
+pub fn phrases_match(first: &str, second: &str) -> bool {
+    use zeroize::Zeroize;
+
+    let entropy = |phrase: &str| -> Option<Vec<u8>> {
+        let mut normalized = normalize_seed_input(phrase);
+        let mut expanded = wordlist::expand_abbreviations(&normalized).unwrap_or_default();
+        let entropy = validation::validate_seed_phrase(&expanded)
+            .and_then(|_| phrase_to_entropy(&expanded))
+            .ok();
+        normalized.zeroize();
+        expanded.zeroize();
+        entropy
+    };
+
+    let mut first = entropy(first);
+    let mut second = entropy(second);
+    let equal = match (&first, &second) {
+        (Some(a), Some(b)) => crate::security::utils::constant_time_eq(a, b),
+        _ => false,
+    };
+    for entropy in [&mut first, &mut second].into_iter().flatten() {
+        entropy.zeroize();
+    }
+    equal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_phrases_match_after_normalization() {
+        let retyped = "1) Abandon 2) abandon 3) abandon 4) abandon\n5) abandon 6) abandon 7) abandon 8) abandon\n9) abandon 10) abandon 11) abandon 12) ABOUT";
+        assert!(phrases_match(PHRASE, retyped));
+        assert!(phrases_match(PHRASE, PHRASE));
+        assert!(phrases_match(PHRASE, &PHRASE.replace("abandon", "aban")));
+    }
+
+    #[test]
+    fn test_phrases_mismatch_and_invalid() {
+        let other = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert!(!phrases_match(PHRASE, other));
+        // Mismo prefijo, checksum incorrecto
+        assert!(!phrases_match(PHRASE, &PHRASE.replace("about", "abandon")));
+        assert!(!phrases_match(PHRASE, "abandon abandon abandon"));
+        assert!(!phrases_match("", ""));
+    }
+}
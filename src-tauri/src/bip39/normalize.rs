@@ -0,0 +1,63 @@
+//! Normalización de la frase introducida por el usuario
+//!
+//! Antes de validar, la entrada pasa por NFKD (la forma que BIP39 exige para
+//! las palabras), se pasa a minúsculas y se descartan marcadores de lista,
+//! puntuación y separadores. Así se aceptan frases pegadas con numeración
+//! ("1) abandon"), comillas tipográficas, letras de ancho completo o espacios
+//! ideográficos, y palabras con acentos (francés) o kana (japonés).
+//!
+//! Los sobres JSON de formatos futuros (`{"format_version": ...}`) no son una
+//! frase y se devuelven sin tocar para que el despachador de formato los vea.
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Letras y marcas combinantes (acentos y dakuten quedan separados tras NFKD)
+fn is_word_char(c: char) -> bool {
+    c.is_alphabetic() || is_combining_mark(c)
+}
+
+/// Normalizar una frase: NFKD, minúsculas y palabras separadas por un espacio
+pub fn normalize_seed_input(input: &str) -> String {
+    let trimmed = input.trim();
+    if trimmed.starts_with('{') {
+        return trimmed.to_string();
+    }
+
+    let decomposed = trimmed.nfkd().collect::<String>().to_lowercase();
+    decomposed
+        .split(|c: char| !is_word_char(c))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strips_numbering_and_punctuation() {
+        let pasted = "1) Abandon\n2) ability, “able” 3. about — above;";
+        assert_eq!(normalize_seed_input(pasted), "abandon ability able about above");
+
+        // Ancho completo y espacio ideográfico (U+3000)
+        assert_eq!(normalize_seed_input("ＡＢＡＮＤＯＮ\u{3000}ａｂｉｌｉｔｙ"), "abandon ability");
+
+        let envelope = r#" {"format_version": 2, "data": "..."} "#;
+        assert_eq!(normalize_seed_input(envelope), envelope.trim());
+    }
+
+    #[test]
+    fn test_non_english_words_use_nfkd() {
+        // Lista francesa: "élève" compuesto y descompuesto dan lo mismo
+        let composed = normalize_seed_input("\u{e9}l\u{e8}ve abeille");
+        let decomposed = normalize_seed_input("e\u{301}le\u{300}ve abeille");
+        assert_eq!(composed, decomposed);
+        assert_eq!(composed.split(' ').count(), 2);
+
+        // Lista japonesa: el dakuten se conserva como marca combinante
+        let japanese = normalize_seed_input("あいこくしん\u{3000}あおぞら\u{3000}ざっか");
+        assert_eq!(japanese, "あいこくしん あおそ\u{3099}ら さ\u{3099}っか");
+    }
+}
@@ -0,0 +1,128 @@
+//! Deletreo fonético (alfabeto OTAN) de una seed phrase
+//!
+//! Para dictar la frase de viva voz a otra persona a través de un air-gap:
+//! cada palabra va con su posición y su deletreo ("zebra" → Zulu-Echo-Bravo-
+//! Romeo-Alfa). Las letras acentuadas se deletrean sin el acento (la palabra
+//! BIP39 se identifica igual) y las palabras japonesas o coreanas se
+//! deletrean a partir de su romanización.
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use crate::error::{SCypherError, Result};
+
+/// Alfabeto fonético OTAN/OACI, de la A a la Z
+pub const NATO_ALPHABET: [&str; 26] = [
+    "Alfa", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India",
+    "Juliett", "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo",
+    "Sierra", "Tango", "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+];
+
+/// Una palabra con su deletreo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhoneticWord {
+    pub position: usize,              // Empieza en 1
+    pub word: String,
+    pub romanized: Option<String>,    // Solo japonés y coreano
+    pub code_words: Vec<String>,      // "Zulu", "Echo"...
+}
+
+/// Palabra del alfabeto OTAN de una letra (otros caracteres se devuelven tal cual)
+pub fn code_word(letter: char) -> String {
+    let lower = letter.to_ascii_lowercase();
+    if lower.is_ascii_lowercase() {
+        NATO_ALPHABET[(lower as u8 - b'a') as usize].to_string()
+    } else {
+        letter.to_uppercase().collect()
+    }
+}
+
+/// Deletrear una palabra: sin acentos y, si hace falta, desde su romanización
+fn spell(word: &str, romanized: Option<&str>) -> Vec<String> {
+    romanized
+        .unwrap_or(word)
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .filter(|c| c.is_alphanumeric())
+        .map(code_word)
+        .collect()
+}
+
+/// Deletrear todas las palabras de la frase
+pub fn build_phonetic_readout(phrase: &str) -> Result<Vec<PhoneticWord>> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.is_empty() {
+        return Err(SCypherError::InvalidWordCount(0));
+    }
+
+    Ok(words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let romanized = crate::bip39::wordlist::transliterate_word(word);
+            PhoneticWord {
+                position: i + 1,
+                word: word.to_string(),
+                code_words: spell(word, romanized.as_deref()),
+                romanized,
+            }
+        })
+        .collect())
+}
+
+/// Quitar los deletreos ": Zulu-Echo-..." de un texto formateado
+///
+/// Solo se quita lo que sigue al último ':' de cada línea si son todo
+/// palabras del alfabeto, para que el resto lo limpie `normalize_seed_input`.
+pub fn strip_phonetic_spelling(text: &str) -> String {
+    text.lines()
+        .map(|line| match line.rsplit_once(':') {
+            Some((before, spelling)) if is_spelling(spelling) => before,
+            _ => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_spelling(text: &str) -> bool {
+    let text = text.trim();
+    // "X-ray" lleva guion: se reconoce antes de partir por guiones
+    let text = text.replace("X-ray", "Xray");
+    !text.is_empty()
+        && text.split('-').all(|part| {
+            NATO_ALPHABET.iter().any(|code| code.replace('-', "") == part)
+                || (part.chars().count() == 1 && !part.chars().all(char::is_lowercase))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spells_words_with_positions() {
+        let readout = build_phonetic_readout("zebra wax abandon").unwrap();
+        assert_eq!(readout[0].position, 1);
+        assert_eq!(readout[0].code_words, vec!["Zulu", "Echo", "Bravo", "Romeo", "Alfa"]);
+        assert_eq!(readout[1].code_words, vec!["Whiskey", "Alfa", "X-ray"]);
+        assert_eq!(readout[2].position, 3);
+        assert!(readout[0].romanized.is_none());
+        assert!(build_phonetic_readout("  ").is_err());
+    }
+
+    #[test]
+    fn test_accents_and_kana() {
+        // Francés: el acento no se deletrea
+        let readout = build_phonetic_readout("\u{e9}l\u{e8}ve").unwrap();
+        assert_eq!(readout[0].code_words, vec!["Echo", "Lima", "Echo", "Victor", "Echo"]);
+
+        // Japonés: se deletrea la romanización
+        let readout = build_phonetic_readout("あおそ\u{3099}ら").unwrap();
+        assert_eq!(readout[0].romanized.as_deref(), Some("aozora"));
+        assert_eq!(readout[0].code_words.first().map(String::as_str), Some("Alfa"));
+        assert_eq!(readout[0].code_words.len(), 6);
+
+        assert_eq!(strip_phonetic_spelling("01 — wax: Whiskey-Alfa-X-ray"), "01 — wax");
+        assert_eq!(strip_phonetic_spelling("note: keep safe"), "note: keep safe");
+    }
+}
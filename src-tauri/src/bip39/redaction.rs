@@ -0,0 +1,102 @@
+//! Vista enmascarada de una seed phrase
+//!
+//! Para comprobar que se cargó la frase correcta (por ejemplo, al hablar con
+//! soporte o compartir pantalla) basta con ver unas pocas palabras. El
+//! backend construye la vista a partir de la frase que ya tiene (resultado
+//! retenido o perfil de la bóveda) y solo envía a la GUI las palabras de las
+//! posiciones visibles; el resto se sustituye por `REDACTED_WORD`.
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Marcador de las palabras ocultas
+pub const REDACTED_WORD: &str = "•••";
+
+/// Frase con las palabras no visibles ocultas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactedPhrase {
+    pub word_count: usize,
+    pub visible_positions: Vec<usize>,  // Empiezan en 1
+    pub words: Vec<String>,
+    pub text: String,
+}
+
+/// Ocultar todas las palabras salvo las de `visible_positions` (empiezan en 1)
+///
+/// Sin posiciones se muestran la primera y la última. No se puede dejar
+/// visible más de la mitad de la frase.
+pub fn redact_phrase(phrase: &str, visible_positions: Option<&[usize]>) -> Result<RedactedPhrase> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let word_count = words.len();
+    if word_count == 0 {
+        return Err(SCypherError::InvalidWordCount(0));
+    }
+
+    let mut visible: Vec<usize> = match visible_positions {
+        Some(positions) => positions.to_vec(),
+        None => vec![1, word_count],
+    };
+    visible.sort_unstable();
+    visible.dedup();
+    if let Some(&position) = visible.iter().find(|&&p| p == 0 || p > word_count) {
+        return Err(SCypherError::crypto(format!(
+            "Word position {} is out of range (1-{})",
+            position, word_count
+        )));
+    }
+    if visible.len() * 2 > word_count {
+        return Err(SCypherError::crypto(format!(
+            "At most {} of {} words can stay visible",
+            word_count / 2,
+            word_count
+        )));
+    }
+
+    let words: Vec<String> = words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| if visible.contains(&(i + 1)) { word.to_string() } else { REDACTED_WORD.to_string() })
+        .collect();
+    let text = words.join(" ");
+    Ok(RedactedPhrase { word_count, visible_positions: visible, words, text })
+}
+
+/// Igual que `redact_phrase`, borrando después la copia de la frase recibida
+pub fn redact_owned_phrase(mut phrase: String, visible_positions: Option<&[usize]>) -> Result<RedactedPhrase> {
+    let redacted = redact_phrase(&phrase, visible_positions);
+    phrase.zeroize();
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon ability able about above absent absorb abstract absurd abuse access accident";
+
+    #[test]
+    fn test_default_shows_first_and_last() {
+        let redacted = redact_phrase(PHRASE, None).unwrap();
+        assert_eq!(redacted.visible_positions, vec![1, 12]);
+        assert_eq!(redacted.words[0], "abandon");
+        assert_eq!(redacted.words[11], "accident");
+        assert!(redacted.words[1..11].iter().all(|w| w == REDACTED_WORD));
+        assert!(!redacted.text.contains("ability"));
+        assert_eq!(redacted.text.split(' ').count(), 12);
+    }
+
+    #[test]
+    fn test_chosen_positions_are_validated() {
+        let redacted = redact_phrase(PHRASE, Some(&[5, 3, 5])).unwrap();
+        assert_eq!(redacted.visible_positions, vec![3, 5]);
+        assert_eq!(redacted.words[2], "able");
+        assert_eq!(redacted.words[4], "above");
+        assert_eq!(redacted.words[0], REDACTED_WORD);
+
+        assert!(redact_phrase(PHRASE, Some(&[0])).is_err());
+        assert!(redact_phrase(PHRASE, Some(&[13])).is_err());
+        assert!(redact_phrase(PHRASE, Some(&[1, 2, 3, 4, 5, 6, 7])).is_err());
+        assert!(redact_phrase("", None).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Ayuda de transcripción: palabra de control por línea
+//!
+//! La frase se escribe en líneas de N palabras (4 por defecto, como Electrum)
+//! y cada línea termina con una palabra de control de la lista BIP39 sacada
+//! del SHA256 de su número de línea y sus palabras. Un error al copiar a mano
+//! cambia la palabra de control de esa línea, así que se localiza la línea
+//! equivocada en vez de saber solo que el checksum de toda la frase falla.
+//! La importación quita las palabras de control y dice qué líneas no cuadran.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+use crate::bip39::wordlist::{expand_abbreviation, expand_abbreviations, word_to_index, BIP39_WORDLIST};
+
+/// Palabras por línea por defecto
+pub const DEFAULT_WORDS_PER_LINE: usize = 4;
+
+/// Máximo de palabras por línea (una frase de 24 en dos líneas)
+pub const MAX_WORDS_PER_LINE: usize = 12;
+
+/// Separación de dominio del hash de la palabra de control
+const CHECKWORD_DOMAIN: &[u8] = b"SCYPHER_CHECKWORD_V1";
+
+/// Separador entre las palabras de la línea y su palabra de control
+const CHECKWORD_SEPARATOR: char = '|';
+
+/// Resultado de importar una transcripción
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionImport {
+    pub phrase: String,              // Palabras sin las de control
+    pub lines: usize,
+    pub invalid_lines: Vec<usize>,   // Empiezan en 1
+    pub valid: bool,
+}
+
+/// Palabra de control de una línea (el número de línea detecta líneas intercambiadas)
+pub fn checkword(line_number: usize, words: &[&str]) -> &'static str {
+    let mut hasher = Sha256::new();
+    hasher.update(CHECKWORD_DOMAIN);
+    hasher.update((line_number as u32).to_be_bytes());
+    hasher.update(words.join(" ").as_bytes());
+    let hash = hasher.finalize();
+
+    // 65536 es múltiplo de 2048: todas las palabras son igual de probables
+    BIP39_WORDLIST[u16::from_be_bytes([hash[0], hash[1]]) as usize % BIP39_WORDLIST.len()]
+}
+
+/// Escribir la frase en líneas numeradas con su palabra de control
+///
+/// " 1. abandon ability able about | <control>"
+pub fn format_transcription(phrase: &str, words_per_line: usize) -> Result<String> {
+    if !(1..=MAX_WORDS_PER_LINE).contains(&words_per_line) {
+        return Err(SCypherError::file(format!(
+            "Words per line must be between 1 and {}",
+            MAX_WORDS_PER_LINE
+        )));
+    }
+
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    super::validate_word_count(words.len())?;
+    if let Some(word) = words.iter().find(|word| word_to_index(word).is_none()) {
+        return Err(SCypherError::InvalidBip39Word(word.to_string()));
+    }
+
+    let lines: Vec<String> = words
+        .chunks(words_per_line)
+        .enumerate()
+        .map(|(i, line)| {
+            format!("{:2}. {} {} {}", i + 1, line.join(" "), CHECKWORD_SEPARATOR, checkword(i + 1, line))
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// Leer una transcripción, quitar las palabras de control y comprobarlas
+///
+/// Acepta numeración, mayúsculas y palabras abreviadas a 4 letras.
+pub fn parse_transcription(text: &str) -> Result<TranscriptionImport> {
+    let mut phrase_words = Vec::new();
+    let mut invalid_lines = Vec::new();
+    let mut lines = 0;
+
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        lines += 1;
+        let (words, check) = line.split_once(CHECKWORD_SEPARATOR).ok_or_else(|| {
+            SCypherError::file(format!("Line {} has no checkword (expected 'words {} checkword')", lines, CHECKWORD_SEPARATOR))
+        })?;
+
+        let words = expand_abbreviations(&super::normalize_seed_input(words))?;
+        let words: Vec<&str> = words.split_whitespace().collect();
+        let check = expand_abbreviation(&super::normalize_seed_input(check))?;
+        if words.is_empty() || check != checkword(lines, &words) {
+            invalid_lines.push(lines);
+        }
+        phrase_words.extend(words.iter().map(|word| word.to_string()));
+    }
+
+    if lines == 0 {
+        return Err(SCypherError::file("Transcription is empty".to_string()));
+    }
+
+    Ok(TranscriptionImport {
+        phrase: phrase_words.join(" "),
+        lines,
+        valid: invalid_lines.is_empty(),
+        invalid_lines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_transcription_roundtrip() {
+        let text = format_transcription(PHRASE, DEFAULT_WORDS_PER_LINE).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.starts_with(" 1. abandon abandon abandon abandon | "));
+
+        let imported = parse_transcription(&text).unwrap();
+        assert!(imported.valid);
+        assert_eq!(imported.lines, 3);
+        assert_eq!(imported.phrase, PHRASE);
+
+        // Abreviaturas y mayúsculas al copiar de vuelta
+        let abbreviated = text.replace("abandon", "ABAN");
+        assert!(parse_transcription(&abbreviated).unwrap().valid);
+    }
+
+    #[test]
+    fn test_transcription_detects_line_errors() {
+        let text = format_transcription(PHRASE, 6).unwrap();
+        let typo: Vec<String> = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| if i == 1 { line.replacen("abandon", "ability", 1) } else { line.to_string() })
+            .collect();
+        let imported = parse_transcription(&typo.join("\n")).unwrap();
+        assert!(!imported.valid);
+        assert_eq!(imported.invalid_lines, vec![2]);
+
+        // Líneas intercambiadas: mismo contenido, otra posición
+        let lines: Vec<&str> = text.lines().map(|line| line.split_once(". ").unwrap().1).collect();
+        let swapped = format!("{}\n{}", lines[1], lines[0]);
+        assert_eq!(parse_transcription(&swapped).unwrap().invalid_lines, vec![1, 2]);
+
+        assert!(parse_transcription("abandon abandon abandon").is_err());
+        assert!(format_transcription(PHRASE, 0).is_err());
+    }
+}
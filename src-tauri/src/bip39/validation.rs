@@ -4,15 +4,18 @@
 //! según el estándar BIP39, incluyendo validación de palabras,
 //! longitud y checksums.
 
+use serde::{Deserialize, Serialize};
 use crate::error::{SCypherError, Result};
-use crate::bip39::wordlist::{is_valid_word, find_closest_word};
+use crate::bip39::wordlist::{is_valid_word, find_closest_word, expand_abbreviations};
 use crate::crypto::checksum;
 
 /// Longitudes válidas de seed phrases BIP39 (en palabras)
 const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
 
 /// Valida una seed phrase completa BIP39
+/// Acepta palabras abreviadas a sus 4 primeras letras ("aban abil ...")
 pub fn validate_seed_phrase(phrase: &str) -> Result<()> {
+    let phrase = expand_abbreviations(phrase)?;
     let words = phrase.split_whitespace().collect::<Vec<&str>>();
 
     // 1. Validar longitud
@@ -22,7 +25,7 @@ pub fn validate_seed_phrase(phrase: &str) -> Result<()> {
     validate_words(&words)?;
 
     // 3. Validar checksum
-    validate_checksum(phrase)?;
+    validate_checksum(&phrase)?;
 
     Ok(())
 }
@@ -37,40 +40,45 @@ pub fn validate_word_count(count: usize) -> Result<()> {
 }
 
 /// Valida que todas las palabras estén en la lista BIP39
+///
+/// El error lleva la posición (desde 0) de cada palabra inválida.
 pub fn validate_words(words: &[&str]) -> Result<()> {
-    let mut invalid_words = Vec::new();
-    let mut suggestions = Vec::new();
-
-    for &word in words {
-        if !is_valid_word(word) {
-            invalid_words.push(word);
-
-            // Intentar encontrar sugerencia para palabra inválida
-            if let Some((closest, distance)) = find_closest_word(word) {
-                if distance <= 2 { // Solo sugerir si la distancia es pequeña
-                    suggestions.push(format!("'{}' -> '{}'", word, closest));
-                }
-            }
-        }
-    }
-
+    let invalid_words = find_invalid_words(words);
     if !invalid_words.is_empty() {
-        let mut error_msg = format!(
-            "Invalid BIP39 words found: {}",
-            invalid_words.join(", ")
-        );
-
-        if !suggestions.is_empty() {
-            error_msg.push_str("\nSuggested corrections: ");
-            error_msg.push_str(&suggestions.join(", "));
-        }
-
-        return Err(SCypherError::InvalidBip39Word(error_msg));
+        return Err(SCypherError::InvalidWords(invalid_words));
     }
 
     Ok(())
 }
 
+/// Palabras fuera de la lista con su posición y la sugerencia más cercana
+fn find_invalid_words(words: &[&str]) -> Vec<InvalidWordInfo> {
+    words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| !is_valid_word(word))
+        .map(|(position, &word)| InvalidWordInfo {
+            position,
+            word: word.to_string(),
+            // Solo sugerir si la distancia es pequeña
+            suggestion: find_closest_word(word)
+                .filter(|(_, distance)| *distance <= 2)
+                .map(|(closest, _)| closest.to_string()),
+        })
+        .collect()
+}
+
+/// Posiciones de las palabras que llevan bits del checksum
+///
+/// El checksum (4 a 8 bits) cabe siempre en la última palabra.
+pub fn checksum_word_positions(word_count: usize) -> Vec<usize> {
+    if VALID_WORD_COUNTS.contains(&word_count) {
+        vec![word_count - 1]
+    } else {
+        Vec::new()
+    }
+}
+
 /// Valida el checksum BIP39 de una seed phrase
 pub fn validate_checksum(phrase: &str) -> Result<()> {
     let words: Vec<&str> = phrase.split_whitespace().collect();
@@ -123,6 +131,8 @@ pub fn analyze_seed_phrase(phrase: &str) -> SeedPhraseAnalysis {
         is_valid_length: VALID_WORD_COUNTS.contains(&word_count),
         invalid_words: Vec::new(),
         suggestions: Vec::new(),
+        invalid_word_details: find_invalid_words(&words),
+        checksum_word_positions: checksum_word_positions(word_count),
         entropy_bits: if VALID_WORD_COUNTS.contains(&word_count) {
             Some(word_count * 32 / 3)
         } else {
@@ -133,15 +143,10 @@ pub fn analyze_seed_phrase(phrase: &str) -> SeedPhraseAnalysis {
     };
 
     // Analizar palabras individuales
-    for &word in &words {
-        if !is_valid_word(word) {
-            analysis.invalid_words.push(word.to_string());
-
-            if let Some((closest, distance)) = find_closest_word(word) {
-                if distance <= 2 {
-                    analysis.suggestions.push(format!("{} -> {}", word, closest));
-                }
-            }
+    for info in &analysis.invalid_word_details {
+        analysis.invalid_words.push(info.word.clone());
+        if let Some(closest) = &info.suggestion {
+            analysis.suggestions.push(format!("{} -> {}", info.word, closest));
         }
     }
 
@@ -154,13 +159,23 @@ pub fn analyze_seed_phrase(phrase: &str) -> SeedPhraseAnalysis {
     analysis
 }
 
+/// Palabra inválida con su posición en la frase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvalidWordInfo {
+    pub position: usize,            // Índice del token en la entrada (desde 0)
+    pub word: String,
+    pub suggestion: Option<String>, // Palabra BIP39 más cercana (distancia <= 2)
+}
+
 /// Estructura que contiene análisis detallado de una seed phrase
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeedPhraseAnalysis {
     pub word_count: usize,
     pub is_valid_length: bool,
     pub invalid_words: Vec<String>,
     pub suggestions: Vec<String>,
+    pub invalid_word_details: Vec<InvalidWordInfo>,
+    pub checksum_word_positions: Vec<usize>,  // Palabras con bits del checksum
     pub entropy_bits: Option<usize>,
     pub checksum_valid: Option<bool>,
     pub overall_valid: bool,
@@ -209,6 +224,43 @@ impl SeedPhraseAnalysis {
     }
 }
 
+/// Resultado de validar una línea de un archivo con varias frases
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineValidation {
+    pub line: usize,                          // Empieza en 1
+    pub valid: bool,
+    pub word_count: usize,
+    pub error_code: Option<String>,
+    pub reason: Option<String>,
+    pub invalid_words: Vec<InvalidWordInfo>,  // Con sugerencia si hay una cercana
+}
+
+/// Validar cada línea no vacía como una frase candidata
+///
+/// El informe no repite las frases: basta el número de línea para localizarlas.
+pub fn validate_phrase_lines(text: &str) -> Vec<LineValidation> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let phrase = crate::bip39::normalize_seed_input(line);
+            let words: Vec<&str> = phrase.split_whitespace().collect();
+            let result = validate_seed_phrase(&phrase)
+                .and_then(|()| crate::security::training::ensure_demo_phrase(&phrase));
+
+            LineValidation {
+                line: i + 1,
+                valid: result.is_ok(),
+                word_count: words.len(),
+                // Las abreviaturas válidas no cuentan como palabras inválidas
+                invalid_words: if result.is_ok() { Vec::new() } else { find_invalid_words(&words) },
+                error_code: result.as_ref().err().map(|e| e.code().to_string()),
+                reason: result.err().map(|e| e.to_string()),
+            }
+        })
+        .collect()
+}
+
 /// Función de conveniencia para validación rápida
 pub fn is_valid_seed_phrase(phrase: &str) -> bool {
     validate_seed_phrase(phrase).is_ok()
@@ -246,9 +298,15 @@ mod tests {
         let valid_words = vec!["abandon", "ability", "able"];
         assert!(validate_words(&valid_words).is_ok());
 
-        // Palabras inválidas
-        let invalid_words = vec!["abandon", "invalid_word", "able"];
-        assert!(validate_words(&invalid_words).is_err());
+        // Palabras inválidas con su posición
+        let invalid_words = vec!["abandon", "invalid_word", "able", "abilty"];
+        match validate_words(&invalid_words) {
+            Err(SCypherError::InvalidWords(words)) => {
+                assert_eq!(words.iter().map(|w| w.position).collect::<Vec<_>>(), vec![1, 3]);
+                assert_eq!(words[1].suggestion.as_deref(), Some("ability"));
+            }
+            other => panic!("Unexpected result: {:?}", other.err()),
+        }
     }
 
     #[test]
@@ -294,6 +352,29 @@ mod tests {
         assert_eq!(analysis.word_count, 3);
         assert!(!analysis.is_valid_length);
         assert!(!analysis.overall_valid);
+
+        // Posición y sugerencia de cada palabra inválida
+        let analysis = analyze_seed_phrase("abandon abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        assert_eq!(analysis.invalid_word_details.len(), 1);
+        assert_eq!(analysis.invalid_word_details[0].position, 1);
+        assert_eq!(analysis.invalid_word_details[0].suggestion.as_deref(), Some("abandon"));
+        assert_eq!(analysis.checksum_valid, None);
+        assert_eq!(analysis.checksum_word_positions, vec![11]);
+    }
+
+    #[test]
+    fn test_validate_phrase_lines() {
+        let text = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n\n\
+                    abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon\n\
+                    1. abandon 2. abandn 3. abandon";
+        let report = validate_phrase_lines(text);
+        assert_eq!(report.len(), 3);
+        assert!(report[0].valid);
+        assert_eq!(report[1].line, 3);
+        assert_eq!(report[1].error_code.as_deref(), Some("invalid_checksum"));
+        assert_eq!(report[2].word_count, 3);
+        assert_eq!(report[2].invalid_words[0].position, 1);
+        assert_eq!(report[2].invalid_words[0].suggestion.as_deref(), Some("abandon"));
     }
 
     #[test]
@@ -2,9 +2,19 @@
 //!
 //! Contiene las 2048 palabras oficiales del estándar BIP39
 //! para la validación y conversión de seed phrases.
+//!
+//! También romaniza palabras de las listas japonesa (Hepburn) y coreana
+//! (romanización revisada) para mostrarlas junto al original. Es solo una
+//! ayuda para copiarlas: la palabra nativa es la que cuenta para el seed.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+use crate::error::{SCypherError, Result};
+
+/// Letras iniciales que identifican cada palabra de forma única (BIP39)
+pub const UNIQUE_PREFIX_LENGTH: usize = 4;
 
 /// Lista completa de 2048 palabras BIP39 (ordenadas alfabéticamente)
 pub const BIP39_WORDLIST: [&str; 2048] = [
@@ -218,8 +228,24 @@ pub const BIP39_WORDLIST: [&str; 2048] = [
 /// HashMap estático para búsquedas O(1) de palabra -> índice
 static WORD_TO_INDEX_MAP: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
 
-/// Obtiene el índice de una palabra en la lista BIP39
-pub fn word_to_index(word: &str) -> Option<usize> {
+/// Normalizar una palabra para buscarla: sin espacios alrededor y en minúsculas
+///
+/// Usa el mapeo de mayúsculas por defecto de Unicode (no depende del locale
+/// del sistema) y no reserva memoria si la palabra ya está normalizada.
+pub fn normalize_word(word: &str) -> Cow<'_, str> {
+    let trimmed = word.trim();
+    if trimmed.chars().any(char::is_uppercase) {
+        Cow::Owned(trimmed.to_lowercase())
+    } else {
+        Cow::Borrowed(trimmed)
+    }
+}
+
+/// Buscar el índice de una palabra
+///
+/// Con `strict` la palabra debe coincidir exactamente con la lista; sin él se
+/// normaliza antes (mayúsculas y espacios alrededor).
+pub fn lookup_word(word: &str, strict: bool) -> Option<usize> {
     let map = WORD_TO_INDEX_MAP.get_or_init(|| {
         BIP39_WORDLIST
             .iter()
@@ -228,7 +254,16 @@ pub fn word_to_index(word: &str) -> Option<usize> {
             .collect()
     });
 
-    map.get(word).copied()
+    if strict {
+        map.get(word).copied()
+    } else {
+        map.get(normalize_word(word).as_ref()).copied()
+    }
+}
+
+/// Obtiene el índice de una palabra en la lista BIP39 (sin distinguir mayúsculas)
+pub fn word_to_index(word: &str) -> Option<usize> {
+    lookup_word(word, false)
 }
 
 /// Obtiene la palabra BIP39 para un índice dado
@@ -236,23 +271,61 @@ pub fn index_to_word(index: usize) -> Option<&'static str> {
     BIP39_WORDLIST.get(index).copied()
 }
 
-/// Verifica si una palabra está en la lista BIP39
+/// Verifica si una palabra está en la lista BIP39 (sin distinguir mayúsculas)
 pub fn is_valid_word(word: &str) -> bool {
     word_to_index(word).is_some()
 }
 
 /// Obtiene todas las palabras que comienzan con un prefijo dado
 pub fn words_with_prefix(prefix: &str) -> Vec<&'static str> {
+    let prefix = normalize_word(prefix);
     BIP39_WORDLIST
         .iter()
-        .filter(|&&word| word.starts_with(prefix))
+        .filter(|&&word| word.starts_with(prefix.as_ref()))
         .copied()
         .collect()
 }
 
+/// Expandir una abreviatura contra una lista de palabras
+fn expand_in_wordlist(word: &str, wordlist: &[&'static str]) -> Result<String> {
+    if wordlist.contains(&word) || word.chars().count() < UNIQUE_PREFIX_LENGTH {
+        return Ok(word.to_string());
+    }
+
+    let candidates: Vec<&str> = wordlist.iter().filter(|w| w.starts_with(word)).copied().collect();
+    match candidates.as_slice() {
+        [single] => Ok(single.to_string()),
+        [] => Ok(word.to_string()), // La validación la marcará como inválida
+        _ => Err(SCypherError::AmbiguousWord {
+            prefix: word.to_string(),
+            candidates: candidates.iter().map(|w| w.to_string()).collect(),
+        }),
+    }
+}
+
+/// Expandir una palabra abreviada ("aban" -> "abandon")
+///
+/// Las palabras completas no cambian. Un prefijo de al menos 4 letras que
+/// solo encaja con una palabra se expande; si encaja con varias (posible en
+/// listas no inglesas) se devuelve `AmbiguousWord`.
+pub fn expand_abbreviation(word: &str) -> Result<String> {
+    expand_in_wordlist(&normalize_word(word), &BIP39_WORDLIST)
+}
+
+/// Expandir todas las abreviaturas de una frase
+pub fn expand_abbreviations(phrase: &str) -> Result<String> {
+    let words = phrase
+        .split_whitespace()
+        .map(expand_abbreviation)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(words.join(" "))
+}
+
 /// Encuentra la palabra más cercana usando distancia de Levenshtein
 /// Útil para sugerir correcciones
 pub fn find_closest_word(input: &str) -> Option<(&'static str, usize)> {
+    let input = normalize_word(input);
+    let input = input.as_ref();
     let mut best_word = None;
     let mut best_distance = usize::MAX;
 
@@ -297,6 +370,159 @@ fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     matrix[len1][len2]
 }
 
+/// Hiragana a Hepburn (sin yōon ni sokuon, que se resuelven al recorrer la palabra)
+fn kana_syllable(c: char) -> Option<&'static str> {
+    Some(match c {
+        'あ' | 'ぁ' => "a", 'い' | 'ぃ' => "i", 'う' | 'ぅ' => "u", 'え' | 'ぇ' => "e", 'お' | 'ぉ' => "o",
+        'か' => "ka", 'き' => "ki", 'く' => "ku", 'け' => "ke", 'こ' => "ko",
+        'が' => "ga", 'ぎ' => "gi", 'ぐ' => "gu", 'げ' => "ge", 'ご' => "go",
+        'さ' => "sa", 'し' => "shi", 'す' => "su", 'せ' => "se", 'そ' => "so",
+        'ざ' => "za", 'じ' => "ji", 'ず' => "zu", 'ぜ' => "ze", 'ぞ' => "zo",
+        'た' => "ta", 'ち' => "chi", 'つ' => "tsu", 'て' => "te", 'と' => "to",
+        'だ' => "da", 'ぢ' => "ji", 'づ' => "zu", 'で' => "de", 'ど' => "do",
+        'な' => "na", 'に' => "ni", 'ぬ' => "nu", 'ね' => "ne", 'の' => "no",
+        'は' => "ha", 'ひ' => "hi", 'ふ' => "fu", 'へ' => "he", 'ほ' => "ho",
+        'ば' => "ba", 'び' => "bi", 'ぶ' => "bu", 'べ' => "be", 'ぼ' => "bo",
+        'ぱ' => "pa", 'ぴ' => "pi", 'ぷ' => "pu", 'ぺ' => "pe", 'ぽ' => "po",
+        'ま' => "ma", 'み' => "mi", 'む' => "mu", 'め' => "me", 'も' => "mo",
+        'や' => "ya", 'ゆ' => "yu", 'よ' => "yo",
+        'ら' => "ra", 'り' => "ri", 'る' => "ru", 'れ' => "re", 'ろ' => "ro",
+        'わ' => "wa", 'を' => "wo", 'ん' => "n", 'ゔ' => "vu",
+        _ => return None,
+    })
+}
+
+/// Vocal de las kana pequeñas ゃ, ゅ y ょ (yōon)
+fn small_ya(c: char) -> Option<char> {
+    match c {
+        'ゃ' => Some('a'),
+        'ゅ' => Some('u'),
+        'ょ' => Some('o'),
+        _ => None,
+    }
+}
+
+/// Romanizar una palabra en hiragana (Hepburn, vocales largas repetidas)
+fn romanize_kana(word: &str) -> Option<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut romaji = String::new();
+    let mut geminate = false;
+    let mut after_n = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            // Sokuon: dobla la consonante siguiente (っち -> tchi)
+            'っ' => geminate = true,
+            'ー' => {
+                let vowel = romaji.chars().last()?;
+                romaji.push(vowel);
+            }
+            c => {
+                let mut syllable = kana_syllable(c)?.to_string();
+                if let Some(vowel) = chars.get(i + 1).copied().and_then(small_ya) {
+                    if syllable.len() > 1 && syllable.ends_with('i') {
+                        syllable.pop();
+                        if !matches!(syllable.as_str(), "sh" | "ch" | "j") {
+                            syllable.push('y');
+                        }
+                        syllable.push(vowel);
+                        i += 1;
+                    }
+                }
+
+                // ん ante vocal o "y" lleva apóstrofo para no confundir sílabas
+                if after_n && syllable.starts_with(['a', 'i', 'u', 'e', 'o', 'y']) {
+                    romaji.push('\'');
+                }
+                if geminate {
+                    romaji.push(if syllable.starts_with("ch") { 't' } else { syllable.chars().next()? });
+                    geminate = false;
+                }
+                after_n = c == 'ん';
+                romaji.push_str(&syllable);
+            }
+        }
+        i += 1;
+    }
+
+    (!geminate).then_some(romaji)
+}
+
+/// Consonantes iniciales, vocales y finales del hangul (romanización revisada)
+const HANGUL_INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+const HANGUL_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu",
+    "ui", "i",
+];
+const HANGUL_FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p", "t", "t", "ng",
+    "t", "t", "k", "t", "p", "t",
+];
+
+/// Romanizar una palabra en hangul sílaba a sílaba (sin reglas de asimilación)
+fn romanize_hangul(word: &str) -> Option<String> {
+    word.chars()
+        .map(|c| {
+            let index = (c as u32).checked_sub(0xAC00).filter(|&index| index < 11172)? as usize;
+            Some(format!(
+                "{}{}{}",
+                HANGUL_INITIALS[index / 588],
+                HANGUL_VOWELS[(index % 588) / 28],
+                HANGUL_FINALS[index % 28]
+            ))
+        })
+        .collect()
+}
+
+/// Transliteración latina de una palabra japonesa (hiragana) o coreana (hangul)
+///
+/// Acepta la forma NFKD de la entrada normalizada. Devuelve None para
+/// palabras latinas o con caracteres de otros sistemas.
+pub fn transliterate_word(word: &str) -> Option<String> {
+    let composed: String = word.trim().nfc().collect();
+    if composed.is_empty() || composed.is_ascii() {
+        return None;
+    }
+    romanize_kana(&composed).or_else(|| romanize_hangul(&composed))
+}
+
+/// Palabra con su transliteración pegada: "あおぞら(aozora)"
+///
+/// Sin espacio, para que cada palabra siga siendo un solo elemento en las
+/// disposiciones numeradas y en rejilla.
+pub fn annotate_transliteration(word: &str) -> String {
+    match transliterate_word(word) {
+        Some(romanized) => format!("{}({})", word, romanized),
+        None => word.to_string(),
+    }
+}
+
+/// Quitar las transliteraciones "(romaji)" añadidas al mostrar la frase
+pub fn strip_transliterations(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(open) = rest.find('(') {
+        let (before, group) = rest.split_at(open);
+        stripped.push_str(before);
+        match group[1..].find(')') {
+            Some(close) if close > 0 && group[1..=close].chars().all(|c| c.is_ascii_lowercase() || c == '\'') => {
+                rest = &group[close + 2..];
+            }
+            _ => {
+                stripped.push('(');
+                rest = &group[1..];
+            }
+        }
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,6 +539,22 @@ mod tests {
         assert_eq!(word_to_index("invalid_word"), None);
     }
 
+    #[test]
+    fn test_case_and_whitespace_tolerance() {
+        assert_eq!(word_to_index("Abandon"), Some(0));
+        assert_eq!(word_to_index("  ZOO\t"), Some(2047));
+        assert!(is_valid_word(" Ability "));
+        assert_eq!(words_with_prefix("ABAN"), vec!["abandon"]);
+        assert_eq!(find_closest_word("ABANDUN").unwrap(), ("abandon", 1));
+
+        // Modo estricto: solo coincidencia exacta
+        assert_eq!(lookup_word("abandon", true), Some(0));
+        assert_eq!(lookup_word("Abandon", true), None);
+        assert_eq!(lookup_word(" abandon", true), None);
+
+        assert!(matches!(normalize_word("about"), Cow::Borrowed("about")));
+    }
+
     #[test]
     fn test_index_to_word() {
         assert_eq!(index_to_word(0), Some("abandon"));
@@ -339,6 +581,28 @@ mod tests {
         assert!(words.len() >= 2);
     }
 
+    #[test]
+    fn test_expand_abbreviations() {
+        assert_eq!(
+            expand_abbreviations("aban abil able abou").unwrap(),
+            "abandon ability able about"
+        );
+        assert_eq!(expand_abbreviation("zoo").unwrap(), "zoo");     // Palabra completa de 3 letras
+        assert_eq!(expand_abbreviation("aba").unwrap(), "aba");     // Prefijo demasiado corto
+        assert_eq!(expand_abbreviation("qqqq").unwrap(), "qqqq");   // Sin coincidencias
+
+        // En otras listas 4 letras pueden no bastar
+        let list = ["maison", "maître", "mairie"];
+        assert_eq!(expand_in_wordlist("mais", &list).unwrap(), "maison");
+        match expand_in_wordlist("mair", &["mairie", "mairies"]) {
+            Err(SCypherError::AmbiguousWord { prefix, candidates }) => {
+                assert_eq!(prefix, "mair");
+                assert_eq!(candidates, vec!["mairie", "mairies"]);
+            }
+            other => panic!("expected ambiguity error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_find_closest_word() {
         let (word, distance) = find_closest_word("abandun").unwrap();
@@ -368,4 +632,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_transliteration() {
+        // Forma NFKD (como llega tras normalizar la entrada)
+        assert_eq!(transliterate_word("あおそ\u{3099}ら").as_deref(), Some("aozora"));
+        assert_eq!(transliterate_word("あいこくしん").as_deref(), Some("aikokushin"));
+        assert_eq!(transliterate_word("さ\u{3099}っか").as_deref(), Some("zakka"));
+        assert_eq!(transliterate_word("しゃかい").as_deref(), Some("shakai"));
+        assert_eq!(transliterate_word("まっちゃ").as_deref(), Some("matcha"));
+        assert_eq!(transliterate_word("こんやく").as_deref(), Some("kon'yaku"));
+
+        // Hangul en NFKD (jamo) y compuesto
+        let nfkd: String = "가격".nfkd().collect();
+        assert_eq!(transliterate_word(&nfkd).as_deref(), Some("gagyeok"));
+        assert_eq!(transliterate_word("사랑").as_deref(), Some("sarang"));
+
+        assert_eq!(transliterate_word("abandon"), None);
+        assert_eq!(transliterate_word("漢字"), None);
+    }
+
+    #[test]
+    fn test_annotate_and_strip() {
+        let annotated = ["あいこくしん", "abandon", "사랑"].map(annotate_transliteration).join(" ");
+        assert_eq!(annotated, "あいこくしん(aikokushin) abandon 사랑(sarang)");
+        assert_eq!(strip_transliterations(&annotated), "あいこくしん abandon 사랑");
+        assert_eq!(strip_transliterations("1) abandon (2) ability"), "1) abandon (2) ability");
+    }
 }
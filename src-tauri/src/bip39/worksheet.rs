@@ -0,0 +1,123 @@
+//! Hoja de verificación manual de una seed phrase
+//!
+//! Desglosa cada palabra en su índice BIP39 y sus 11 bits, separa los bits
+//! de checksum y muestra el SHA256 de la entropía, para que el usuario pueda
+//! comprobar las cuentas de la herramienta con lápiz y papel.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+use crate::bip39::wordlist::word_to_index;
+use crate::crypto::checksum;
+
+/// Una fila de la hoja: palabra, índice y bits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorksheetWord {
+    pub position: usize,        // Empieza en 1
+    pub word: String,
+    pub index: u16,             // Índice BIP39 (0-2047)
+    pub binary: String,         // 11 bits
+    pub checksum_bits: usize,   // Bits finales de esta palabra que son checksum
+}
+
+/// Hoja completa de verificación
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationWorksheet {
+    pub word_count: usize,
+    pub entropy_bits: usize,
+    pub checksum_bits: usize,
+    pub entropy_hex: String,
+    pub entropy_sha256: String,
+    pub checksum_binary: String,           // Checksum presente en la frase
+    pub expected_checksum_binary: String,  // Primeros bits de SHA256(entropía)
+    pub checksum_valid: bool,
+    pub words: Vec<WorksheetWord>,
+}
+
+fn bits_to_string(bits: &[bool]) -> String {
+    bits.iter().map(|&b| if b { '1' } else { '0' }).collect()
+}
+
+/// Construir la hoja de verificación de una seed phrase
+///
+/// Funciona también con checksum incorrecto, para que el usuario vea la diferencia.
+pub fn build_worksheet(phrase: &str) -> Result<VerificationWorksheet> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let word_count = words.len();
+
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        return Err(SCypherError::InvalidWordCount(word_count));
+    }
+
+    let entropy_bits = word_count * 32 / 3;
+    let checksum_bits = checksum::get_checksum_length(entropy_bits);
+
+    let mut rows = Vec::with_capacity(word_count);
+    let mut all_bits = Vec::with_capacity(word_count * 11);
+
+    for (i, word) in words.iter().enumerate() {
+        let index = word_to_index(word)
+            .ok_or_else(|| SCypherError::InvalidBip39Word(word.to_string()))?;
+        let bits: Vec<bool> = (0..11).rev().map(|b| (index >> b) & 1 == 1).collect();
+
+        rows.push(WorksheetWord {
+            position: i + 1,
+            word: word.to_string(),
+            index: index as u16,
+            binary: bits_to_string(&bits),
+            checksum_bits: if i == word_count - 1 { checksum_bits } else { 0 },
+        });
+        all_bits.extend(bits);
+    }
+
+    let entropy = checksum::bits_to_bytes_padded(&all_bits[..entropy_bits]);
+    let entropy_hash = Sha256::digest(&entropy);
+    let expected = checksum::extract_bits(&entropy_hash, 0, checksum_bits)?;
+    let actual = &all_bits[entropy_bits..];
+
+    Ok(VerificationWorksheet {
+        word_count,
+        entropy_bits,
+        checksum_bits,
+        entropy_hex: hex::encode(&entropy),
+        entropy_sha256: hex::encode(entropy_hash),
+        checksum_binary: bits_to_string(actual),
+        expected_checksum_binary: bits_to_string(&expected),
+        checksum_valid: actual == expected.as_slice(),
+        words: rows,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worksheet_for_known_phrase() {
+        let sheet = build_worksheet(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ).unwrap();
+
+        assert_eq!(sheet.entropy_hex, "00000000000000000000000000000000");
+        assert_eq!(sheet.checksum_bits, 4);
+        assert_eq!(sheet.words[0].binary, "00000000000");
+        assert_eq!(sheet.words[11].index, 3);
+        assert_eq!(sheet.words[11].binary, "00000000011");
+        assert_eq!(sheet.words[11].checksum_bits, 4);
+        assert_eq!(sheet.checksum_binary, "0011");
+        assert!(sheet.checksum_valid);
+    }
+
+    #[test]
+    fn test_worksheet_reports_bad_checksum() {
+        let sheet = build_worksheet(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+        ).unwrap();
+
+        assert!(!sheet.checksum_valid);
+        assert_eq!(sheet.checksum_binary, "0000");
+        assert_eq!(sheet.expected_checksum_binary, "0011");
+
+        assert!(build_worksheet("abandon about").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// =============================================================================
+// CÓDIGOS DE PAGO BIP47 (PAYNYM)
+// Un código de pago reutilizable que se puede publicar sin revelar
+// direcciones: quien paga deriva direcciones nuevas para cada pago a partir
+// de su propio código y del nuestro. Se deriva de la cuenta m/47'/0'/n'
+// (versión 1, la que usan Samourai, Sparrow y PayNym) y se acompaña de la
+// dirección de notificación (primer hijo de la cuenta, P2PKH).
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+
+/// Prefijo Base58Check de los códigos de pago ("PM8T...")
+const PAYMENT_CODE_PREFIX: u8 = 0x47;
+
+/// Versión del código de pago
+const PAYMENT_CODE_VERSION: u8 = 0x01;
+
+/// Longitud del payload (versión, opciones, clave, chain code y reserva)
+const PAYMENT_CODE_LEN: usize = 80;
+
+/// Código de pago de una cuenta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentCode {
+    pub payment_code: String,
+    pub notification_address: String,
+    pub path: String,
+}
+
+/// Derivar el código de pago BIP47 v1 de la cuenta `account`
+pub fn derive_payment_code(seed_phrase: &str, passphrase: Option<&str>, account: u32) -> Result<PaymentCode> {
+    if account >= 0x8000_0000 {
+        return Err(SCypherError::crypto(format!("Invalid account index: {}", account)));
+    }
+
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+    let path = format!("m/47'/0'/{}'", account);
+    let account_key = crate::addresses::derive_key_at_path(&master_key, &path)?.public_key();
+
+    let mut payload = Vec::with_capacity(1 + PAYMENT_CODE_LEN + 4);
+    payload.push(PAYMENT_CODE_PREFIX);
+    payload.push(PAYMENT_CODE_VERSION);
+    payload.push(0x00); // Sin opciones (bitmessage)
+    payload.extend_from_slice(&account_key.to_bytes());
+    payload.extend_from_slice(&account_key.attrs().chain_code);
+    payload.resize(1 + PAYMENT_CODE_LEN, 0x00);
+
+    let checksum = Sha256::digest(Sha256::digest(&payload));
+    payload.extend_from_slice(&checksum[..4]);
+
+    // Dirección de notificación: primer hijo (no reforzado) de la cuenta
+    let notification_key = crate::addresses::derive_key_at_path(&master_key, &format!("{}/0", path))?;
+    let notification_address = crate::addresses::p2pkh_address(&notification_key.public_key().to_bytes(), 0x00);
+
+    Ok(PaymentCode {
+        payment_code: bs58::encode(payload).into_string(),
+        notification_address,
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bip47_vectors() {
+        // Vectores de Alice y Bob de la especificación BIP47
+        let alice = derive_payment_code("response seminar brave tip suit recall often sound stick owner lottery motion", None, 0).unwrap();
+        assert_eq!(
+            alice.payment_code,
+            "PM8TJTLJbPRGxSbc8EJi42Wrr6QbNSaSSVJ5Y3E4pbCYiTHUskHg13935Ubb7q8tx9GVbh2UuRnBc3WSyJHhUrw8KhprKnn9eDznYGieTzFcwQRya4GA"
+        );
+        assert_eq!(alice.notification_address, "1JDdmqFLhpzcUwPeinhJbUPw4Co3aWLyzW");
+        assert_eq!(alice.path, "m/47'/0'/0'");
+
+        let bob = derive_payment_code("reward upper indicate eight swift arch injury crystal super wrestle already dentist", None, 0).unwrap();
+        assert_eq!(
+            bob.payment_code,
+            "PM8TJS2JxQ5ztXUpBBRnpTbcUXbUHy2T1abfrb3KkAAtMEGNbey4oumH7Hc578WgQJhPjBxteQ5GHHToTYHE3A1w6p7tU6KSoFmWBVbFGjKPisZDbP97"
+        );
+        assert_eq!(bob.notification_address, "1ChvUUvht2hUQufHBXF8NgLhW8SwE2ecGV");
+    }
+
+    #[test]
+    fn test_payment_code_accounts() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let first = derive_payment_code(phrase, None, 0).unwrap();
+        let second = derive_payment_code(phrase, None, 1).unwrap();
+        assert_ne!(first.payment_code, second.payment_code);
+        assert!(first.payment_code.starts_with("PM8T"));
+
+        // La passphrase cambia el código
+        assert_ne!(derive_payment_code(phrase, Some("TREZOR"), 0).unwrap().payment_code, first.payment_code);
+        assert!(derive_payment_code(phrase, None, 0x8000_0000).is_err());
+    }
+}
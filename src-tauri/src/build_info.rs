@@ -0,0 +1,72 @@
+// =============================================================================
+// INFORMACIÓN DE COMPILACIÓN
+// Qué capacidades tiene exactamente este binario: versión, commit, features
+// de cargo, redes compiladas, backends de KDF, target y estado del bloqueo de
+// memoria. El commit y el target los fija build.rs en tiempo de compilación,
+// así que dos compilaciones del mismo commit devuelven lo mismo.
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// Backends de KDF compilados (nombres iguales a los del informe de diagnóstico)
+const KDF_BACKENDS: &[&str] = &["argon2id", "argon2id_dual", "bash_v2_shake256"];
+
+/// Información del binario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_commit: String,           // "unknown" si se compiló sin git
+    pub target: String,
+    pub profile: String,              // "debug" o "release"
+    pub features: Vec<String>,        // Features de cargo activas
+    pub networks: Vec<String>,        // Redes compiladas (claves del registro)
+    pub kdf_backends: Vec<String>,
+    pub memory_locked: bool,
+    pub entropy_source: String,       // "os" salvo en builds con test-determinism
+}
+
+/// Features de cargo activas en esta compilación
+fn enabled_features() -> Vec<String> {
+    let features = [
+        ("tui", cfg!(feature = "tui")),
+        ("network-ergo", cfg!(feature = "network-ergo")),
+        ("network-cardano", cfg!(feature = "network-cardano")),
+        ("network-solana", cfg!(feature = "network-solana")),
+        ("test-determinism", cfg!(feature = "test-determinism")),
+    ];
+    features
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Información de este binario
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: env!("SCYPHER_GIT_COMMIT").to_string(),
+        target: env!("SCYPHER_TARGET").to_string(),
+        profile: if cfg!(debug_assertions) { "debug" } else { "release" }.to_string(),
+        features: enabled_features(),
+        networks: crate::addresses::compiled_networks().map(|spec| spec.key.to_string()).collect(),
+        kdf_backends: KDF_BACKENDS.iter().map(|backend| backend.to_string()).collect(),
+        memory_locked: crate::security::memory::process_memory_locked(),
+        entropy_source: crate::security::rng::active_source_name().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info() {
+        let info = build_info();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.git_commit.is_empty());
+        assert!(info.networks.iter().any(|network| network == "bitcoin"));
+        assert!(info.kdf_backends.contains(&"argon2id".to_string()));
+        assert_eq!(info.features.contains(&"tui".to_string()), cfg!(feature = "tui"));
+    }
+}
@@ -3,8 +3,9 @@
 use std::io::{self, Write};
 use rpassword::read_password;
 use crate::error::{SCypherError, Result};
+use crate::cli::output::format;
 
-const MIN_PASSWORD_LENGTH: usize = 8;
+pub(crate) const MIN_PASSWORD_LENGTH: usize = 8;
 const MAX_SEED_LENGTH: usize = 1000; // Límite razonable para frases semilla
 
 /// Lee la frase semilla de forma interactiva
@@ -31,6 +32,8 @@ pub fn read_seed_interactive(is_decrypt_mode: bool) -> Result<String> {
         return read_seed_from_file(&seed_phrase);
     }
 
+    // Normalizar: NFKD, minúsculas, sin numeración ni separadores ("1. abandon", "abandon-ability")
+    let seed_phrase = format::parse_formatted_phrase(&seed_phrase);
     if seed_phrase.is_empty() {
         return Err(SCypherError::InvalidSeedPhrase);
     }
@@ -46,13 +49,8 @@ pub fn read_seed_from_file(file_path: &str) -> Result<String> {
     let content = std::fs::read_to_string(file_path)
         .map_err(|e| SCypherError::file(format!("Cannot read file '{}': {}", file_path, e)))?;
 
-    // Limpiar contenido: remover saltos de línea excesivos y espacios
-    let seed_phrase = content
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .collect::<Vec<&str>>()
-        .join(" ");
+    // Limpiar contenido: numeración, separadores y saltos de línea de cualquier disposición
+    let seed_phrase = format::parse_formatted_phrase(&content);
 
     if seed_phrase.is_empty() {
         return Err(SCypherError::file("File is empty or contains no valid content".to_string()));
@@ -89,7 +87,7 @@ pub fn read_password_secure() -> Result<String> {
             continue;
         }
 
-        if password.len() < MIN_PASSWORD_LENGTH {
+        if !is_password_long_enough(&password) {
             println!("❌ Password too short (minimum {} characters). Please try again.\n", MIN_PASSWORD_LENGTH);
             continue;
         }
@@ -166,6 +164,11 @@ fn read_password_with_asterisks() -> Result<String> {
     Ok(password)
 }
 
+/// ¿Alcanza la contraseña la longitud mínima? (en caracteres, no en bytes)
+pub(crate) fn is_password_long_enough(password: &str) -> bool {
+    password.chars().count() >= MIN_PASSWORD_LENGTH
+}
+
 /// Validar entrada de frase semilla
 fn validate_seed_input(seed_phrase: &str) -> Result<()> {
     // Verificar longitud máxima
@@ -183,8 +186,11 @@ fn validate_seed_input(seed_phrase: &str) -> Result<()> {
         return Ok(()); // Los archivos se validan en otra función
     }
 
-    // Verificar caracteres básicos (solo letras, números y espacios)
-    if !seed_phrase.chars().all(|c| c.is_ascii_alphanumeric() || c.is_ascii_whitespace()) {
+    // Verificar caracteres básicos (letras de cualquier idioma, acentos NFKD, números y espacios)
+    let is_allowed = |c: char| {
+        c.is_alphanumeric() || c.is_whitespace() || unicode_normalization::char::is_combining_mark(c)
+    };
+    if !seed_phrase.chars().all(is_allowed) {
         return Err(SCypherError::InvalidSeedPhrase);
     }
 
@@ -222,6 +228,36 @@ pub fn read_confirmation(prompt: &str) -> Result<bool> {
     }
 }
 
+/// Pregunta la disposición con la que se muestra y se guarda el resultado
+pub fn read_layout_choice() -> Result<format::PhraseLayout> {
+    println!("Output layout:");
+    println!("1. Single line");
+    println!("2. Numbered (one word per line)");
+    println!("3. Grid (4 words per line)");
+    println!("4. Custom separator");
+
+    loop {
+        let layout = match read_number("Select layout", 1u8, 4u8)? {
+            1 => format::PhraseLayout::SingleLine,
+            2 => format::PhraseLayout::Numbered,
+            3 => format::PhraseLayout::Grid,
+            _ => {
+                print!("Separator: ");
+                io::stdout().flush().map_err(SCypherError::from)?;
+
+                let mut separator = String::new();
+                io::stdin().read_line(&mut separator).map_err(SCypherError::from)?;
+                format::PhraseLayout::Custom(separator.trim_end_matches(['\r', '\n']).to_string())
+            }
+        };
+
+        match layout.validate() {
+            Ok(()) => return Ok(layout),
+            Err(e) => println!("{}", e),
+        }
+    }
+}
+
 /// Utilidad para leer un número entero con validación
 pub fn read_number<T>(prompt: &str, min: T, max: T) -> Result<T>
 where
@@ -258,6 +294,18 @@ mod tests {
         assert!(validate_seed_input("word1").is_err());              // Solo 1 palabra
         assert!(validate_seed_input("word1 word2 word3 word4 word5 word6 word7 word8 word9 word10 word11").is_err()); // 11 palabras
         assert!(validate_seed_input("word1 word2! word3").is_err()); // Caracteres especiales
+
+        // Listas no inglesas ya normalizadas (acentos NFKD)
+        let french = crate::bip39::normalize_seed_input(&"élève ".repeat(12));
+        assert!(validate_seed_input(&french).is_ok());
+    }
+
+    #[test]
+    fn test_password_length() {
+        assert!(!is_password_long_enough("1234567"));
+        assert!(is_password_long_enough("12345678"));
+        // Se cuentan caracteres: 4 letras acentuadas ocupan 8 bytes
+        assert!(!is_password_long_enough("ñáéí"));
     }
 
     #[test]
@@ -1,8 +1,11 @@
 // src/cli/menu.rs - Sistema de menús interactivo
 
 use crate::cli::display::{self, colors};
+use crate::cli::input;
+use crate::cli::output::{self, format::PhraseLayout};
 use crate::error::Result;
 use std::process;
+use zeroize::Zeroize;
 
 /// Opciones del menú principal
 #[derive(Debug, Clone, Copy)]
@@ -26,8 +29,9 @@ pub enum HelpMenuChoice {
 #[derive(Debug, Clone, Copy)]
 pub enum PostProcessChoice {
     SaveToFile = 1,
-    ReturnToMain = 2,
-    Exit = 3,
+    PhoneticReadout = 2,
+    ReturnToMain = 3,
+    Exit = 4,
 }
 
 /// Opciones después de guardar archivo
@@ -145,19 +149,21 @@ pub fn show_post_processing_menu(result: &str) -> Result<PostProcessChoice> {
         println!();
         println!("{}What would you like to do next?{}", colors::SUCCESS, colors::RESET);
         println!("1. Save result to file");
-        println!("2. Return to main menu");
-        println!("3. Exit");
+        println!("2. Show phonetic readout (for dictation)");
+        println!("3. Return to main menu");
+        println!("4. Exit");
         println!();
 
-        let choice = display::read_user_input("Select option [1-3]: ");
+        let choice = display::read_user_input("Select option [1-4]: ");
         println!();
 
         match choice.as_str() {
             "1" => return Ok(PostProcessChoice::SaveToFile),
-            "2" => return Ok(PostProcessChoice::ReturnToMain),
-            "3" | "" => return Ok(PostProcessChoice::Exit),
+            "2" => return Ok(PostProcessChoice::PhoneticReadout),
+            "3" => return Ok(PostProcessChoice::ReturnToMain),
+            "4" | "" => return Ok(PostProcessChoice::Exit),
             _ => {
-                println!("{}Invalid option. Please select 1-3.{}", colors::ERROR, colors::RESET);
+                println!("{}Invalid option. Please select 1-4.{}", colors::ERROR, colors::RESET);
                 println!();
                 display::wait_for_enter();
             }
@@ -165,8 +171,10 @@ pub fn show_post_processing_menu(result: &str) -> Result<PostProcessChoice> {
     }
 }
 
-/// Manejar guardado de resultado en archivo
-pub fn handle_save_result(result: &str) -> Result<bool> {
+/// Manejar guardado de resultado en archivo (con la disposición elegida)
+pub fn handle_save_result(result: &str, layout: &PhraseLayout) -> Result<bool> {
+    let formatted = output::format::format_phrase(result, layout)?;
+
     loop {
         println!("{}Enter filename to save result:{}", colors::PRIMARY, colors::RESET);
         let save_file = display::read_user_input("> ");
@@ -188,7 +196,7 @@ pub fn handle_save_result(result: &str) -> Result<bool> {
         };
 
         // Intentar guardar el archivo usando la función del módulo output
-        match crate::cli::output::save_to_file(result, &save_file) {
+        match crate::cli::output::save_to_file(&formatted, &save_file) {
             Ok(()) => {
                 println!("{}✓ Result successfully saved to {}{}",
                          colors::SUCCESS, save_file, colors::RESET);
@@ -240,15 +248,17 @@ pub fn handle_post_save_menu() -> Result<bool> {
 }
 
 /// Manejar el menú post-procesamiento completo
-pub fn handle_post_processing_menu(result: &str) -> Result<bool> {
+pub fn handle_post_processing_menu(result: &str, layout: &PhraseLayout) -> Result<bool> {
     loop {
         match show_post_processing_menu(result)? {
-            PostProcessChoice::SaveToFile => {
-                if handle_save_result(result)? {
-                    return Ok(true); // Usuario eligió salir después de guardar
-                }
-                // Si no salió, volver al menú principal
-                return Ok(false);
+            // Guardar y preguntar si salir después de guardar
+            PostProcessChoice::SaveToFile => return handle_save_result(result, layout),
+            // El deletreo se muestra y se vuelve a este mismo menú
+            PostProcessChoice::PhoneticReadout => {
+                let readout = crate::bip39::phonetic::build_phonetic_readout(result)?;
+                println!("{}", output::format::format_phonetic_readout(&readout));
+                println!();
+                display::wait_for_enter();
             }
             PostProcessChoice::ReturnToMain => {
                 display::clear_screen();
@@ -294,6 +304,84 @@ pub fn run_interactive_menu() -> Result<MenuState> {
     }
 }
 
+/// Sesión de línea de comandos (`--cli`): menú principal, transformación y
+/// menú post-procesamiento con la disposición elegida
+///
+/// Con `output_file` (`-o <archivo>`) el resultado se guarda sin preguntar.
+pub fn run_cli_session(output_file: Option<&String>) -> Result<()> {
+    loop {
+        if run_interactive_menu()?.should_exit {
+            return Ok(());
+        }
+
+        let mut result = match process_seed_interactive() {
+            Ok(result) => result,
+            Err(e) => {
+                handle_menu_error(&e.to_string());
+                continue;
+            }
+        };
+
+        let outcome = show_result(&result, output_file);
+        result.zeroize();
+        match outcome {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(e) => handle_menu_error(&e.to_string()),
+        }
+    }
+}
+
+/// Leer frase y contraseña y transformarlas en el proceso aislado de cifrado
+fn process_seed_interactive() -> Result<String> {
+    let mut phrase = input::read_seed_interactive(false)?;
+    let password = match input::read_password_secure() {
+        Ok(password) => password,
+        Err(e) => {
+            phrase.zeroize();
+            return Err(e);
+        }
+    };
+
+    let request = crate::crypto_worker::PhraseRequest {
+        phrase,
+        password,
+        second_password: None,
+        params: crate::pipeline::TransformParams {
+            iterations: super::DEFAULT_ITERATIONS,
+            memory_cost: super::DEFAULT_MEMORY_COST,
+            parallelism: None,
+            mode: Default::default(),
+            checksum_policy: Default::default(),
+            compatibility: Default::default(),
+            hardware_pepper: None,
+        },
+        verify: false,
+    };
+    crate::security::training::ensure_demo_phrase(&request.phrase)?;
+
+    println!("{}Processing...{}", colors::DIM, colors::RESET);
+    let (mut output, _) = crate::crypto_worker::transform(request)?;
+    crate::security::training::record_output(&output.phrase);
+    Ok(std::mem::take(&mut output.phrase))
+}
+
+/// Mostrar el resultado con la disposición elegida; `true` si el usuario sale
+fn show_result(result: &str, output_file: Option<&String>) -> Result<bool> {
+    let layout = input::read_layout_choice()?;
+
+    // La romanización solo tiene sentido con palabras japonesas o coreanas
+    let has_transliteration = result
+        .split_whitespace()
+        .any(|word| crate::bip39::wordlist::transliterate_word(word).is_some());
+    let transliterate = has_transliteration
+        && input::read_confirmation("Show the romanization of each word?")?;
+
+    println!();
+    output::output_result(result, output_file, &layout, transliterate)?;
+    handle_post_processing_menu(result, &layout)
+}
+
 /// Función utilitaria para manejo de errores en menús
 pub fn handle_menu_error(error_message: &str) {
     println!("{}✗ Error: {}{}", colors::ERROR, error_message, colors::RESET);
@@ -4,6 +4,12 @@ pub mod input;
 pub mod output;
 pub mod display;
 pub mod menu;
+#[cfg(feature = "tui")]
+pub mod tui;
+
+// Parámetros Argon2id por defecto de la CLI y la TUI (los mismos que la GUI)
+pub(crate) const DEFAULT_ITERATIONS: u32 = 5;
+pub(crate) const DEFAULT_MEMORY_COST: u32 = 131072;
 
 // Re-exportar funciones principales para fácil acceso
 pub use input::{
@@ -18,6 +24,7 @@ pub use output::{
 };
 
 pub use menu::{
+    run_cli_session,
     run_interactive_menu,
     handle_post_processing_menu,
     handle_menu_error,
@@ -10,13 +10,31 @@ const DEFAULT_EXTENSION: &str = ".txt";
 const FILE_PERMISSIONS: u32 = 0o600; // Solo lectura/escritura para el propietario
 
 /// Mostrar resultado y opcionalmente guardarlo en archivo
-pub fn output_result(result: &str, output_file: Option<&String>) -> Result<()> {
+/// La disposición elegida se usa tanto en pantalla como en el archivo
+/// Con `transliterate` las palabras japonesas o coreanas llevan su romanización
+pub fn output_result(
+    result: &str,
+    output_file: Option<&String>,
+    layout: &format::PhraseLayout,
+    transliterate: bool,
+) -> Result<()> {
+    let formatted = format::format_phrase_with(result, layout, transliterate)?;
+    let result = formatted.as_str();
+
     // Siempre mostrar el resultado en pantalla
+    if let Some(mark) = crate::security::training::watermark() {
+        println!("[{}] Training mode - this is not a real seed", mark);
+    }
     println!("Result:");
     println!("─────────────────────────────────────────────────────────────");
     println!("{}", result);
     println!("─────────────────────────────────────────────────────────────");
 
+    // En modo formación las frases no se guardan en archivos
+    if crate::security::training::is_training_mode() {
+        return Ok(());
+    }
+
     // Guardar en archivo si se especificó
     if let Some(file_path) = output_file {
         let final_path = ensure_extension(file_path);
@@ -47,6 +65,8 @@ pub fn output_result(result: &str, output_file: Option<&String>) -> Result<()> {
 pub fn save_to_file(content: &str, file_path: &str) -> Result<()> {
     use std::path::Path;
 
+    crate::security::training::ensure_export_allowed()?;
+
     if file_path.is_empty() {
         return Err(SCypherError::file("File path is empty".to_string()));
     }
@@ -158,6 +178,77 @@ pub fn show_file_info(file_path: &str) -> Result<()> {
 
 /// Utilidades para formateo de salida
 pub mod format {
+    use serde::{Deserialize, Serialize};
+    use crate::error::{SCypherError, Result};
+
+    /// Longitud máxima de un separador personalizado
+    const MAX_SEPARATOR_LENGTH: usize = 8;
+
+    /// Disposición de la frase al mostrarla, guardarla o imprimirla
+    ///
+    /// Se serializa como `{ "layout": "grid" }` o
+    /// `{ "layout": "custom", "separator": " - " }`.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case", tag = "layout", content = "separator")]
+    pub enum PhraseLayout {
+        #[default]
+        SingleLine,       // Palabras separadas por un espacio
+        Numbered,         // Una palabra por línea: " 1. abandon"
+        Grid,             // 4 palabras numeradas por línea (format_seed_phrase)
+        Custom(String),   // Separador elegido por el usuario
+    }
+
+    impl PhraseLayout {
+        /// Separador entre palabras de las disposiciones en una sola línea
+        pub fn separator(&self) -> Option<&str> {
+            match self {
+                PhraseLayout::SingleLine => Some(" "),
+                PhraseLayout::Custom(separator) => Some(separator),
+                PhraseLayout::Numbered | PhraseLayout::Grid => None,
+            }
+        }
+
+        /// Un separador con letras no se podría distinguir de las palabras al leerlo
+        pub fn validate(&self) -> Result<()> {
+            if let PhraseLayout::Custom(separator) = self {
+                if separator.is_empty() || separator.chars().count() > MAX_SEPARATOR_LENGTH {
+                    return Err(SCypherError::file(format!(
+                        "Separator must be 1-{} characters",
+                        MAX_SEPARATOR_LENGTH
+                    )));
+                }
+                if separator.chars().any(char::is_alphabetic) {
+                    return Err(SCypherError::file("Separator cannot contain letters".to_string()));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl std::str::FromStr for PhraseLayout {
+        type Err = SCypherError;
+
+        /// "single", "numbered", "grid" o "custom:<separador>"
+        fn from_str(value: &str) -> Result<Self> {
+            let layout = match value {
+                "single" | "single_line" => PhraseLayout::SingleLine,
+                "numbered" => PhraseLayout::Numbered,
+                "grid" => PhraseLayout::Grid,
+                other => match other.strip_prefix("custom:") {
+                    Some(separator) => PhraseLayout::Custom(separator.to_string()),
+                    None => {
+                        return Err(SCypherError::file(format!(
+                            "Unknown phrase layout '{}'. Use single, numbered, grid or custom:<separator>",
+                            other
+                        )))
+                    }
+                },
+            };
+            layout.validate()?;
+            Ok(layout)
+        }
+    }
+
     /// Crear una línea separadora
     pub fn separator_line(length: usize) -> String {
         "─".repeat(length)
@@ -206,6 +297,139 @@ pub mod format {
 
         formatted
     }
+
+    /// Formatear la frase con la disposición elegida
+    pub fn format_phrase(phrase: &str, layout: &PhraseLayout) -> Result<String> {
+        format_phrase_with(phrase, layout, false)
+    }
+
+    /// Formatear la frase; con `transliterate` cada palabra japonesa o
+    /// coreana lleva su romanización pegada ("あおぞら(aozora)")
+    pub fn format_phrase_with(phrase: &str, layout: &PhraseLayout, transliterate: bool) -> Result<String> {
+        layout.validate()?;
+        let words: Vec<String> = phrase
+            .split_whitespace()
+            .map(|word| {
+                if transliterate {
+                    crate::bip39::wordlist::annotate_transliteration(word)
+                } else {
+                    word.to_string()
+                }
+            })
+            .collect();
+
+        Ok(match layout {
+            PhraseLayout::Grid => format_seed_phrase(&words.join(" "))
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PhraseLayout::Numbered => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| format!("{:2}. {}", i + 1, word))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PhraseLayout::SingleLine | PhraseLayout::Custom(_) => {
+                words.join(layout.separator().unwrap_or(" "))
+            }
+        })
+    }
+
+    /// Recuperar la frase desde cualquiera de las disposiciones anteriores
+    ///
+    /// Descarta numeración, puntuación, separadores (ver `bip39::normalize`),
+    /// las transliteraciones añadidas por `format_phrase_with` y los deletreos
+    /// de `format_phonetic_readout`.
+    pub fn parse_formatted_phrase(text: &str) -> String {
+        let text = crate::bip39::phonetic::strip_phonetic_spelling(text);
+        crate::bip39::normalize_seed_input(&crate::bip39::wordlist::strip_transliterations(&text))
+    }
+
+    /// Formatear el deletreo para dictar: "07 — zebra: Zulu-Echo-Bravo-Romeo-Alfa"
+    pub fn format_phonetic_readout(words: &[crate::bip39::phonetic::PhoneticWord]) -> String {
+        words
+            .iter()
+            .map(|entry| {
+                let word = match &entry.romanized {
+                    Some(romanized) => format!("{} ({})", entry.word, romanized),
+                    None => entry.word.clone(),
+                };
+                format!("{:02} — {}: {}", entry.position, word, entry.code_words.join("-"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Formatear la hoja de verificación manual (checksum entre corchetes)
+    pub fn format_verification_worksheet(sheet: &crate::bip39::worksheet::VerificationWorksheet) -> String {
+        let mut text = String::new();
+
+        text.push_str("SCYPHER - BIP39 MANUAL VERIFICATION WORKSHEET\n");
+        text.push_str(&separator_line(52));
+        text.push('\n');
+        text.push_str(&format!(
+            "Words: {}   Entropy: {} bits   Checksum: {} bits\n\n",
+            sheet.word_count, sheet.entropy_bits, sheet.checksum_bits
+        ));
+        text.push_str(&format!(" #   {:<12} {:>5}   Binary (11 bits)\n", "Word", "Index"));
+
+        for row in &sheet.words {
+            let split = row.binary.len() - row.checksum_bits;
+            let binary = if row.checksum_bits > 0 {
+                format!("{}[{}]", &row.binary[..split], &row.binary[split..])
+            } else {
+                row.binary.clone()
+            };
+            text.push_str(&format!("{:2}.  {:<12} {:>5}   {}\n", row.position, row.word, row.index, binary));
+        }
+
+        text.push('\n');
+        text.push_str(&separator_line(52));
+        text.push('\n');
+        text.push_str(&format!("Entropy (hex):      {}\n", sheet.entropy_hex));
+        text.push_str(&format!("SHA256(entropy):    {}\n", sheet.entropy_sha256));
+        text.push_str(&format!("Checksum in phrase: {}\n", sheet.checksum_binary));
+        text.push_str(&format!("Expected checksum:  {} (first {} bits of SHA256)\n", sheet.expected_checksum_binary, sheet.checksum_bits));
+        text.push_str(&format!("Result:             {}\n", if sheet.checksum_valid { "VALID" } else { "INVALID CHECKSUM" }));
+
+        text
+    }
+
+    /// Nombre serializado de un enum (`secp256k1`, `bitcoin_multi`...)
+    fn serde_label<T: Serialize>(value: &T) -> String {
+        serde_json::to_value(value)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    /// Formatear la lista de verificación cruzada de direcciones
+    pub fn format_cross_check_checklist(entries: &[crate::addresses::CrossCheckEntry]) -> String {
+        let mut text = String::new();
+
+        text.push_str("SCYPHER - ADDRESS CROSS-CHECK CHECKLIST\n");
+        text.push_str(&separator_line(52));
+        text.push('\n');
+        text.push_str("Enter the same phrase, passphrase and path in each tool\n");
+        text.push_str("(on an offline machine) and tick the address if it matches.\n");
+
+        for entry in entries {
+            text.push('\n');
+            text.push_str(&format!("[ ] {} - {}\n", entry.name, entry.address_type));
+            text.push_str(&format!("    Path:       {}\n", entry.path));
+            text.push_str(&format!("    Curve:      {}\n", serde_label(&entry.curve)));
+            text.push_str(&format!("    Encoding:   {}\n", serde_label(&entry.encoding)));
+            text.push_str(&format!(
+                "    Passphrase: {}\n",
+                if entry.passphrase_applied { "applied" } else { "not applied" }
+            ));
+            text.push_str(&format!("    Address:    {}\n", entry.address));
+            text.push_str(&format!("    Check with: {}\n", entry.reference_tools.join(", ")));
+        }
+
+        text
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +474,104 @@ mod tests {
         assert!(formatted.contains("4. about"));
     }
 
+    #[test]
+    fn test_phrase_layouts_roundtrip() {
+        use format::PhraseLayout;
+
+        let phrase = "abandon ability able about above absent";
+        assert_eq!(format::format_phrase(phrase, &PhraseLayout::SingleLine).unwrap(), phrase);
+        assert_eq!(
+            format::format_phrase(phrase, &PhraseLayout::Custom(" - ".to_string())).unwrap(),
+            "abandon - ability - able - about - above - absent"
+        );
+        assert!(format::format_phrase(phrase, &PhraseLayout::Numbered).unwrap().starts_with(" 1. abandon\n 2. ability"));
+        assert_eq!(format::format_phrase(phrase, &PhraseLayout::Grid).unwrap().lines().count(), 2);
+
+        // Cualquier disposición se vuelve a leer como la frase original
+        let layouts = [
+            PhraseLayout::SingleLine,
+            PhraseLayout::Numbered,
+            PhraseLayout::Grid,
+            PhraseLayout::Custom(",".to_string()),
+        ];
+        for layout in layouts {
+            let formatted = format::format_phrase(phrase, &layout).unwrap();
+            assert_eq!(format::parse_formatted_phrase(&formatted), phrase, "{:?}", layout);
+        }
+        assert_eq!(format::parse_formatted_phrase("1) abandon\n2) ability;"), "abandon ability");
+
+        // La romanización añadida no se confunde con palabras de la frase
+        let japanese = "あいこくしん あおそ\u{3099}ら";
+        let formatted = format::format_phrase_with(japanese, &PhraseLayout::Numbered, true).unwrap();
+        assert_eq!(formatted, " 1. あいこくしん(aikokushin)\n 2. あおそ\u{3099}ら(aozora)");
+        assert_eq!(format::parse_formatted_phrase(&formatted), japanese);
+    }
+
+    #[test]
+    fn test_format_phonetic_readout() {
+        let phrase = "zebra wax abandon";
+        let readout = crate::bip39::phonetic::build_phonetic_readout(phrase).unwrap();
+        let text = format::format_phonetic_readout(&readout);
+        assert_eq!(text.lines().next(), Some("01 — zebra: Zulu-Echo-Bravo-Romeo-Alfa"));
+        assert_eq!(text.lines().nth(1), Some("02 — wax: Whiskey-Alfa-X-ray"));
+
+        // El texto dictado se vuelve a leer como la frase
+        assert_eq!(format::parse_formatted_phrase(&text), phrase);
+        let japanese = crate::bip39::phonetic::build_phonetic_readout("あおそ\u{3099}ら").unwrap();
+        let text = format::format_phonetic_readout(&japanese);
+        assert!(text.starts_with("01 — あおそ\u{3099}ら (aozora): Alfa-Oscar-Zulu"));
+        assert_eq!(format::parse_formatted_phrase(&text), "あおそ\u{3099}ら");
+    }
+
+    #[test]
+    fn test_phrase_layout_parsing() {
+        use format::PhraseLayout;
+
+        assert_eq!("grid".parse::<PhraseLayout>().unwrap(), PhraseLayout::Grid);
+        assert_eq!("custom:|".parse::<PhraseLayout>().unwrap(), PhraseLayout::Custom("|".to_string()));
+        assert!("custom:x".parse::<PhraseLayout>().is_err());
+        assert!("custom:".parse::<PhraseLayout>().is_err());
+        assert!("columns".parse::<PhraseLayout>().is_err());
+
+        let layout: PhraseLayout = serde_json::from_str(r#"{"layout":"custom","separator":"-"}"#).unwrap();
+        assert_eq!(layout, PhraseLayout::Custom("-".to_string()));
+        let layout: PhraseLayout = serde_json::from_str(r#"{"layout":"numbered"}"#).unwrap();
+        assert_eq!(layout, PhraseLayout::Numbered);
+    }
+
+    #[test]
+    fn test_format_verification_worksheet() {
+        let sheet = crate::bip39::worksheet::build_worksheet(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ).unwrap();
+        let text = format::format_verification_worksheet(&sheet);
+        assert!(text.contains("12.  about            3   0000000[0011]"));
+        assert!(text.contains("VALID"));
+    }
+
+    #[test]
+    fn test_format_cross_check_checklist() {
+        use crate::addresses::{AddressEncoding, CrossCheckEntry, NetworkCurve, NetworkId};
+
+        let entry = CrossCheckEntry {
+            network: NetworkId::Bitcoin,
+            name: "Bitcoin",
+            address_type: "Native SegWit #0".to_string(),
+            path: "m/84'/0'/0'/0/0".to_string(),
+            curve: NetworkCurve::Secp256k1,
+            encoding: AddressEncoding::BitcoinMulti,
+            passphrase_applied: false,
+            address: "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu".to_string(),
+            reference_tools: &["Sparrow", "Electrum"],
+        };
+        let text = format::format_cross_check_checklist(&[entry]);
+        assert!(text.contains("[ ] Bitcoin - Native SegWit #0"));
+        assert!(text.contains("Curve:      secp256k1"));
+        assert!(text.contains("Encoding:   bitcoin_multi"));
+        assert!(text.contains("Passphrase: not applied"));
+        assert!(text.contains("Check with: Sparrow, Electrum"));
+    }
+
     #[test]
     fn test_save_to_file() {
         let temp_dir = env::temp_dir();
@@ -12,8 +12,8 @@ pub fn read_seed_from_stdin() -> Result<String> {
         .read_line(&mut buffer)
         .map_err(|e| SCypherError::IoError(e))?;
 
-    // Limpiar input: remover salto de línea y espacios extra
-    let cleaned = buffer.trim().to_string();
+    // Limpiar input: numeración, separadores y espacios extra
+    let cleaned = crate::cli::output::format::parse_formatted_phrase(&buffer);
 
     if cleaned.is_empty() {
         return Err(SCypherError::InvalidSeedPhrase);
@@ -55,7 +55,7 @@ pub fn detect_input_type(input: &str) -> InputType {
     let words: Vec<&str> = input.split_whitespace().collect();
     if words.len() >= 12 && words.len() <= 24 {
         let valid_count = words.iter()
-            .filter(|&word| crate::bip39::is_valid_word(word))
+            .filter(|&word| crate::bip39::wordlist::is_valid_word(word))
             .count();
 
         let validity_ratio = valid_count as f64 / words.len() as f64;
@@ -0,0 +1,549 @@
+// src/cli/tui.rs - Interfaz de terminal a pantalla completa (ratatui/crossterm)
+//
+// Alternativa opcional al menú por líneas para equipos sin entorno gráfico
+// (air-gapped, servidores). Se compila con la feature `tui` y se lanza con
+// `scypher-gui --tui`.
+
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap};
+use zeroize::Zeroize;
+
+use crate::addresses::NetworkId;
+use crate::cli::input::{is_password_long_enough, MIN_PASSWORD_LENGTH};
+use crate::cli::{DEFAULT_ITERATIONS, DEFAULT_MEMORY_COST};
+use crate::error::{SCypherError, Result};
+
+/// Redes mostradas en la tabla de direcciones
+const TABLE_NETWORKS: [NetworkId; 6] = [
+    NetworkId::Bitcoin,
+    NetworkId::Ethereum,
+    NetworkId::Litecoin,
+    NetworkId::Dogecoin,
+    NetworkId::Tron,
+    NetworkId::Solana,
+];
+
+/// Número máximo de sugerencias de autocompletado
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Pantallas de la TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Screen {
+    SeedEntry,
+    PasswordEntry,
+    Processing,
+    Result,
+    Addresses,
+}
+
+/// Entrada de la seed phrase con enmascarado y autocompletado
+#[derive(Default)]
+struct SeedInput {
+    text: String,
+    reveal: bool,
+}
+
+impl SeedInput {
+    /// Palabra que se está escribiendo (vacía tras un espacio)
+    fn current_prefix(&self) -> &str {
+        if self.text.ends_with(' ') {
+            ""
+        } else {
+            self.text.rsplit(' ').next().unwrap_or("")
+        }
+    }
+
+    fn suggestions(&self) -> Vec<&'static str> {
+        let prefix = self.current_prefix();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        crate::bip39::wordlist::words_with_prefix(prefix)
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+
+    /// Completar la palabra actual con la primera sugerencia
+    fn complete(&mut self) {
+        if let Some(word) = self.suggestions().first() {
+            let prefix_len = self.current_prefix().len();
+            self.text.truncate(self.text.len() - prefix_len);
+            self.text.push_str(word);
+            self.text.push(' ');
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        if c == ' ' {
+            // Evitar espacios dobles o iniciales
+            if !self.text.is_empty() && !self.text.ends_with(' ') {
+                self.text.push(' ');
+            }
+        } else if c.is_ascii_alphabetic() {
+            self.text.push(c.to_ascii_lowercase());
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        self.text.split_whitespace().count()
+    }
+
+    /// Texto mostrado: las letras se ocultan pero se conservan los límites de palabra
+    fn display(&self) -> String {
+        if self.reveal {
+            self.text.clone()
+        } else {
+            self.text.chars().map(|c| if c == ' ' { ' ' } else { '•' }).collect()
+        }
+    }
+}
+
+/// Estado de la aplicación TUI
+struct TuiApp {
+    screen: Screen,
+    seed: SeedInput,
+    password: String,
+    password_confirm: String,
+    confirming: bool,
+    status: Option<String>,
+    result: Option<String>,
+    reveal_result: bool,
+    started: Option<Instant>,
+    estimate: Duration,
+    receiver: Option<mpsc::Receiver<Result<String>>>,
+    addresses: Vec<[String; 3]>,
+    should_quit: bool,
+}
+
+impl TuiApp {
+    fn new() -> Self {
+        Self {
+            screen: Screen::SeedEntry,
+            seed: SeedInput::default(),
+            password: String::new(),
+            password_confirm: String::new(),
+            confirming: false,
+            status: None,
+            result: None,
+            reveal_result: false,
+            started: None,
+            estimate: Duration::from_secs(1),
+            receiver: None,
+            addresses: Vec::new(),
+            should_quit: false,
+        }
+    }
+
+    /// Borrar todo el material sensible
+    fn clear_secrets(&mut self) {
+        self.seed.text.zeroize();
+        self.password.zeroize();
+        self.password_confirm.zeroize();
+        if let Some(result) = self.result.as_mut() {
+            result.zeroize();
+        }
+        self.result = None;
+        self.addresses.clear();
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.should_quit = true;
+            return;
+        }
+
+        match self.screen {
+            Screen::SeedEntry => self.handle_seed_key(key),
+            Screen::PasswordEntry => self.handle_password_key(key),
+            Screen::Processing => {}
+            Screen::Result => match key.code {
+                KeyCode::Char('r') => self.reveal_result = !self.reveal_result,
+                KeyCode::Char('a') => self.load_addresses(),
+                KeyCode::Char('n') => {
+                    self.clear_secrets();
+                    self.screen = Screen::SeedEntry;
+                }
+                KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+                _ => {}
+            },
+            Screen::Addresses => {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('b')) {
+                    self.screen = Screen::Result;
+                }
+            }
+        }
+    }
+
+    fn handle_seed_key(&mut self, key: KeyEvent) {
+        self.status = None;
+        match key.code {
+            KeyCode::Char(c) => self.seed.push(c),
+            KeyCode::Backspace => {
+                self.seed.text.pop();
+            }
+            KeyCode::Tab => self.seed.complete(),
+            KeyCode::F(2) => self.seed.reveal = !self.seed.reveal,
+            KeyCode::Esc => self.should_quit = true,
+            KeyCode::Enter => {
+                let phrase = self.seed.text.trim().to_string();
+                match crate::bip39::validate_seed_phrase_complete(&phrase) {
+                    Ok(()) => {
+                        self.seed.text = phrase;
+                        self.screen = Screen::PasswordEntry;
+                    }
+                    Err(e) => self.status = Some(e.to_string()),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_password_key(&mut self, key: KeyEvent) {
+        self.status = None;
+        let field = if self.confirming { &mut self.password_confirm } else { &mut self.password };
+
+        match key.code {
+            KeyCode::Char(c) => field.push(c),
+            KeyCode::Backspace => {
+                field.pop();
+            }
+            KeyCode::Esc => {
+                self.password.zeroize();
+                self.password_confirm.zeroize();
+                self.confirming = false;
+                self.screen = Screen::SeedEntry;
+            }
+            KeyCode::Enter if !self.confirming => {
+                if !is_password_long_enough(&self.password) {
+                    self.status = Some(format!("Password too short (minimum {} characters)", MIN_PASSWORD_LENGTH));
+                } else {
+                    self.confirming = true;
+                }
+            }
+            KeyCode::Enter => {
+                if self.password != self.password_confirm {
+                    self.password_confirm.zeroize();
+                    self.status = Some("Password mismatch. Please try again.".to_string());
+                } else {
+                    self.confirming = false;
+                    self.start_processing();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Lanzar la transformación Argon2id + XOR en un hilo aparte
+    fn start_processing(&mut self) {
+        let (sender, receiver) = mpsc::channel();
+        let seed = self.seed.text.clone();
+        let password = self.password.clone();
+
+        thread::spawn(move || {
+            // Argon2id en el proceso aislado de cifrado, como en la GUI
+            let request = crate::crypto_worker::PhraseRequest {
+                phrase: seed,
+                password,
+                second_password: None,
+                params: crate::pipeline::TransformParams {
+                    iterations: DEFAULT_ITERATIONS,
+                    memory_cost: DEFAULT_MEMORY_COST,
+                    parallelism: None,
+                    mode: Default::default(),
+                    checksum_policy: Default::default(),
+                    compatibility: Default::default(),
+                    hardware_pepper: None,
+                },
+                verify: false,
+            };
+            let result = crate::security::training::ensure_demo_phrase(&request.phrase)
+                .and_then(|()| crate::crypto_worker::transform(request))
+                .map(|(output, _)| output.phrase);
+            if let Ok(transformed) = &result {
+                crate::security::training::record_output(transformed);
+            }
+            let _ = sender.send(result);
+        });
+
+        // Estimación aproximada: ~1.5 ms por MiB y pasada de Argon2id
+        let estimate_ms = DEFAULT_ITERATIONS as u64 * DEFAULT_MEMORY_COST as u64 / 1024 * 3 / 2;
+        self.estimate = Duration::from_millis(estimate_ms.max(200));
+        self.started = Some(Instant::now());
+        self.receiver = Some(receiver);
+        self.screen = Screen::Processing;
+    }
+
+    /// Comprobar si la transformación terminó
+    fn poll_processing(&mut self) {
+        let Some(receiver) = &self.receiver else {
+            return;
+        };
+
+        match receiver.try_recv() {
+            Ok(Ok(result)) => {
+                self.password.zeroize();
+                self.password_confirm.zeroize();
+                self.result = Some(result);
+                self.receiver = None;
+                self.screen = Screen::Result;
+            }
+            Ok(Err(e)) => {
+                self.status = Some(e.to_string());
+                self.receiver = None;
+                self.screen = Screen::PasswordEntry;
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.status = Some("Processing thread stopped unexpectedly".to_string());
+                self.receiver = None;
+                self.screen = Screen::PasswordEntry;
+            }
+        }
+    }
+
+    /// Progreso estimado (nunca llega al 100% antes de terminar)
+    fn progress(&self) -> f64 {
+        self.started
+            .map(|start| (start.elapsed().as_secs_f64() / self.estimate.as_secs_f64()).min(0.95))
+            .unwrap_or(0.0)
+    }
+
+    /// Derivar direcciones del resultado para la vista en tabla
+    fn load_addresses(&mut self) {
+        let Some(result) = &self.result else {
+            return;
+        };
+
+        let networks: Vec<String> = TABLE_NETWORKS
+            .iter()
+            .filter(|&&n| crate::addresses::network_compiled(n))
+            .map(|n| n.as_str().to_string())
+            .collect();
+        match crate::addresses::derive_addresses(result, None, &networks) {
+            Ok(set) => {
+                self.addresses = TABLE_NETWORKS
+                    .iter()
+                    .flat_map(|&network| {
+                        let name = crate::addresses::network_spec(network).name;
+                        set[network]
+                            .iter()
+                            .map(move |a| [name.to_string(), a.path.clone(), a.address.clone()])
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+                self.screen = Screen::Addresses;
+            }
+            Err(e) => self.status = Some(e.to_string()),
+        }
+    }
+}
+
+// =============================================================================
+// RENDERIZADO
+// =============================================================================
+
+fn draw(frame: &mut Frame, app: &TuiApp) {
+    let layout = Layout::vertical([Constraint::Length(3), Constraint::Min(8), Constraint::Length(3)])
+        .split(frame.area());
+
+    let title = Paragraph::new("SCypher - XOR-based BIP39 seed cipher")
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(title, layout[0]);
+
+    match app.screen {
+        Screen::SeedEntry => draw_seed_entry(frame, app, layout[1]),
+        Screen::PasswordEntry => draw_password_entry(frame, app, layout[1]),
+        Screen::Processing => draw_processing(frame, app, layout[1]),
+        Screen::Result => draw_result(frame, app, layout[1]),
+        Screen::Addresses => draw_addresses(frame, app, layout[1]),
+    }
+
+    let help = match app.screen {
+        Screen::SeedEntry => "Tab: autocomplete  F2: show/hide  Enter: continue  Esc: quit",
+        Screen::PasswordEntry => "Enter: continue  Esc: back",
+        Screen::Processing => "Deriving keystream with Argon2id...",
+        Screen::Result => "r: show/hide  a: addresses  n: new  q: quit",
+        Screen::Addresses => "b/Esc: back",
+    };
+    let footer = match &app.status {
+        Some(status) => Paragraph::new(status.as_str()).style(Style::default().fg(Color::Red)),
+        None => Paragraph::new(help).style(Style::default().fg(Color::DarkGray)),
+    };
+    frame.render_widget(footer.block(Block::default().borders(Borders::ALL)), layout[2]);
+}
+
+fn draw_seed_entry(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let parts = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(area);
+
+    let input = Paragraph::new(format!("{}_", app.seed.display()))
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(format!(" Seed phrase ({} words) ", app.seed.word_count())));
+    frame.render_widget(input, parts[0]);
+
+    let suggestions = Paragraph::new(app.seed.suggestions().join("  "))
+        .style(Style::default().fg(Color::Cyan))
+        .block(Block::default().borders(Borders::ALL).title(" Suggestions "));
+    frame.render_widget(suggestions, parts[1]);
+}
+
+fn draw_password_entry(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let lines = vec![
+        Line::from(format!("Password:         {}", "*".repeat(app.password.chars().count()))),
+        Line::from(if app.confirming {
+            format!("Confirm password: {}", "*".repeat(app.password_confirm.chars().count()))
+        } else {
+            String::new()
+        }),
+        Line::from(""),
+        Line::from(format!("Minimum {} characters. The same password is needed to reverse the transformation.", MIN_PASSWORD_LENGTH))
+            .style(Style::default().fg(Color::DarkGray)),
+    ];
+    let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Password "));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_processing(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " Argon2id ({} iterations, {} MB) ",
+            DEFAULT_ITERATIONS,
+            DEFAULT_MEMORY_COST / 1024
+        )))
+        .gauge_style(Style::default().fg(Color::Green))
+        .ratio(app.progress());
+    frame.render_widget(gauge, area);
+}
+
+fn draw_result(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let text = match &app.result {
+        Some(result) if app.reveal_result => crate::cli::output::format::format_seed_phrase(result),
+        Some(result) => format!("{} words hidden - press 'r' to reveal", result.split_whitespace().count()),
+        None => String::new(),
+    };
+    let title = match crate::security::training::watermark() {
+        Some(mark) => format!(" Result [{} - training mode] ", mark),
+        None => " Result ".to_string(),
+    };
+    let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_addresses(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let rows = app
+        .addresses
+        .iter()
+        .map(|[network, path, address]| Row::new(vec![Cell::from(network.as_str()), Cell::from(path.as_str()), Cell::from(address.as_str())]));
+    let table = Table::new(rows, [Constraint::Length(10), Constraint::Length(20), Constraint::Min(30)])
+        .header(Row::new(vec!["Network", "Path", "Address"]).style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().borders(Borders::ALL).title(" Addresses (no passphrase) "));
+    frame.render_widget(table, area);
+}
+
+// =============================================================================
+// BUCLE PRINCIPAL
+// =============================================================================
+
+/// Ejecutar la interfaz TUI hasta que el usuario salga
+pub fn run_tui() -> Result<()> {
+    enable_raw_mode().map_err(SCypherError::from)?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).map_err(SCypherError::from)?;
+
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout)).map_err(SCypherError::from)?;
+    let mut app = TuiApp::new();
+    let outcome = event_loop(&mut terminal, &mut app);
+
+    // Restaurar siempre el terminal, incluso tras un error
+    app.clear_secrets();
+    let _ = disable_raw_mode();
+    let _ = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let _ = terminal.show_cursor();
+    crate::security::secure_cleanup();
+
+    outcome
+}
+
+fn event_loop<B: Backend>(terminal: &mut Terminal<B>, app: &mut TuiApp) -> Result<()> {
+    while !app.should_quit {
+        app.poll_processing();
+        terminal.draw(|frame| draw(frame, app)).map_err(SCypherError::from)?;
+
+        if event::poll(Duration::from_millis(100)).map_err(SCypherError::from)? {
+            if let Event::Key(key) = event::read().map_err(SCypherError::from)? {
+                app.handle_key(key);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_input_autocomplete() {
+        let mut input = SeedInput::default();
+        for c in "abandon aba".chars() {
+            input.push(c);
+        }
+        assert_eq!(input.current_prefix(), "aba");
+        assert_eq!(input.suggestions(), vec!["abandon"]);
+
+        input.complete();
+        assert_eq!(input.text, "abandon abandon ");
+        assert!(input.suggestions().is_empty());
+    }
+
+    #[test]
+    fn test_seed_input_masking() {
+        let mut input = SeedInput::default();
+        for c in " Zoo  zoo1".chars() {
+            input.push(c);
+        }
+        assert_eq!(input.text, "zoo zoo");
+        assert_eq!(input.display(), "••• •••");
+        input.reveal = true;
+        assert_eq!(input.display(), "zoo zoo");
+    }
+
+    #[test]
+    fn test_password_flow_requires_confirmation() {
+        let mut app = TuiApp::new();
+        app.screen = Screen::PasswordEntry;
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        for c in "short".chars() {
+            app.handle_key(press(KeyCode::Char(c)));
+        }
+        app.handle_key(press(KeyCode::Enter));
+        assert!(!app.confirming);
+        assert!(app.status.is_some());
+
+        for c in "-enough".chars() {
+            app.handle_key(press(KeyCode::Char(c)));
+        }
+        app.handle_key(press(KeyCode::Enter));
+        assert!(app.confirming);
+
+        for c in "different".chars() {
+            app.handle_key(press(KeyCode::Char(c)));
+        }
+        app.handle_key(press(KeyCode::Enter));
+        assert!(app.password_confirm.is_empty());
+        assert_eq!(app.screen, Screen::PasswordEntry);
+    }
+}
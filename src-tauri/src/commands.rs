@@ -3,6 +3,15 @@ use tokio::task;
 use serde::{Deserialize, Serialize};
 use crate::error::{SCypherError, Result};
 use crate::addresses::{derive_addresses as derive_addr, AddressSet};
+use crate::bip39::normalize_seed_input;
+use crate::cli::output::format::{format_phrase_with, parse_formatted_phrase, PhraseLayout};
+
+/// Normalizar la frase recibida y aplicar el modo formación (solo vectores de prueba)
+fn accept_seed_input(phrase: &str) -> Result<String> {
+    let phrase = normalize_seed_input(phrase);
+    crate::security::training::ensure_demo_phrase(&phrase)?;
+    Ok(phrase)
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct SeedValidation {
@@ -17,11 +26,20 @@ pub struct ProcessResult {
     pub success: bool,
     pub result: Option<String>,
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>, // `SCypherError::code()` del error, si lo hay
+    pub verified: Option<bool>, // Solo si se pidió verificación de ida y vuelta
+    pub warnings: Vec<crate::crypto::sanity::OutputWarning>, // Comprobaciones según el modo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watermark: Option<String>, // "TEST" en modo formación
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_ticket: Option<crate::security::result_store::ResultTicket>, // En lugar de `result` si se pidió caducidad
 }
 
 /// Validar frase semilla BIP39 completa
 #[command]
 pub fn validate_seed_phrase(phrase: String) -> SeedValidation {
+    let phrase = normalize_seed_input(&phrase);
     let word_count = phrase.split_whitespace().count();
 
     if phrase.trim().is_empty() {
@@ -33,7 +51,10 @@ pub fn validate_seed_phrase(phrase: String) -> SeedValidation {
         };
     }
 
-    match crate::bip39::validate_seed_phrase_complete(&phrase) {
+    let validation = crate::bip39::validate_seed_phrase_complete(&phrase)
+        .and_then(|()| crate::security::training::ensure_demo_phrase(&phrase));
+
+    match validation {
         Ok(()) => SeedValidation {
             valid: true,
             word_count,
@@ -52,6 +73,12 @@ pub fn validate_seed_phrase(phrase: String) -> SeedValidation {
             message: format!("Invalid BIP39 word: '{}'", word),
             status: "invalid".to_string(),
         },
+        Err(e @ SCypherError::InvalidWords(_)) => SeedValidation {
+            valid: false,
+            word_count,
+            message: e.to_string(),
+            status: "invalid".to_string(),
+        },
         Err(SCypherError::InvalidChecksum) => SeedValidation {
             valid: false,
             word_count,
@@ -67,39 +94,229 @@ pub fn validate_seed_phrase(phrase: String) -> SeedValidation {
     }
 }
 
+/// Análisis completo de la frase: palabras inválidas con su posición y sugerencia,
+/// bits de entropía y estado del checksum (para resaltar la palabra errónea en la GUI)
+#[command]
+pub fn analyze_seed_phrase(phrase: String) -> Result<crate::bip39::validation::SeedPhraseAnalysis> {
+    let phrase = normalize_seed_input(&phrase);
+    let analysis = crate::bip39::analyze_seed_phrase(&phrase);
+
+    // En modo formación una frase válida que no sea de prueba se rechaza
+    if analysis.overall_valid {
+        crate::security::training::ensure_demo_phrase(&phrase)?;
+    }
+    Ok(analysis)
+}
+
 /// Transformar frase semilla usando XOR - VERSIÓN ASYNC NO BLOQUEANTE
+/// Con `verify_roundtrip` revierte el resultado y lo compara con la entrada
+/// Con `mode: "decrypt"` advierte si el resultado no parece una seed real
+/// `checksum_policy`: "strict", "fix" (por defecto) o "pass_through"
+/// `parallelism` (carriles Argon2id) por defecto 1; al cifrar no puede superar los núcleos detectados
+/// `compatibility: "bash_v2"` usa el keystream SHAKE-256 del script Bash (ignora memoria y carriles)
+/// Con `display_timeout` (segundos) el resultado no viaja en la respuesta: se entrega un token
+/// para `fetch_result` y el backend lo borra al caducar (evento `result-expired`)
+/// Con `second_password` el keystream combina ambas contraseñas (control dual)
 #[command]
+#[allow(clippy::too_many_arguments)]
 pub async fn transform_seed_phrase(
     phrase: String,
     password: String,
     iterations: u32,
     memory_cost: u32,
+    verify_roundtrip: Option<bool>,
+    mode: Option<crate::crypto::sanity::TransformMode>,
+    parallelism: Option<u32>,
+    checksum_policy: Option<crate::crypto::ChecksumPolicy>,
+    compatibility: Option<crate::crypto::compat::CompatibilityMode>,
+    display_timeout: Option<u64>,
+    second_password: Option<String>,
+    hardware_pepper: Option<crate::security::hardware_pepper::PepperSource>,
 ) -> ProcessResult {
+    let phrase = normalize_seed_input(&phrase);
+    let verify = verify_roundtrip.unwrap_or(false);
+    let mode = mode.unwrap_or_default();
+    let policy = checksum_policy.unwrap_or_default();
+    let bash_v2 = compatibility.unwrap_or_default() == crate::crypto::compat::CompatibilityMode::BashV2;
+    let parallelism = parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM);
+    let algorithm = match (bash_v2, second_password.is_some()) {
+        (true, _) => "bash_v2_shake256",
+        (false, true) => "argon2id_dual",
+        (false, false) => "argon2id",
+    };
+    let started = std::time::Instant::now();
+
+    // Política "solo sin conexión" (si el usuario la activó)
+    if let Err(e) = crate::security::network_guard::ensure_offline() {
+        return ProcessResult {
+            success: false,
+            result: None,
+            error: Some(e.to_string()),
+            error_code: Some(e.code().to_string()),
+            verified: None,
+            warnings: Vec::new(),
+            watermark: None,
+            result_ticket: None,
+        };
+    }
+
     // Ejecutar Argon2id en thread separado para no bloquear UI
     let result = task::spawn_blocking(move || {
+        // Modo formación: solo vectores de prueba y resultados de la sesión
+        crate::security::training::ensure_demo_phrase(&phrase)?;
+
         // LA MISMA LÓGICA CRIPTOGRÁFICA EXACTA - SIN CAMBIOS
-        crate::crypto::transform_seed(&phrase, &password, iterations, memory_cost)
+        // Se ejecuta en el proceso aislado de cifrado (salvo que esté desactivado);
+        // al descifrar se pasa por el despachador de versiones de formato
+        crate::crypto_worker::transform(crate::crypto_worker::PhraseRequest {
+            phrase,
+            password,
+            second_password,
+            params: crate::pipeline::TransformParams {
+                iterations,
+                memory_cost,
+                parallelism: Some(parallelism),
+                mode,
+                checksum_policy: policy,
+                compatibility: compatibility.unwrap_or_default(),
+                hardware_pepper,
+            },
+            verify,
+        })
     }).await;
 
+    // Historial de diagnóstico: parámetros, duración y código de error
+    let error_code = match &result {
+        Ok(Ok((_, Some(false)))) => Some("roundtrip_failed"),
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.code()),
+        Err(_) => Some("task_error"),
+    };
+    crate::diagnostics::record_operation(
+        "transform_seed_phrase",
+        Some(crate::diagnostics::kdf_params(algorithm, iterations, memory_cost, parallelism)),
+        started,
+        error_code,
+    );
+
     match result {
-        Ok(Ok(transformed)) => ProcessResult {
-            success: true,
-            result: Some(transformed),
-            error: None,
+        Ok(Ok((_, Some(false)))) => ProcessResult {
+            success: false,
+            result: None,
+            error: Some("Round-trip verification failed: the result does not reverse to the input".to_string()),
+            error_code: None,
+            verified: Some(false),
+            warnings: Vec::new(),
+            watermark: None,
+            result_ticket: None,
         },
+        Ok(Ok((output, verified))) => {
+            crate::security::training::record_output(&output.phrase);
+            let mut warnings = crate::crypto::sanity::check_output(mode, &output.phrase);
+            if output.checksum_corrected {
+                warnings.push(crate::crypto::sanity::checksum_corrected_warning());
+            }
+
+            // Resultado retenido en el backend: la GUI solo recibe el token
+            let (result, result_ticket) = match display_timeout {
+                Some(timeout) => match crate::security::result_store::store_result(&output.phrase, Some(timeout)) {
+                    Ok(ticket) => (None, Some(ticket)),
+                    Err(e) => {
+                        return ProcessResult {
+                            success: false,
+                            result: None,
+                            error: Some(e.to_string()),
+                            error_code: Some(e.code().to_string()),
+                            verified: None,
+                            warnings: Vec::new(),
+                            watermark: None,
+                            result_ticket: None,
+                        }
+                    }
+                },
+                None => (Some(output.phrase), None),
+            };
+            ProcessResult {
+                success: true,
+                warnings,
+                result,
+                error: None,
+                error_code: None,
+                verified,
+                watermark: crate::security::training::watermark().map(String::from),
+                result_ticket,
+            }
+        }
         Ok(Err(e)) => ProcessResult {
             success: false,
             result: None,
             error: Some(e.to_string()),
+            error_code: Some(e.code().to_string()),
+            verified: None,
+            warnings: Vec::new(),
+            watermark: None,
+            result_ticket: None,
         },
         Err(e) => ProcessResult {
             success: false,
             result: None,
             error: Some(format!("Task error: {}", e)),
+            error_code: None,
+            verified: None,
+            warnings: Vec::new(),
+            watermark: None,
+            result_ticket: None,
         },
     }
 }
 
+/// Transformar entropía en hexadecimal (sin palabras) con el mismo keystream Argon2id
+/// Con `include_phrase` devuelve también la frase BIP39 equivalente
+#[command]
+pub async fn transform_entropy(
+    entropy_hex: String,
+    password: String,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: Option<u32>,
+    include_phrase: Option<bool>,
+) -> Result<crate::crypto::EntropyTransform> {
+    crate::security::network_guard::ensure_offline()?;
+    let parallelism = parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM);
+    let started = std::time::Instant::now();
+
+    let result = task::spawn_blocking(move || {
+        // Modo formación: la entropía también debe ser de un vector de prueba
+        if crate::security::training::is_training_mode() {
+            let digits = entropy_hex.trim().trim_start_matches("0x");
+            crate::security::training::ensure_demo_phrase(&crate::bip39::hex_to_phrase(digits)?)?;
+        }
+
+        let result = crate::crypto_worker::transform_entropy(crate::crypto_worker::EntropyRequest {
+            entropy_hex,
+            password,
+            iterations,
+            memory_cost,
+            parallelism,
+            include_phrase: include_phrase.unwrap_or(false),
+        })?;
+        if crate::security::training::is_training_mode() {
+            crate::security::training::record_output(&crate::bip39::hex_to_phrase(&result.entropy_hex)?);
+        }
+        Ok(result)
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?;
+
+    crate::diagnostics::record_operation(
+        "transform_entropy",
+        Some(crate::diagnostics::kdf_params("argon2id", iterations, memory_cost, parallelism)),
+        started,
+        result.as_ref().err().map(SCypherError::code),
+    );
+    result
+}
+
 /// Obtener lista completa de palabras BIP39
 #[command]
 pub fn get_bip39_wordlist() -> Vec<String> {
@@ -107,9 +324,10 @@ pub fn get_bip39_wordlist() -> Vec<String> {
 }
 
 /// Validar palabra individual BIP39
+/// Sin distinguir mayúsculas ni espacios alrededor salvo con `strict`
 #[command]
-pub fn validate_bip39_word(word: String) -> bool {
-    crate::bip39::is_valid_word(&word)
+pub fn validate_bip39_word(word: String, strict: Option<bool>) -> bool {
+    crate::bip39::lookup_word(&word, strict.unwrap_or(false)).is_some()
 }
 
 /// Obtener sugerencias para palabra incorrecta
@@ -130,23 +348,69 @@ pub fn get_word_suggestions(word: String) -> Vec<String> {
 #[command]
 pub fn read_seed_file(path: String) -> Result<String> {
     match std::fs::read_to_string(&path) {
-        Ok(content) => {
-            let cleaned = content
-                .lines()
-                .map(|line| line.trim())
-                .filter(|line| !line.is_empty())
-                .collect::<Vec<&str>>()
-                .join(" ");
-            Ok(cleaned)
-        }
+        Ok(content) => Ok(parse_formatted_phrase(&content)),
         Err(e) => Err(SCypherError::file(format!("Cannot read file: {}", e))),
     }
 }
 
+/// Parámetros no secretos que se guardan junto al resultado
+#[derive(Serialize, Deserialize)]
+pub struct SaveMetadataOptions {
+    pub iterations: u32,
+    pub memory_cost: u32,
+    #[serde(default)]
+    pub parallelism: Option<u32>,
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub dual_control: bool,
+    #[serde(default)]
+    pub custom_wordlist: bool,  // El resultado está escrito con la lista propia registrada
+}
+
 /// Guardar resultado en archivo (sin async para Tauri v1)
+/// Con `metadata` escribe además `<archivo>.meta.json` con los parámetros del KDF
+/// y con `layout` guarda la frase numerada, en rejilla o con otro separador
+/// Con `transliterate` las palabras japonesas o coreanas llevan su romanización
+/// Requiere un token de `request_consent("write_file")`
 #[command]
-pub fn save_result_file(content: String, path: String) -> Result<()> {
+pub fn save_result_file(
+    content: String,
+    path: String,
+    metadata: Option<SaveMetadataOptions>,
+    layout: Option<PhraseLayout>,
+    transliterate: Option<bool>,
+    consent_token: Option<String>,
+) -> Result<()> {
     use std::fs;
+
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+
+    // Validar los metadatos antes de escribir nada
+    let sidecar = match metadata {
+        Some(options) => Some(crate::metadata::build_metadata(
+            options.iterations,
+            options.memory_cost,
+            options.parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM),
+            Some(content.split_whitespace().count()),
+            options.hint,
+            options.dual_control,
+            if options.custom_wordlist {
+                Some(crate::bip39::custom_wordlist::with_active_wordlist(|wordlist| Ok(wordlist.hash().to_string()))?)
+            } else {
+                None
+            },
+        )?),
+        None => None,
+    };
+
+    let transliterate = transliterate.unwrap_or(false);
+    let content = match layout {
+        Some(layout) => format_phrase_with(&content, &layout, transliterate)?,
+        None if transliterate => format_phrase_with(&content, &PhraseLayout::SingleLine, true)?,
+        None => content,
+    };
+
     fs::write(&path, &content)
         .map_err(|e| SCypherError::file(format!("Cannot write file: {}", e)))?;
 
@@ -162,9 +426,64 @@ pub fn save_result_file(content: String, path: String) -> Result<()> {
             .map_err(|e| SCypherError::file(format!("Cannot set permissions: {}", e)))?;
     }
 
+    if let Some(sidecar) = sidecar {
+        crate::metadata::write_sidecar(&path, &sidecar)?;
+    }
+
     Ok(())
 }
 
+/// Pedir confirmación al usuario en un diálogo nativo y emitir un token de un solo uso
+/// para `action` (caduca a los 60 segundos); si el usuario cancela devuelve un error
+#[command]
+pub async fn request_consent(
+    action: crate::security::consent::ConsentAction,
+) -> Result<crate::security::consent::ConsentGrant> {
+    use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+    let answer = AsyncMessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title("SCypher - Confirm action")
+        .set_description(action.prompt())
+        .set_buttons(MessageButtons::OkCancel)
+        .show()
+        .await;
+
+    match answer {
+        MessageDialogResult::Ok | MessageDialogResult::Yes => Ok(crate::security::consent::issue_consent(action)),
+        _ => Err(SCypherError::ConsentRequired("The user declined the action".to_string())),
+    }
+}
+
+/// Copiar un resultado al portapapeles desde el backend
+/// Requiere un token de `request_consent("copy_to_clipboard")`
+#[command]
+pub fn copy_to_clipboard(app: tauri::AppHandle, text: String, consent_token: Option<String>) -> Result<()> {
+    use tauri::ClipboardManager;
+
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::CopyToClipboard)?;
+    app.clipboard_manager()
+        .write_text(text)
+        .map_err(|e| SCypherError::file(format!("Cannot write to clipboard: {}", e)))
+}
+
+/// Detectar la versión de formato de una entrada (usando sus metadatos si existen)
+#[command]
+pub fn detect_format(input: String, path: Option<String>) -> Result<crate::crypto::format::FormatDetection> {
+    let declared = match path {
+        Some(path) => crate::metadata::load_metadata(&path)?.map(|m| m.format_version),
+        None => None,
+    };
+    Ok(crate::crypto::format::detect_format(&input, declared))
+}
+
+/// Leer los metadatos guardados junto a un resultado para rellenar el descifrado
+#[command]
+pub fn load_metadata(path: String) -> Result<Option<crate::metadata::ResultMetadata>> {
+    crate::metadata::load_metadata(&path)
+}
+
 /// Abrir diálogo moderno de archivo (usa XDG Portal en Linux)
 #[command]
 pub async fn open_file_dialog() -> Result<Option<String>> {
@@ -196,8 +515,12 @@ pub async fn save_file_dialog() -> Result<Option<String>> {
 }
 
 /// Generar nueva frase semilla BIP39 válida
+/// Con `trng` se añade (XOR) la entropía de un TRNG serie tras pasar los tests de salud
 #[command]
-pub fn generate_seed_phrase(word_count: serde_json::Value) -> Result<String> {
+pub async fn generate_seed_phrase(
+    word_count: serde_json::Value,
+    trng: Option<crate::security::trng::TrngSource>,
+) -> Result<String> {
     // Parsear el word_count de manera flexible
     let count: usize = match word_count {
         serde_json::Value::Number(n) => {
@@ -226,11 +549,181 @@ pub fn generate_seed_phrase(word_count: serde_json::Value) -> Result<String> {
 
     // Generar entropía criptográficamente segura
     let mut entropy = vec![0u8; entropy_bytes];
-    use rand::RngCore;
-    rand::thread_rng().fill_bytes(&mut entropy);
+    crate::security::rng::fill_random(&mut entropy);
+
+    // El puerto serie puede tardar: leer fuera del hilo de UI
+    if let Some(source) = trng {
+        entropy = task::spawn_blocking(move || {
+            crate::security::trng::mix_trng_entropy(&mut entropy, &source).map(|_| entropy)
+        })
+        .await
+        .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))??;
+    }
 
     // Convertir entropía a frase BIP39 válida (con checksum correcto)
-    crate::bip39::conversion::entropy_to_phrase(&entropy)
+    let phrase = crate::bip39::conversion::entropy_to_phrase(&entropy);
+    zeroize::Zeroize::zeroize(&mut entropy);
+    phrase
+}
+
+/// Leer un TRNG serie y pasar los tests de salud (SP 800-90B) sin generar nada
+#[command]
+pub async fn test_trng(source: crate::security::trng::TrngSource) -> Result<crate::security::trng::TrngHealthReport> {
+    task::spawn_blocking(move || crate::security::trng::check_trng(&source))
+        .await
+        .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Generar frase semilla BIP39 a partir de tiradas de dados o lanzamientos de moneda
+#[command]
+pub fn generate_seed_from_manual_entropy(
+    input: String,
+    source: String,
+    word_count: usize,
+    mix_with_os_rng: bool,
+) -> Result<String> {
+    use crate::bip39::manual_entropy::{phrase_from_manual_entropy, EntropySource};
+
+    phrase_from_manual_entropy(&input, EntropySource::parse(&source)?, word_count, mix_with_os_rng)
+}
+
+/// Número mínimo de tiradas/lanzamientos necesarios para una longitud de frase
+#[command]
+pub fn manual_entropy_requirements(source: String, word_count: usize) -> Result<usize> {
+    use crate::bip39::manual_entropy::{required_events, EntropySource};
+
+    required_events(EntropySource::parse(&source)?, word_count)
+}
+
+/// Analizar la calidad estadística de entropía en hexadecimal
+#[command]
+pub fn analyze_entropy(entropy_hex: String) -> Result<crate::crypto::entropy_analysis::EntropyAnalysis> {
+    crate::crypto::entropy_analysis::analyze_hex(&entropy_hex)
+}
+
+/// Hoja de verificación manual: datos estructurados y texto formateado
+#[derive(Serialize, Deserialize)]
+pub struct WorksheetExport {
+    pub worksheet: crate::bip39::worksheet::VerificationWorksheet,
+    pub text: String,
+}
+
+/// Generar hoja de verificación manual (índices, binario y checksum) de una frase
+#[command]
+pub fn export_verification_worksheet(seed_phrase: String) -> Result<WorksheetExport> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let worksheet = crate::bip39::worksheet::build_worksheet(&seed_phrase)?;
+    let text = crate::cli::output::format::format_verification_worksheet(&worksheet);
+    Ok(WorksheetExport { worksheet, text })
+}
+
+/// Deletreo fonético: datos por palabra y texto para dictar
+#[derive(Serialize, Deserialize)]
+pub struct PhoneticReadoutExport {
+    pub words: Vec<crate::bip39::phonetic::PhoneticWord>,
+    pub text: String,
+}
+
+/// Deletrear cada palabra con el alfabeto OTAN y su posición (dictado a través de un air-gap)
+#[command]
+pub fn export_phonetic_readout(seed_phrase: String) -> Result<PhoneticReadoutExport> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let words = crate::bip39::phonetic::build_phonetic_readout(&seed_phrase)?;
+    let text = crate::cli::output::format::format_phonetic_readout(&words);
+    Ok(PhoneticReadoutExport { words, text })
+}
+
+/// Buscar en qué ruta de la frase está una dirección (redes, esquemas, cuentas,
+/// cadena de cambio e índices dentro de `search_space`)
+/// Emite `scan-progress` durante la búsqueda y `scan-summary` al terminar;
+/// `cancel_locate_address` la detiene
+#[command]
+pub async fn locate_address(
+    app: tauri::AppHandle,
+    seed_phrase: String,
+    address: String,
+    search_space: crate::locate::SearchSpace,
+    passphrase: Option<String>,
+) -> Result<Option<crate::locate::LocatedAddress>> {
+    use tauri::Manager;
+
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::locate::cancel_flag().store(false, std::sync::atomic::Ordering::Relaxed);
+
+    task::spawn_blocking(move || {
+        crate::locate::locate_address(
+            &seed_phrase,
+            passphrase.as_deref(),
+            &address,
+            &search_space,
+            crate::locate::cancel_flag(),
+            |event| {
+                let _ = app.emit_all(event.name(), event);
+            },
+        )
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Detener la búsqueda de `locate_address` en curso
+#[command]
+pub fn cancel_locate_address() {
+    crate::locate::cancel_search();
+}
+
+/// Validar un archivo con una frase candidata por línea (cuadernos antiguos, copias dudosas)
+/// Devuelve el resultado de cada línea no vacía sin repetir las frases
+#[command]
+pub fn validate_seed_file(path: String) -> Result<Vec<crate::bip39::validation::LineValidation>> {
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| SCypherError::file(format!("Cannot read file: {}", e)))?;
+    Ok(crate::bip39::validation::validate_phrase_lines(&content))
+}
+
+/// Buscar frases BIP39 válidas en un texto pegado (correo, exportación de wallet)
+/// Las candidatas vienen ordenadas por confianza, con su posición en el texto
+#[command]
+pub fn extract_mnemonic_from_text(text: String) -> Vec<crate::bip39::extract::MnemonicCandidate> {
+    crate::bip39::extract::extract_mnemonics(&text)
+        .into_iter()
+        // En modo formación solo se ofrecen los vectores de prueba
+        .filter(|candidate| crate::security::training::ensure_demo_phrase(&candidate.phrase).is_ok())
+        .collect()
+}
+
+/// Escribir la frase en líneas con palabra de control (4 palabras por línea por defecto)
+#[command]
+pub fn export_transcription_aid(seed_phrase: String, words_per_line: Option<usize>) -> Result<String> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::bip39::transcription::format_transcription(
+        &seed_phrase,
+        words_per_line.unwrap_or(crate::bip39::transcription::DEFAULT_WORDS_PER_LINE),
+    )
+}
+
+/// Importar una transcripción: quita las palabras de control e indica las líneas erróneas
+#[command]
+pub fn import_transcription_aid(text: String) -> Result<crate::bip39::transcription::TranscriptionImport> {
+    let imported = crate::bip39::transcription::parse_transcription(&text)?;
+    crate::security::training::ensure_demo_phrase(&imported.phrase)?;
+    Ok(imported)
+}
+
+/// Lista de verificación cruzada: datos para otras herramientas y texto formateado
+#[derive(Serialize)]
+pub struct CrossCheckExport {
+    pub entries: Vec<crate::addresses::CrossCheckEntry>,
+    pub text: String,
+}
+
+/// Generar la lista para contrastar cada red con herramientas de terceros
+#[command]
+pub fn export_cross_check_checklist(seed_phrase: String, passphrase: Option<String>) -> Result<CrossCheckExport> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let entries = crate::addresses::cross_check_entries(&seed_phrase, passphrase.as_deref())?;
+    let text = crate::cli::output::format::format_cross_check_checklist(&entries);
+    Ok(CrossCheckExport { entries, text })
 }
 
 /// Derivar direcciones HD Wallet con configuración individual por red
@@ -240,6 +733,7 @@ pub fn derive_addresses_with_config(
     passphrase: Option<String>,
     network_configs: std::collections::HashMap<String, crate::addresses::NetworkConfig>,
 ) -> Result<AddressSet> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
     crate::addresses::derive_addresses_with_config(
         &seed_phrase,
         passphrase.as_deref(),
@@ -255,6 +749,7 @@ pub fn derive_addresses(
     networks: Vec<String>,
     address_count: u32, // NUEVO PARÁMETRO
 ) -> Result<AddressSet> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
     // Validar address_count
     let count = if address_count < 1 { 1 } else if address_count > 100 { 100 } else { address_count };
 
@@ -264,6 +759,9 @@ pub fn derive_addresses(
         network_configs.insert(network, crate::addresses::NetworkConfig {
             count,
             use_passphrase: true, // Será aplicado solo a redes que lo soporten
+            solana_scheme: crate::addresses::SolanaScheme::default(),
+            evm_scheme: crate::addresses::EvmPathScheme::default(),
+            account: 0,
         });
     }
 
@@ -274,87 +772,1175 @@ pub fn derive_addresses(
     )
 }
 
-/// Validar que una red sea soportada
+/// Listar los perfiles de wallets con sus rutas de derivación
 #[command]
-pub fn validate_network(network: String) -> bool {
-    matches!(network.as_str(),
-        "bitcoin" | "ethereum" | "ergo" |
-        "bsc" | "polygon" | "cardano" |
-        "dogecoin" | "litecoin" | "solana"
-    )
+pub fn get_wallet_profiles() -> Vec<crate::addresses::WalletProfile> {
+    crate::addresses::wallet_profiles()
 }
 
-/// Obtener información sobre redes soportadas
+/// Derivar direcciones con las rutas exactas de un perfil de wallet (Ledger Live, Trezor Suite...)
 #[command]
-pub fn get_supported_networks() -> Vec<NetworkInfo> {
-    vec![
-        // REDES EXISTENTES
-        NetworkInfo {
-            id: "bitcoin".to_string(),
-            name: "Bitcoin".to_string(),
-            symbol: "₿".to_string(),
-            coin_type: 0,
-            description: "Bitcoin mainnet addresses".to_string(),
-        },
-        NetworkInfo {
-            id: "ethereum".to_string(),
-            name: "Ethereum".to_string(),
-            symbol: "Ξ".to_string(),
-            coin_type: 60,
-            description: "Ethereum mainnet addresses".to_string(),
-        },
-        NetworkInfo {
-            id: "ergo".to_string(),
-            name: "Ergo".to_string(),
-            symbol: "⚡".to_string(),
-            coin_type: 429,
-            description: "Ergo platform addresses".to_string(),
-        },
+pub fn derive_addresses_with_profile(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    profile_name: String,
+    address_count: u32,
+) -> Result<AddressSet> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let count = address_count.clamp(1, 100);
+    crate::addresses::derive_addresses_with_profile(&seed_phrase, passphrase.as_deref(), &profile_name, count)
+}
 
-        // NUEVAS REDES
-        NetworkInfo {
-            id: "bsc".to_string(),
-            name: "Binance Smart Chain".to_string(),
-            symbol: "🟡".to_string(),
-            coin_type: 60, // Mismo que Ethereum (EVM compatible)
-            description: "BSC mainnet addresses (EVM compatible)".to_string(),
-        },
-        NetworkInfo {
-            id: "polygon".to_string(),
-            name: "Polygon".to_string(),
-            symbol: "🔷".to_string(),
-            coin_type: 60, // Mismo que Ethereum (EVM compatible)
-            description: "Polygon mainnet addresses (EVM compatible)".to_string(),
-        },
-        NetworkInfo {
-            id: "cardano".to_string(),
-            name: "Cardano".to_string(),
-            symbol: "₳".to_string(),
-            coin_type: 1815,
-            description: "Cardano Shelley addresses".to_string(),
-        },
-        NetworkInfo {
-            id: "dogecoin".to_string(),
-            name: "Dogecoin".to_string(),
-            symbol: "Ð".to_string(),
-            coin_type: 3,
-            description: "Dogecoin mainnet addresses".to_string(),
-        },
-        NetworkInfo {
-            id: "litecoin".to_string(),
-            name: "Litecoin".to_string(),
-            symbol: "Ł".to_string(),
-            coin_type: 2,
-            description: "Litecoin mainnet addresses".to_string(),
-        },
-        NetworkInfo {
-            id: "solana".to_string(),
-            name: "Solana".to_string(),
-            symbol: "◎".to_string(),
-            coin_type: 501,
-            description: "Solana mainnet addresses".to_string(),
-        },
-    ]
+/// Registro EVM embebido: chain IDs, exploradores y tokens comunes
+#[command]
+pub fn get_evm_chain_registry() -> Vec<crate::evm_registry::EvmChain> {
+    crate::evm_registry::evm_chains()
+}
+
+/// Vaciar la caché en memoria de keystreams Argon2id
+#[command]
+pub fn clear_kdf_cache() {
+    crate::crypto::keystream::clear_kdf_cache()
+}
+
+/// Auditoría de seguridad del entorno para el panel de la GUI
+#[command]
+pub fn run_security_audit() -> crate::security::SecurityAuditReport {
+    crate::security::security_audit()
+}
+
+/// Auditoría de seguridad como texto, agrupada por gravedad (para copiar o adjuntar)
+#[command]
+pub fn security_audit_text() -> String {
+    crate::security::security_audit().generate_report()
+}
+
+/// Aplicar una acción correctiva de la auditoría y devolver el nuevo reporte
+#[command]
+pub fn apply_remediation(code: String) -> Result<crate::security::SecurityAuditReport> {
+    crate::security::apply_remediation(&code)
+}
+
+/// Estado de red actual y política de uso sin conexión
+#[command]
+pub fn get_network_status() -> crate::security::network_guard::NetworkStatus {
+    crate::security::network_guard::network_status()
+}
+
+/// Exigir modo sin conexión para las transformaciones (y autorizar la excepción)
+#[command]
+pub fn set_network_policy(enforce_offline: bool, allow_online: bool) -> crate::security::network_guard::NetworkStatus {
+    crate::security::network_guard::set_network_policy(enforce_offline, allow_online);
+    crate::security::network_guard::network_status()
+}
+
+/// Verificar la integridad del ejecutable y sus recursos contra el manifiesto firmado
+#[command]
+pub fn verify_integrity() -> Result<crate::integrity::IntegrityReport> {
+    crate::integrity::verify_integrity()
+}
+
+/// Nivel de registro actual
+#[command]
+pub fn get_log_level() -> String {
+    crate::logging::current_log_level()
+}
+
+/// Cambiar el nivel de registro en tiempo de ejecución
+#[command]
+pub fn set_log_level(level: String) -> Result<String> {
+    crate::logging::set_log_level(&level)
+}
+
+/// Núcleos de CPU detectados: máximo paralelismo Argon2id permitido al cifrar
+#[command]
+pub fn get_available_parallelism() -> u32 {
+    crate::crypto::keystream::available_parallelism()
+}
+
+/// Comprobar si los parámetros del KDF caben en la memoria de este equipo
+#[command]
+pub fn check_kdf_feasibility(
+    params: crate::crypto::feasibility::KdfFeasibilityRequest,
+) -> Result<crate::crypto::feasibility::KdfFeasibility> {
+    crate::crypto::feasibility::check_kdf_feasibility(&params)
+}
+
+/// Estimar la duración de Argon2id con una calibración reducida en este equipo
+#[command]
+pub fn estimate_kdf_time(
+    params: crate::crypto::estimate::KdfEstimateRequest,
+) -> Result<crate::crypto::estimate::KdfEstimate> {
+    crate::crypto::estimate::estimate_kdf_time(&params)
+}
+
+/// Activar el registro de auditoría cifrado (clave en el llavero del sistema)
+#[command]
+pub fn enable_audit_log() -> Result<crate::audit_log::AuditLogStatus> {
+    crate::audit_log::enable_audit_log()
+}
+
+/// Estado del registro de auditoría
+#[command]
+pub fn get_audit_log_status() -> Result<crate::audit_log::AuditLogStatus> {
+    crate::audit_log::audit_log_status()
+}
+
+/// Verificar la cadena de hashes y la autenticación del registro de auditoría
+#[command]
+pub fn verify_log_integrity() -> Result<crate::audit_log::AuditLogVerification> {
+    crate::audit_log::verify_log_integrity()
+}
+
+/// Copias cifradas vigiladas y cuáles hay que volver a verificar
+#[command]
+pub fn get_backup_health() -> Result<crate::backup_health::BackupHealth> {
+    crate::backup_health::backup_health()
+}
+
+/// Empezar a vigilar una copia cifrada (ruta y etiqueta opcional)
+#[command]
+pub fn track_backup(path: String, label: Option<String>) -> Result<crate::backup_health::BackupHealth> {
+    crate::backup_health::track_backup(&path, label)
+}
+
+/// Dejar de vigilar una copia cifrada
+#[command]
+pub fn untrack_backup(path: String) -> Result<crate::backup_health::BackupHealth> {
+    crate::backup_health::untrack_backup(&path)
+}
+
+/// Días tras los que se pide volver a verificar una copia
+#[command]
+pub fn set_backup_reminder_interval(days: u32) -> Result<crate::backup_health::BackupHealth> {
+    crate::backup_health::set_reminder_interval(days)
+}
+
+/// Verificar una copia cifrada (ida y vuelta y huella BIP32) y anotar la fecha
+#[command]
+pub async fn verify_backup(
+    path: String,
+    password: String,
+    second_password: Option<String>,
+    params: crate::pipeline::TransformParams,
+    passphrase: Option<String>,
+    expected_fingerprint: String,
+) -> Result<crate::backup_health::BackupVerification> {
+    task::spawn_blocking(move || {
+        crate::backup_health::verify_backup(&path, password, second_password, params, passphrase.as_deref(), &expected_fingerprint)
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Sobrescribir el heap libre al cerrar la aplicación (más lento al salir)
+#[command]
+pub fn set_heap_scrub_on_exit(enabled: bool) {
+    crate::security::memory::set_heap_scrub_on_exit(enabled)
+}
+
+/// Ejecutar las transformaciones en el proceso aislado de cifrado (por defecto activado)
+/// Desactivarlo requiere un token de `request_consent("disable_crypto_worker")`
+#[command]
+pub fn set_crypto_worker(enabled: bool, consent_token: Option<String>) -> Result<()> {
+    if !enabled {
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::DisableCryptoWorker)?;
+    }
+    crate::crypto_worker::set_crypto_worker_enabled(enabled);
+    Ok(())
+}
+
+/// Política de entorno de los procesos hijo (impresora, tokens)
+#[command]
+pub fn get_environment_policy() -> crate::security::environment::EnvironmentPolicy {
+    crate::security::environment::environment_policy()
+}
+
+/// Cambiar la política de entorno de los procesos hijo
+#[command]
+pub fn set_environment_policy(
+    policy: crate::security::environment::EnvironmentPolicy,
+) -> crate::security::environment::EnvironmentPolicy {
+    crate::security::environment::configure_environment_policy(policy)
+}
+
+/// Registrar una credencial FIDO2 con hmac-secret para usarla como pepper
+/// Devuelve el identificador de credencial (no es secreto) a guardar con los parámetros
+#[command]
+pub async fn enroll_fido2_pepper(device: String, rp_id: Option<String>) -> Result<String> {
+    task::spawn_blocking(move || {
+        crate::security::hardware_pepper::enroll_fido2_credential(&device, rp_id.as_deref())
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Validar formato y checksum de una dirección (verificación con hardware wallet)
+#[command]
+pub fn validate_address(network: String, address: String) -> Result<crate::addresses::AddressValidation> {
+    crate::addresses::validate_address(&network, &address)
+}
+
+/// Calcular las direcciones de contrato (CREATE/CREATE2) de una cuenta EVM o TRON
+#[command]
+pub fn predict_contract_addresses(
+    network: String,
+    deployer: String,
+    deployment: crate::addresses::ContractDeployment,
+) -> Result<crate::addresses::ContractAddressPrediction> {
+    crate::addresses::predict_contract_addresses(&network, &deployer, &deployment)
+}
+
+/// Importar una clave privada WIF (Bitcoin, Litecoin, Dogecoin): red y dirección P2PKH
+#[command]
+pub fn inspect_wif_key(wif: String) -> Result<crate::addresses::WifKeyInfo> {
+    crate::addresses::inspect_wif(&wif)
+}
+
+/// Exportar las direcciones derivadas a un archivo CSV o JSON
+/// Requiere un token de `request_consent("write_file")`
+#[command]
+pub fn export_addresses(addresses: AddressSet, format: String, path: String, consent_token: Option<String>) -> Result<usize> {
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+    crate::addresses::export_addresses(&addresses, &format, &path)
+}
+
+/// Estado del modo formación (demostraciones con vectores de prueba)
+#[command]
+pub fn get_training_status() -> crate::security::training::TrainingStatus {
+    crate::security::training::training_status()
+}
+
+/// Activar o desactivar el modo formación
+#[command]
+pub fn set_training_mode(enabled: bool) -> crate::security::training::TrainingStatus {
+    crate::security::training::set_training_mode(enabled);
+    crate::security::training::training_status()
+}
+
+/// Añadir una frase etiquetada a la bóveda de la sesión (memoria bloqueada, nunca en disco)
+#[command]
+pub fn vault_add_profile(label: String, phrase: String) -> Result<Vec<crate::security::vault::VaultProfileSummary>> {
+    let phrase = accept_seed_input(&phrase)?;
+    crate::security::vault::add_profile(&label, &phrase)
+}
+
+/// Elegir el perfil de la bóveda con el que se trabaja
+#[command]
+pub fn vault_select_profile(label: String) -> Result<Vec<crate::security::vault::VaultProfileSummary>> {
+    crate::security::vault::select_profile(&label)
+}
+
+/// Eliminar un perfil de la bóveda
+#[command]
+pub fn vault_remove_profile(label: String) -> Result<Vec<crate::security::vault::VaultProfileSummary>> {
+    crate::security::vault::remove_profile(&label)
+}
+
+/// Perfiles de la bóveda (solo etiquetas, nunca las frases)
+#[command]
+pub fn vault_list_profiles() -> Vec<crate::security::vault::VaultProfileSummary> {
+    crate::security::vault::list_profiles()
+}
+
+/// Vaciar la bóveda
+#[command]
+pub fn vault_clear() {
+    crate::security::vault::clear_vault();
+}
+
+/// Transformar la frase del perfil seleccionado (mismas opciones que `transform_seed_phrase`)
+#[command]
+pub async fn vault_transform(
+    password: String,
+    iterations: u32,
+    memory_cost: u32,
+    verify_roundtrip: Option<bool>,
+    mode: Option<crate::crypto::sanity::TransformMode>,
+    parallelism: Option<u32>,
+) -> ProcessResult {
+    let phrase = match crate::security::vault::selected_phrase() {
+        Ok(phrase) => phrase,
+        Err(e) => {
+            return ProcessResult {
+                success: false,
+                result: None,
+                error: Some(e.to_string()),
+                error_code: Some(e.code().to_string()),
+                verified: None,
+                warnings: Vec::new(),
+                watermark: None,
+                result_ticket: None,
+            }
+        }
+    };
+    transform_seed_phrase(phrase, password, iterations, memory_cost, verify_roundtrip, mode, parallelism, None, None, None, None, None).await
+}
+
+/// Derivar direcciones de la frase del perfil seleccionado
+#[command]
+pub fn vault_derive_addresses(
+    passphrase: Option<String>,
+    networks: Vec<String>,
+    address_count: u32,
+) -> Result<AddressSet> {
+    let phrase = crate::security::vault::selected_phrase()?;
+    derive_addresses(phrase, passphrase, networks, address_count)
+}
+
+/// Vista enmascarada de la frase del perfil seleccionado
+#[command]
+pub fn vault_redact_selected(visible_positions: Option<Vec<usize>>) -> Result<crate::bip39::redaction::RedactedPhrase> {
+    let phrase = crate::security::vault::selected_phrase()?;
+    crate::bip39::redaction::redact_owned_phrase(phrase, visible_positions.as_deref())
+}
+
+/// Recoger (una sola vez) un resultado retenido por `display_timeout`
+#[command]
+pub fn fetch_result(token: String) -> Result<String> {
+    crate::security::result_store::fetch_result(&token)
+}
+
+/// Vista enmascarada de un resultado retenido (sin recogerlo): solo viajan
+/// las palabras de `visible_positions` (por defecto la primera y la última)
+#[command]
+pub fn redact_result(
+    token: String,
+    visible_positions: Option<Vec<usize>>,
+) -> Result<crate::bip39::redaction::RedactedPhrase> {
+    crate::security::result_store::inspect_result(&token, |phrase| {
+        crate::bip39::redaction::redact_phrase(phrase, visible_positions.as_deref())
+    })
+}
+
+/// Descartar un resultado retenido antes de que caduque
+#[command]
+pub fn consume_result(token: String) -> bool {
+    crate::security::result_store::consume_result(&token)
+}
+
+/// Borrar los resultados caducados cada segundo y avisar a la GUI para que limpie la pantalla
+pub fn start_result_expiry_watcher(app: tauri::AppHandle) {
+    use tauri::Manager;
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        for token in crate::security::result_store::expire_results() {
+            let _ = app.emit_all(crate::security::result_store::RESULT_EXPIRED_EVENT, token);
+        }
+    });
+}
+
+/// Comprobar la huella BIP32 de una frase descifrada; los fallos cuentan para el límite de intentos
+#[command]
+pub fn verify_expected_fingerprint(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    expected_fingerprint: String,
+) -> Result<bool> {
+    crate::security::attempt_guard::ensure_attempt_allowed()?;
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+
+    let fingerprint = crate::addresses::master_fingerprint(&seed_phrase, passphrase.as_deref())?;
+    let expected = expected_fingerprint.trim().trim_start_matches("0x").to_lowercase();
+    let matches = crate::security::utils::constant_time_eq(fingerprint.as_bytes(), expected.as_bytes());
+    crate::security::attempt_guard::record_attempt(matches);
+    Ok(matches)
+}
+
+/// Comparar una copia reescrita con la frase original: solo devuelve si
+/// coinciden, nunca qué palabras difieren
+#[command]
+pub fn compare_phrases_secure(a: String, b: String) -> bool {
+    crate::bip39::phrases_match(&a, &b)
+}
+
+/// Estado del límite de intentos de descifrado
+#[command]
+pub fn get_attempt_guard_status() -> crate::security::attempt_guard::AttemptGuardStatus {
+    crate::security::attempt_guard::status()
+}
+
+/// Configurar el límite de intentos (espera exponencial tras los intentos libres)
+/// Durante una espera solo se puede endurecer
+#[command]
+pub fn set_attempt_guard(
+    config: crate::security::attempt_guard::AttemptGuardConfig,
+) -> Result<crate::security::attempt_guard::AttemptGuardStatus> {
+    crate::security::attempt_guard::configure(config)
+}
+
+/// Crear un contenedor de volúmenes ocultos (formato v2): cada contraseña abre una frase distinta
+/// Se descifra con `transform_seed_phrase` en modo "decrypt" como cualquier otro formato
+#[command]
+pub async fn create_hidden_container(
+    volumes: Vec<crate::crypto::hidden::HiddenVolume>,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: Option<u32>,
+) -> Result<String> {
+    crate::security::network_guard::ensure_offline()?;
+    crate::security::training::ensure_export_allowed()?;
+    let parallelism = parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM);
+    crate::crypto::keystream::validate_encrypt_parallelism(parallelism)?;
+
+    task::spawn_blocking(move || {
+        crate::crypto_worker::create_hidden_container(crate::crypto_worker::HiddenContainerRequest {
+            volumes,
+            iterations,
+            memory_cost,
+            parallelism,
+        })
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Transformar y derivar las direcciones del resultado en un solo trabajo del backend
+/// La frase transformada solo viaja a la GUI con `include_result`; se devuelven las
+/// huellas BIP32 de entrada y salida para comprobar la wallet sin verla
+/// Con `second_password` la transformación usa el control dual
+#[command]
+pub async fn transform_and_derive(
+    phrase: String,
+    password: String,
+    params: crate::pipeline::TransformParams,
+    network_configs: std::collections::HashMap<String, crate::addresses::NetworkConfig>,
+    passphrase: Option<String>,
+    include_result: Option<bool>,
+    second_password: Option<String>,
+) -> Result<crate::pipeline::PipelineResult> {
+    crate::security::network_guard::ensure_offline()?;
+    let phrase = accept_seed_input(&phrase)?;
+    let started = std::time::Instant::now();
+    let algorithm = match (params.compatibility, second_password.is_some()) {
+        (crate::crypto::compat::CompatibilityMode::BashV2, _) => "bash_v2_shake256",
+        (_, true) => "argon2id_dual",
+        (_, false) => "argon2id",
+    };
+    let kdf = crate::diagnostics::kdf_params(
+        algorithm,
+        params.iterations,
+        params.memory_cost,
+        params.parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM),
+    );
+
+    let result = task::spawn_blocking(move || {
+        // La transformación (incluidos control dual y contenedores ocultos) va al proceso aislado
+        let (output, _) = crate::crypto_worker::transform(crate::crypto_worker::PhraseRequest {
+            phrase: phrase.clone(),
+            password,
+            second_password,
+            params,
+            verify: false,
+        })?;
+        crate::pipeline::derive_transformed(
+            &phrase,
+            output,
+            passphrase.as_deref(),
+            network_configs,
+            include_result.unwrap_or(false),
+        )
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))
+    .and_then(|result| result);
+
+    crate::diagnostics::record_operation("transform_and_derive", Some(kdf), started, result.as_ref().err().map(SCypherError::code));
+    let result = result?;
+
+    // El resultado queda disponible en modo formación aunque no se haya devuelto
+    if let Some(phrase) = &result.result {
+        crate::security::training::record_output(phrase);
+    }
+    Ok(result)
+}
+
+/// Paquete de solo lectura para wallets móviles: JSON (formato Coldcard para Sparrow y
+/// BlueWallet, direcciones para wallets EVM) y QR animados BC-UR/BBQr
+/// Con `path` se guarda además el JSON en disco (requiere un token de `request_consent("write_file")`)
+#[command]
+pub fn export_watch_bundle(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    networks: Vec<String>,
+    address_count: u32,
+    account: Option<u32>,
+    path: Option<String>,
+    consent_token: Option<String>,
+) -> Result<crate::watch_export::WatchBundle> {
+    if path.is_some() {
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+    }
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let bundle = crate::watch_export::build_watch_bundle(
+        &seed_phrase,
+        passphrase.as_deref(),
+        &networks,
+        address_count,
+        account.unwrap_or(0),
+    )?;
+    if let Some(path) = path {
+        crate::watch_export::write_watch_bundle(&bundle, &path)?;
+    }
+    Ok(bundle)
+}
+
+/// Código de pago BIP47 (PayNym) y dirección de notificación de una cuenta Bitcoin
+#[command]
+pub fn derive_payment_code(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    account: Option<u32>,
+) -> Result<crate::bip47::PaymentCode> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::bip47::derive_payment_code(&seed_phrase, passphrase.as_deref(), account.unwrap_or(0))
+}
+
+/// Identidad Nostr (NIP-06): npub siempre; nsec solo con doble confirmación
+/// y un token de `request_consent("export_private_keys")`
+#[command]
+pub fn derive_nostr_identity(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    account: Option<u32>,
+    confirmation: Option<crate::key_export::ExportConfirmation>,
+    consent_token: Option<String>,
+) -> Result<crate::nostr::NostrIdentity> {
+    if confirmation.is_some() {
+        crate::security::training::ensure_export_allowed()?;
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportPrivateKeys)?;
+    }
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::nostr::derive_nostr_identity(&seed_phrase, passphrase.as_deref(), account.unwrap_or(0), confirmation.as_ref())
+}
+
+/// Clave SSH Ed25519 (SLIP-0013) de una identidad; privada solo con doble confirmación
+/// y un token de `request_consent("export_private_keys")`
+#[command]
+pub fn derive_ssh_key(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    identity: String,
+    confirmation: Option<crate::key_export::ExportConfirmation>,
+    consent_token: Option<String>,
+) -> Result<crate::dev_identity::SshIdentity> {
+    if confirmation.is_some() {
+        crate::security::training::ensure_export_allowed()?;
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportPrivateKeys)?;
+    }
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::dev_identity::derive_ssh_identity(&seed_phrase, passphrase.as_deref(), &identity, confirmation.as_ref())
+}
+
+/// Clave OpenPGP Ed25519 (SLIP-0013) con fecha de creación fija; privada solo con doble confirmación
+/// y un token de `request_consent("export_private_keys")`
+#[command]
+pub fn derive_gpg_key(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    user_id: String,
+    created: Option<u32>,
+    confirmation: Option<crate::key_export::ExportConfirmation>,
+    consent_token: Option<String>,
+) -> Result<crate::dev_identity::GpgIdentity> {
+    if confirmation.is_some() {
+        crate::security::training::ensure_export_allowed()?;
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportPrivateKeys)?;
+    }
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::dev_identity::derive_gpg_identity(&seed_phrase, passphrase.as_deref(), &user_id, created, confirmation.as_ref())
+}
+
+/// Informe de diagnóstico redactado (versiones, entorno, parámetros y códigos de error)
+/// Con `path` se guarda además como JSON para adjuntarlo a un reporte de fallo
+/// (requiere un token de `request_consent("write_file")`)
+#[command]
+pub fn export_diagnostics(path: Option<String>, consent_token: Option<String>) -> Result<crate::diagnostics::DiagnosticsReport> {
+    crate::security::incognito::ensure_persistence_allowed("exporting diagnostics")?;
+    if path.is_some() {
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+    }
+    let report = crate::diagnostics::build_report();
+    if let Some(path) = path {
+        crate::diagnostics::write_report(&report, &path)?;
+    }
+    Ok(report)
+}
+
+/// Activar el modo incógnito hasta cerrar la aplicación (sin historial,
+/// caché de KDF, escrituras de configuración ni exportación de diagnóstico)
+#[command]
+pub fn enable_incognito_mode() -> crate::security::incognito::IncognitoStatus {
+    // El historial anterior a la activación tampoco se conserva
+    crate::diagnostics::clear_operations();
+    crate::security::incognito::enable_incognito()
+}
+
+/// Estado del modo incógnito
+#[command]
+pub fn get_incognito_status() -> crate::security::incognito::IncognitoStatus {
+    crate::security::incognito::incognito_status()
+}
+
+/// Información de compilación: versión, commit, features, redes y backends de KDF
+#[command]
+pub fn get_build_info() -> crate::build_info::BuildInfo {
+    crate::build_info::build_info()
+}
+
+/// Recordar la contraseña en el llavero del sistema durante esta sesión
+#[command]
+pub fn remember_session_password(password: String) -> Result<()> {
+    let secret = crate::security::SecureString::from(password);
+    crate::security::keychain::store_session_secret(&secret)
+}
+
+/// Recuperar la contraseña recordada en esta sesión (si existe)
+#[command]
+pub fn recall_session_password() -> Result<Option<String>> {
+    Ok(crate::security::keychain::retrieve_session_secret()?
+        .map(|secret| secret.as_str().to_string()))
+}
+
+/// Olvidar la contraseña recordada en esta sesión
+#[command]
+pub fn forget_session_password() -> Result<()> {
+    crate::security::keychain::clear_session_secret()
+}
+
+/// Verificar si el llavero del sistema está disponible
+#[command]
+pub fn is_keychain_available() -> bool {
+    crate::security::keychain::is_keychain_available()
+}
+
+/// Activar protección contra capturas mientras se muestra una frase semilla
+#[command]
+pub fn enable_capture_protection(window: tauri::Window) -> Result<crate::security::capture::CaptureProtectionStatus> {
+    set_window_capture_protection(&window, true)
+}
+
+/// Desactivar protección contra capturas al ocultar la frase semilla
+#[command]
+pub fn disable_capture_protection(window: tauri::Window) -> Result<crate::security::capture::CaptureProtectionStatus> {
+    set_window_capture_protection(&window, false)
+}
+
+/// Consultar el estado de la protección contra capturas
+#[command]
+pub fn get_capture_protection_status() -> crate::security::capture::CaptureProtectionStatus {
+    crate::security::capture::capture_protection_status()
+}
+
+/// Obtener el handle nativo de la ventana y delegar en el módulo de seguridad
+fn set_window_capture_protection(
+    window: &tauri::Window,
+    enabled: bool,
+) -> Result<crate::security::capture::CaptureProtectionStatus> {
+    #[cfg(target_os = "windows")]
+    let native_window = window
+        .hwnd()
+        .map_err(|e| SCypherError::WindowError(format!("Cannot access window handle: {}", e)))?
+        .0 as *mut std::ffi::c_void;
+
+    #[cfg(target_os = "macos")]
+    let native_window = window
+        .ns_window()
+        .map_err(|e| SCypherError::WindowError(format!("Cannot access window handle: {}", e)))?;
+
+    // Sin mecanismo nativo: el módulo de seguridad reporta plataforma no soportada
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let native_window = {
+        let _ = window;
+        std::ptr::null_mut()
+    };
+
+    crate::security::capture::set_capture_protection(native_window, enabled)
+}
+
+/// Iniciar teclado aleatorizado: lista BIP39 permutada + nonce de sesión
+#[command]
+pub fn start_scrambled_keypad() -> crate::security::keypad::ScrambledKeypad {
+    crate::security::keypad::start_keypad_session()
+}
+
+/// Resolver selección por índices permutados a la frase semilla real
+#[command]
+pub fn resolve_scrambled_selection(nonce: String, indices: Vec<usize>) -> Result<String> {
+    crate::security::keypad::resolve_selection(&nonce, &indices)
+}
+
+/// Finalizar sesión del teclado aleatorizado
+#[command]
+pub fn end_scrambled_keypad() {
+    crate::security::keypad::end_keypad_session()
+}
+
+/// Exportar respaldo en papel (PDF) de la frase transformada
+/// Requiere un token de `request_consent("write_file")`
+#[command]
+pub fn export_paper_backup(
+    phrase: String,
+    iterations: u32,
+    memory_cost: u32,
+    path: String,
+    split: bool,
+    layout: Option<PhraseLayout>,
+    consent_token: Option<String>,
+) -> Result<()> {
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+    let phrase = accept_seed_input(&phrase)?;
+    crate::paper::export_paper_backup(&phrase, iterations, memory_cost, &path, split, layout.as_ref())
+}
+
+/// Ocultar la frase transformada en una imagen PNG (LSB + MAC)
+/// Requiere un token de `request_consent("write_file")`
+#[command]
+pub async fn embed_in_image(
+    phrase: String,
+    password: String,
+    iterations: u32,
+    memory_cost: u32,
+    cover_path: String,
+    output_path: String,
+    consent_token: Option<String>,
+) -> Result<()> {
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::WriteFile)?;
+    let phrase = accept_seed_input(&phrase)?;
+    // Argon2id para la clave del MAC: ejecutar fuera del hilo de UI
+    task::spawn_blocking(move || {
+        crate::stego::embed_in_image(&phrase, &password, iterations, memory_cost, &cover_path, &output_path)
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Recuperar la frase transformada oculta en una imagen PNG
+#[command]
+pub async fn extract_from_image(
+    image_path: String,
+    password: String,
+    iterations: u32,
+    memory_cost: u32,
+) -> Result<String> {
+    task::spawn_blocking(move || {
+        crate::stego::extract_from_image(&image_path, &password, iterations, memory_cost)
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Dividir la contraseña en partes de Shamir (N de M) en hex o palabras
+#[command]
+pub fn split_password_shares(
+    password: String,
+    threshold: u8,
+    total_shares: u8,
+    encoding: String,
+) -> Result<Vec<String>> {
+    let encoding = crate::crypto::shamir::ShareEncoding::parse(&encoding)?;
+    crate::crypto::shamir::split_password(&password, threshold, total_shares, encoding)
+}
+
+/// Reconstruir la contraseña a partir de partes de Shamir
+#[command]
+pub fn combine_password_shares(shares: Vec<String>) -> Result<String> {
+    crate::crypto::shamir::combine_password(&shares)
+}
+
+/// Dividir la frase en partes XOR (todas son frases BIP39 válidas y hacen falta todas)
+#[command]
+pub fn split_seed_xor(phrase: String, shares: usize) -> Result<Vec<String>> {
+    let phrase = accept_seed_input(&phrase)?;
+    let shares = crate::crypto::xor_shares::split_seed_xor(&phrase, shares)?;
+    for share in &shares {
+        crate::security::training::record_output(share);
+    }
+    Ok(shares)
+}
+
+/// Reconstruir la frase combinando todas sus partes XOR
+#[command]
+pub fn combine_seed_xor(shares: Vec<String>) -> Result<String> {
+    let shares = shares.iter().map(|share| accept_seed_input(share)).collect::<Result<Vec<_>>>()?;
+    let phrase = crate::crypto::xor_shares::combine_seed_xor(&shares)?;
+    crate::security::training::record_output(&phrase);
+    Ok(phrase)
+}
+
+/// Registrar una lista propia de 2048 palabras para escribir los resultados
+#[command]
+pub fn register_custom_wordlist(wordlist: String) -> Result<crate::bip39::custom_wordlist::CustomWordlistInfo> {
+    crate::bip39::custom_wordlist::register_custom_wordlist(&wordlist)
+}
+
+/// Olvidar la lista propia registrada
+#[command]
+pub fn clear_custom_wordlist() {
+    crate::bip39::custom_wordlist::clear_custom_wordlist()
+}
+
+/// Lista propia registrada (hash y primeras palabras), si hay una
+#[command]
+pub fn get_custom_wordlist() -> Option<crate::bip39::custom_wordlist::CustomWordlistInfo> {
+    crate::bip39::custom_wordlist::active_custom_wordlist()
+}
+
+/// Escribir la frase transformada con la lista propia
+#[command]
+pub fn encode_with_custom_wordlist(phrase: String) -> Result<String> {
+    let phrase = normalize_seed_input(&phrase);
+    crate::bip39::custom_wordlist::with_active_wordlist(|wordlist| wordlist.encode_phrase(&phrase))
+}
+
+/// Leer una frase escrita con la lista propia y devolverla en BIP39 para descifrarla
+#[command]
+pub fn decode_custom_wordlist_phrase(phrase: String) -> Result<String> {
+    crate::bip39::custom_wordlist::with_active_wordlist(|wordlist| wordlist.decode_phrase(&phrase))
+}
+
+/// Exportar claves privadas cifradas (BIP38 / keystore v3) para índices seleccionados
+/// Requiere un token de `request_consent("export_encrypted_keys")`
+#[command]
+pub async fn export_encrypted_keys(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    network: String,
+    indices: Vec<u32>,
+    password: String,
+    password_confirm: String,
+    consent_token: Option<String>,
+) -> Result<Vec<crate::key_export::EncryptedKeyExport>> {
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportEncryptedKeys)?;
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    // scrypt es costoso: ejecutar fuera del hilo de UI
+    task::spawn_blocking(move || {
+        crate::key_export::export_encrypted_keys(
+            &seed_phrase,
+            passphrase.as_deref(),
+            &network,
+            &indices,
+            &password,
+            &password_confirm,
+        )
+    })
+    .await
+    .map_err(|e| SCypherError::crypto(format!("Task error: {}", e)))?
+}
+
+/// Exportar claves privadas en claro (WIF / hex / base58) - requiere doble confirmación
+/// y un token de `request_consent("export_private_keys")`
+#[command]
+pub fn export_private_keys(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    network: String,
+    indices: Vec<u32>,
+    confirmation: crate::key_export::ExportConfirmation,
+    consent_token: Option<String>,
+) -> Result<Vec<crate::key_export::PrivateKeyExport>> {
+    crate::security::training::ensure_export_allowed()?;
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportPrivateKeys)?;
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::key_export::export_private_keys(
+        &seed_phrase,
+        passphrase.as_deref(),
+        &network,
+        &indices,
+        &confirmation,
+    )
+}
+
+/// Huella BIP32 y xpub raíz de la frase; con `include_xprv` también la xprv raíz,
+/// que requiere doble confirmación y un token de `request_consent("export_private_keys")`
+#[command]
+pub fn get_master_keys(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    include_xprv: Option<bool>,
+    confirmation: Option<crate::key_export::ExportConfirmation>,
+    consent_token: Option<String>,
+) -> Result<crate::key_export::MasterKeys> {
+    let confirmation = if include_xprv.unwrap_or(false) {
+        crate::security::training::ensure_export_allowed()?;
+        crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::ExportPrivateKeys)?;
+        Some(confirmation.unwrap_or_default())
+    } else {
+        None
+    };
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::key_export::master_keys(&seed_phrase, passphrase.as_deref(), confirmation.as_ref())
+}
+
+/// Firmar un mensaje con la clave derivada en una ruta (prueba de propiedad offline)
+#[command]
+pub fn sign_message(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    network: String,
+    path: String,
+    message: String,
+) -> Result<crate::signing::SignedMessage> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    let passphrase = if crate::addresses::network_supports_passphrase(&network) {
+        passphrase
+    } else {
+        None
+    };
+    crate::signing::sign_message(&seed_phrase, passphrase.as_deref(), &network, &path, &message)
+}
+
+/// Verificar la firma de un mensaje para una dirección
+#[command]
+pub fn verify_message(network: String, address: String, message: String, signature: String) -> Result<bool> {
+    crate::signing::verify_message(&network, &address, &message, &signature)
+}
+
+/// Firmar offline las entradas de una PSBT (base64) controladas por la semilla
+#[command]
+pub fn sign_psbt(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    psbt: String,
+    testnet: bool,
+) -> Result<crate::psbt_signer::PsbtSignResult> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::psbt_signer::sign_psbt(&seed_phrase, passphrase.as_deref(), &psbt, testnet)
+}
+
+/// Firmar offline una transacción EVM sin firmar (RLP en hex)
+#[command]
+pub fn sign_evm_transaction(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    path: String,
+    unsigned_tx: String,
+) -> Result<crate::evm_signer::EvmSignature> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::evm_signer::sign_transaction(&seed_phrase, passphrase.as_deref(), &path, &unsigned_tx)
+}
+
+/// Firmar offline datos tipados EIP-712 (JSON)
+#[command]
+pub fn sign_eip712_typed_data(
+    seed_phrase: String,
+    passphrase: Option<String>,
+    path: String,
+    typed_data: String,
+) -> Result<crate::evm_signer::EvmSignature> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::evm_signer::sign_typed_data(&seed_phrase, passphrase.as_deref(), &path, &typed_data)
+}
+
+/// Imprimir el resultado directamente en una impresora local (sin archivo temporal)
+/// Requiere un token de `request_consent("print")`
+#[command]
+pub fn print_result(result: String, printer: Option<String>, consent_token: Option<String>) -> Result<()> {
+    crate::security::consent::consume_consent(consent_token.as_deref(), crate::security::consent::ConsentAction::Print)?;
+    let document = crate::printing::format_print_document(&result);
+    crate::printing::print_text(&document, printer.as_deref())
+}
+
+/// Codificar datos como secuencia de QR animados BC-UR
+#[command]
+pub fn encode_ur(
+    payload: String,
+    ur_type: String,
+    payload_encoding: Option<String>,
+    max_fragment_len: Option<usize>,
+) -> Result<Vec<String>> {
+    let data = crate::airgap::payload_from_text(&payload, payload_encoding.as_deref().unwrap_or("utf8"))?;
+    crate::airgap::encode_ur(
+        &data,
+        &ur_type,
+        max_fragment_len.unwrap_or(crate::airgap::DEFAULT_UR_FRAGMENT_LEN),
+    )
+}
+
+/// Reensamblar datos a partir de QR BC-UR escaneados
+#[command]
+pub fn decode_ur(frames: Vec<String>) -> Result<crate::airgap::AirgapPayload> {
+    crate::airgap::decode_ur(&frames)
+}
+
+/// Codificar datos como secuencia de QR BBQr
+#[command]
+pub fn encode_bbqr(
+    payload: String,
+    file_type: String,
+    payload_encoding: Option<String>,
+    max_part_chars: Option<usize>,
+) -> Result<Vec<String>> {
+    let data = crate::airgap::payload_from_text(&payload, payload_encoding.as_deref().unwrap_or("utf8"))?;
+    let file_type = file_type
+        .chars()
+        .next()
+        .ok_or_else(|| SCypherError::crypto("BBQr file type is required".to_string()))?;
+    crate::airgap::encode_bbqr(
+        &data,
+        file_type,
+        max_part_chars.unwrap_or(crate::airgap::DEFAULT_BBQR_PART_CHARS),
+    )
+}
+
+/// Reensamblar datos a partir de QR BBQr escaneados
+#[command]
+pub fn decode_bbqr(frames: Vec<String>) -> Result<crate::airgap::AirgapPayload> {
+    crate::airgap::decode_bbqr(&frames)
+}
+
+/// Exportar una frase como SeedQR estándar o CompactSeedQR (formato SeedSigner)
+#[command]
+pub fn export_seedqr(
+    seed_phrase: String,
+    format: crate::seedqr::SeedQrFormat,
+) -> Result<crate::seedqr::SeedQrExport> {
+    let seed_phrase = accept_seed_input(&seed_phrase)?;
+    crate::seedqr::export_seedqr(&seed_phrase, format)
+}
+
+/// Importar una frase desde datos SeedQR escaneados
+#[command]
+pub fn import_seedqr(scanned_data: String) -> Result<String> {
+    crate::seedqr::import_seedqr(&scanned_data)
+}
+
+/// Validar que una red sea soportada
+#[command]
+pub fn validate_network(network: String) -> bool {
+    matches!(network.as_str(),
+        "bitcoin" | "ethereum" | "ergo" |
+        "bsc" | "polygon" | "cardano" |
+        "dogecoin" | "litecoin" | "solana"
+    ) && network_available(&network)
+}
+
+/// La red está compilada en este binario (features `network-*`)
+fn network_available(network: &str) -> bool {
+    network
+        .parse::<crate::addresses::NetworkId>()
+        .is_ok_and(crate::addresses::network_compiled)
+}
+
+/// Obtener información sobre redes soportadas (solo las compiladas)
+#[command]
+pub fn get_supported_networks() -> Vec<NetworkInfo> {
+    let networks = vec![
+        // REDES EXISTENTES
+        NetworkInfo {
+            id: "bitcoin".to_string(),
+            name: "Bitcoin".to_string(),
+            symbol: "₿".to_string(),
+            coin_type: 0,
+            description: "Bitcoin mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "ethereum".to_string(),
+            name: "Ethereum".to_string(),
+            symbol: "Ξ".to_string(),
+            coin_type: 60,
+            description: "Ethereum mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "ergo".to_string(),
+            name: "Ergo".to_string(),
+            symbol: "⚡".to_string(),
+            coin_type: 429,
+            description: "Ergo platform addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "tron".to_string(),
+            name: "TRON".to_string(),
+            symbol: "🔺".to_string(),
+            coin_type: 195,
+            description: "TRON mainnet addresses".to_string(),
+        },
+
+        // NUEVAS REDES
+        NetworkInfo {
+            id: "bsc".to_string(),
+            name: "Binance Smart Chain".to_string(),
+            symbol: "🟡".to_string(),
+            coin_type: 60, // Mismo que Ethereum (EVM compatible)
+            description: "BSC mainnet addresses (EVM compatible)".to_string(),
+        },
+        NetworkInfo {
+            id: "polygon".to_string(),
+            name: "Polygon".to_string(),
+            symbol: "🔷".to_string(),
+            coin_type: 60, // Mismo que Ethereum (EVM compatible)
+            description: "Polygon mainnet addresses (EVM compatible)".to_string(),
+        },
+        NetworkInfo {
+            id: "cardano".to_string(),
+            name: "Cardano".to_string(),
+            symbol: "₳".to_string(),
+            coin_type: 1815,
+            description: "Cardano Shelley addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "dogecoin".to_string(),
+            name: "Dogecoin".to_string(),
+            symbol: "Ð".to_string(),
+            coin_type: 3,
+            description: "Dogecoin mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "litecoin".to_string(),
+            name: "Litecoin".to_string(),
+            symbol: "Ł".to_string(),
+            coin_type: 2,
+            description: "Litecoin mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "solana".to_string(),
+            name: "Solana".to_string(),
+            symbol: "◎".to_string(),
+            coin_type: 501,
+            description: "Solana mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "bitcoincash".to_string(),
+            name: "Bitcoin Cash".to_string(),
+            symbol: "Ƀ".to_string(),
+            coin_type: 145,
+            description: "Bitcoin Cash CashAddr addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "stellar".to_string(),
+            name: "Stellar".to_string(),
+            symbol: "✦".to_string(),
+            coin_type: 148,
+            description: "Stellar mainnet accounts".to_string(),
+        },
+        NetworkInfo {
+            id: "ton".to_string(),
+            name: "TON".to_string(),
+            symbol: "◇".to_string(),
+            coin_type: 607,
+            description: "TON wallet v4r2 addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "liquid".to_string(),
+            name: "Liquid".to_string(),
+            symbol: "◈".to_string(),
+            coin_type: 1776,
+            description: "Liquid mainnet addresses".to_string(),
+        },
+        NetworkInfo {
+            id: "zcash".to_string(),
+            name: "Zcash (transparent)".to_string(),
+            symbol: "ⓩ".to_string(),
+            coin_type: 133,
+            description: "Zcash transparent addresses".to_string(),
+        },
+    ];
+
+    networks.into_iter().filter(|info| network_available(&info.id)).collect()
 }
 
 /// Información sobre una red soportada
@@ -115,8 +115,9 @@ pub fn verify_bip39_checksum(seed_bits: &[bool], entropy_bits: usize) -> Result<
     Ok(checksum_part == expected_checksum_bits)
 }
 
-/// Recalcula el checksum BIP39 para una entropía dada
-/// Útil después de transformaciones XOR para mantener validez BIP39
+/// Recalcula el checksum BIP39 para una entropía dada (en bits, longitud libre)
+/// El pipeline de transformación usa `Bip39Entropy::checksum_bits`, que fija la
+/// longitud en el tipo; esta función queda para validaciones a nivel de bits
 pub fn recalculate_bip39_checksum(entropy_bits: &[bool]) -> Result<Vec<bool>> {
     let entropy_bytes = bits_to_bytes_padded(entropy_bits);
     let checksum_bytes = calculate_checksum(&entropy_bytes)?;
@@ -259,4 +260,49 @@ mod tests {
         assert!(extract_bits(&bytes, 16, 1).is_err());
         assert!(extract_bits(&bytes, 10, 8).is_err());
     }
+
+    // Propiedades (proptest): invariantes del checksum recalculado
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn entropy_bits() -> impl Strategy<Value = Vec<bool>> {
+            prop::sample::select(vec![128usize, 160, 192, 224, 256])
+                .prop_flat_map(|len| prop::collection::vec(any::<bool>(), len))
+        }
+
+        proptest! {
+            #[test]
+            fn prop_recalculated_checksum_verifies(entropy in entropy_bits()) {
+                let checksum = recalculate_bip39_checksum(&entropy).unwrap();
+                prop_assert_eq!(checksum.len(), get_checksum_length(entropy.len()));
+
+                let mut seed_bits = entropy.clone();
+                seed_bits.extend(&checksum);
+                prop_assert!(verify_bip39_checksum(&seed_bits, entropy.len()).unwrap());
+            }
+
+            #[test]
+            fn prop_flipped_checksum_bit_fails(entropy in entropy_bits(), flip in any::<prop::sample::Index>()) {
+                let checksum = recalculate_bip39_checksum(&entropy).unwrap();
+                let mut seed_bits = entropy.clone();
+                seed_bits.extend(&checksum);
+
+                let position = entropy.len() + flip.index(checksum.len());
+                seed_bits[position] = !seed_bits[position];
+                prop_assert!(!verify_bip39_checksum(&seed_bits, entropy.len()).unwrap());
+            }
+
+            #[test]
+            fn prop_checksum_matches_sha256_prefix(entropy in entropy_bits()) {
+                use sha2::{Digest, Sha256};
+
+                let hash = Sha256::digest(bits_to_bytes_padded(&entropy));
+                let expected: Vec<bool> = (0..entropy.len() / 32)
+                    .map(|i| (hash[i / 8] >> (7 - i % 8)) & 1 == 1)
+                    .collect();
+                prop_assert_eq!(recalculate_bip39_checksum(&entropy).unwrap(), expected);
+            }
+        }
+    }
 }
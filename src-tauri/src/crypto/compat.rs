@@ -0,0 +1,174 @@
+//! Compatibilidad con versiones anteriores de SCypher
+//!
+//! La versión 2.0 (script Bash) derivaba el keystream con SHAKE-256 en lugar
+//! de Argon2id. Las frases cifradas con ella solo se pueden recuperar con el
+//! mismo algoritmo:
+//!
+//! 1. `hash = SHAKE-256(contraseña)` con salida de `ceil(11 * palabras / 8)` bytes
+//! 2. Cada iteración adicional aplica SHAKE-256 al hash anterior en
+//!    hexadecimal (minúsculas), como hacía `openssl dgst -shake256 -xoflen`
+//! 3. XOR de los bits de la frase (11 por palabra) con el keystream
+//! 4. Se conserva la entropía y se recalcula el checksum BIP39
+//!
+//! Como en la versión actual, la misma operación cifra y descifra.
+
+use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Shake};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use super::{checksum, ChecksumPolicy, PolicyTransform};
+
+/// Iteraciones máximas aceptadas en modo Bash v2
+pub const MAX_BASH_V2_ITERATIONS: u32 = 1_000_000;
+
+/// Algoritmo de derivación del keystream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilityMode {
+    /// Argon2id (versión actual)
+    #[default]
+    Current,
+    /// SHAKE-256 iterado del script Bash v2.0
+    BashV2,
+}
+
+fn shake256(input: &[u8], length: usize) -> Vec<u8> {
+    let mut output = vec![0u8; length];
+    let mut shake = Shake::v256();
+    shake.update(input);
+    shake.finalize(&mut output);
+    output
+}
+
+/// Keystream SHAKE-256 iterado del script Bash v2.0
+pub fn bash_v2_keystream(password: &str, length: usize, iterations: u32) -> Result<Vec<u8>> {
+    if !(1..=MAX_BASH_V2_ITERATIONS).contains(&iterations) {
+        return Err(SCypherError::crypto(format!(
+            "Bash v2 iterations must be between 1 and {}",
+            MAX_BASH_V2_ITERATIONS
+        )));
+    }
+
+    let mut keystream = shake256(password.as_bytes(), length);
+    for _ in 1..iterations {
+        let mut hex_input = hex::encode(&keystream);
+        keystream.zeroize();
+        keystream = shake256(hex_input.as_bytes(), length);
+        hex_input.zeroize();
+    }
+    Ok(keystream)
+}
+
+/// Transformar una frase con el algoritmo de SCypher v2.0 (Bash)
+///
+/// `PassThrough` no existe en v2.0: el script siempre recalculaba el checksum.
+pub fn transform_bash_v2(
+    seed_phrase: &str,
+    password: &str,
+    iterations: u32,
+    policy: ChecksumPolicy,
+) -> Result<PolicyTransform> {
+    if policy == ChecksumPolicy::PassThrough {
+        return Err(SCypherError::crypto(
+            "The pass-through checksum policy is not available in Bash v2 compatibility mode".to_string(),
+        ));
+    }
+
+    let seed_phrase = crate::bip39::wordlist::expand_abbreviations(seed_phrase)?;
+    let word_count = seed_phrase.split_whitespace().count();
+    crate::bip39::validation::validate_word_count(word_count)?;
+
+    let checksum_valid = crate::bip39::validation::validate_checksum(&seed_phrase).is_ok();
+    if policy == ChecksumPolicy::Strict && !checksum_valid {
+        return Err(SCypherError::InvalidChecksum);
+    }
+
+    let bits = crate::bip39::conversion::phrase_to_bits(&seed_phrase)?;
+    let mut keystream = bash_v2_keystream(password, bits.len().div_ceil(8), iterations)?;
+    let keystream_bits = checksum::extract_bits(&keystream, 0, bits.len());
+    keystream.zeroize();
+
+    // Solo la entropía sobrevive; el checksum se recalcula como en el script
+    let entropy_bits = word_count * 32 / 3;
+    let mut transformed: Vec<bool> = bits[..entropy_bits]
+        .iter()
+        .zip(keystream_bits?.iter())
+        .map(|(bit, key)| bit ^ key)
+        .collect();
+    transformed.extend(checksum::recalculate_bip39_checksum(&transformed)?);
+
+    Ok(PolicyTransform {
+        phrase: crate::bip39::conversion::bits_to_phrase(&transformed)?,
+        checksum_corrected: !checksum_valid,
+    })
+}
+
+/// Comprobar que el resultado revierte a la entrada (con el checksum ya corregido)
+pub fn verify_roundtrip_bash_v2(
+    original: &str,
+    transformed: &str,
+    password: &str,
+    iterations: u32,
+    policy: ChecksumPolicy,
+) -> Result<bool> {
+    let reversed = transform_bash_v2(transformed, password, iterations, policy)?.phrase;
+    let expanded = crate::bip39::wordlist::expand_abbreviations(original)?;
+    let normalized = crate::bip39::conversion::validate_and_fix_checksum(&expanded)?;
+
+    Ok(crate::security::utils::constant_time_eq(
+        normalized.as_bytes(),
+        reversed.as_bytes(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Vectores generados con la tubería del script v2.0:
+    // printf %s "$hash" | openssl dgst -shake256 -xoflen N
+    const VECTORS: [(&str, &str, u32, &str); 2] = [
+        (
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "legacy_password",
+            5,
+            "beyond prefer adult honey federal flush east drill similar office shallow stove",
+        ),
+        (
+            "legal winner thank year wave sausage worth useful legal winner thank year \
+             wave sausage worth useful legal winner thank year wave sausage worth title",
+            "MyS3cretPass",
+            10,
+            "foster script weekend miss category obscure element night skate tribe warfare swift \
+             cousin pretty blame clarify member kidney present bleak talk all flag unhappy",
+        ),
+    ];
+
+    #[test]
+    fn test_shake256_keystream() {
+        // openssl dgst -shake256 -xoflen 17 de "abc"
+        assert_eq!(
+            hex::encode(bash_v2_keystream("abc", 17, 1).unwrap()),
+            "483366601360a8771c6863080cc4114d8d"
+        );
+        assert!(bash_v2_keystream("abc", 17, 0).is_err());
+    }
+
+    #[test]
+    fn test_bash_v2_vectors() {
+        for (plain, password, iterations, cipher) in VECTORS {
+            let cipher = cipher.split_whitespace().collect::<Vec<_>>().join(" ");
+            let plain = plain.split_whitespace().collect::<Vec<_>>().join(" ");
+
+            let encrypted = transform_bash_v2(&plain, password, iterations, ChecksumPolicy::Fix).unwrap();
+            assert_eq!(encrypted.phrase, cipher);
+            assert_eq!(transform_bash_v2(&cipher, password, iterations, ChecksumPolicy::Strict).unwrap().phrase, plain);
+            assert!(verify_roundtrip_bash_v2(&plain, &cipher, password, iterations, ChecksumPolicy::Fix).unwrap());
+        }
+
+        // Distinto de la derivación Argon2id actual
+        let (plain, password, iterations, cipher) = VECTORS[0];
+        assert_ne!(super::super::transform_seed(plain, password, iterations, 8192).unwrap(), cipher);
+        assert!(transform_bash_v2(plain, password, iterations, ChecksumPolicy::PassThrough).is_err());
+    }
+}
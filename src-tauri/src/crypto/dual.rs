@@ -0,0 +1,120 @@
+//! Control dual: dos contraseñas para transformar una frase
+//!
+//! Cada contraseña pasa por Argon2id con los mismos parámetros (y su propia
+//! sal determinista) y HKDF-SHA256 combina las dos salidas en el keystream.
+//! Ninguna contraseña basta por sí sola, así que una organización puede
+//! exigir que dos responsables estén presentes para descifrar. El orden
+//! cuenta: primera y segunda contraseña no son intercambiables.
+
+use hkdf::Hkdf;
+use sha2::Sha256;
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use super::{keystream, ChecksumPolicy, PolicyTransform};
+
+/// Sal de HKDF (separación de dominio respecto a la contraseña única)
+const DUAL_CONTROL_SALT: &[u8] = b"SCYPHER_DUAL_CONTROL_V1";
+
+/// Información de HKDF para el keystream de la frase
+const DUAL_CONTROL_INFO: &[u8] = b"keystream";
+
+/// Rechazar combinaciones que no aportan un segundo factor
+fn validate_passwords(first: &str, second: &str) -> Result<()> {
+    if first.is_empty() || second.is_empty() {
+        return Err(SCypherError::crypto("Dual control requires both passwords".to_string()));
+    }
+    if crate::security::utils::constant_time_eq(first.as_bytes(), second.as_bytes()) {
+        return Err(SCypherError::crypto("Dual control requires two different passwords".to_string()));
+    }
+    Ok(())
+}
+
+/// Keystream combinado: HKDF-SHA256 sobre las dos salidas Argon2id
+pub fn derive_dual_keystream(
+    first_password: &str,
+    second_password: &str,
+    length: usize,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<Vec<u8>> {
+    validate_passwords(first_password, second_password)?;
+
+    let mut material = keystream::derive_keystream(first_password, length, iterations, memory_cost, parallelism)?;
+    let mut second = keystream::derive_keystream(second_password, length, iterations, memory_cost, parallelism)?;
+    material.extend_from_slice(&second);
+    second.zeroize();
+
+    let mut output = vec![0u8; length];
+    let expanded = Hkdf::<Sha256>::new(Some(DUAL_CONTROL_SALT), &material).expand(DUAL_CONTROL_INFO, &mut output);
+    material.zeroize();
+    expanded.map_err(|_| SCypherError::crypto(format!("Invalid dual-control keystream length: {}", length)))?;
+
+    Ok(output)
+}
+
+/// Transformar con control dual aplicando la política de checksum
+pub fn transform_seed_dual(
+    seed_phrase: &str,
+    first_password: &str,
+    second_password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<PolicyTransform> {
+    keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+    validate_passwords(first_password, second_password)?;
+
+    super::transform_with_keystream(seed_phrase, policy, |length| {
+        derive_dual_keystream(first_password, second_password, length, iterations, memory_cost, parallelism)
+    })
+}
+
+/// Comprobar que el resultado revierte a la entrada con las mismas dos contraseñas
+#[allow(clippy::too_many_arguments)]
+pub fn verify_roundtrip_dual(
+    original: &str,
+    transformed: &str,
+    first_password: &str,
+    second_password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<bool> {
+    let reversed = transform_seed_dual(
+        transformed, first_password, second_password, iterations, memory_cost, parallelism, policy,
+    )?;
+    super::roundtrip_matches(original, &reversed.phrase, policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_dual_control_roundtrip() {
+        let encrypted = transform_seed_dual(PHRASE, "officer_one", "officer_two", 1, 8192, 1, ChecksumPolicy::Fix).unwrap();
+        assert!(verify_roundtrip_dual(PHRASE, &encrypted.phrase, "officer_one", "officer_two", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+
+        // Ninguna contraseña sola ni el orden inverso descifran
+        assert_ne!(super::super::transform_seed(&encrypted.phrase, "officer_one", 1, 8192).unwrap(), PHRASE);
+        assert_ne!(super::super::transform_seed(&encrypted.phrase, "officer_two", 1, 8192).unwrap(), PHRASE);
+        assert!(!verify_roundtrip_dual(PHRASE, &encrypted.phrase, "officer_two", "officer_one", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+
+        // También sobre los bits completos
+        let pass_through = transform_seed_dual(PHRASE, "officer_one", "officer_two", 1, 8192, 1, ChecksumPolicy::PassThrough).unwrap();
+        let reversed = transform_seed_dual(&pass_through.phrase, "officer_one", "officer_two", 1, 8192, 1, ChecksumPolicy::PassThrough).unwrap();
+        assert_eq!(reversed.phrase, PHRASE);
+    }
+
+    #[test]
+    fn test_dual_control_rejects_weak_pairs() {
+        assert!(transform_seed_dual(PHRASE, "same_password", "same_password", 1, 8192, 1, ChecksumPolicy::Fix).is_err());
+        assert!(transform_seed_dual(PHRASE, "officer_one", "", 1, 8192, 1, ChecksumPolicy::Fix).is_err());
+        assert_eq!(derive_dual_keystream("officer_one", "officer_two", 33, 1, 8192, 1).unwrap().len(), 33);
+    }
+}
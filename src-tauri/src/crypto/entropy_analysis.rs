@@ -0,0 +1,305 @@
+//! Análisis estadístico de entropía
+//!
+//! Produce los datos para el panel de calidad de entropía de la GUI:
+//! distribución de bits, histograma de bytes y tres pruebas clásicas
+//! (frecuencia y rachas de NIST SP 800-22, chi-cuadrado sobre nibbles).
+//!
+//! Con 128-256 bits las pruebas solo detectan fallos groseros (bytes
+//! repetidos, patrones, teclado); un resultado correcto no prueba aleatoriedad.
+
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Nivel de significación de las pruebas
+const ALPHA: f64 = 0.01;
+
+/// Resultado de una prueba estadística
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatTest {
+    pub name: String,
+    pub statistic: f64,
+    pub p_value: f64,
+    pub passed: bool,
+}
+
+/// Informe completo de calidad de entropía
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntropyAnalysis {
+    pub byte_length: usize,
+    pub total_bits: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    pub ones_ratio: f64,
+    pub longest_run: usize,          // Racha más larga de bits iguales
+    pub shannon_entropy: f64,        // Bits por byte estimados (máx. 8)
+    pub byte_histogram: Vec<u32>,    // 256 contadores
+    pub monobit: StatTest,
+    pub runs: StatTest,
+    pub chi_square: StatTest,
+    pub warnings: Vec<String>,
+}
+
+/// Función de error complementaria (Numerical Recipes, error < 1.2e-7)
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let r = t * (-z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+        .exp();
+    if x >= 0.0 { r } else { 2.0 - r }
+}
+
+/// ln Γ(x) por aproximación de Lanczos
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let series: f64 = COEFFS
+        .iter()
+        .enumerate()
+        .fold(1.000000000190015, |acc, (i, c)| acc + c / (x + 1.0 + i as f64));
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/// Función gamma incompleta regularizada superior Q(a, x)
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let ln_prefix = -x + a * x.ln() - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // Serie para P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut n = a;
+        for _ in 0..500 {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+        1.0 - sum * ln_prefix.exp()
+    } else {
+        // Fracción continua (Lentz) para Q(a, x)
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..500 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        ln_prefix.exp() * h
+    }
+}
+
+fn to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+/// Prueba de frecuencia (monobit)
+fn monobit_test(bits: &[bool], ones: usize) -> StatTest {
+    let n = bits.len() as f64;
+    let sum = 2.0 * ones as f64 - n;
+    let statistic = sum.abs() / n.sqrt();
+    let p_value = erfc(statistic / std::f64::consts::SQRT_2);
+
+    StatTest { name: "Frequency (monobit)".to_string(), statistic, p_value, passed: p_value >= ALPHA }
+}
+
+/// Prueba de rachas: número de cambios entre bits consecutivos
+fn runs_test(bits: &[bool], ones: usize) -> StatTest {
+    let n = bits.len() as f64;
+    let pi = ones as f64 / n;
+    let observed_runs = 1 + bits.windows(2).filter(|w| w[0] != w[1]).count();
+
+    // Requisito previo de NIST: la prueba de frecuencia debe ser razonable
+    let p_value = if (pi - 0.5).abs() >= 2.0 / n.sqrt() {
+        0.0
+    } else {
+        let expected = 2.0 * n * pi * (1.0 - pi);
+        erfc((observed_runs as f64 - expected).abs() / (2.0 * (2.0 * n).sqrt() * pi * (1.0 - pi)))
+    };
+
+    StatTest { name: "Runs".to_string(), statistic: observed_runs as f64, p_value, passed: p_value >= ALPHA }
+}
+
+/// Chi-cuadrado de la distribución de nibbles (16 categorías, 15 grados de libertad)
+fn chi_square_test(bytes: &[u8]) -> StatTest {
+    let mut counts = [0u32; 16];
+    for byte in bytes {
+        counts[(byte >> 4) as usize] += 1;
+        counts[(byte & 0x0f) as usize] += 1;
+    }
+
+    let expected = (bytes.len() * 2) as f64 / 16.0;
+    let statistic: f64 = counts
+        .iter()
+        .map(|&observed| (observed as f64 - expected).powi(2) / expected)
+        .sum();
+    let p_value = gamma_q(15.0 / 2.0, statistic / 2.0);
+
+    StatTest { name: "Chi-square (nibbles)".to_string(), statistic, p_value, passed: p_value >= ALPHA }
+}
+
+/// Analizar bytes de entropía
+pub fn analyze_bytes(bytes: &[u8]) -> Result<EntropyAnalysis> {
+    if bytes.is_empty() {
+        return Err(SCypherError::crypto("Entropy cannot be empty".to_string()));
+    }
+
+    let bits = to_bits(bytes);
+    let ones = bits.iter().filter(|&&b| b).count();
+
+    let mut longest_run = 0;
+    let mut current_run = 0;
+    for (i, bit) in bits.iter().enumerate() {
+        current_run = if i > 0 && bits[i - 1] == *bit { current_run + 1 } else { 1 };
+        longest_run = longest_run.max(current_run);
+    }
+
+    let mut byte_histogram = vec![0u32; 256];
+    for &byte in bytes {
+        byte_histogram[byte as usize] += 1;
+    }
+
+    let shannon_entropy = byte_histogram
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / bytes.len() as f64;
+            -p * p.log2()
+        })
+        .sum();
+
+    let monobit = monobit_test(&bits, ones);
+    let runs = runs_test(&bits, ones);
+    let chi_square = chi_square_test(bytes);
+
+    let mut warnings = Vec::new();
+    if bytes.len() < 16 {
+        warnings.push("Less than 128 bits: too short for a BIP39 seed".to_string());
+    }
+    if bytes.iter().all(|&b| b == bytes[0]) {
+        warnings.push("All bytes are identical".to_string());
+    }
+    if longest_run > 16 + (bits.len() as f64).log2() as usize {
+        warnings.push(format!("Unusually long run of identical bits ({})", longest_run));
+    }
+    for test in [&monobit, &runs, &chi_square] {
+        if !test.passed {
+            warnings.push(format!("{} test failed (p = {:.4})", test.name, test.p_value));
+        }
+    }
+
+    Ok(EntropyAnalysis {
+        byte_length: bytes.len(),
+        total_bits: bits.len(),
+        ones,
+        zeros: bits.len() - ones,
+        ones_ratio: ones as f64 / bits.len() as f64,
+        longest_run,
+        shannon_entropy,
+        byte_histogram,
+        monobit,
+        runs,
+        chi_square,
+        warnings,
+    })
+}
+
+/// Analizar entropía en hexadecimal
+pub fn analyze_hex(entropy_hex: &str) -> Result<EntropyAnalysis> {
+    let bytes = hex::decode(entropy_hex.trim().trim_start_matches("0x"))
+        .map_err(|_| SCypherError::crypto("Entropy is not valid hex".to_string()))?;
+    analyze_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_special_functions() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157299).abs() < 1e-5);
+        // Q(7.5, x) con estadístico 0 -> p = 1; con estadístico enorme -> p ≈ 0
+        assert!((gamma_q(7.5, 0.0) - 1.0).abs() < 1e-12);
+        assert!(gamma_q(7.5, 100.0) < 1e-10);
+        // Valor crítico chi-cuadrado df=15, alfa=0.05 ≈ 24.996
+        assert!((gamma_q(7.5, 24.996 / 2.0) - 0.05).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_nist_monobit_and_runs_examples() {
+        // Ejemplos de NIST SP 800-22: ε = 1011010101 (monobit p = 0.527089)
+        // y ε = 1001101011 (runs p = 0.147232)
+        let monobit_bits: Vec<bool> = "1011010101".chars().map(|c| c == '1').collect();
+        let monobit = monobit_test(&monobit_bits, 6);
+        assert!((monobit.p_value - 0.527089).abs() < 1e-5);
+
+        let runs_bits: Vec<bool> = "1001101011".chars().map(|c| c == '1').collect();
+        let runs = runs_test(&runs_bits, 6);
+        assert_eq!(runs.statistic, 7.0);
+        assert!((runs.p_value - 0.147232).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_degenerate_entropy_flagged() {
+        let analysis = analyze_hex(&"00".repeat(32)).unwrap();
+        assert_eq!(analysis.ones, 0);
+        assert_eq!(analysis.longest_run, 256);
+        assert_eq!(analysis.byte_histogram[0], 32);
+        assert!(!analysis.monobit.passed);
+        assert!(!analysis.chi_square.passed);
+        assert!(!analysis.warnings.is_empty());
+
+        assert!(analyze_hex("").is_err());
+        assert!(analyze_hex("zz").is_err());
+    }
+
+    #[test]
+    fn test_random_entropy_passes() {
+        use sha2::{Digest, Sha256};
+        // Bytes pseudoaleatorios deterministas
+        let bytes: Vec<u8> = (0u8..8).flat_map(|i| Sha256::digest([i]).to_vec()).collect();
+        let analysis = analyze_bytes(&bytes).unwrap();
+
+        assert_eq!(analysis.total_bits, 2048);
+        assert!(analysis.monobit.passed);
+        assert!(analysis.runs.passed);
+        assert!(analysis.shannon_entropy > 7.0);
+    }
+}
@@ -0,0 +1,125 @@
+//! Estimación del tiempo de Argon2id antes de derivar
+//!
+//! El coste de Argon2id crece casi linealmente con la memoria y con las
+//! iteraciones. Se ejecuta una calibración reducida (1/16 de la memoria, una
+//! sola pasada, sin caché) y se extrapola al tamaño real, de modo que la GUI
+//! pueda mostrar "about 45 seconds" antes de que el usuario confirme los
+//! parámetros. Es una aproximación: con poca memoria la caché de la CPU hace
+//! la calibración algo optimista.
+
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::error::Result;
+use super::keystream::{self, DEFAULT_PARALLELISM};
+
+/// Fracción de la memoria usada en la calibración
+const CALIBRATION_DIVISOR: u32 = 16;
+
+/// Memoria mínima de la calibración en KB (por debajo el tiempo es ruido)
+const CALIBRATION_MIN_KB: u32 = 1024;
+
+/// Parámetros a estimar (los mismos que recibe la transformación)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfEstimateRequest {
+    pub iterations: u32,
+    pub memory_cost: u32,
+    #[serde(default)]
+    pub parallelism: Option<u32>,
+    #[serde(default)]
+    pub dual: bool,  // Control dual: dos ejecuciones de Argon2id
+}
+
+/// Resultado de la estimación para la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfEstimate {
+    pub estimated_ms: u64,
+    pub calibration_ms: u64,
+    pub calibration_memory_kb: u32,
+    pub description: String,  // "about 45 seconds"
+}
+
+/// Memoria de la calibración: 1/16 del total, sin bajar del mínimo de Argon2id
+fn calibration_memory(memory_cost: u32, parallelism: u32) -> u32 {
+    (memory_cost / CALIBRATION_DIVISOR)
+        .max(CALIBRATION_MIN_KB)
+        .max(8 * parallelism)
+        .min(memory_cost)
+}
+
+/// Extrapolar el tiempo de la calibración (una pasada) a los parámetros reales
+fn extrapolate(calibration: Duration, calibration_kb: u32, request: &KdfEstimateRequest) -> Duration {
+    let runs = if request.dual { 2 } else { 1 };
+    let scale = request.memory_cost as f64 / calibration_kb as f64 * request.iterations as f64 * runs as f64;
+    calibration.mul_f64(scale)
+}
+
+/// Texto legible para la GUI
+fn describe(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds < 1.0 {
+        "less than a second".to_string()
+    } else if seconds < 90.0 {
+        let rounded = seconds.round() as u64;
+        format!("about {} second{}", rounded, if rounded == 1 { "" } else { "s" })
+    } else {
+        format!("about {} minutes", (seconds / 60.0).round() as u64)
+    }
+}
+
+/// Calibrar en este equipo y estimar la duración de la derivación completa
+pub fn estimate_kdf_time(request: &KdfEstimateRequest) -> Result<KdfEstimate> {
+    let parallelism = request.parallelism.unwrap_or(DEFAULT_PARALLELISM);
+    keystream::validate_argon2_params(request.iterations, request.memory_cost, parallelism)?;
+
+    let calibration_kb = calibration_memory(request.memory_cost, parallelism);
+    let started = Instant::now();
+    keystream::compute_keystream("scypher-kdf-calibration", None, 32, 1, calibration_kb, parallelism)?;
+    let calibration = started.elapsed();
+
+    let estimated = extrapolate(calibration, calibration_kb, request);
+    tracing::debug!(
+        "Calibración Argon2id: {} KB en {:?}, estimado {:?}",
+        calibration_kb, calibration, estimated
+    );
+
+    Ok(KdfEstimate {
+        estimated_ms: estimated.as_millis() as u64,
+        calibration_ms: calibration.as_millis() as u64,
+        calibration_memory_kb: calibration_kb,
+        description: describe(estimated),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate_and_describe() {
+        let request = KdfEstimateRequest { iterations: 3, memory_cost: 131072, parallelism: None, dual: false };
+        assert_eq!(calibration_memory(request.memory_cost, 1), 8192);
+        assert_eq!(calibration_memory(8192, 1), 1024);
+        assert_eq!(calibration_memory(65536, 64), 4096);
+
+        // 1/16 de la memoria y una pasada: x16 x3 iteraciones
+        let estimated = extrapolate(Duration::from_millis(100), 8192, &request);
+        assert_eq!(estimated, Duration::from_millis(4800));
+        let dual = KdfEstimateRequest { dual: true, ..request };
+        assert_eq!(extrapolate(Duration::from_millis(100), 8192, &dual), Duration::from_millis(9600));
+
+        assert_eq!(describe(Duration::from_millis(300)), "less than a second");
+        assert_eq!(describe(Duration::from_secs(45)), "about 45 seconds");
+        assert_eq!(describe(Duration::from_secs(600)), "about 10 minutes");
+    }
+
+    #[test]
+    fn test_estimate_runs_calibration() {
+        let request = KdfEstimateRequest { iterations: 2, memory_cost: 8192, parallelism: None, dual: false };
+        let estimate = estimate_kdf_time(&request).unwrap();
+        assert_eq!(estimate.calibration_memory_kb, 1024);
+        assert!(estimate.estimated_ms >= estimate.calibration_ms);
+
+        let invalid = KdfEstimateRequest { memory_cost: 1024, ..request };
+        assert!(estimate_kdf_time(&invalid).is_err());
+    }
+}
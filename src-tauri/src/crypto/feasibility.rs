@@ -0,0 +1,195 @@
+//! Comprobación previa de memoria antes de ejecutar Argon2id
+//!
+//! Argon2id reserva `memory_cost` KB de una vez. Si el equipo no los tiene
+//! libres (o, con la memoria del proceso bloqueada, si superan el límite
+//! RLIMIT_MEMLOCK) la reserva falla o el sistema empieza a paginar. Antes de
+//! derivar se comprueba la memoria disponible y se devuelve
+//! `InsufficientMemory` con el máximo valor que sí cabe, para que la GUI
+//! pueda deshabilitar los ajustes imposibles.
+//!
+//! - Linux: `MemAvailable` de `/proc/meminfo`
+//! - Windows: `GlobalMemoryStatusEx`
+//! - Otras plataformas: desconocida (no se bloquea la operación)
+
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+use crate::security::memory;
+use super::keystream::{MAX_MEMORY_COST, MIN_MEMORY_COST};
+
+/// Memoria que se deja libre para el resto de la aplicación (64MB)
+const MEMORY_HEADROOM_KB: u64 = 64 * 1024;
+
+/// Parámetros a comprobar (los mismos que recibe la transformación)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfFeasibilityRequest {
+    pub iterations: u32,
+    pub memory_cost: u32,
+    #[serde(default)]
+    pub parallelism: Option<u32>,
+    #[serde(default)]
+    pub lock_memory: Option<bool>,  // Por defecto: estado actual del proceso
+}
+
+/// Resultado de la comprobación para la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfFeasibility {
+    pub feasible: bool,
+    pub required_kb: u64,
+    pub available_kb: Option<u64>,     // None si la plataforma no lo informa
+    pub memlock_limit_kb: Option<u64>, // Solo si se bloquea la memoria y hay límite
+    pub max_memory_cost: u32,          // Mayor memory_cost que cabe ahora
+    pub message: Option<String>,
+}
+
+/// Memoria disponible del sistema en KB
+#[cfg(target_os = "linux")]
+pub fn available_memory_kb() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_meminfo_available(&meminfo)
+}
+
+/// Memoria disponible del sistema en KB
+#[cfg(windows)]
+pub fn available_memory_kb() -> Option<u64> {
+    use winapi::um::sysinfoapi::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+    let mut status: MEMORYSTATUSEX = unsafe { std::mem::zeroed() };
+    status.dwLength = std::mem::size_of::<MEMORYSTATUSEX>() as u32;
+    if unsafe { GlobalMemoryStatusEx(&mut status) } == 0 {
+        return None;
+    }
+    Some(status.ullAvailPhys / 1024)
+}
+
+/// Memoria disponible del sistema en KB
+#[cfg(not(any(target_os = "linux", windows)))]
+pub fn available_memory_kb() -> Option<u64> {
+    None
+}
+
+/// Valor de `MemAvailable` (en kB) del formato de `/proc/meminfo`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_meminfo_available(meminfo: &str) -> Option<u64> {
+    meminfo.lines().find_map(|line| {
+        let value = line.strip_prefix("MemAvailable:")?;
+        value.split_whitespace().next()?.parse().ok()
+    })
+}
+
+/// Límite de memoria bloqueable en KB (None si no hay límite o no aplica)
+fn memlock_limit_kb() -> Option<u64> {
+    if !cfg!(unix) {
+        return None;
+    }
+    let (current, _max) = memory::check_memory_lock_limits();
+    if current == usize::MAX {
+        None // RLIM_INFINITY
+    } else {
+        Some(current as u64 / 1024)
+    }
+}
+
+/// Mayor memory_cost que cabe con la memoria y el límite dados
+fn max_feasible(available_kb: Option<u64>, memlock_limit_kb: Option<u64>) -> u32 {
+    let usable = available_kb.map(|kb| kb.saturating_sub(MEMORY_HEADROOM_KB));
+    [usable, memlock_limit_kb]
+        .into_iter()
+        .flatten()
+        .fold(MAX_MEMORY_COST as u64, u64::min) as u32
+}
+
+fn assess(memory_cost: u32, available_kb: Option<u64>, memlock_limit_kb: Option<u64>) -> KdfFeasibility {
+    let max_memory_cost = max_feasible(available_kb, memlock_limit_kb);
+    let feasible = memory_cost <= max_memory_cost;
+
+    let message = if feasible {
+        None
+    } else if max_memory_cost < MIN_MEMORY_COST {
+        Some("Not enough free memory for the minimum Argon2id memory cost".to_string())
+    } else {
+        Some(format!("Memory cost is too high for this machine (maximum {} KB)", max_memory_cost))
+    };
+
+    KdfFeasibility {
+        feasible,
+        required_kb: memory_cost as u64,
+        available_kb,
+        memlock_limit_kb,
+        max_memory_cost,
+        message,
+    }
+}
+
+/// Comprobar si los parámetros caben en la memoria actual
+pub fn check_kdf_feasibility(request: &KdfFeasibilityRequest) -> Result<KdfFeasibility> {
+    let parallelism = request.parallelism.unwrap_or(super::keystream::DEFAULT_PARALLELISM);
+    super::keystream::validate_argon2_params(request.iterations, request.memory_cost, parallelism)?;
+
+    let lock_memory = request.lock_memory.unwrap_or_else(memory::process_memory_locked);
+    let memlock = if lock_memory { memlock_limit_kb() } else { None };
+    Ok(assess(request.memory_cost, available_memory_kb(), memlock))
+}
+
+/// Comprobación previa a Argon2id: error estructurado si no hay memoria suficiente
+pub fn ensure_kdf_feasible(memory_cost: u32) -> Result<()> {
+    let memlock = if memory::process_memory_locked() { memlock_limit_kb() } else { None };
+    let available_kb = available_memory_kb();
+    let report = assess(memory_cost, available_kb, memlock);
+
+    if report.feasible {
+        return Ok(());
+    }
+    Err(SCypherError::InsufficientMemory {
+        required_kb: report.required_kb,
+        available_kb: memlock.into_iter().chain(available_kb).min().unwrap_or(0),
+        max_memory_cost: report.max_memory_cost,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = "MemTotal:       16318480 kB\n\
+                       MemFree:         1198764 kB\n\
+                       MemAvailable:    9876544 kB\n";
+        assert_eq!(parse_meminfo_available(meminfo), Some(9_876_544));
+        assert_eq!(parse_meminfo_available("MemTotal: 1 kB\n"), None);
+    }
+
+    #[test]
+    fn test_assess_limits() {
+        // 1GB libre: 2GB no cabe, el máximo descuenta el margen
+        let report = assess(2_097_152, Some(1_048_576), None);
+        assert!(!report.feasible);
+        assert_eq!(report.max_memory_cost, 1_048_576 - MEMORY_HEADROOM_KB as u32);
+        assert!(assess(131072, Some(1_048_576), None).feasible);
+
+        // Con memoria bloqueada manda el límite RLIMIT_MEMLOCK
+        let report = assess(131072, Some(8_388_608), Some(65536));
+        assert!(!report.feasible);
+        assert_eq!(report.max_memory_cost, 65536);
+
+        // Plataforma sin información: solo el máximo de Argon2id
+        assert_eq!(assess(131072, None, None).max_memory_cost, MAX_MEMORY_COST);
+        assert!(assess(8192, Some(1024), None).message.unwrap().contains("minimum"));
+    }
+
+    #[test]
+    fn test_check_request() {
+        let request = KdfFeasibilityRequest {
+            iterations: 1,
+            memory_cost: MIN_MEMORY_COST,
+            parallelism: None,
+            lock_memory: Some(false),
+        };
+        let report = check_kdf_feasibility(&request).unwrap();
+        assert_eq!(report.required_kb, MIN_MEMORY_COST as u64);
+        assert!(report.memlock_limit_kb.is_none());
+
+        let invalid = KdfFeasibilityRequest { memory_cost: 1024, ..request };
+        assert!(check_kdf_feasibility(&invalid).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Versiones del formato de cifrado y despachador de descifrado
+//!
+//! Cada cambio futuro (sal aleatoria, otros KDF, capas) será una versión
+//! nueva. Los resultados antiguos deben poder descifrarse siempre, así que
+//! el descifrado pasa por `decode`, que elige la implementación según la
+//! versión declarada (metadatos o sobre JSON) o detectada en la entrada.
+//!
+//! - v1: Argon2id con sal determinista derivada de la contraseña, XOR sobre
+//!   la entropía BIP39 y checksum recalculado. La salida es una frase BIP39
+//!   sin sobre, por lo que una frase sin más información se trata como v1.
+//! - v2: contenedor de volúmenes ocultos (`crypto::hidden`), sobre JSON con
+//!   una frase cifrada y varias ranuras; cada contraseña abre una frase.
+
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Versiones de formato conocidas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormatVersion {
+    V1,
+    V2,
+}
+
+/// Versión que produce esta build
+pub const CURRENT_FORMAT: FormatVersion = FormatVersion::V1;
+
+impl FormatVersion {
+    /// Número de versión tal como aparece en metadatos y sobres
+    pub fn number(&self) -> u32 {
+        match self {
+            FormatVersion::V1 => 1,
+            FormatVersion::V2 => 2,
+        }
+    }
+
+    /// Versión a partir de su número
+    pub fn from_number(version: u32) -> Result<Self> {
+        match version {
+            1 => Ok(FormatVersion::V1),
+            2 => Ok(FormatVersion::V2),
+            _ => Err(SCypherError::crypto(format!(
+                "Unsupported format version {}; a newer version of SCypher is required",
+                version
+            ))),
+        }
+    }
+
+    /// Descripción para la GUI
+    pub fn description(&self) -> &'static str {
+        match self {
+            FormatVersion::V1 => "Argon2id (deterministic salt) + XOR over BIP39 entropy",
+            FormatVersion::V2 => "Hidden volume container (one ciphertext, one phrase per password)",
+        }
+    }
+}
+
+/// Resultado de la detección de formato
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatDetection {
+    pub format_version: u32,
+    pub supported: bool,
+    pub detected_from: String,   // "envelope", "metadata" o "plain_phrase"
+    pub description: String,
+}
+
+/// Sobre JSON mínimo de formatos futuros: `{"format_version": N, ...}`
+#[derive(Deserialize)]
+struct EnvelopeHeader {
+    format_version: u32,
+}
+
+fn envelope_version(input: &str) -> Option<u32> {
+    let trimmed = input.trim();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    serde_json::from_str::<EnvelopeHeader>(trimmed)
+        .ok()
+        .map(|header| header.format_version)
+}
+
+/// Detectar el formato de una entrada cifrada
+///
+/// Prioridad: versión del sobre, versión declarada en metadatos y, si no hay
+/// ninguna, frase BIP39 sin sobre (v1).
+pub fn detect_format(input: &str, declared_version: Option<u32>) -> FormatDetection {
+    let (version, detected_from) = match (envelope_version(input), declared_version) {
+        (Some(version), _) => (version, "envelope"),
+        (None, Some(version)) => (version, "metadata"),
+        (None, None) => (FormatVersion::V1.number(), "plain_phrase"),
+    };
+
+    let known = FormatVersion::from_number(version).ok();
+    FormatDetection {
+        format_version: version,
+        supported: known.is_some(),
+        detected_from: detected_from.to_string(),
+        description: known
+            .map(|v| v.description().to_string())
+            .unwrap_or_else(|| "Unknown format (created by a newer version)".to_string()),
+    }
+}
+
+/// Descifrar una entrada con la implementación de su versión y la política de checksum dada
+pub fn decode_with_policy(
+    input: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    declared_version: Option<u32>,
+    policy: super::ChecksumPolicy,
+) -> Result<super::PolicyTransform> {
+    let detection = detect_format(input, declared_version);
+
+    match FormatVersion::from_number(detection.format_version)? {
+        FormatVersion::V1 => {
+            super::transform_seed_with_policy(input, password, iterations, memory_cost, parallelism, policy)
+        }
+        // El contenedor guarda entropía: la política de checksum no aplica
+        FormatVersion::V2 => Ok(super::PolicyTransform {
+            phrase: super::hidden::open_container(input, password, iterations, memory_cost, parallelism)?,
+            checksum_corrected: false,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_detect_format() {
+        let plain = detect_format(PHRASE, None);
+        assert_eq!(plain.format_version, 1);
+        assert_eq!(plain.detected_from, "plain_phrase");
+        assert!(plain.supported);
+
+        assert_eq!(detect_format(PHRASE, Some(1)).detected_from, "metadata");
+
+        let future = detect_format(r#"{"format_version": 7, "payload": "..."}"#, Some(1));
+        assert_eq!(future.format_version, 7);
+        assert_eq!(future.detected_from, "envelope");
+        assert!(!future.supported);
+    }
+
+    #[test]
+    fn test_decode_dispatches_v1() {
+        let encrypted = super::super::transform_seed(PHRASE, "format_password", 1, 8192).unwrap();
+        assert_eq!(decode_with_policy(&encrypted, "format_password", 1, 8192, 1, None, super::super::ChecksumPolicy::Fix).unwrap().phrase, PHRASE);
+        assert!(decode_with_policy(&encrypted, "format_password", 1, 8192, 1, Some(3), super::super::ChecksumPolicy::Fix).is_err());
+    }
+}
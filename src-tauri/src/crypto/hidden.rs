@@ -0,0 +1,231 @@
+//! Volúmenes ocultos: una frase cifrada, varias frases reales (formato v2)
+//!
+//! El contenedor guarda una frase "cifrada" aleatoria C y siempre
+//! `SLOT_COUNT` ranuras. Cada contraseña deriva con Argon2id un keystream K
+//! de la longitud de la entropía más una clave de etiqueta:
+//!
+//! - ranura = etiqueta (16 bytes) || desplazamiento O
+//! - etiqueta = SHA256(clave de etiqueta || C)[..16]
+//! - O = C ⊕ K ⊕ P, así que P = C ⊕ K ⊕ O
+//!
+//! Las ranuras sin usar se rellenan con bytes aleatorios, indistinguibles de
+//! las usadas: quien conozca una contraseña no puede saber cuántas frases más
+//! contiene el contenedor. Por la misma razón no se puede añadir un volumen
+//! a un contenedor existente sin todas sus contraseñas; hay que crearlo de
+//! nuevo con todos los volúmenes.
+
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Ranuras por contenedor (usadas o no)
+pub const SLOT_COUNT: usize = 4;
+
+/// Longitud de la etiqueta que identifica la ranura de una contraseña
+const TAG_LEN: usize = 16;
+
+/// Bytes extra de keystream para la clave de etiqueta
+const TAG_KEY_LEN: usize = 32;
+
+/// Volumen a guardar: frase real y su contraseña
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HiddenVolume {
+    pub phrase: String,
+    pub password: String,
+}
+
+impl Drop for HiddenVolume {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.password.zeroize();
+    }
+}
+
+/// Sobre JSON del contenedor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HiddenContainer {
+    pub format_version: u32,
+    pub ciphertext: String,   // Frase BIP39 aleatoria
+    pub slots: Vec<String>,   // Hex: etiqueta || desplazamiento
+}
+
+/// Keystream de una contraseña: (clave XOR, clave de etiqueta)
+fn slot_keys(
+    password: &str,
+    entropy_len: usize,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut keystream =
+        super::keystream::derive_keystream(password, entropy_len + TAG_KEY_LEN, iterations, memory_cost, parallelism)?;
+    let tag_key = keystream.split_off(entropy_len);
+    Ok((keystream, tag_key))
+}
+
+fn slot_tag(tag_key: &[u8], ciphertext: &[u8]) -> [u8; TAG_LEN] {
+    let hash = Sha256::new().chain_update(tag_key).chain_update(ciphertext).finalize();
+    let mut tag = [0u8; TAG_LEN];
+    tag.copy_from_slice(&hash[..TAG_LEN]);
+    tag
+}
+
+fn xor3(a: &[u8], b: &[u8], c: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).zip(c).map(|((x, y), z)| x ^ y ^ z).collect()
+}
+
+/// Crear un contenedor con 1..=SLOT_COUNT volúmenes del mismo número de palabras
+pub fn create_container(
+    volumes: &[HiddenVolume],
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<String> {
+    if volumes.is_empty() || volumes.len() > SLOT_COUNT {
+        return Err(SCypherError::crypto(format!(
+            "A hidden container holds between 1 and {} volumes",
+            SLOT_COUNT
+        )));
+    }
+    super::keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    let mut plaintexts = Vec::with_capacity(volumes.len());
+    for volume in volumes {
+        let phrase = crate::bip39::wordlist::expand_abbreviations(&volume.phrase)?;
+        crate::bip39::validate_seed_phrase(&phrase)?;
+        plaintexts.push(crate::bip39::phrase_to_entropy(&phrase)?);
+    }
+    let entropy_len = plaintexts[0].len();
+    if plaintexts.iter().any(|entropy| entropy.len() != entropy_len) {
+        return Err(SCypherError::crypto("All hidden volumes must have the same word count".to_string()));
+    }
+
+    // Contraseñas repetidas harían inaccesible uno de los volúmenes
+    for (i, volume) in volumes.iter().enumerate() {
+        if volumes[..i].iter().any(|other| other.password == volume.password) {
+            return Err(SCypherError::crypto("Each hidden volume needs a different password".to_string()));
+        }
+    }
+
+    let ciphertext = crate::security::utils::secure_random_bytes(entropy_len);
+    let mut slots = Vec::with_capacity(SLOT_COUNT);
+    for (volume, plaintext) in volumes.iter().zip(plaintexts.iter_mut()) {
+        let (mut key, mut tag_key) = slot_keys(&volume.password, entropy_len, iterations, memory_cost, parallelism)?;
+        let mut slot = slot_tag(&tag_key, &ciphertext).to_vec();
+        slot.extend(xor3(&ciphertext, &key, plaintext));
+        slots.push(hex::encode(&slot));
+
+        slot.zeroize();
+        key.zeroize();
+        tag_key.zeroize();
+        plaintext.zeroize();
+    }
+    while slots.len() < SLOT_COUNT {
+        slots.push(hex::encode(crate::security::utils::secure_random_bytes(TAG_LEN + entropy_len)));
+    }
+
+    // El orden de las ranuras no revela cuál se creó primero
+    slots.shuffle(&mut crate::security::rng::SourceRng);
+
+    let container = HiddenContainer {
+        format_version: super::format::FormatVersion::V2.number(),
+        ciphertext: crate::bip39::entropy_to_phrase(&ciphertext)?,
+        slots,
+    };
+    serde_json::to_string(&container)
+        .map_err(|e| SCypherError::crypto(format!("Cannot encode hidden container: {}", e)))
+}
+
+/// Abrir el volumen que corresponde a la contraseña
+pub fn open_container(
+    input: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<String> {
+    let container: HiddenContainer = serde_json::from_str(input.trim())
+        .map_err(|e| SCypherError::crypto(format!("Invalid hidden container: {}", e)))?;
+    super::keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    let ciphertext = crate::bip39::phrase_to_entropy(&container.ciphertext)?;
+    let (mut key, mut tag_key) = slot_keys(password, ciphertext.len(), iterations, memory_cost, parallelism)?;
+    let tag = slot_tag(&tag_key, &ciphertext);
+    tag_key.zeroize();
+
+    // Se comprueban todas las ranuras para no revelar la posición por tiempos
+    let mut matched: Option<Vec<u8>> = None;
+    for slot in &container.slots {
+        let slot = hex::decode(slot)
+            .map_err(|_| SCypherError::crypto("Invalid hidden container slot".to_string()))?;
+        if slot.len() != TAG_LEN + ciphertext.len() {
+            return Err(SCypherError::crypto("Invalid hidden container slot".to_string()));
+        }
+        if crate::security::utils::constant_time_eq(&slot[..TAG_LEN], &tag) && matched.is_none() {
+            matched = Some(slot[TAG_LEN..].to_vec());
+        }
+    }
+
+    let result = match matched {
+        Some(mut offset) => {
+            let mut plaintext = xor3(&ciphertext, &key, &offset);
+            let phrase = crate::bip39::entropy_to_phrase(&plaintext);
+            plaintext.zeroize();
+            offset.zeroize();
+            phrase
+        }
+        None => Err(SCypherError::crypto("No hidden volume matches this password".to_string())),
+    };
+    key.zeroize();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERSONAL: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const BUSINESS: &str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    fn volume(phrase: &str, password: &str) -> HiddenVolume {
+        HiddenVolume { phrase: phrase.to_string(), password: password.to_string() }
+    }
+
+    #[test]
+    fn test_hidden_volumes_roundtrip() {
+        let container = create_container(
+            &[volume(PERSONAL, "decoy_password"), volume(BUSINESS, "real_password")],
+            1,
+            8192,
+            1,
+        )
+        .unwrap();
+
+        let parsed: HiddenContainer = serde_json::from_str(&container).unwrap();
+        assert_eq!(parsed.format_version, 2);
+        assert_eq!(parsed.slots.len(), SLOT_COUNT);
+        assert!(crate::bip39::validate_seed_phrase(&parsed.ciphertext).is_ok());
+
+        assert_eq!(open_container(&container, "decoy_password", 1, 8192, 1).unwrap(), PERSONAL);
+        assert_eq!(open_container(&container, "real_password", 1, 8192, 1).unwrap(), BUSINESS);
+        assert!(open_container(&container, "other_password", 1, 8192, 1).is_err());
+
+        // Dispatcher de formatos
+        assert_eq!(super::super::format::decode_with_policy(&container, "real_password", 1, 8192, 1, None, super::super::ChecksumPolicy::Fix).unwrap().phrase, BUSINESS);
+    }
+
+    #[test]
+    fn test_hidden_container_validation() {
+        let twenty_four = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                           abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+        assert!(create_container(&[], 1, 8192, 1).is_err());
+        assert!(create_container(&[volume(PERSONAL, "a_password"), volume(twenty_four, "b_password")], 1, 8192, 1).is_err());
+        assert!(create_container(&[volume(PERSONAL, "same"), volume(BUSINESS, "same")], 1, 8192, 1).is_err());
+
+        let too_many: Vec<HiddenVolume> = (0..=SLOT_COUNT).map(|i| volume(PERSONAL, &format!("password_{}", i))).collect();
+        assert!(create_container(&too_many, 1, 8192, 1).is_err());
+    }
+}
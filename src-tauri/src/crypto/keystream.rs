@@ -3,10 +3,119 @@
 //! Este módulo se encarga de generar un flujo de claves (keystream) usando
 //! Argon2id a partir de una contraseña. El keystream se usa luego para
 //! la operación XOR con la frase semilla.
+//!
+//! Los keystreams recientes se guardan en una caché LRU en memoria para que
+//! cifrar y descifrar a continuación (verificación) no repita Argon2id.
+//! La caché nunca se escribe a disco y se borra con `clear_kdf_cache`.
+//!
+//! El paralelismo (carriles de Argon2id) forma parte de los parámetros: el
+//! mismo valor es necesario para descifrar, por eso se guarda en los
+//! metadatos. Con argon2 0.5 los carriles se calculan en un solo hilo; más
+//! carriles cambian la distribución de memoria, no el tiempo de pared.
+//!
+//! Opcionalmente se añade un secreto ("pepper") de Argon2id obtenido de un
+//! token hardware (`security::hardware_pepper`): sin el token no se puede
+//! reproducir el keystream aunque se conozca la contraseña.
 
+use std::sync::{Mutex, OnceLock};
 use argon2::{Argon2, Algorithm, Version, Params};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
 use crate::error::{SCypherError, Result};
 
+/// Número máximo de keystreams en caché
+const KDF_CACHE_CAPACITY: usize = 4;
+
+/// Rango aceptado de memory_cost en KB (8MB - 2GB)
+pub const MIN_MEMORY_COST: u32 = 8192;
+pub const MAX_MEMORY_COST: u32 = 2_097_152;
+
+/// Paralelismo por defecto (formato v1 original)
+pub const DEFAULT_PARALLELISM: u32 = 1;
+
+/// Límite absoluto de carriles aceptado al descifrar
+pub const MAX_PARALLELISM: u32 = 64;
+
+/// Clave de caché: hash de la contraseña + parámetros + longitud
+#[derive(Clone, PartialEq, Eq)]
+struct CacheKey {
+    password_hash: [u8; 32],
+    pepper_hash: Option<[u8; 32]>,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    length: usize,
+}
+
+/// Entrada de la caché; se borra de memoria al expulsarla
+struct CacheEntry {
+    key: CacheKey,
+    keystream: Vec<u8>,
+}
+
+impl Drop for CacheEntry {
+    fn drop(&mut self) {
+        self.key.password_hash.zeroize();
+        if let Some(pepper_hash) = self.key.pepper_hash.as_mut() {
+            pepper_hash.zeroize();
+        }
+        self.keystream.zeroize();
+    }
+}
+
+/// Caché LRU: la entrada más reciente al final. Incluye una sal aleatoria
+/// por proceso para que el hash de la contraseña no sirva fuera de esta sesión.
+struct KdfCache {
+    salt: [u8; 32],
+    entries: Vec<CacheEntry>,
+}
+
+static KDF_CACHE: OnceLock<Mutex<KdfCache>> = OnceLock::new();
+
+fn cache_slot() -> &'static Mutex<KdfCache> {
+    KDF_CACHE.get_or_init(|| {
+        let mut salt = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut salt);
+        Mutex::new(KdfCache { salt, entries: Vec::new() })
+    })
+}
+
+fn cache_key(
+    salt: &[u8],
+    password: &str,
+    pepper: Option<&[u8]>,
+    length: usize,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> CacheKey {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(password.as_bytes());
+
+    let pepper_hash = pepper.map(|pepper| {
+        let mut hasher = Sha256::new();
+        hasher.update(salt);
+        hasher.update(pepper);
+        hasher.finalize().into()
+    });
+
+    CacheKey {
+        password_hash: hasher.finalize().into(),
+        pepper_hash,
+        iterations,
+        memory_cost,
+        parallelism,
+        length,
+    }
+}
+
+/// Vaciar la caché de keystreams (borrando su contenido de memoria)
+pub fn clear_kdf_cache() {
+    let mut cache = cache_slot().lock().unwrap_or_else(|e| e.into_inner());
+    cache.entries.clear();
+}
+
 /// Genera un keystream usando Argon2id
 ///
 /// # Parámetros
@@ -14,6 +123,7 @@ use crate::error::{SCypherError, Result};
 /// - `length`: Longitud deseada del keystream en bytes
 /// - `iterations`: Número de iteraciones de Argon2id
 /// - `memory_cost`: Costo de memoria en KB
+/// - `parallelism`: Carriles de Argon2id
 ///
 /// # Retorna
 /// Vector de bytes que representa el keystream
@@ -22,7 +132,66 @@ pub fn derive_keystream(
     length: usize,
     iterations: u32,
     memory_cost: u32,
+    parallelism: u32,
+) -> Result<Vec<u8>> {
+    derive_keystream_with_pepper(password, None, length, iterations, memory_cost, parallelism)
+}
+
+/// Keystream Argon2id con un secreto adicional (pepper) opcional
+///
+/// Con `pepper` igual a None el resultado es idéntico a `derive_keystream`.
+pub fn derive_keystream_with_pepper(
+    password: &str,
+    pepper: Option<&[u8]>,
+    length: usize,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
 ) -> Result<Vec<u8>> {
+    let key = {
+        let mut cache = cache_slot().lock().unwrap_or_else(|e| e.into_inner());
+        let key = cache_key(&cache.salt, password, pepper, length, iterations, memory_cost, parallelism);
+
+        // Acierto: mover la entrada al final (más reciente) y devolver una copia
+        if let Some(position) = cache.entries.iter().position(|entry| entry.key == key) {
+            let entry = cache.entries.remove(position);
+            let keystream = entry.keystream.clone();
+            cache.entries.push(entry);
+            return Ok(keystream);
+        }
+        key
+    };
+
+    // Argon2id se ejecuta sin mantener el bloqueo
+    let keystream = compute_keystream(password, pepper, length, iterations, memory_cost, parallelism)?;
+
+    // En modo incógnito no se guarda nada entre operaciones
+    if crate::security::incognito::is_incognito() {
+        return Ok(keystream);
+    }
+    let mut cache = cache_slot().lock().unwrap_or_else(|e| e.into_inner());
+    if !cache.entries.iter().any(|entry| entry.key == key) {
+        if cache.entries.len() >= KDF_CACHE_CAPACITY {
+            cache.entries.remove(0);
+        }
+        cache.entries.push(CacheEntry { key, keystream: keystream.clone() });
+    }
+
+    Ok(keystream)
+}
+
+/// Ejecutar Argon2id sin pasar por la caché
+pub(super) fn compute_keystream(
+    password: &str,
+    pepper: Option<&[u8]>,
+    length: usize,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<Vec<u8>> {
+    // Fallar con un error claro antes de reservar la memoria de Argon2id
+    super::feasibility::ensure_kdf_feasible(memory_cost)?;
+
     // Usar un salt fijo derivado de la contraseña para hacer determinista
     let salt_bytes = generate_deterministic_salt(password);
 
@@ -30,11 +199,15 @@ pub fn derive_keystream(
     let params = Params::new(
         memory_cost,
         iterations,
-        1, // parallelism
+        parallelism,
         Some(length),
     ).map_err(|e| SCypherError::crypto(format!("Invalid Argon2 parameters: {:?}", e)))?;
 
-    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+    let argon2 = match pepper {
+        Some(secret) => Argon2::new_with_secret(secret, Algorithm::Argon2id, Version::V0x13, params)
+            .map_err(|e| SCypherError::crypto(format!("Invalid Argon2 secret: {:?}", e)))?,
+        None => Argon2::new(Algorithm::Argon2id, Version::V0x13, params),
+    };
 
     // Implementación real de Argon2id
     let mut keystream = vec![0u8; length];
@@ -48,8 +221,6 @@ pub fn derive_keystream(
 /// Genera un salt determinista basado en la contraseña
 /// Esto asegura que la misma contraseña produzca el mismo resultado
 fn generate_deterministic_salt(password: &str) -> Vec<u8> {
-    use sha2::{Sha256, Digest};
-
     let mut hasher = Sha256::new();
     hasher.update(password.as_bytes());
     hasher.update(b"SCYPHER_SALT_V1"); // Valor constante para consistencia
@@ -58,16 +229,40 @@ fn generate_deterministic_salt(password: &str) -> Vec<u8> {
 }
 
 /// Valida que los parámetros Argon2id estén en rangos seguros
-pub fn validate_argon2_params(iterations: u32, memory_cost: u32) -> Result<()> {
+pub fn validate_argon2_params(iterations: u32, memory_cost: u32, parallelism: u32) -> Result<()> {
     // Validaciones de rango seguro
     if iterations == 0 || iterations > 100 {
         return Err(SCypherError::InvalidIterations(iterations.to_string()));
     }
 
-    if memory_cost < 8192 || memory_cost > 2_097_152 {  // 8MB - 2GB
+    if !(MIN_MEMORY_COST..=MAX_MEMORY_COST).contains(&memory_cost) {
         return Err(SCypherError::InvalidMemoryCost(memory_cost.to_string()));
     }
 
+    if parallelism == 0 || parallelism > MAX_PARALLELISM {
+        return Err(SCypherError::InvalidParallelism(parallelism.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Núcleos de CPU detectados (límite para el paralelismo al cifrar)
+pub fn available_parallelism() -> u32 {
+    std::thread::available_parallelism()
+        .map(|n| n.get() as u32)
+        .unwrap_or(1)
+        .min(MAX_PARALLELISM)
+}
+
+/// Validar el paralelismo elegido para cifrar: no más carriles que núcleos
+pub fn validate_encrypt_parallelism(parallelism: u32) -> Result<()> {
+    let available = available_parallelism();
+    if parallelism > available {
+        return Err(SCypherError::crypto(format!(
+            "Parallelism {} exceeds the {} CPU cores available on this machine",
+            parallelism, available
+        )));
+    }
     Ok(())
 }
 
@@ -78,15 +273,15 @@ mod tests {
 
     #[test]
     fn test_derive_keystream() {
-        let keystream = derive_keystream("test_password", 32, 3, 65536).unwrap();
+        let keystream = derive_keystream("test_password", 32, 3, 65536, 1).unwrap();
         assert_eq!(keystream.len(), 32);
 
         // Debe ser determinista
-        let keystream2 = derive_keystream("test_password", 32, 3, 65536).unwrap();
+        let keystream2 = derive_keystream("test_password", 32, 3, 65536, 1).unwrap();
         assert_eq!(keystream, keystream2);
 
         // Diferente contraseña debe dar resultado diferente
-        let keystream3 = derive_keystream("different_password", 32, 3, 65536).unwrap();
+        let keystream3 = derive_keystream("different_password", 32, 3, 65536, 1).unwrap();
         assert_ne!(keystream, keystream3);
     }
 
@@ -94,21 +289,21 @@ mod tests {
     fn test_password_sensitivity() {
         // Test específico para verificar sensibilidad a cambios en la contraseña
         let base_password = "CONTRASEÑA";
-        let keystream_base = derive_keystream(base_password, 32, 5, 131072).unwrap();
+        let keystream_base = derive_keystream(base_password, 32, 5, 131072, 1).unwrap();
 
         // Cambio al final debe producir resultado diferente
         let changed_end = "CONTRASEÑ8";
-        let keystream_end = derive_keystream(changed_end, 32, 5, 131072).unwrap();
+        let keystream_end = derive_keystream(changed_end, 32, 5, 131072, 1).unwrap();
         assert_ne!(keystream_base, keystream_end, "Cambio al final debe producir keystream diferente");
 
         // Cambio al principio debe producir resultado diferente
         let changed_start = "AONTRASEÑA";
-        let keystream_start = derive_keystream(changed_start, 32, 5, 131072).unwrap();
+        let keystream_start = derive_keystream(changed_start, 32, 5, 131072, 1).unwrap();
         assert_ne!(keystream_base, keystream_start, "Cambio al principio debe producir keystream diferente");
 
         // Cambio en el medio debe producir resultado diferente
         let changed_middle = "CONTRXSEÑA";
-        let keystream_middle = derive_keystream(changed_middle, 32, 5, 131072).unwrap();
+        let keystream_middle = derive_keystream(changed_middle, 32, 5, 131072, 1).unwrap();
         assert_ne!(keystream_base, keystream_middle, "Cambio en el medio debe producir keystream diferente");
 
         // Todos deben ser diferentes entre sí
@@ -117,17 +312,63 @@ mod tests {
         assert_ne!(keystream_start, keystream_middle);
     }
 
+    #[test]
+    fn test_kdf_cache_hit_and_clear() {
+        let password = "kdf_cache_test_password";
+        let first = derive_keystream(password, 16, 1, 8192, 1).unwrap();
+
+        // Un acierto devuelve exactamente el resultado de Argon2id
+        assert_eq!(derive_keystream(password, 16, 1, 8192, 1).unwrap(), first);
+        assert_eq!(compute_keystream(password, None, 16, 1, 8192, 1).unwrap(), first);
+
+        // Otros tests usan la caché en paralelo: solo se comprueba esta clave
+        let is_cached = || {
+            let cache = cache_slot().lock().unwrap();
+            let key = cache_key(&cache.salt, password, None, 16, 1, 8192, 1);
+            cache.entries.iter().any(|entry| entry.key == key)
+        };
+        clear_kdf_cache();
+        assert!(!is_cached());
+        assert!(cache_slot().lock().unwrap().entries.len() <= KDF_CACHE_CAPACITY);
+    }
+
     #[test]
     fn test_validate_argon2_params() {
         // Casos válidos
-        assert!(validate_argon2_params(3, 65536).is_ok());
-        assert!(validate_argon2_params(5, 131072).is_ok());
+        assert!(validate_argon2_params(3, 65536, 1).is_ok());
+        assert!(validate_argon2_params(5, 131072, 1).is_ok());
 
         // Casos inválidos
-        assert!(validate_argon2_params(0, 65536).is_err());
-        assert!(validate_argon2_params(101, 65536).is_err());
-        assert!(validate_argon2_params(5, 4096).is_err());
-        assert!(validate_argon2_params(5, 3_000_000).is_err());
+        assert!(validate_argon2_params(0, 65536, 1).is_err());
+        assert!(validate_argon2_params(101, 65536, 1).is_err());
+        assert!(validate_argon2_params(5, 4096, 1).is_err());
+        assert!(validate_argon2_params(5, 3_000_000, 1).is_err());
+        assert!(validate_argon2_params(5, 131072, 0).is_err());
+        assert!(validate_argon2_params(5, 131072, MAX_PARALLELISM + 1).is_err());
+    }
+
+    #[test]
+    fn test_parallelism_changes_keystream() {
+        let single = derive_keystream("lanes_password", 16, 1, 8192, 1).unwrap();
+        let dual = derive_keystream("lanes_password", 16, 1, 8192, 2).unwrap();
+        assert_ne!(single, dual);
+        assert_eq!(compute_keystream("lanes_password", None, 16, 1, 8192, 2).unwrap(), dual);
+
+        assert!(available_parallelism() >= 1);
+        assert!(validate_encrypt_parallelism(available_parallelism()).is_ok());
+        assert!(validate_encrypt_parallelism(available_parallelism() + 1).is_err());
+    }
+
+    #[test]
+    fn test_pepper_changes_keystream() {
+        let plain = derive_keystream("pepper_password", 16, 1, 8192, 1).unwrap();
+        assert_eq!(derive_keystream_with_pepper("pepper_password", None, 16, 1, 8192, 1).unwrap(), plain);
+
+        // Con pepper el keystream cambia y la caché no lo confunde con el de sin pepper
+        let peppered = derive_keystream_with_pepper("pepper_password", Some(&[7u8; 20]), 16, 1, 8192, 1).unwrap();
+        assert_ne!(peppered, plain);
+        assert_eq!(compute_keystream("pepper_password", Some(&[7u8; 20]), 16, 1, 8192, 1).unwrap(), peppered);
+        assert_ne!(derive_keystream_with_pepper("pepper_password", Some(&[8u8; 20]), 16, 1, 8192, 1).unwrap(), peppered);
     }
 
     #[test]
@@ -3,8 +3,20 @@
 pub mod keystream;
 pub mod xor;
 pub mod checksum;
+pub mod shamir;
+pub mod entropy_analysis;
+pub mod sanity;
+pub mod format;
+pub mod feasibility;
+pub mod compat;
+pub mod hidden;
+pub mod dual;
+pub mod estimate;
+pub mod xor_shares;
 
-use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
 
 /// Función principal para transformar seed phrase usando XOR
 /// Esta es la función que une todos los componentes criptográficos
@@ -14,55 +26,328 @@ pub fn transform_seed(
     iterations: u32,
     memory_cost: u32,
 ) -> Result<String> {
+    transform_seed_with_parallelism(
+        seed_phrase,
+        password,
+        iterations,
+        memory_cost,
+        keystream::DEFAULT_PARALLELISM,
+    )
+}
+
+/// Transformar con un número de carriles Argon2id explícito
+pub fn transform_seed_with_parallelism(
+    seed_phrase: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+) -> Result<String> {
+    transform_seed_with_policy(seed_phrase, password, iterations, memory_cost, parallelism, ChecksumPolicy::Fix)
+        .map(|result| result.phrase)
+}
+
+/// Tratamiento del checksum de la frase de entrada
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumPolicy {
+    /// Rechazar entradas con checksum inválido
+    Strict,
+    /// Ignorar el checksum de entrada y recalcularlo en la salida (avisando si era inválido)
+    #[default]
+    Fix,
+    /// XOR sobre todos los bits (entropía y checksum) sin recalcular, como otras herramientas XOR
+    PassThrough,
+}
+
+/// Resultado de una transformación con política de checksum
+#[derive(Debug, Clone)]
+pub struct PolicyTransform {
+    pub phrase: String,
+    pub checksum_corrected: bool,  // La entrada tenía un checksum inválido (solo Fix)
+}
+
+/// Transformar aplicando una política de checksum explícita
+///
+/// Con `Fix` y `Strict` el XOR se aplica a la entropía y la salida lleva un
+/// checksum válido. Con `PassThrough` se aplica a los 11 bits de cada palabra
+/// (keystream de `ceil(bits / 8)` bytes) y el checksum resultante no es
+/// válido en general, pero un error de transcripción en la entrada se
+/// conserva al descifrar.
+pub fn transform_seed_with_policy(
+    seed_phrase: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<PolicyTransform> {
+    transform_seed_with_pepper(seed_phrase, password, None, iterations, memory_cost, parallelism, policy)
+}
+
+/// Transformar añadiendo a Argon2id el secreto de un token hardware
+///
+/// Sin `pepper` equivale a `transform_seed_with_policy`.
+pub fn transform_seed_with_pepper(
+    seed_phrase: &str,
+    password: &str,
+    pepper: Option<&[u8]>,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<PolicyTransform> {
     // Validar parámetros Argon2id
-    keystream::validate_argon2_params(iterations, memory_cost)?;
+    keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    transform_with_keystream(seed_phrase, policy, |length| {
+        keystream::derive_keystream_with_pepper(password, pepper, length, iterations, memory_cost, parallelism)
+    })
+}
 
-    // Convertir seed phrase a bits usando BIP39
-    let seed_bits = crate::bip39::conversion::phrase_to_bits(seed_phrase)?;
+/// Aplicar la política de checksum con un keystream de la longitud pedida
+///
+/// `derive` recibe el número de bytes necesario; así el control dual
+/// (`crypto::dual`) comparte esta lógica con la contraseña única.
+pub(crate) fn transform_with_keystream(
+    seed_phrase: &str,
+    policy: ChecksumPolicy,
+    derive: impl Fn(usize) -> Result<Vec<u8>>,
+) -> Result<PolicyTransform> {
+    // Palabras abreviadas a 4 letras se expanden antes de extraer la entropía
+    let seed_phrase = crate::bip39::wordlist::expand_abbreviations(seed_phrase)?;
+
+    if policy == ChecksumPolicy::PassThrough {
+        return Ok(PolicyTransform {
+            phrase: transform_full_bits(&seed_phrase, derive)?,
+            checksum_corrected: false,
+        });
+    }
+
+    // Entropía tipada por longitud: el resultado conserva el número de palabras
+    // y el checksum se recalcula para la nueva entropía
+    let entropy = crate::bip39::entropy::AnyBip39Entropy::from_phrase(&seed_phrase)?;
+    let checksum_valid = crate::bip39::validation::validate_checksum(&seed_phrase).is_ok();
+    if policy == ChecksumPolicy::Strict && !checksum_valid {
+        return Err(SCypherError::InvalidChecksum);
+    }
 
-    // Separar entropía y checksum
+    let mut keystream = derive(entropy.as_bytes().len())?;
+    let transformed = entropy.xor_slice(&keystream);
+    keystream.zeroize();
+
+    Ok(PolicyTransform {
+        phrase: transformed?.to_phrase()?,
+        checksum_corrected: !checksum_valid,
+    })
+}
+
+/// XOR sobre los bits completos de la frase (política `PassThrough`)
+fn transform_full_bits(seed_phrase: &str, derive: impl Fn(usize) -> Result<Vec<u8>>) -> Result<String> {
     let word_count = seed_phrase.split_whitespace().count();
-    let entropy_bits = word_count * 32 / 3;  // Bits de entropía según BIP39
-    let checksum_bits = entropy_bits / 32;   // Bits de checksum según BIP39
+    crate::bip39::validation::validate_word_count(word_count)?;
 
-    if seed_bits.len() != entropy_bits + checksum_bits {
-        return Err(crate::error::SCypherError::crypto(
-            "Invalid seed phrase bit length".to_string()
-        ));
+    let bits = crate::bip39::conversion::phrase_to_bits(seed_phrase)?;
+    let mut bytes = checksum::bits_to_bytes_padded(&bits);
+    let mut keystream = derive(bytes.len())?;
+    for (byte, key) in bytes.iter_mut().zip(keystream.iter()) {
+        *byte ^= key;
+    }
+    keystream.zeroize();
+
+    let extracted = checksum::extract_bits(&bytes, 0, bits.len());
+    bytes.zeroize();
+    crate::bip39::conversion::bits_to_phrase(&extracted?)
+}
+
+/// Resultado de transformar entropía en bruto
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntropyTransform {
+    pub entropy_hex: String,
+    pub phrase: Option<String>,  // Frase BIP39 equivalente (solo si se pidió)
+}
+
+/// Transformar entropía en hexadecimal sin pasar por palabras
+///
+/// Acepta 16, 20, 24, 28 o 32 bytes (con o sin prefijo `0x`). Usa el mismo
+/// keystream Argon2id que la transformación de frases, así que el resultado
+/// equivale a transformar la frase correspondiente.
+pub fn transform_entropy(
+    entropy_hex: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    include_phrase: bool,
+) -> Result<EntropyTransform> {
+    keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    let trimmed = entropy_hex.trim();
+    let digits = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")).unwrap_or(trimmed);
+    let mut bytes = hex::decode(digits)
+        .map_err(|e| SCypherError::crypto(format!("Invalid entropy hex: {}", e)))?;
+
+    let entropy = crate::bip39::entropy::AnyBip39Entropy::from_slice(&bytes);
+    bytes.zeroize();
+
+    let transformed = entropy?.transform(password, iterations, memory_cost, parallelism)?;
+    Ok(EntropyTransform {
+        entropy_hex: hex::encode(transformed.as_bytes()),
+        phrase: if include_phrase { Some(transformed.to_phrase()?) } else { None },
+    })
+}
+
+/// Comprobar que el resultado de una transformación revierte a la frase original
+///
+/// XOR es simétrico: transformar el resultado con la misma contraseña y la
+/// misma política de checksum debe devolver exactamente la entrada (con `Fix`,
+/// la entrada con el checksum ya corregido). La comparación es de tiempo constante.
+pub fn verify_roundtrip_with_policy(
+    original: &str,
+    transformed: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<bool> {
+    verify_roundtrip_with_pepper(original, transformed, password, None, iterations, memory_cost, parallelism, policy)
+}
+
+/// Comprobar la ida y vuelta con el mismo secreto de token hardware
+#[allow(clippy::too_many_arguments)]
+pub fn verify_roundtrip_with_pepper(
+    original: &str,
+    transformed: &str,
+    password: &str,
+    pepper: Option<&[u8]>,
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    policy: ChecksumPolicy,
+) -> Result<bool> {
+    let reversed = transform_seed_with_pepper(transformed, password, pepper, iterations, memory_cost, parallelism, policy)?.phrase;
+    roundtrip_matches(original, &reversed, policy)
+}
+
+/// Comparar en tiempo constante la frase revertida con la entrada normalizada
+pub(crate) fn roundtrip_matches(original: &str, reversed: &str, policy: ChecksumPolicy) -> Result<bool> {
+    let mut normalized = crate::bip39::wordlist::expand_abbreviations(original)?;
+    if policy == ChecksumPolicy::Fix {
+        normalized = crate::bip39::conversion::validate_and_fix_checksum(&normalized)?;
     }
 
-    // Extraer SOLO la parte de entropía (ignorar checksum actual)
-    let entropy_part = &seed_bits[0..entropy_bits];
+    Ok(crate::security::utils::constant_time_eq(
+        normalized.as_bytes(),
+        reversed.as_bytes(),
+    ))
+}
 
-    // Convertir entropía a bytes para XOR
-    let entropy_bytes = crate::crypto::checksum::bits_to_bytes_padded(entropy_part);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Generar keystream del tamaño de la entropía
-    let keystream = keystream::derive_keystream(password, entropy_bytes.len(), iterations, memory_cost)?;
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
 
-    // Aplicar XOR solo a la entropía
-    let encrypted_entropy_bytes = xor::xor_data(&entropy_bytes, &keystream)?;
+    #[test]
+    fn test_verify_roundtrip() {
+        let transformed = transform_seed(PHRASE, "roundtrip_password", 1, 8192).unwrap();
+        assert!(verify_roundtrip_with_policy(PHRASE, &transformed, "roundtrip_password", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+        assert!(!verify_roundtrip_with_policy(PHRASE, &transformed, "other_password", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+        assert!(!verify_roundtrip_with_policy(PHRASE, &transformed, "roundtrip_password", 1, 8192, 2, ChecksumPolicy::Fix).unwrap());
+    }
 
-    // Convertir entropía cifrada de vuelta a bits
-    let mut encrypted_entropy_bits = Vec::new();
-    for byte in &encrypted_entropy_bytes {
-        for i in (0..8).rev() {
-            encrypted_entropy_bits.push((byte >> i) & 1 == 1);
-        }
+    #[test]
+    fn test_transform_entropy_matches_phrase_flow() {
+        let result = transform_entropy("0x00000000000000000000000000000000", "entropy_password", 1, 8192, 1, true).unwrap();
+        let phrase = transform_seed(PHRASE, "entropy_password", 1, 8192).unwrap();
+        assert_eq!(result.phrase.as_deref(), Some(phrase.as_str()));
+        assert_eq!(result.entropy_hex, crate::bip39::phrase_to_hex(&phrase).unwrap());
+
+        // XOR simétrico también sobre la entropía
+        let reversed = transform_entropy(&result.entropy_hex, "entropy_password", 1, 8192, 1, false).unwrap();
+        assert_eq!(reversed.entropy_hex, "0".repeat(32));
+        assert!(reversed.phrase.is_none());
+
+        assert!(transform_entropy("00ff", "entropy_password", 1, 8192, 1, false).is_err());
+        assert!(transform_entropy("zz".repeat(16).as_str(), "entropy_password", 1, 8192, 1, false).is_err());
     }
 
-    // Truncar a la longitud exacta de entropía
-    encrypted_entropy_bits.truncate(entropy_bits);
+    #[test]
+    fn test_checksum_policies() {
+        // "abandon" x12 tiene checksum inválido (la palabra correcta es "about")
+        let invalid = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+
+        assert!(matches!(
+            transform_seed_with_policy(invalid, "policy_password", 1, 8192, 1, ChecksumPolicy::Strict),
+            Err(SCypherError::InvalidChecksum)
+        ));
+        assert!(!transform_seed_with_policy(PHRASE, "policy_password", 1, 8192, 1, ChecksumPolicy::Strict)
+            .unwrap()
+            .checksum_corrected);
 
-    // Recalcular checksum BIP39 para la nueva entropía
-    let new_checksum_bits = crate::crypto::checksum::recalculate_bip39_checksum(&encrypted_entropy_bits)?;
+        // Fix: misma entropía que la frase válida y aviso de corrección
+        let fixed = transform_seed_with_policy(invalid, "policy_password", 1, 8192, 1, ChecksumPolicy::Fix).unwrap();
+        assert!(fixed.checksum_corrected);
+        assert_eq!(fixed.phrase, transform_seed(PHRASE, "policy_password", 1, 8192).unwrap());
+        assert!(verify_roundtrip_with_policy(invalid, &fixed.phrase, "policy_password", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+    }
 
-    // Combinar entropía cifrada + nuevo checksum
-    let mut final_bits = encrypted_entropy_bits;
-    final_bits.extend(new_checksum_bits);
+    #[test]
+    fn test_pass_through_preserves_input_bits() {
+        let invalid = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        let policy = ChecksumPolicy::PassThrough;
 
-    // Convertir de vuelta a seed phrase BIP39
-    let result_phrase = crate::bip39::conversion::bits_to_phrase(&final_bits)?;
+        let transformed = transform_seed_with_policy(invalid, "policy_password", 1, 8192, 1, policy).unwrap();
+        assert!(!transformed.checksum_corrected);
+        assert_eq!(transformed.phrase.split_whitespace().count(), 12);
 
-    Ok(result_phrase)
+        // Al revertir vuelve la entrada exacta, con su checksum erróneo
+        let reversed = transform_seed_with_policy(&transformed.phrase, "policy_password", 1, 8192, 1, policy).unwrap();
+        assert_eq!(reversed.phrase, invalid);
+        assert!(verify_roundtrip_with_policy(invalid, &transformed.phrase, "policy_password", 1, 8192, 1, policy).unwrap());
+    }
+
+    #[test]
+    fn test_abbreviated_input() {
+        let abbreviated = "aban aban aban aban aban aban aban aban aban aban aban abou";
+        let transformed = transform_seed(abbreviated, "roundtrip_password", 1, 8192).unwrap();
+        assert_eq!(transformed, transform_seed(PHRASE, "roundtrip_password", 1, 8192).unwrap());
+        assert!(verify_roundtrip_with_policy(abbreviated, &transformed, "roundtrip_password", 1, 8192, 1, ChecksumPolicy::Fix).unwrap());
+        assert!(crate::bip39::validate_seed_phrase(abbreviated).is_ok());
+    }
+
+    // Propiedades (proptest): la transformación es reversible para todas las longitudes
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn valid_entropy() -> impl Strategy<Value = Vec<u8>> {
+            prop::sample::select(vec![16usize, 20, 24, 28, 32])
+                .prop_flat_map(|len| prop::collection::vec(any::<u8>(), len))
+        }
+
+        proptest! {
+            // Cada caso ejecuta Argon2id; pocos casos con parámetros mínimos
+            #![proptest_config(ProptestConfig::with_cases(16))]
+
+            #[test]
+            fn prop_transform_roundtrip_all_word_counts(
+                entropy in valid_entropy(),
+                password in "[ -~]{1,32}",
+            ) {
+                let phrase = crate::bip39::conversion::entropy_to_phrase(&entropy).unwrap();
+                let transformed = transform_seed(&phrase, &password, 1, 8192).unwrap();
+
+                // Misma longitud y checksum BIP39 válido
+                prop_assert_eq!(
+                    transformed.split_whitespace().count(),
+                    phrase.split_whitespace().count()
+                );
+                prop_assert!(crate::bip39::verify_checksum(&transformed).unwrap());
+
+                prop_assert_eq!(transform_seed(&transformed, &password, 1, 8192).unwrap(), phrase);
+            }
+        }
+    }
 }
@@ -0,0 +1,135 @@
+//! Intención explícita de cifrado/descifrado y comprobaciones del resultado
+//!
+//! XOR es simétrico, así que la misma operación cifra y descifra. Cuando el
+//! usuario indica que está descifrando, el resultado debería ser su frase
+//! original: aquí se detectan resultados que no lo parecen (frases de prueba
+//! conocidas, palabras repetidas, entropía con patrones).
+//!
+//! Una contraseña equivocada produce una frase aleatoria válida que ninguna
+//! heurística puede distinguir de la real; estas advertencias solo cubren
+//! los casos sospechosos.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Dirección que el usuario cree estar aplicando
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransformMode {
+    Encrypt,
+    Decrypt,
+    #[default]
+    Auto,
+}
+
+/// Advertencia estructurada sobre el resultado
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputWarning {
+    pub code: String,     // "known_test_phrase", "repeated_words", "low_entropy", "checksum_corrected"
+    pub message: String,
+}
+
+/// Aviso para entradas cuyo checksum se corrigió (política `Fix`)
+pub fn checksum_corrected_warning() -> OutputWarning {
+    OutputWarning {
+        code: "checksum_corrected".to_string(),
+        message: "The input checksum was invalid and has been recalculated; check the phrase for transcription errors".to_string(),
+    }
+}
+
+/// Frases públicas de vectores de prueba y herramientas de desarrollo
+const KNOWN_TEST_PHRASES: &[&str] = &[
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+    "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+    "test test test test test test test test test test test junk",
+];
+
+fn warning(code: &str, message: &str) -> OutputWarning {
+    OutputWarning {
+        code: code.to_string(),
+        message: message.to_string(),
+    }
+}
+
+/// Comprobar si una frase parece una seed real del usuario
+pub fn check_plausibility(phrase: &str) -> Vec<OutputWarning> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let normalized = words.join(" ");
+    let mut warnings = Vec::new();
+
+    if KNOWN_TEST_PHRASES.contains(&normalized.as_str()) {
+        warnings.push(warning(
+            "known_test_phrase",
+            "The result is a public test phrase; it is almost certainly not your seed",
+        ));
+    }
+
+    // En una frase aleatoria una palabra casi nunca aparece tres veces
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in &words {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    if counts.values().any(|&count| count >= 3) {
+        warnings.push(warning(
+            "repeated_words",
+            "The result repeats a word three or more times, which is unusual for a real seed",
+        ));
+    }
+
+    // Entropía con muy pocos bytes distintos (ceros, patrones repetidos)
+    if let Ok(entropy) = crate::bip39::phrase_to_entropy(&normalized) {
+        let mut distinct = entropy.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() * 4 <= entropy.len() {
+            warnings.push(warning(
+                "low_entropy",
+                "The result's entropy has a repeating pattern; check the password",
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Advertencias sobre un resultado según la intención declarada
+pub fn check_output(mode: TransformMode, output: &str) -> Vec<OutputWarning> {
+    match mode {
+        TransformMode::Decrypt => check_plausibility(output),
+        TransformMode::Encrypt | TransformMode::Auto => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_known_and_patterned_phrases() {
+        let codes = |phrase: &str| -> Vec<String> {
+            check_plausibility(phrase).into_iter().map(|w| w.code).collect()
+        };
+
+        let abandon = codes(KNOWN_TEST_PHRASES[0]);
+        assert!(abandon.contains(&"known_test_phrase".to_string()));
+        assert!(abandon.contains(&"repeated_words".to_string()));
+        assert!(abandon.contains(&"low_entropy".to_string()));
+
+        // "legal winner..." es entropía 0x7f repetida: sin palabras triplicadas
+        let legal = codes(KNOWN_TEST_PHRASES[2]);
+        assert!(legal.contains(&"low_entropy".to_string()));
+        assert!(!legal.contains(&"repeated_words".to_string()));
+    }
+
+    #[test]
+    fn test_decrypt_mode_only() {
+        let random = "cat swing flag economy stadium alone churn speed unique patch report train";
+        assert!(check_output(TransformMode::Decrypt, random).is_empty());
+        assert!(check_output(TransformMode::Encrypt, KNOWN_TEST_PHRASES[0]).is_empty());
+        assert!(!check_output(TransformMode::Decrypt, KNOWN_TEST_PHRASES[0]).is_empty());
+    }
+}
@@ -0,0 +1,342 @@
+//! Reparto de secretos de Shamir sobre la contraseña
+//!
+//! A diferencia de repartir la frase semilla, este módulo divide la
+//! *contraseña* de descifrado en N partes con umbral K (GF(2^8)), para que
+//! varias personas custodien fragmentos sin que ninguna tenga el secreto
+//! completo. Cada parte se codifica en hexadecimal o en palabras BIP39
+//! e incluye una suma de verificación corta para detectar errores de copia.
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Longitud de la cabecera codificada: umbral + índice + longitud
+const SHARE_HEADER_LENGTH: usize = 3;
+
+/// Longitud de la suma de verificación de cada parte
+const SHARE_CHECKSUM_LENGTH: usize = 2;
+
+/// Prefijo de las partes en formato hexadecimal
+const HEX_SHARE_PREFIX: &str = "scs1-";
+
+/// Formato de codificación de las partes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareEncoding {
+    Hex,
+    Words,
+}
+
+impl ShareEncoding {
+    /// Interpretar el formato recibido desde la GUI ("hex" o "words")
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "hex" => Ok(ShareEncoding::Hex),
+            "words" => Ok(ShareEncoding::Words),
+            other => Err(SCypherError::crypto(format!("Unknown share encoding: {}", other))),
+        }
+    }
+}
+
+/// Una parte del secreto: punto (x = índice, y = datos) del polinomio
+#[derive(Debug, Clone)]
+pub struct SecretShare {
+    pub threshold: u8,
+    pub index: u8,
+    pub data: Vec<u8>,
+}
+
+impl Drop for SecretShare {
+    fn drop(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+/// Multiplicación en GF(2^8) con polinomio de reducción 0x11B (sin ramas)
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = a >> 7;
+        a <<= 1;
+        a ^= 0x1B & 0u8.wrapping_sub(carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Inverso multiplicativo en GF(2^8): a^254
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Dividir un secreto en `total` partes de las que bastan `threshold`
+pub fn split_secret(secret: &[u8], threshold: u8, total: u8) -> Result<Vec<SecretShare>> {
+    if secret.is_empty() || secret.len() > u8::MAX as usize {
+        return Err(SCypherError::crypto("Secret must be between 1 and 255 bytes".to_string()));
+    }
+    if threshold < 2 || threshold > total {
+        return Err(SCypherError::crypto(format!(
+            "Invalid threshold {} of {} (need 2 <= threshold <= shares)",
+            threshold, total
+        )));
+    }
+
+    let mut shares: Vec<SecretShare> = (1..=total)
+        .map(|index| SecretShare { threshold, index, data: Vec::with_capacity(secret.len()) })
+        .collect();
+
+    // Un polinomio aleatorio de grado threshold-1 por cada byte del secreto
+    let mut coefficients = vec![0u8; threshold as usize];
+    for &byte in secret {
+        coefficients[0] = byte;
+        let random = crate::security::utils::secure_random_bytes(threshold as usize - 1);
+        coefficients[1..].copy_from_slice(&random);
+
+        for share in shares.iter_mut() {
+            // Evaluación de Horner en x = índice
+            let mut value = 0u8;
+            for &coefficient in coefficients.iter().rev() {
+                value = gf_mul(value, share.index) ^ coefficient;
+            }
+            share.data.push(value);
+        }
+    }
+    coefficients.zeroize();
+
+    Ok(shares)
+}
+
+/// Reconstruir el secreto a partir de al menos `threshold` partes
+pub fn combine_shares(shares: &[SecretShare]) -> Result<Vec<u8>> {
+    let first = shares
+        .first()
+        .ok_or_else(|| SCypherError::crypto("No shares provided".to_string()))?;
+
+    let threshold = first.threshold as usize;
+    let length = first.data.len();
+
+    if shares.iter().any(|s| s.threshold != first.threshold || s.data.len() != length) {
+        return Err(SCypherError::crypto("Shares belong to different secrets".to_string()));
+    }
+    if shares.iter().any(|s| s.index == 0) {
+        return Err(SCypherError::crypto("Invalid share index".to_string()));
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if shares[..i].iter().any(|other| other.index == share.index) {
+            return Err(SCypherError::crypto(format!("Duplicate share #{}", share.index)));
+        }
+    }
+    if shares.len() < threshold {
+        return Err(SCypherError::crypto(format!(
+            "Not enough shares: {} provided, {} required",
+            shares.len(),
+            threshold
+        )));
+    }
+
+    let used = &shares[..threshold];
+
+    // Interpolación de Lagrange en x = 0
+    let mut secret = vec![0u8; length];
+    for (i, share) in used.iter().enumerate() {
+        let mut basis = 1u8;
+        for (j, other) in used.iter().enumerate() {
+            if i != j {
+                // l_i(0) = prod x_j / (x_j - x_i); en GF(2^8) la resta es XOR
+                basis = gf_mul(basis, gf_mul(other.index, gf_inv(other.index ^ share.index)));
+            }
+        }
+        for (byte, &y) in secret.iter_mut().zip(share.data.iter()) {
+            *byte ^= gf_mul(y, basis);
+        }
+    }
+
+    Ok(secret)
+}
+
+/// Suma de verificación corta de una parte serializada
+fn share_checksum(bytes: &[u8]) -> [u8; SHARE_CHECKSUM_LENGTH] {
+    let digest = Sha256::digest(bytes);
+    [digest[0], digest[1]]
+}
+
+/// Serializar: umbral | índice | longitud | datos | checksum
+fn share_to_bytes(share: &SecretShare) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(SHARE_HEADER_LENGTH + share.data.len() + SHARE_CHECKSUM_LENGTH);
+    bytes.push(share.threshold);
+    bytes.push(share.index);
+    bytes.push(share.data.len() as u8);
+    bytes.extend_from_slice(&share.data);
+    let checksum = share_checksum(&bytes);
+    bytes.extend_from_slice(&checksum);
+    bytes
+}
+
+/// Deserializar y verificar una parte
+fn share_from_bytes(bytes: &[u8]) -> Result<SecretShare> {
+    if bytes.len() < SHARE_HEADER_LENGTH + 1 + SHARE_CHECKSUM_LENGTH {
+        return Err(SCypherError::crypto("Share is too short".to_string()));
+    }
+
+    let length = bytes[2] as usize;
+    let total = SHARE_HEADER_LENGTH + length + SHARE_CHECKSUM_LENGTH;
+
+    // La codificación en palabras puede añadir como mucho un byte de relleno a cero
+    if bytes.len() < total || bytes[total..].iter().any(|&b| b != 0) || bytes.len() > total + 1 {
+        return Err(SCypherError::crypto("Share has an invalid length".to_string()));
+    }
+
+    let (body, checksum) = bytes[..total].split_at(total - SHARE_CHECKSUM_LENGTH);
+    if share_checksum(body) != checksum {
+        return Err(SCypherError::crypto("Share checksum mismatch (check for typos)".to_string()));
+    }
+
+    Ok(SecretShare {
+        threshold: body[0],
+        index: body[1],
+        data: body[SHARE_HEADER_LENGTH..].to_vec(),
+    })
+}
+
+/// Codificar una parte como texto
+pub fn encode_share(share: &SecretShare, encoding: ShareEncoding) -> String {
+    let mut bytes = share_to_bytes(share);
+
+    let encoded = match encoding {
+        ShareEncoding::Hex => format!("{}{}", HEX_SHARE_PREFIX, hex::encode(&bytes)),
+        ShareEncoding::Words => {
+            // Grupos de 11 bits -> palabras BIP39 (relleno con ceros al final)
+            let mut bits = crate::crypto::xor::bytes_to_bits(&bytes);
+            while !bits.len().is_multiple_of(11) {
+                bits.push(false);
+            }
+            bits.chunks(11)
+                .map(|chunk| {
+                    let index = chunk.iter().fold(0usize, |acc, &bit| (acc << 1) | bit as usize);
+                    crate::bip39::BIP39_WORDLIST[index]
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    };
+
+    bytes.zeroize();
+    encoded
+}
+
+/// Decodificar una parte en hexadecimal o palabras (detección automática)
+pub fn decode_share(text: &str) -> Result<SecretShare> {
+    let text = text.trim();
+
+    let mut bytes = if let Some(hex_part) = text.strip_prefix(HEX_SHARE_PREFIX) {
+        hex::decode(hex_part)
+            .map_err(|_| SCypherError::crypto("Share is not valid hex".to_string()))?
+    } else {
+        let mut bits = Vec::new();
+        for word in text.split_whitespace() {
+            let word = word.to_lowercase();
+            let index = crate::bip39::wordlist::word_to_index(&word)
+                .ok_or_else(|| SCypherError::InvalidBip39Word(word.clone()))?;
+            for i in (0..11).rev() {
+                bits.push((index >> i) & 1 == 1);
+            }
+        }
+        bits.truncate(bits.len() / 8 * 8);
+        crate::crypto::xor::bits_to_bytes(&bits)
+    };
+
+    let share = share_from_bytes(&bytes);
+    bytes.zeroize();
+    share
+}
+
+/// Dividir una contraseña en partes codificadas
+pub fn split_password(
+    password: &str,
+    threshold: u8,
+    total: u8,
+    encoding: ShareEncoding,
+) -> Result<Vec<String>> {
+    let shares = split_secret(password.as_bytes(), threshold, total)?;
+    Ok(shares.iter().map(|share| encode_share(share, encoding)).collect())
+}
+
+/// Reconstruir la contraseña a partir de partes codificadas
+pub fn combine_password(encoded_shares: &[String]) -> Result<String> {
+    let shares = encoded_shares
+        .iter()
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| decode_share(s))
+        .collect::<Result<Vec<_>>>()?;
+
+    let secret = combine_shares(&shares)?;
+
+    String::from_utf8(secret).map_err(|e| {
+        let mut bytes = e.into_bytes();
+        bytes.zeroize();
+        SCypherError::crypto("Recovered password is not valid UTF-8".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_inverse() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+
+    #[test]
+    fn test_split_combine_any_subset() {
+        let secret = b"correct horse battery staple";
+        let shares = split_secret(secret, 3, 5).unwrap();
+
+        assert_eq!(combine_shares(&shares[0..3]).unwrap(), secret);
+        assert_eq!(combine_shares(&shares[2..5]).unwrap(), secret);
+
+        let subset = vec![shares[4].clone(), shares[0].clone(), shares[3].clone()];
+        assert_eq!(combine_shares(&subset).unwrap(), secret);
+
+        // Menos partes que el umbral no deben reconstruir nada
+        assert!(combine_shares(&shares[0..2]).is_err());
+    }
+
+    #[test]
+    fn test_password_roundtrip_both_encodings() {
+        for encoding in [ShareEncoding::Hex, ShareEncoding::Words] {
+            let shares = split_password("my_secure_password", 2, 3, encoding).unwrap();
+            let picked = vec![shares[2].clone(), shares[0].clone()];
+            assert_eq!(combine_password(&picked).unwrap(), "my_secure_password");
+        }
+    }
+
+    #[test]
+    fn test_corrupted_share_detected() {
+        let shares = split_password("my_secure_password", 2, 3, ShareEncoding::Hex).unwrap();
+        let mut corrupted = shares[0].clone();
+        let last = corrupted.pop().unwrap();
+        corrupted.push(if last == '0' { '1' } else { '0' });
+
+        assert!(combine_password(&[corrupted, shares[1].clone()]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_parameters_rejected() {
+        assert!(split_secret(b"secret", 1, 3).is_err());
+        assert!(split_secret(b"secret", 4, 3).is_err());
+        assert!(split_secret(b"", 2, 3).is_err());
+    }
+}
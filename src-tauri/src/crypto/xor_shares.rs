@@ -0,0 +1,122 @@
+//! División de una frase en partes XOR (cada parte es una frase BIP39 válida)
+//!
+//! Se generan K-1 entropías aleatorias del mismo tamaño y la última parte es
+//! la entropía original XOR todas ellas; el checksum de cada parte se calcula
+//! de nuevo, así que cualquier wallet BIP39 las acepta. Hacen falta todas las
+//! partes para reconstruir: con K-1 la frase original es indistinguible del
+//! azar. Es el mismo esquema que Seed XOR de Coldcard (XOR de la entropía).
+
+use zeroize::Zeroize;
+use crate::bip39::entropy::AnyBip39Entropy;
+use crate::error::{SCypherError, Result};
+use super::xor::xor_data;
+
+/// Rango aceptado de partes
+pub const MIN_XOR_SHARES: usize = 2;
+pub const MAX_XOR_SHARES: usize = 16;
+
+/// Entropía de una frase con checksum válido
+fn checked_entropy(phrase: &str) -> Result<AnyBip39Entropy> {
+    let phrase = crate::bip39::wordlist::expand_abbreviations(phrase)?;
+    crate::bip39::validation::validate_seed_phrase(&phrase)?;
+    AnyBip39Entropy::from_phrase(&phrase)
+}
+
+/// Dividir la frase en `shares` frases cuyo XOR es la original
+pub fn split_seed_xor(phrase: &str, shares: usize) -> Result<Vec<String>> {
+    if !(MIN_XOR_SHARES..=MAX_XOR_SHARES).contains(&shares) {
+        return Err(SCypherError::crypto(format!(
+            "Number of XOR shares must be between {} and {}",
+            MIN_XOR_SHARES, MAX_XOR_SHARES
+        )));
+    }
+
+    let entropy = checked_entropy(phrase)?;
+    let mut last = entropy.as_bytes().to_vec();
+    let mut phrases = Vec::with_capacity(shares);
+
+    for _ in 1..shares {
+        let mut random = crate::security::utils::secure_random_bytes(last.len());
+        let combined = xor_data(&last, &random)?;
+        last.zeroize();
+        last = combined;
+
+        phrases.push(AnyBip39Entropy::from_slice(&random)?.to_phrase()?);
+        random.zeroize();
+    }
+
+    let final_share = AnyBip39Entropy::from_slice(&last);
+    last.zeroize();
+    phrases.push(final_share?.to_phrase()?);
+    Ok(phrases)
+}
+
+/// Reconstruir la frase con el XOR de todas las partes (en cualquier orden)
+pub fn combine_seed_xor(shares: &[String]) -> Result<String> {
+    if !(MIN_XOR_SHARES..=MAX_XOR_SHARES).contains(&shares.len()) {
+        return Err(SCypherError::crypto(format!(
+            "Between {} and {} XOR shares are required",
+            MIN_XOR_SHARES, MAX_XOR_SHARES
+        )));
+    }
+
+    let mut combined = checked_entropy(&shares[0])?;
+    for (index, share) in shares.iter().enumerate().skip(1) {
+        let entropy = checked_entropy(share)?;
+        if entropy.word_count() != combined.word_count() {
+            return Err(SCypherError::crypto(format!(
+                "Share {} has {} words but share 1 has {}",
+                index + 1,
+                entropy.word_count(),
+                combined.word_count()
+            )));
+        }
+        combined = combined.xor_slice(entropy.as_bytes())?;
+    }
+
+    combined.to_phrase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_split_and_combine() {
+        let phrase_24 = "legal winner thank year wave sausage worth useful legal winner thank year \
+                         wave sausage worth useful legal winner thank year wave sausage worth title";
+        for (phrase, count) in [(PHRASE, 2), (phrase_24, 3), (PHRASE, MAX_XOR_SHARES)] {
+            let shares = split_seed_xor(phrase, count).unwrap();
+            assert_eq!(shares.len(), count);
+            assert!(shares.iter().all(|share| crate::bip39::validation::validate_seed_phrase(share).is_ok()));
+
+            // El orden no importa; faltando una parte no se recupera
+            let mut reversed = shares.clone();
+            reversed.reverse();
+            assert_eq!(combine_seed_xor(&reversed).unwrap(), phrase);
+            if count > 2 {
+                assert_ne!(combine_seed_xor(&shares[1..]).unwrap(), phrase);
+            }
+        }
+    }
+
+    #[test]
+    fn test_combine_validation() {
+        // La entropía cero es el elemento neutro del XOR
+        let other = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert_eq!(combine_seed_xor(&[PHRASE.to_string(), other.to_string()]).unwrap(), other);
+
+        assert!(split_seed_xor(PHRASE, 1).is_err());
+        assert!(combine_seed_xor(&[PHRASE.to_string()]).is_err());
+
+        let wrong_length = "legal winner thank year wave sausage worth useful legal winner thank year \
+                            wave sausage worth useful legal winner thank year wave sausage worth title";
+        assert!(combine_seed_xor(&[PHRASE.to_string(), wrong_length.to_string()]).is_err());
+
+        // Una parte con checksum inválido se rechaza
+        let bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(combine_seed_xor(&[PHRASE.to_string(), bad_checksum.to_string()]).is_err());
+    }
+}
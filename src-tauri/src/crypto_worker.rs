@@ -0,0 +1,488 @@
+// =============================================================================
+// PROCESO AISLADO DE CIFRADO
+// Todo Argon2id con secretos del usuario se ejecuta en un proceso hijo de un
+// solo uso: la transformación de frases (cifrar, descifrar, control dual,
+// contenedores ocultos y Bash v2, con verificación de ida y vuelta), la de
+// entropía en hexadecimal, la creación de contenedores ocultos y las claves
+// de la exportación esteganográfica. El hijo es el mismo ejecutable con
+// `--crypto-worker`, sin entorno heredado, sin descriptores heredados y
+// encerrado por `security::sandbox` (seccomp en Linux, job object en
+// Windows). Protocolo: una petición y una respuesta por stdin/stdout con las
+// tramas de `ipc` (longitud de 4 bytes + JSON). El keystream, la memoria de
+// Argon2id y el pepper del token solo existen en el hijo, que termina al
+// responder.
+//
+// La frase de entrada y la de salida sí pasan por el proceso de la GUI (llegan
+// de la vista web y vuelven a ella); ahí solo viajan y se borran al terminar.
+// Quedan fuera la calibración de Argon2id (sin secretos) y la API de la
+// biblioteca (`lib.rs`), que no lanza procesos.
+// Se desactiva con `SCYPHER_CRYPTO_WORKER=0` o desde la GUI (con consentimiento).
+// =============================================================================
+
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::crypto::compat::CompatibilityMode;
+use crate::crypto::hidden::HiddenVolume;
+use crate::crypto::{EntropyTransform, PolicyTransform};
+use crate::error::{SCypherError, Result};
+use crate::pipeline::TransformParams;
+use crate::security::SecureBytes;
+use crate::stego::StegoKeys;
+
+/// Argumento con el que el ejecutable arranca como proceso aislado
+pub const WORKER_FLAG: &str = "--crypto-worker";
+
+/// Variable de entorno que desactiva el proceso aislado (0, false, no u off)
+const WORKER_ENV_VAR: &str = "SCYPHER_CRYPTO_WORKER";
+
+// Desactivado desde la GUI (por defecto se usa el proceso aislado)
+static WORKER_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Activar o desactivar el proceso aislado para las siguientes transformaciones
+///
+/// El cambio queda en el historial de la sesión (y en el registro de
+/// auditoría si está activado); desactivarlo exige consentimiento en `commands`.
+pub fn set_crypto_worker_enabled(enabled: bool) {
+    let was_disabled = WORKER_DISABLED.swap(!enabled, Ordering::Relaxed);
+    if was_disabled != enabled {
+        return;
+    }
+    if enabled {
+        tracing::info!("Proceso aislado de cifrado activado");
+    } else {
+        tracing::warn!("Proceso aislado de cifrado desactivado: las transformaciones se ejecutan en el proceso principal");
+    }
+    let operation = if enabled { "crypto_worker_enabled" } else { "crypto_worker_disabled" };
+    crate::diagnostics::record_operation(operation, None, std::time::Instant::now(), None);
+}
+
+/// Proceso aislado activo salvo que la GUI o `SCYPHER_CRYPTO_WORKER` lo desactiven
+pub fn crypto_worker_enabled() -> bool {
+    !WORKER_DISABLED.load(Ordering::Relaxed)
+        && !std::env::var(WORKER_ENV_VAR)
+            .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no" | "off"))
+            .unwrap_or(false)
+}
+
+/// Transformación de una frase
+#[derive(Serialize, Deserialize)]
+pub struct PhraseRequest {
+    pub phrase: String,
+    pub password: String,
+    #[serde(default)]
+    pub second_password: Option<String>,
+    pub params: TransformParams,
+    #[serde(default)]
+    pub verify: bool,
+}
+
+impl Drop for PhraseRequest {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.password.zeroize();
+        if let Some(second) = self.second_password.as_mut() {
+            second.zeroize();
+        }
+    }
+}
+
+/// Transformación de entropía en hexadecimal
+#[derive(Serialize, Deserialize)]
+pub struct EntropyRequest {
+    pub entropy_hex: String,
+    pub password: String,
+    pub iterations: u32,
+    pub memory_cost: u32,
+    pub parallelism: u32,
+    #[serde(default)]
+    pub include_phrase: bool,
+}
+
+impl Drop for EntropyRequest {
+    fn drop(&mut self) {
+        self.entropy_hex.zeroize();
+        self.password.zeroize();
+    }
+}
+
+/// Creación de un contenedor de volúmenes ocultos (cada volumen se borra solo)
+#[derive(Serialize, Deserialize)]
+pub struct HiddenContainerRequest {
+    pub volumes: Vec<HiddenVolume>,
+    pub iterations: u32,
+    pub memory_cost: u32,
+    pub parallelism: u32,
+}
+
+/// Claves de la exportación esteganográfica
+#[derive(Serialize, Deserialize)]
+pub struct StegoKeyRequest {
+    pub password: String,
+    pub iterations: u32,
+    pub memory_cost: u32,
+}
+
+impl Drop for StegoKeyRequest {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+/// Petición al proceso aislado
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum WorkerRequest {
+    Transform(PhraseRequest),
+    Entropy(EntropyRequest),
+    HiddenContainer(HiddenContainerRequest),
+    StegoKeys(StegoKeyRequest),
+}
+
+/// Resultado de cada tipo de petición
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+enum WorkerReply {
+    Transform {
+        phrase: String,
+        #[serde(default)]
+        checksum_corrected: bool,
+        #[serde(default)]
+        verified: Option<bool>,
+    },
+    Entropy(EntropyTransform),
+    HiddenContainer { phrase: String },
+    StegoKeys(StegoKeys),
+}
+
+impl Drop for WorkerReply {
+    fn drop(&mut self) {
+        match self {
+            WorkerReply::Transform { phrase, .. } | WorkerReply::HiddenContainer { phrase } => phrase.zeroize(),
+            WorkerReply::Entropy(result) => {
+                result.entropy_hex.zeroize();
+                if let Some(phrase) = result.phrase.as_mut() {
+                    phrase.zeroize();
+                }
+            }
+            WorkerReply::StegoKeys(_) => {} // StegoKeys se borra al liberarse
+        }
+    }
+}
+
+/// Respuesta: el resultado o el error serializado
+#[derive(Serialize, Deserialize)]
+struct WorkerResponse {
+    #[serde(default)]
+    result: Option<WorkerReply>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+/// Transformación y resultado de la verificación de ida y vuelta (si se pidió)
+pub type WorkerOutput = (PolicyTransform, Option<bool>);
+
+/// Ejecutar en el proceso aislado o, si está desactivado, en este proceso
+fn run(request: &WorkerRequest) -> Result<WorkerReply> {
+    if crypto_worker_enabled() {
+        run_in_worker(request)
+    } else {
+        let pepper = acquire_pepper(request)?;
+        execute(request, pepper.as_ref().map(|p| p.as_slice()))
+    }
+}
+
+fn unexpected_reply() -> SCypherError {
+    worker_error("Worker answered a different operation".to_string())
+}
+
+/// Transformar una frase
+pub fn transform(request: PhraseRequest) -> Result<WorkerOutput> {
+    match &mut run(&WorkerRequest::Transform(request))? {
+        WorkerReply::Transform { phrase, checksum_corrected, verified } => Ok((
+            PolicyTransform { phrase: std::mem::take(phrase), checksum_corrected: *checksum_corrected },
+            *verified,
+        )),
+        _ => Err(unexpected_reply()),
+    }
+}
+
+/// Transformar entropía en hexadecimal
+pub fn transform_entropy(request: EntropyRequest) -> Result<EntropyTransform> {
+    match &mut run(&WorkerRequest::Entropy(request))? {
+        WorkerReply::Entropy(result) => Ok(std::mem::take(result)),
+        _ => Err(unexpected_reply()),
+    }
+}
+
+/// Crear un contenedor de volúmenes ocultos
+pub fn create_hidden_container(request: HiddenContainerRequest) -> Result<String> {
+    match &mut run(&WorkerRequest::HiddenContainer(request))? {
+        WorkerReply::HiddenContainer { phrase } => Ok(std::mem::take(phrase)),
+        _ => Err(unexpected_reply()),
+    }
+}
+
+/// Derivar las claves de la exportación esteganográfica
+pub fn derive_stego_keys(password: &str, iterations: u32, memory_cost: u32) -> Result<StegoKeys> {
+    let request = StegoKeyRequest { password: password.to_string(), iterations, memory_cost };
+    match &mut run(&WorkerRequest::StegoKeys(request))? {
+        WorkerReply::StegoKeys(keys) => Ok(std::mem::take(keys)),
+        _ => Err(unexpected_reply()),
+    }
+}
+
+/// El token se consulta una sola vez: la verificación reutiliza el pepper
+fn acquire_pepper(request: &WorkerRequest) -> Result<Option<SecureBytes>> {
+    match request {
+        WorkerRequest::Transform(request) => request
+            .params
+            .hardware_pepper
+            .as_ref()
+            .map(crate::security::hardware_pepper::acquire_pepper)
+            .transpose(),
+        _ => Ok(None),
+    }
+}
+
+/// Ejecutar una petición (el mismo código en ambos procesos)
+fn execute(request: &WorkerRequest, pepper: Option<&[u8]>) -> Result<WorkerReply> {
+    Ok(match request {
+        WorkerRequest::Transform(request) => {
+            let (mut output, verified) = compute(request, pepper)?;
+            WorkerReply::Transform {
+                phrase: std::mem::take(&mut output.phrase),
+                checksum_corrected: output.checksum_corrected,
+                verified,
+            }
+        }
+        WorkerRequest::Entropy(request) => WorkerReply::Entropy(crate::crypto::transform_entropy(
+            &request.entropy_hex,
+            &request.password,
+            request.iterations,
+            request.memory_cost,
+            request.parallelism,
+            request.include_phrase,
+        )?),
+        WorkerRequest::HiddenContainer(request) => WorkerReply::HiddenContainer {
+            phrase: crate::crypto::hidden::create_container(
+                &request.volumes, request.iterations, request.memory_cost, request.parallelism,
+            )?,
+        },
+        WorkerRequest::StegoKeys(request) => WorkerReply::StegoKeys(crate::stego::derive_keys(
+            &request.password, request.iterations, request.memory_cost,
+        )?),
+    })
+}
+
+/// Transformación y verificación de una frase
+fn compute(request: &PhraseRequest, pepper: Option<&[u8]>) -> Result<WorkerOutput> {
+    let params = &request.params;
+    let parallelism = params.parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM);
+    let policy = params.checksum_policy;
+    let output = crate::pipeline::transform_phrase_with_pepper(
+        &request.phrase, &request.password, request.second_password.as_deref(), params, pepper,
+    )?;
+
+    if !request.verify {
+        return Ok((output, None));
+    }
+
+    // La segunda pasada reutiliza el keystream en caché
+    let verified = if params.compatibility == CompatibilityMode::BashV2 {
+        crate::crypto::compat::verify_roundtrip_bash_v2(&request.phrase, &output.phrase, &request.password, params.iterations, policy)?
+    } else if let Some(second_password) = &request.second_password {
+        crate::crypto::dual::verify_roundtrip_dual(
+            &request.phrase, &output.phrase, &request.password, second_password,
+            params.iterations, params.memory_cost, parallelism, policy,
+        )?
+    } else if pepper.is_some() {
+        crate::crypto::verify_roundtrip_with_pepper(
+            &request.phrase, &output.phrase, &request.password, pepper,
+            params.iterations, params.memory_cost, parallelism, policy,
+        )?
+    } else {
+        crate::crypto::verify_roundtrip_with_policy(
+            &request.phrase, &output.phrase, &request.password,
+            params.iterations, params.memory_cost, parallelism, policy,
+        )?
+    };
+    Ok((output, Some(verified)))
+}
+
+fn worker_error(message: String) -> SCypherError {
+    SCypherError::WorkerError(message)
+}
+
+/// Lanzar el proceso aislado, enviarle la petición y esperar la respuesta
+fn run_in_worker(request: &WorkerRequest) -> Result<WorkerReply> {
+    // El límite de intentos vive en este proceso, no en el hijo (todos los modos, como en pipeline)
+    if let WorkerRequest::Transform(_) = request {
+        crate::security::attempt_guard::ensure_attempt_allowed()?;
+    }
+
+    let executable = std::env::current_exe()
+        .map_err(|e| worker_error(format!("Cannot locate the application executable: {}", e)))?;
+    let mut command = Command::new(executable);
+    crate::security::environment::harden_command(&mut command);
+    let mut child = command
+        .arg(WORKER_FLAG)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| worker_error(format!("Cannot start crypto worker: {}", e)))?;
+
+    // stdin se cierra al enviar: el hijo solo atiende una petición
+    let mut encoded = serde_json::to_vec(request).map_err(|e| worker_error(format!("Cannot encode request: {}", e)))?;
+    let sent = match child.stdin.take() {
+        Some(mut stdin) => crate::ipc::write_frame(&mut stdin, &encoded),
+        None => Err(worker_error("Cannot open worker input".to_string())),
+    };
+    encoded.zeroize();
+
+    let reply = match child.stdout.take() {
+        Some(mut stdout) => crate::ipc::read_frame(&mut stdout),
+        None => Err(worker_error("Cannot open worker output".to_string())),
+    };
+    let status = child.wait()?;
+    sent?;
+
+    let mut payload = reply?.ok_or_else(|| worker_error(format!("Crypto worker exited without answering ({})", status)))?;
+    let response: std::result::Result<WorkerResponse, _> = serde_json::from_slice(&payload);
+    payload.zeroize();
+    let mut response = response.map_err(|e| worker_error(format!("Invalid worker response: {}", e)))?;
+
+    if let Some(error) = &response.error {
+        return Err(SCypherError::from_serialized(error));
+    }
+    response
+        .result
+        .take()
+        .ok_or_else(|| worker_error("Worker response has no result".to_string()))
+}
+
+/// Atender una petición: pepper, encierro, operación y respuesta
+fn serve<R: Read, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    enter_sandbox: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    let mut payload = crate::ipc::read_frame(input)?.ok_or_else(|| worker_error("No request received".to_string()))?;
+    let request: std::result::Result<WorkerRequest, _> = serde_json::from_slice(&payload);
+    payload.zeroize();
+
+    let result = request
+        .map_err(|e| worker_error(format!("Invalid worker request: {}", e)))
+        .and_then(|request| {
+            // El token se consulta antes del encierro: sus herramientas son programas externos
+            let pepper = acquire_pepper(&request)?;
+            enter_sandbox()?;
+            execute(&request, pepper.as_ref().map(|p| p.as_slice()))
+        });
+    crate::crypto::keystream::clear_kdf_cache();
+
+    let response = match result {
+        Ok(reply) => WorkerResponse { result: Some(reply), error: None },
+        Err(e) => WorkerResponse { result: None, error: Some(serde_json::to_value(&e).unwrap_or_default()) },
+    };
+
+    let mut encoded = serde_json::to_vec(&response).map_err(|e| worker_error(format!("Cannot encode response: {}", e)))?;
+    let written = crate::ipc::write_frame(output, &encoded);
+    encoded.zeroize();
+    written
+}
+
+/// Punto de entrada del proceso aislado; devuelve el código de salida
+pub fn run_worker() -> i32 {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    match serve(&mut stdin.lock(), &mut stdout.lock(), crate::security::sandbox::enter_worker_sandbox) {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn request(verify: bool) -> PhraseRequest {
+        PhraseRequest {
+            phrase: PHRASE.to_string(),
+            password: "worker test password".to_string(),
+            second_password: None,
+            params: serde_json::from_value(serde_json::json!({"iterations": 1, "memory_cost": 8192})).unwrap(),
+            verify,
+        }
+    }
+
+    fn exchange(request: &WorkerRequest) -> WorkerResponse {
+        let mut input = Vec::new();
+        crate::ipc::write_frame(&mut input, &serde_json::to_vec(request).unwrap()).unwrap();
+        let mut output = Vec::new();
+        serve(&mut std::io::Cursor::new(input), &mut output, || Ok(())).unwrap();
+        serde_json::from_slice(&crate::ipc::read_frame(&mut std::io::Cursor::new(output)).unwrap().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_worker_protocol_matches_in_process() {
+        let response = exchange(&WorkerRequest::Transform(request(true)));
+        let (expected, verified) = compute(&request(true), None).unwrap();
+        match response.result.as_ref().unwrap() {
+            WorkerReply::Transform { phrase, verified: reply_verified, .. } => {
+                assert_eq!(phrase, &expected.phrase);
+                assert_eq!(*reply_verified, Some(true));
+            }
+            _ => panic!("Wrong reply"),
+        }
+        assert_eq!(verified, Some(true));
+
+        // Entropía y claves de esteganografía: mismo resultado que en este proceso
+        let entropy = EntropyRequest {
+            entropy_hex: "00".repeat(16),
+            password: "worker test password".to_string(),
+            iterations: 1,
+            memory_cost: 8192,
+            parallelism: 1,
+            include_phrase: true,
+        };
+        let expected = crate::crypto::transform_entropy(&entropy.entropy_hex, &entropy.password, 1, 8192, 1, true).unwrap();
+        match exchange(&WorkerRequest::Entropy(entropy)).result.as_ref().unwrap() {
+            WorkerReply::Entropy(result) => {
+                assert_eq!(result.entropy_hex, expected.entropy_hex);
+                assert_eq!(result.phrase, expected.phrase);
+            }
+            _ => panic!("Wrong reply"),
+        }
+
+        let keys = StegoKeyRequest { password: "worker test password".to_string(), iterations: 1, memory_cost: 8192 };
+        let expected = serde_json::to_value(crate::stego::derive_keys(&keys.password, 1, 8192).unwrap()).unwrap();
+        match exchange(&WorkerRequest::StegoKeys(keys)).result.as_ref().unwrap() {
+            WorkerReply::StegoKeys(keys) => assert_eq!(serde_json::to_value(keys).unwrap(), expected),
+            _ => panic!("Wrong reply"),
+        }
+    }
+
+    #[test]
+    fn test_worker_returns_original_error() {
+        let mut bad = request(false);
+        bad.phrase = "abandon abandon abandon".to_string();
+        let response = exchange(&WorkerRequest::Transform(bad));
+        assert!(response.result.is_none());
+        let error = SCypherError::from_serialized(response.error.as_ref().unwrap());
+        assert_eq!(error.code(), "invalid_word_count");
+
+        // Una petición ilegible también recibe respuesta
+        let mut input = Vec::new();
+        crate::ipc::write_frame(&mut input, b"not json").unwrap();
+        let mut output = Vec::new();
+        serve(&mut std::io::Cursor::new(input), &mut output, || Ok(())).unwrap();
+        let reply: WorkerResponse = serde_json::from_slice(&crate::ipc::read_frame(&mut std::io::Cursor::new(output)).unwrap().unwrap()).unwrap();
+        assert_eq!(reply.error.as_ref().unwrap()["code"], "worker_error");
+    }
+}
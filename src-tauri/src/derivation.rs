@@ -0,0 +1,303 @@
+// =============================================================================
+// RUTAS DE DERIVACIÓN BIP32 / SLIP-0010
+// Tipo común para leer, validar y mostrar rutas como "m/44'/0'/0'/0/5" en
+// todas las redes (secp256k1 y Ed25519), y rangos de una componente como
+// "m/44'/0'/0'/0/0-99" o "m/44'/60'/0-4'/0/0" que se recorren ruta a ruta.
+// Se aceptan ', h y H como marca de endurecida; al mostrar se usa siempre '.
+// =============================================================================
+
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::error::{SCypherError, Result};
+
+/// Bit de las componentes endurecidas
+pub const HARDENED_BIT: u32 = 0x8000_0000;
+
+fn path_error(path: &str, reason: impl fmt::Display) -> SCypherError {
+    SCypherError::crypto(format!("Invalid derivation path '{}': {}", path, reason))
+}
+
+/// Una componente de la ruta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChildIndex(u32);
+
+impl ChildIndex {
+    fn new(index: u32, hardened: bool) -> Result<Self> {
+        if index >= HARDENED_BIT {
+            return Err(SCypherError::crypto(format!("Child index {} is out of range (must be below 2^31)", index)));
+        }
+        Ok(Self(if hardened { index | HARDENED_BIT } else { index }))
+    }
+
+    /// Valor de 32 bits que entra en el HMAC de la derivación
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// Índice sin el bit de endurecida
+    pub fn index(self) -> u32 {
+        self.0 & !HARDENED_BIT
+    }
+
+    pub fn is_hardened(self) -> bool {
+        self.0 & HARDENED_BIT != 0
+    }
+}
+
+impl fmt::Display for ChildIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.index(), if self.is_hardened() { "'" } else { "" })
+    }
+}
+
+/// Separar la marca de endurecida de una componente ("44'", "44h")
+fn split_hardened(component: &str) -> (&str, bool) {
+    match component.strip_suffix(['\'', 'h', 'H']) {
+        Some(number) => (number, true),
+        None => (component, false),
+    }
+}
+
+fn parse_index(path: &str, number: &str) -> Result<u32> {
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(path_error(path, format!("'{}' is not a child index", number)));
+    }
+    let index: u32 = number.parse().map_err(|_| path_error(path, format!("index {} is out of range", number)))?;
+    if index >= HARDENED_BIT {
+        return Err(path_error(path, format!("index {} is out of range (must be below 2^31)", index)));
+    }
+    Ok(index)
+}
+
+/// Componentes de "m/..." sin la "m" (vacío para "m")
+fn split_components(path: &str) -> Result<Vec<&str>> {
+    let trimmed = path.trim();
+    match trimmed {
+        "m" | "M" => Ok(Vec::new()),
+        _ => {
+            let rest = trimmed
+                .strip_prefix("m/")
+                .or_else(|| trimmed.strip_prefix("M/"))
+                .ok_or_else(|| path_error(path, "paths start with 'm/'"))?;
+            let components: Vec<&str> = rest.split('/').map(str::trim).collect();
+            if components.iter().any(|c| c.is_empty()) {
+                return Err(path_error(path, "empty component"));
+            }
+            Ok(components)
+        }
+    }
+}
+
+/// Ruta de derivación completa desde la clave maestra
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Path(Vec<ChildIndex>);
+
+impl Path {
+    /// Leer una ruta ("m/44'/0'/0'/0/0")
+    pub fn parse(path: &str) -> Result<Self> {
+        split_components(path)?
+            .into_iter()
+            .map(|component| {
+                let (number, hardened) = split_hardened(component);
+                let index = parse_index(path, number)?;
+                ChildIndex::new(index, hardened)
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+
+    pub fn components(&self) -> &[ChildIndex] {
+        &self.0
+    }
+
+    /// Valores de 32 bits de cada componente
+    pub fn raw_components(&self) -> Vec<u32> {
+        self.0.iter().map(|c| c.raw()).collect()
+    }
+
+    /// ¿Todas las componentes son endurecidas? (obligatorio en Ed25519 SLIP-0010)
+    pub fn is_fully_hardened(&self) -> bool {
+        self.0.iter().all(|c| c.is_hardened())
+    }
+
+    /// Comprobar que la ruta se puede usar con Ed25519 (SLIP-0010)
+    pub fn ensure_fully_hardened(&self) -> Result<()> {
+        if !self.is_fully_hardened() {
+            return Err(path_error(&self.to_string(), "Ed25519 (SLIP-0010) paths must be fully hardened"));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for component in &self.0 {
+            write!(f, "/{}", component)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Path {
+    type Err = SCypherError;
+
+    fn from_str(path: &str) -> Result<Self> {
+        Self::parse(path)
+    }
+}
+
+impl Serialize for Path {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Self::parse(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Rango de rutas con una componente variable ("m/44'/0'/0'/0/0-99")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathRange {
+    template: Vec<ChildIndex>,  // La componente variable guarda el inicio
+    position: usize,
+    start: u32,
+    end: u32,                   // Incluido
+    hardened: bool,
+}
+
+impl PathRange {
+    /// Leer un rango; una ruta sin rango da un rango de una sola ruta
+    pub fn parse(path: &str) -> Result<Self> {
+        let components = split_components(path)?;
+        let mut template = Vec::with_capacity(components.len());
+        let mut range = None;
+
+        for (position, component) in components.iter().enumerate() {
+            let (body, hardened) = split_hardened(component);
+            match body.split_once('-') {
+                Some((first, last)) => {
+                    if range.is_some() {
+                        return Err(path_error(path, "only one component can be a range"));
+                    }
+                    // "0'-9'" y "0-9'" son el mismo rango
+                    let (first, first_hardened) = split_hardened(first);
+                    if first_hardened && !hardened {
+                        return Err(path_error(path, format!("range '{}' mixes hardened and normal indexes", component)));
+                    }
+                    let (start, end) = (parse_index(path, first)?, parse_index(path, last)?);
+                    if start > end {
+                        return Err(path_error(path, format!("range '{}' is reversed", component)));
+                    }
+                    range = Some((position, start, end, hardened));
+                    template.push(ChildIndex::new(start, hardened)?);
+                }
+                None => template.push(ChildIndex::new(parse_index(path, body)?, hardened)?),
+            }
+        }
+
+        Ok(match range {
+            Some((position, start, end, hardened)) => Self { template, position, start, end, hardened },
+            None => {
+                // Sin rango: la última componente (si la hay) es un rango de uno
+                let position = template.len().saturating_sub(1);
+                let last = template.last().copied();
+                Self {
+                    position,
+                    start: last.map_or(0, ChildIndex::index),
+                    end: last.map_or(0, ChildIndex::index),
+                    hardened: last.is_some_and(ChildIndex::is_hardened),
+                    template,
+                }
+            }
+        })
+    }
+
+    fn path_at(&self, index: u32) -> Path {
+        let mut components = self.template.clone();
+        if let Some(component) = components.get_mut(self.position) {
+            *component = ChildIndex(if self.hardened { index | HARDENED_BIT } else { index });
+        }
+        Path(components)
+    }
+
+    /// Recorrer las rutas del rango en orden
+    pub fn iter(&self) -> impl Iterator<Item = Path> + '_ {
+        let end = if self.template.is_empty() { self.start } else { self.end };
+        (self.start..=end).map(move |index| self.path_at(index))
+    }
+}
+
+impl fmt::Display for PathRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for (position, component) in self.template.iter().enumerate() {
+            if position == self.position && self.start != self.end {
+                write!(f, "/{}-{}{}", self.start, self.end, if self.hardened { "'" } else { "" })?;
+            } else {
+                write!(f, "/{}", component)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PathRange {
+    type Err = SCypherError;
+
+    fn from_str(path: &str) -> Result<Self> {
+        Self::parse(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let path = Path::parse("m/44'/501h/0H/0/7").unwrap();
+        assert_eq!(path.to_string(), "m/44'/501'/0'/0/7");
+        assert_eq!(path.raw_components(), vec![0x8000_002C, 0x8000_01F5, 0x8000_0000, 0, 7]);
+        assert_eq!(path.components()[4].index(), 7);
+        assert!(path.components()[1].is_hardened());
+        assert!(!path.is_fully_hardened());
+        assert!(Path::parse("m/44'/501'/0'/0'").unwrap().ensure_fully_hardened().is_ok());
+        assert!(path.ensure_fully_hardened().is_err());
+
+        assert!(Path::parse("m").unwrap().components().is_empty());
+        assert_eq!(serde_json::to_string(&path).unwrap(), "\"m/44'/501'/0'/0/7\"");
+
+        for invalid in ["", "44'/0'", "m/", "m/44'//0", "m/x", "m/-1", "m/2147483648", "m/1''", "m/0-3"] {
+            assert!(Path::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let range = PathRange::parse("m/44'/0'/0'/0/0-99").unwrap();
+        assert_eq!(range.to_string(), "m/44'/0'/0'/0/0-99");
+        let paths: Vec<String> = range.iter().map(|p| p.to_string()).collect();
+        assert_eq!(paths.len(), 100);
+        assert_eq!(paths[0], "m/44'/0'/0'/0/0");
+        assert_eq!(paths[99], "m/44'/0'/0'/0/99");
+
+        // Rango endurecido en medio (Ledger Live)
+        let ledger = PathRange::parse("m/44'/60'/0'-2'/0/0").unwrap();
+        assert_eq!(ledger.to_string(), "m/44'/60'/0-2'/0/0");
+        assert_eq!(ledger.iter().last().unwrap().to_string(), "m/44'/60'/2'/0/0");
+
+        // Una ruta sin rango es un rango de una ruta
+        let single = PathRange::parse("m/84'/0'/0'/1/5").unwrap();
+        assert_eq!(single.iter().collect::<Vec<_>>(), vec![Path::parse("m/84'/0'/0'/1/5").unwrap()]);
+
+        for invalid in ["m/0-1/0-1", "m/5-2", "m/0'-9", "m/0-2147483648"] {
+            assert!(PathRange::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
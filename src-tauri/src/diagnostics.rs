@@ -0,0 +1,175 @@
+// =============================================================================
+// INFORME DE DIAGNÓSTICO DE LA SESIÓN
+// Historial en memoria de las operaciones de la sesión (parámetros del KDF,
+// duración y código de error) e informe JSON para adjuntar a un reporte de
+// fallo. Solo se guardan datos no secretos: de los errores se conserva el
+// código, no el mensaje (puede citar una palabra de la frase), y del entorno
+// solo las claves de la lista permitida (sin usuario ni directorio).
+// =============================================================================
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+use crate::metadata::KdfParams;
+
+/// Identificador de formato del informe
+pub const DIAGNOSTICS_FORMAT: &str = "scypher-diagnostics";
+
+/// Versión actual del formato
+pub const DIAGNOSTICS_VERSION: u32 = 1;
+
+/// Operaciones que se conservan (las más antiguas se descartan)
+const MAX_OPERATIONS: usize = 100;
+
+/// Claves de `get_environment_info` que pueden salir del equipo
+const ENVIRONMENT_KEYS: &[&str] = &["os", "arch", "container", "debugger", "development"];
+
+/// Una operación de la sesión
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationRecord {
+    pub operation: String,
+    pub kdf: Option<KdfParams>,
+    pub duration_ms: u64,
+    pub error_code: Option<String>, // None si terminó bien
+    pub at: u64,                    // Segundos Unix
+}
+
+/// Informe completo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    pub format: String,
+    pub version: u32,
+    pub app_version: String,
+    pub generated_at: u64,
+    pub environment: BTreeMap<String, String>,
+    pub log_level: String,
+    pub training_mode: bool,
+    pub operations: Vec<OperationRecord>,
+}
+
+static OPERATIONS: OnceLock<Mutex<VecDeque<OperationRecord>>> = OnceLock::new();
+
+fn operations_slot() -> &'static Mutex<VecDeque<OperationRecord>> {
+    OPERATIONS.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Parámetros del KDF para el historial
+pub fn kdf_params(algorithm: &str, iterations: u32, memory_cost: u32, parallelism: u32) -> KdfParams {
+    KdfParams {
+        algorithm: algorithm.to_string(),
+        iterations,
+        memory_cost,
+        parallelism,
+    }
+}
+
+/// Registrar una operación terminada
+pub fn record_operation(operation: &str, kdf: Option<KdfParams>, started: Instant, error_code: Option<&str>) {
+    // Modo incógnito: ni historial de la sesión ni registro de auditoría
+    if crate::security::incognito::is_incognito() {
+        return;
+    }
+    let record = OperationRecord {
+        operation: operation.to_string(),
+        kdf,
+        duration_ms: started.elapsed().as_millis() as u64,
+        error_code: error_code.map(str::to_string),
+        at: unix_now(),
+    };
+
+    // Registro de auditoría persistente (solo si está activado)
+    crate::audit_log::record(&record.operation, record.error_code.as_deref());
+
+    let mut operations = operations_slot().lock().unwrap_or_else(|e| e.into_inner());
+    if operations.len() >= MAX_OPERATIONS {
+        operations.pop_front();
+    }
+    operations.push_back(record);
+}
+
+/// Olvidar el historial de operaciones de la sesión
+pub fn clear_operations() {
+    operations_slot().lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Construir el informe de la sesión
+pub fn build_report() -> DiagnosticsReport {
+    let environment = crate::security::environment::get_environment_info()
+        .into_iter()
+        .filter(|(key, _)| ENVIRONMENT_KEYS.contains(&key.as_str()))
+        .collect();
+
+    DiagnosticsReport {
+        format: DIAGNOSTICS_FORMAT.to_string(),
+        version: DIAGNOSTICS_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        generated_at: unix_now(),
+        environment,
+        log_level: crate::logging::current_log_level(),
+        training_mode: crate::security::training::is_training_mode(),
+        operations: operations_slot().lock().unwrap_or_else(|e| e.into_inner()).iter().cloned().collect(),
+    }
+}
+
+/// JSON del informe, pasado además por la política de redacción del registro
+pub fn report_json(report: &DiagnosticsReport) -> Result<String> {
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| SCypherError::file(format!("Cannot encode diagnostics: {}", e)))?;
+    Ok(json.lines().map(crate::logging::redact_line).collect::<Vec<_>>().join("\n"))
+}
+
+/// Guardar el informe (permisos 0600)
+pub fn write_report(report: &DiagnosticsReport, path: &str) -> Result<()> {
+    crate::security::incognito::ensure_persistence_allowed("exporting diagnostics")?;
+    std::fs::write(path, report_json(report)?)
+        .map_err(|e| SCypherError::file(format!("Cannot write diagnostics file: {}", e)))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+            .map_err(|e| SCypherError::file(format!("Cannot set file permissions: {}", e)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_excludes_identifying_environment() {
+        let report = build_report();
+        assert_eq!(report.format, DIAGNOSTICS_FORMAT);
+        assert!(report.environment.contains_key("os"));
+        assert!(!report.environment.contains_key("working_dir"));
+        assert!(!report.environment.contains_key("uid"));
+    }
+
+    #[test]
+    fn test_operations_are_recorded() {
+        let started = Instant::now();
+        record_operation("diagnostics_test_operation", Some(kdf_params("argon2id", 5, 131072, 1)), started, Some("invalid_checksum"));
+
+        let report = build_report();
+        let record = report
+            .operations
+            .iter()
+            .rev()
+            .find(|record| record.operation == "diagnostics_test_operation")
+            .unwrap();
+        assert_eq!(record.error_code.as_deref(), Some("invalid_checksum"));
+        assert_eq!(record.kdf.as_ref().unwrap().memory_cost, 131072);
+        assert!(report_json(&report).unwrap().contains("\"diagnostics_test_operation\""));
+        assert!(report.operations.len() <= MAX_OPERATIONS);
+    }
+}
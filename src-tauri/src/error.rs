@@ -1,22 +1,35 @@
 // src/error.rs - Manejo centralizado de errores para SCypher
 
 use std::fmt;
-use serde::{Serialize, Deserialize};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// Enum principal que representa todos los posibles errores en SCypher
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Se serializa como `{"code", "numeric_code", "message", "details"?}` para
+/// que la GUI y los scripts distingan errores sin comparar el texto.
+#[derive(Debug)]
 pub enum SCypherError {
     // Errores de entrada y validación
     InvalidSeedPhrase,
     InvalidWordCount(usize),           // Guarda el número de palabras encontradas
     InvalidBip39Word(String),          // Guarda la palabra inválida
+    AmbiguousWord {
+        prefix: String,
+        candidates: Vec<String>,       // Palabras de la lista que empiezan por el prefijo
+    },
     InvalidChecksum,
+    InvalidWords(Vec<crate::bip39::validation::InvalidWordInfo>), // Con la posición de cada palabra
 
     // Errores de entrada del usuario
     InvalidPassword,
     PasswordMismatch,
     InvalidIterations(String),         // Guarda el valor inválido
     InvalidMemoryCost(String),         // Guarda el valor inválido
+    InvalidParallelism(String),        // Guarda el valor inválido
+    TooManyAttempts {
+        retry_after_secs: u64,         // Espera antes del siguiente intento
+    },
+    ConsentRequired(String),           // Acción sensible sin token de consentimiento válido
 
     // Errores criptográficos
     CryptoError(String),               // Errores de Argon2 u otras operaciones crypto
@@ -27,8 +40,16 @@ pub enum SCypherError {
     FileError(String),
 
     // Errores del sistema
-    InsufficientMemory,
+    InsufficientMemory {
+        required_kb: u64,
+        available_kb: u64,
+        max_memory_cost: u32,          // Mayor memory_cost (KB) que cabe en este equipo
+    },
     UnsupportedPlatform,
+    KeychainError(String),             // Errores del llavero del sistema operativo
+    HardwareTokenError(String),        // Token FIDO2/YubiKey ausente o sin respuesta
+    WorkerError(String),               // El proceso aislado de cifrado falló o no respondió
+    WindowError(String),               // No se pudo acceder a la ventana nativa de la GUI
 }
 
 impl fmt::Display for SCypherError {
@@ -44,9 +65,22 @@ impl fmt::Display for SCypherError {
             SCypherError::InvalidBip39Word(word) => {
                 write!(f, "Word '{}' is not in the BIP39 wordlist", word)
             }
+            SCypherError::AmbiguousWord { prefix, candidates } => {
+                write!(f, "Abbreviation '{}' matches several BIP39 words: {}", prefix, candidates.join(", "))
+            }
             SCypherError::InvalidChecksum => {
                 write!(f, "Invalid BIP39 checksum - seed phrase may be corrupted")
             }
+            SCypherError::InvalidWords(words) => {
+                let list: Vec<String> = words
+                    .iter()
+                    .map(|w| match &w.suggestion {
+                        Some(suggestion) => format!("'{}' at word {} (did you mean '{}'?)", w.word, w.position + 1, suggestion),
+                        None => format!("'{}' at word {}", w.word, w.position + 1),
+                    })
+                    .collect();
+                write!(f, "Invalid BIP39 words: {}", list.join(", "))
+            }
 
             // Errores de entrada del usuario
             SCypherError::InvalidPassword => {
@@ -61,6 +95,17 @@ impl fmt::Display for SCypherError {
             SCypherError::InvalidMemoryCost(val) => {
                 write!(f, "Invalid memory cost '{}' (must be a positive number in KB)", val)
             }
+            SCypherError::InvalidParallelism(val) => {
+                write!(f, "Invalid parallelism '{}' (must be between 1 and {} lanes)", val, crate::crypto::keystream::MAX_PARALLELISM)
+            }
+
+            SCypherError::TooManyAttempts { retry_after_secs } => {
+                write!(f, "Too many failed attempts: try again in {} seconds", retry_after_secs)
+            }
+
+            SCypherError::ConsentRequired(msg) => {
+                write!(f, "User consent required: {}", msg)
+            }
 
             // Errores criptográficos
             SCypherError::CryptoError(msg) => {
@@ -79,18 +124,121 @@ impl fmt::Display for SCypherError {
             }
 
             // Errores del sistema
-            SCypherError::InsufficientMemory => {
-                write!(f, "Insufficient system memory for secure operation")
+            SCypherError::InsufficientMemory { required_kb, available_kb, max_memory_cost } => {
+                write!(
+                    f,
+                    "Insufficient system memory: {} KB required, {} KB available (maximum memory cost: {} KB)",
+                    required_kb, available_kb, max_memory_cost
+                )
             }
             SCypherError::UnsupportedPlatform => {
                 write!(f, "This platform is not supported")
             }
+            SCypherError::KeychainError(msg) => {
+                write!(f, "OS keychain error: {}", msg)
+            }
+            SCypherError::HardwareTokenError(msg) => {
+                write!(f, "Hardware token error: {}", msg)
+            }
+            SCypherError::WorkerError(msg) => {
+                write!(f, "Crypto worker error: {}", msg)
+            }
+            SCypherError::WindowError(msg) => {
+                write!(f, "Window error: {}", msg)
+            }
         }
     }
 }
 
 impl std::error::Error for SCypherError {}
 
+impl SCypherError {
+    /// Código estable del error (no cambia aunque cambie el mensaje)
+    ///
+    /// Espacio de códigos numéricos:
+    /// - 1xxx: validación BIP39 (frase, palabras, checksum)
+    /// - 2xxx: parámetros introducidos por el usuario
+    /// - 3xxx: operaciones criptográficas
+    /// - 4xxx: entrada/salida y archivos
+    /// - 5xxx: sistema (memoria, plataforma, llavero, token hardware)
+    ///
+    /// Los códigos existentes no se reutilizan ni se renumeran.
+    pub fn code(&self) -> &'static str {
+        self.codes().0
+    }
+
+    /// Código numérico equivalente a `code()`
+    pub fn numeric_code(&self) -> u32 {
+        self.codes().1
+    }
+
+    fn codes(&self) -> (&'static str, u32) {
+        match self {
+            SCypherError::InvalidSeedPhrase => ("invalid_seed_phrase", 1001),
+            SCypherError::InvalidWordCount(_) => ("invalid_word_count", 1002),
+            SCypherError::InvalidBip39Word(_) => ("invalid_bip39_word", 1003),
+            SCypherError::AmbiguousWord { .. } => ("ambiguous_word", 1004),
+            SCypherError::InvalidChecksum => ("invalid_checksum", 1005),
+            SCypherError::InvalidWords(_) => ("invalid_words", 1006),
+            SCypherError::InvalidPassword => ("invalid_password", 2001),
+            SCypherError::PasswordMismatch => ("password_mismatch", 2002),
+            SCypherError::InvalidIterations(_) => ("invalid_iterations", 2003),
+            SCypherError::InvalidMemoryCost(_) => ("invalid_memory_cost", 2004),
+            SCypherError::InvalidParallelism(_) => ("invalid_parallelism", 2005),
+            SCypherError::TooManyAttempts { .. } => ("too_many_attempts", 2006),
+            SCypherError::ConsentRequired(_) => ("consent_required", 2007),
+            SCypherError::CryptoError(_) => ("crypto_error", 3001),
+            SCypherError::KeyDerivationFailed => ("key_derivation_failed", 3002),
+            SCypherError::IoError(_) => ("io_error", 4001),
+            SCypherError::FileError(_) => ("file_error", 4002),
+            SCypherError::InsufficientMemory { .. } => ("insufficient_memory", 5001),
+            SCypherError::UnsupportedPlatform => ("unsupported_platform", 5002),
+            SCypherError::KeychainError(_) => ("keychain_error", 5003),
+            SCypherError::HardwareTokenError(_) => ("hardware_token_error", 5004),
+            SCypherError::WorkerError(_) => ("worker_error", 5005),
+            SCypherError::WindowError(_) => ("window_error", 5006),
+        }
+    }
+
+    /// Datos estructurados de los errores que los tienen
+    fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            SCypherError::InvalidWordCount(count) => Some(serde_json::json!({ "word_count": count })),
+            SCypherError::InvalidBip39Word(word) => Some(serde_json::json!({ "word": word })),
+            SCypherError::InvalidWords(words) => Some(serde_json::json!({
+                "positions": words.iter().map(|w| w.position).collect::<Vec<_>>(),
+                "words": words,
+            })),
+            SCypherError::AmbiguousWord { prefix, candidates } => {
+                Some(serde_json::json!({ "prefix": prefix, "candidates": candidates }))
+            }
+            SCypherError::TooManyAttempts { retry_after_secs } => {
+                Some(serde_json::json!({ "retry_after_secs": retry_after_secs }))
+            }
+            SCypherError::InsufficientMemory { required_kb, available_kb, max_memory_cost } => Some(serde_json::json!({
+                "required_kb": required_kb,
+                "available_kb": available_kb,
+                "max_memory_cost": max_memory_cost,
+            })),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for SCypherError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let details = self.details();
+        let mut state = serializer.serialize_struct("SCypherError", if details.is_some() { 4 } else { 3 })?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("numeric_code", &self.numeric_code())?;
+        state.serialize_field("message", &self.to_string())?;
+        if let Some(details) = details {
+            state.serialize_field("details", &details)?;
+        }
+        state.end()
+    }
+}
+
 // Conversión automática desde std::io::Error (convertimos a String)
 impl From<std::io::Error> for SCypherError {
     fn from(error: std::io::Error) -> Self {
@@ -114,6 +262,52 @@ pub type Result<T> = std::result::Result<T, SCypherError>;
 
 /// Funciones helper para crear errores comunes de manera más fácil
 impl SCypherError {
+    /// Reconstruir un error a partir de su forma serializada
+    ///
+    /// Lo usa el proceso aislado de cifrado para devolver el error original
+    /// (mismo código y detalles); lo que no se reconoce es `WorkerError`.
+    pub fn from_serialized(value: &serde_json::Value) -> Self {
+        let message = value["message"].as_str().unwrap_or_default();
+        let details = &value["details"];
+        let after = |prefix: &str| message.strip_prefix(prefix).unwrap_or(message).to_string();
+        let quoted = || message.split('\'').nth(1).unwrap_or_default().to_string();
+        let number = |key: &str| details[key].as_u64().unwrap_or_default();
+
+        match value["code"].as_str().unwrap_or_default() {
+            "invalid_seed_phrase" => SCypherError::InvalidSeedPhrase,
+            "invalid_word_count" => SCypherError::InvalidWordCount(number("word_count") as usize),
+            "invalid_bip39_word" => SCypherError::InvalidBip39Word(details["word"].as_str().unwrap_or_default().to_string()),
+            "ambiguous_word" => SCypherError::AmbiguousWord {
+                prefix: details["prefix"].as_str().unwrap_or_default().to_string(),
+                candidates: serde_json::from_value(details["candidates"].clone()).unwrap_or_default(),
+            },
+            "invalid_checksum" => SCypherError::InvalidChecksum,
+            "invalid_words" => SCypherError::InvalidWords(serde_json::from_value(details["words"].clone()).unwrap_or_default()),
+            "invalid_password" => SCypherError::InvalidPassword,
+            "password_mismatch" => SCypherError::PasswordMismatch,
+            "invalid_iterations" => SCypherError::InvalidIterations(quoted()),
+            "invalid_memory_cost" => SCypherError::InvalidMemoryCost(quoted()),
+            "invalid_parallelism" => SCypherError::InvalidParallelism(quoted()),
+            "too_many_attempts" => SCypherError::TooManyAttempts { retry_after_secs: number("retry_after_secs") },
+            "consent_required" => SCypherError::ConsentRequired(after("User consent required: ")),
+            "crypto_error" => SCypherError::CryptoError(after("Cryptographic error: ")),
+            "key_derivation_failed" => SCypherError::KeyDerivationFailed,
+            "io_error" => SCypherError::IoError(after("IO error: ")),
+            "file_error" => SCypherError::FileError(after("File error: ")),
+            "insufficient_memory" => SCypherError::InsufficientMemory {
+                required_kb: number("required_kb"),
+                available_kb: number("available_kb"),
+                max_memory_cost: number("max_memory_cost") as u32,
+            },
+            "unsupported_platform" => SCypherError::UnsupportedPlatform,
+            "keychain_error" => SCypherError::KeychainError(after("OS keychain error: ")),
+            "hardware_token_error" => SCypherError::HardwareTokenError(after("Hardware token error: ")),
+            "worker_error" => SCypherError::WorkerError(after("Crypto worker error: ")),
+            "window_error" => SCypherError::WindowError(after("Window error: ")),
+            _ => SCypherError::WorkerError(format!("Unrecognized error from worker: {}", message)),
+        }
+    }
+
     /// Crear error de palabra BIP39 inválida
     pub fn invalid_word<S: Into<String>>(word: S) -> Self {
         SCypherError::InvalidBip39Word(word.into())
@@ -141,6 +335,9 @@ mod tests {
 
         let error = SCypherError::InvalidBip39Word("invalid".to_string());
         assert!(error.to_string().contains("invalid"));
+
+        let error = SCypherError::InsufficientMemory { required_kb: 2_097_152, available_kb: 1_048_576, max_memory_cost: 983_040 };
+        assert!(error.to_string().contains("983040"));
     }
 
     #[test]
@@ -158,5 +355,80 @@ mod tests {
         // Test que se puede serializar (requerido por Tauri)
         let serialized = serde_json::to_string(&error);
         assert!(serialized.is_ok());
+
+        let value = serde_json::to_value(SCypherError::InvalidChecksum).unwrap();
+        assert_eq!(value["code"], "invalid_checksum");
+        assert_eq!(value["numeric_code"], 1005);
+        assert!(value["message"].as_str().unwrap().contains("checksum"));
+        assert!(value.get("details").is_none());
+
+        let value = serde_json::to_value(SCypherError::InsufficientMemory {
+            required_kb: 2_097_152,
+            available_kb: 1_048_576,
+            max_memory_cost: 983_040,
+        }).unwrap();
+        assert_eq!(value["details"]["max_memory_cost"], 983_040);
+
+        let value = serde_json::to_value(SCypherError::InvalidWords(vec![crate::bip39::validation::InvalidWordInfo {
+            position: 3,
+            word: "abandn".to_string(),
+            suggestion: Some("abandon".to_string()),
+        }])).unwrap();
+        assert_eq!(value["code"], "invalid_words");
+        assert_eq!(value["details"]["positions"], serde_json::json!([3]));
+        assert!(value["message"].as_str().unwrap().contains("at word 4"));
+    }
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let errors = [
+            SCypherError::InvalidSeedPhrase,
+            SCypherError::InvalidWordCount(0),
+            SCypherError::InvalidBip39Word(String::new()),
+            SCypherError::AmbiguousWord { prefix: String::new(), candidates: Vec::new() },
+            SCypherError::InvalidChecksum,
+            SCypherError::InvalidWords(Vec::new()),
+            SCypherError::InvalidPassword,
+            SCypherError::PasswordMismatch,
+            SCypherError::InvalidIterations(String::new()),
+            SCypherError::InvalidMemoryCost(String::new()),
+            SCypherError::InvalidParallelism(String::new()),
+            SCypherError::TooManyAttempts { retry_after_secs: 0 },
+            SCypherError::ConsentRequired(String::new()),
+            SCypherError::CryptoError(String::new()),
+            SCypherError::KeyDerivationFailed,
+            SCypherError::IoError(String::new()),
+            SCypherError::FileError(String::new()),
+            SCypherError::InsufficientMemory { required_kb: 0, available_kb: 0, max_memory_cost: 0 },
+            SCypherError::UnsupportedPlatform,
+            SCypherError::KeychainError(String::new()),
+            SCypherError::HardwareTokenError(String::new()),
+            SCypherError::WorkerError(String::new()),
+            SCypherError::WindowError(String::new()),
+        ];
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.code()).collect();
+        let numeric: std::collections::HashSet<_> = errors.iter().map(|e| e.numeric_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert_eq!(numeric.len(), errors.len());
+        assert_eq!(SCypherError::KeyDerivationFailed.numeric_code(), 3002);
+    }
+
+    #[test]
+    fn test_from_serialized_roundtrip() {
+        let errors = [
+            SCypherError::InvalidChecksum,
+            SCypherError::InvalidWordCount(11),
+            SCypherError::InvalidParallelism("0".to_string()),
+            SCypherError::CryptoError("Argon2 error: MemoryTooLittle".to_string()),
+            SCypherError::InsufficientMemory { required_kb: 2_097_152, available_kb: 1_048_576, max_memory_cost: 983_040 },
+            SCypherError::AmbiguousWord { prefix: "aba".to_string(), candidates: vec!["abandon".to_string()] },
+        ];
+        for error in errors {
+            let value = serde_json::to_value(&error).unwrap();
+            assert_eq!(serde_json::to_value(SCypherError::from_serialized(&value)).unwrap(), value);
+        }
+
+        let unknown = SCypherError::from_serialized(&serde_json::json!({"code": "other", "message": "boom"}));
+        assert_eq!(unknown.code(), "worker_error");
     }
 }
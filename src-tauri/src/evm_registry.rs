@@ -0,0 +1,136 @@
+// =============================================================================
+// REGISTRO EVM EMBEBIDO (CHAIN IDS, EXPLORADORES Y TOKENS COMUNES)
+// Datos fijos para que el frontend pueda enlazar direcciones a su explorador
+// (cuando el usuario pase a una máquina conectada) sin codificarlos en JS.
+// Solo se incluyen contratos muy conocidos; no es una lista de tokens completa.
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// Marcador que se sustituye por la dirección en la plantilla del explorador
+pub const ADDRESS_PLACEHOLDER: &str = "{address}";
+
+/// Metadatos de un token ERC-20/BEP-20
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub symbol: String,
+    pub name: String,
+    pub contract: String,     // Dirección EIP-55
+    pub decimals: u8,
+}
+
+/// Cadena EVM con su chain ID, explorador y tokens comunes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvmChain {
+    pub network: String,              // Clave de red usada en AddressSet
+    pub name: String,
+    pub chain_id: u64,
+    pub native_symbol: String,
+    pub explorer_url_template: String, // Contiene {address}
+    pub tokens: Vec<TokenInfo>,
+}
+
+/// Tabla de tokens: (símbolo, nombre, contrato, decimales)
+type TokenTable = &'static [(&'static str, &'static str, &'static str, u8)];
+
+const ETHEREUM_TOKENS: TokenTable = &[
+    ("USDT", "Tether USD", "0xdAC17F958D2ee523a2206206994597C13D831ec7", 6),
+    ("USDC", "USD Coin", "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6),
+    ("DAI", "Dai Stablecoin", "0x6B175474E89094C44Da98b954EedeAC495271d0F", 18),
+    ("WETH", "Wrapped Ether", "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", 18),
+    ("WBTC", "Wrapped BTC", "0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599", 8),
+];
+
+const BSC_TOKENS: TokenTable = &[
+    ("USDT", "Tether USD", "0x55d398326f99059fF775485246999027B3197955", 18),
+    ("USDC", "USD Coin", "0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d", 18),
+    ("BUSD", "Binance USD", "0xe9e7CEA3DedcA5984780Bafc599bD69ADd087D56", 18),
+    ("WBNB", "Wrapped BNB", "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c", 18),
+];
+
+const POLYGON_TOKENS: TokenTable = &[
+    ("USDT", "Tether USD", "0xc2132D05D31c914a87C6611C10748AEb04B58e8F", 6),
+    ("USDC", "USD Coin", "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359", 6),
+    ("DAI", "Dai Stablecoin", "0x8f3Cf7ad23Cd3CaDbD9735AFf958023239c6A063", 18),
+    ("WMATIC", "Wrapped Matic", "0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270", 18),
+];
+
+/// Cadenas conocidas: (red, nombre, chain ID, símbolo nativo, explorador, tokens)
+const EVM_CHAINS: &[(&str, &str, u64, &str, &str, TokenTable)] = &[
+    ("ethereum", "Ethereum Mainnet", 1, "ETH", "https://etherscan.io/address/{address}", ETHEREUM_TOKENS),
+    ("bsc", "BNB Smart Chain", 56, "BNB", "https://bscscan.com/address/{address}", BSC_TOKENS),
+    ("polygon", "Polygon PoS", 137, "POL", "https://polygonscan.com/address/{address}", POLYGON_TOKENS),
+];
+
+fn build_chain(entry: &(&str, &str, u64, &str, &str, TokenTable)) -> EvmChain {
+    let (network, name, chain_id, native_symbol, explorer, tokens) = *entry;
+    EvmChain {
+        network: network.to_string(),
+        name: name.to_string(),
+        chain_id,
+        native_symbol: native_symbol.to_string(),
+        explorer_url_template: explorer.to_string(),
+        tokens: tokens
+            .iter()
+            .map(|(symbol, name, contract, decimals)| TokenInfo {
+                symbol: symbol.to_string(),
+                name: name.to_string(),
+                contract: contract.to_string(),
+                decimals: *decimals,
+            })
+            .collect(),
+    }
+}
+
+/// Todas las cadenas EVM del registro
+pub fn evm_chains() -> Vec<EvmChain> {
+    EVM_CHAINS.iter().map(build_chain).collect()
+}
+
+/// Chain ID de una red EVM ("ethereum", "bsc", "polygon")
+pub fn chain_id(network: &str) -> Option<u64> {
+    EVM_CHAINS.iter().find(|entry| entry.0 == network).map(|entry| entry.2)
+}
+
+/// URL del explorador para una dirección concreta
+pub fn explorer_url(network: &str, address: &str) -> Option<String> {
+    EVM_CHAINS
+        .iter()
+        .find(|entry| entry.0 == network)
+        .map(|entry| entry.4.replace(ADDRESS_PLACEHOLDER, address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_lookup() {
+        assert_eq!(chain_id("ethereum"), Some(1));
+        assert_eq!(chain_id("bsc"), Some(56));
+        assert_eq!(chain_id("polygon"), Some(137));
+        assert_eq!(chain_id("bitcoin"), None);
+
+        assert_eq!(
+            explorer_url("ethereum", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94").unwrap(),
+            "https://etherscan.io/address/0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+        assert_eq!(evm_chains()[2].native_symbol, "POL");
+    }
+
+    #[test]
+    fn test_token_contracts_are_eip55() {
+        for chain in evm_chains() {
+            for token in chain.tokens {
+                let bytes = hex::decode(&token.contract[2..]).unwrap();
+                assert_eq!(
+                    crate::addresses::to_eip55_checksum_address(&bytes),
+                    token.contract,
+                    "{} on {}",
+                    token.symbol,
+                    chain.network
+                );
+            }
+        }
+    }
+}
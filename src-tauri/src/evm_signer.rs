@@ -0,0 +1,590 @@
+// =============================================================================
+// FIRMANTE EVM OFFLINE
+// Firma con una clave Ethereum derivada, sin conexión:
+// - Transacciones sin firmar codificadas en RLP: legacy (con o sin EIP-155),
+//   EIP-2930 (tipo 0x01) y EIP-1559 (tipo 0x02). Devuelve la rawTx firmada.
+// - Datos tipados EIP-712 (JSON con types, primaryType, domain y message).
+// =============================================================================
+
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tiny_keccak::{Hasher, Keccak};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Resultado de una firma EVM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvmSignature {
+    pub address: String,
+    pub path: String,
+    pub hash: String,                     // Hash firmado (0x...)
+    pub signature: String,                // r || s || v (0x...)
+    pub raw_transaction: Option<String>,  // Solo para transacciones
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+// =============================================================================
+// RLP MÍNIMO (CODIFICACIÓN Y DECODIFICACIÓN)
+// =============================================================================
+
+/// Elemento RLP: cadena de bytes o lista
+#[derive(Debug, Clone, PartialEq)]
+enum Rlp {
+    Bytes(Vec<u8>),
+    List(Vec<Rlp>),
+}
+
+/// Entero sin ceros a la izquierda (representación canónica RLP)
+fn trim_leading_zeros(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    bytes[start..].to_vec()
+}
+
+fn rlp_length_prefix(length: usize, offset: u8) -> Vec<u8> {
+    if length < 56 {
+        vec![offset + length as u8]
+    } else {
+        let length_bytes = trim_leading_zeros(&(length as u64).to_be_bytes());
+        let mut prefix = vec![offset + 55 + length_bytes.len() as u8];
+        prefix.extend_from_slice(&length_bytes);
+        prefix
+    }
+}
+
+fn rlp_encode(item: &Rlp) -> Vec<u8> {
+    match item {
+        Rlp::Bytes(bytes) if bytes.len() == 1 && bytes[0] < 0x80 => bytes.clone(),
+        Rlp::Bytes(bytes) => {
+            let mut out = rlp_length_prefix(bytes.len(), 0x80);
+            out.extend_from_slice(bytes);
+            out
+        }
+        Rlp::List(items) => {
+            let payload: Vec<u8> = items.iter().flat_map(rlp_encode).collect();
+            let mut out = rlp_length_prefix(payload.len(), 0xc0);
+            out.extend_from_slice(&payload);
+            out
+        }
+    }
+}
+
+/// Decodificar un elemento RLP devolviendo los bytes consumidos
+fn rlp_decode_item(data: &[u8]) -> Result<(Rlp, usize)> {
+    let invalid = || SCypherError::crypto("Invalid RLP encoding".to_string());
+
+    let prefix = *data.first().ok_or_else(invalid)?;
+
+    let read_length = |length_of_length: usize| -> Result<usize> {
+        let bytes = data.get(1..1 + length_of_length).ok_or_else(invalid)?;
+        if length_of_length > 8 || bytes.first() == Some(&0) {
+            return Err(invalid());
+        }
+        Ok(bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize))
+    };
+
+    let (is_list, header, length) = match prefix {
+        0x00..=0x7f => return Ok((Rlp::Bytes(vec![prefix]), 1)),
+        0x80..=0xb7 => (false, 1, (prefix - 0x80) as usize),
+        0xb8..=0xbf => {
+            let length_of_length = (prefix - 0xb7) as usize;
+            (false, 1 + length_of_length, read_length(length_of_length)?)
+        }
+        0xc0..=0xf7 => (true, 1, (prefix - 0xc0) as usize),
+        0xf8..=0xff => {
+            let length_of_length = (prefix - 0xf7) as usize;
+            (true, 1 + length_of_length, read_length(length_of_length)?)
+        }
+    };
+
+    let end = header.checked_add(length).ok_or_else(invalid)?;
+    let payload = data.get(header..end).ok_or_else(invalid)?;
+
+    if !is_list {
+        return Ok((Rlp::Bytes(payload.to_vec()), end));
+    }
+
+    let mut items = Vec::new();
+    let mut offset = 0;
+    while offset < payload.len() {
+        let (item, consumed) = rlp_decode_item(&payload[offset..])?;
+        items.push(item);
+        offset += consumed;
+    }
+
+    Ok((Rlp::List(items), end))
+}
+
+/// Decodificar una lista RLP que ocupe todo el buffer
+fn rlp_decode_list(data: &[u8]) -> Result<Vec<Rlp>> {
+    match rlp_decode_item(data)? {
+        (Rlp::List(items), consumed) if consumed == data.len() => Ok(items),
+        _ => Err(SCypherError::crypto("Unsigned transaction must be a single RLP list".to_string())),
+    }
+}
+
+fn rlp_to_u64(item: &Rlp) -> Result<u64> {
+    match item {
+        Rlp::Bytes(bytes) if bytes.len() <= 8 => Ok(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64)),
+        _ => Err(SCypherError::crypto("Invalid integer field in transaction".to_string())),
+    }
+}
+
+// =============================================================================
+// FIRMA DE TRANSACCIONES
+// =============================================================================
+
+/// Firma recuperable secp256k1: (recovery id, r, s)
+fn sign_hash(secret: &[u8; 32], hash: &[u8; 32]) -> Result<(u8, [u8; 32], [u8; 32])> {
+    let secp = secp256k1::Secp256k1::new();
+    let secret_key = secp256k1::SecretKey::from_slice(secret)
+        .map_err(|e| SCypherError::crypto(format!("Invalid private key: {}", e)))?;
+    let message = secp256k1::Message::from_slice(hash)
+        .map_err(|e| SCypherError::crypto(format!("Invalid hash: {}", e)))?;
+
+    let (recovery_id, compact) = secp.sign_ecdsa_recoverable(&message, &secret_key).serialize_compact();
+
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&compact[..32]);
+    s.copy_from_slice(&compact[32..]);
+
+    Ok((recovery_id.to_i32() as u8, r, s))
+}
+
+/// Firmar una transacción sin firmar (hex) y devolver (hash, firma, rawTx)
+fn sign_transaction_with_secret(secret: &[u8; 32], unsigned_tx: &[u8]) -> Result<([u8; 32], Vec<u8>, Vec<u8>)> {
+    let first = *unsigned_tx
+        .first()
+        .ok_or_else(|| SCypherError::crypto("Empty transaction".to_string()))?;
+
+    match first {
+        // Transacción tipada (EIP-2718): tipo || rlp([...campos])
+        0x01 | 0x02 => {
+            let expected_fields = if first == 0x01 { 8 } else { 9 };
+            let mut fields = rlp_decode_list(&unsigned_tx[1..])?;
+            if fields.len() != expected_fields {
+                return Err(SCypherError::crypto(format!(
+                    "Type 0x{:02x} transaction must have {} fields, found {}",
+                    first,
+                    expected_fields,
+                    fields.len()
+                )));
+            }
+
+            let hash = keccak256(unsigned_tx);
+            let (recovery_id, r, s) = sign_hash(secret, &hash)?;
+
+            fields.push(Rlp::Bytes(trim_leading_zeros(&[recovery_id])));
+            fields.push(Rlp::Bytes(trim_leading_zeros(&r)));
+            fields.push(Rlp::Bytes(trim_leading_zeros(&s)));
+
+            let mut raw = vec![first];
+            raw.extend(rlp_encode(&Rlp::List(fields)));
+
+            let mut signature = [r, s].concat();
+            signature.push(27 + recovery_id);
+            Ok((hash, signature, raw))
+        }
+        // Transacción legacy: rlp([nonce, gasPrice, gas, to, value, data(, chainId, 0, 0)])
+        0xc0..=0xff => {
+            let fields = rlp_decode_list(unsigned_tx)?;
+            let chain_id = match fields.len() {
+                6 => None,
+                9 => Some(rlp_to_u64(&fields[6])?),
+                count => {
+                    return Err(SCypherError::crypto(format!(
+                        "Legacy transaction must have 6 or 9 fields, found {}",
+                        count
+                    )))
+                }
+            };
+
+            let hash = keccak256(unsigned_tx);
+            let (recovery_id, r, s) = sign_hash(secret, &hash)?;
+
+            // EIP-155: v = chainId * 2 + 35 + recovery_id
+            let v = match chain_id {
+                Some(chain_id) => chain_id
+                    .checked_mul(2)
+                    .and_then(|v| v.checked_add(35 + recovery_id as u64))
+                    .ok_or_else(|| SCypherError::crypto("Chain id too large".to_string()))?,
+                None => 27 + recovery_id as u64,
+            };
+
+            let mut signed_fields = fields[..6].to_vec();
+            signed_fields.push(Rlp::Bytes(trim_leading_zeros(&v.to_be_bytes())));
+            signed_fields.push(Rlp::Bytes(trim_leading_zeros(&r)));
+            signed_fields.push(Rlp::Bytes(trim_leading_zeros(&s)));
+
+            let mut signature = [r, s].concat();
+            signature.push(27 + recovery_id);
+            Ok((hash, signature, rlp_encode(&Rlp::List(signed_fields))))
+        }
+        _ => Err(SCypherError::crypto(format!("Unsupported transaction type: 0x{:02x}", first))),
+    }
+}
+
+// =============================================================================
+// EIP-712 (DATOS TIPADOS)
+// =============================================================================
+
+/// Campos declarados para un tipo struct
+fn struct_fields<'a>(types: &'a Value, type_name: &str) -> Option<&'a Vec<Value>> {
+    types.get(type_name).and_then(Value::as_array)
+}
+
+/// Tipo base sin sufijos de array ("Person[]" -> "Person")
+fn base_type(type_name: &str) -> &str {
+    type_name.split('[').next().unwrap_or(type_name)
+}
+
+/// Recopilar dependencias struct de un tipo (incluyéndolo)
+fn collect_dependencies(types: &Value, type_name: &str, found: &mut Vec<String>) -> Result<()> {
+    let name = base_type(type_name);
+    if found.iter().any(|t| t == name) {
+        return Ok(());
+    }
+    let Some(fields) = struct_fields(types, name) else {
+        return Ok(());
+    };
+
+    found.push(name.to_string());
+    for field in fields {
+        let field_type = field["type"]
+            .as_str()
+            .ok_or_else(|| SCypherError::crypto(format!("Field without type in {}", name)))?;
+        collect_dependencies(types, field_type, found)?;
+    }
+    Ok(())
+}
+
+/// encodeType: tipo principal seguido de dependencias en orden alfabético
+fn encode_type(types: &Value, primary_type: &str) -> Result<String> {
+    let mut dependencies = Vec::new();
+    collect_dependencies(types, primary_type, &mut dependencies)?;
+    dependencies.retain(|t| t != primary_type);
+    dependencies.sort();
+
+    let mut encoded = String::new();
+    for type_name in std::iter::once(primary_type.to_string()).chain(dependencies) {
+        let fields = struct_fields(types, &type_name)
+            .ok_or_else(|| SCypherError::crypto(format!("Unknown EIP-712 type: {}", type_name)))?;
+        let members: Vec<String> = fields
+            .iter()
+            .map(|f| format!("{} {}", f["type"].as_str().unwrap_or(""), f["name"].as_str().unwrap_or("")))
+            .collect();
+        encoded.push_str(&format!("{}({})", type_name, members.join(",")));
+    }
+    Ok(encoded)
+}
+
+/// Interpretar un entero (número JSON, decimal o hex) como palabra de 256 bits
+fn parse_integer(value: &Value, signed: bool) -> Result<[u8; 32]> {
+    let invalid = || SCypherError::crypto(format!("Invalid EIP-712 integer: {}", value));
+
+    let (negative, magnitude) = match value {
+        Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => (false, U256::from(u)),
+            (None, Some(i)) => (true, U256::from(i.unsigned_abs())),
+            _ => return Err(invalid()),
+        },
+        Value::String(s) => {
+            let s = s.trim();
+            let (negative, digits) = match s.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, s),
+            };
+            let magnitude = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                Some(hex_digits) => U256::from_str_radix(hex_digits, 16).map_err(|_| invalid())?,
+                None => U256::from_dec_str(digits).map_err(|_| invalid())?,
+            };
+            (negative, magnitude)
+        }
+        _ => return Err(invalid()),
+    };
+
+    if negative && !signed {
+        return Err(invalid());
+    }
+
+    // Complemento a dos para enteros negativos
+    let word = if negative { (!magnitude).overflowing_add(U256::one()).0 } else { magnitude };
+    let mut bytes = [0u8; 32];
+    word.to_big_endian(&mut bytes);
+    Ok(bytes)
+}
+
+fn parse_hex_value(value: &Value) -> Result<Vec<u8>> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| SCypherError::crypto(format!("Expected hex string, found {}", value)))?;
+    hex::decode(text.trim_start_matches("0x"))
+        .map_err(|_| SCypherError::crypto(format!("Invalid hex value: {}", text)))
+}
+
+/// Codificar un valor según su tipo EIP-712 (32 bytes)
+fn encode_value(types: &Value, type_name: &str, value: &Value) -> Result<[u8; 32]> {
+    // Arrays: keccak256 de la concatenación de los elementos codificados
+    if let Some(open) = type_name.rfind('[') {
+        let element_type = &type_name[..open];
+        let elements = value
+            .as_array()
+            .ok_or_else(|| SCypherError::crypto(format!("Expected array for {}", type_name)))?;
+        let mut encoded = Vec::with_capacity(elements.len() * 32);
+        for element in elements {
+            encoded.extend_from_slice(&encode_value(types, element_type, element)?);
+        }
+        return Ok(keccak256(&encoded));
+    }
+
+    if struct_fields(types, type_name).is_some() {
+        return Ok(keccak256(&encode_data(types, type_name, value)?));
+    }
+
+    let mut word = [0u8; 32];
+    match type_name {
+        "string" => {
+            let text = value
+                .as_str()
+                .ok_or_else(|| SCypherError::crypto("Expected string value".to_string()))?;
+            word = keccak256(text.as_bytes());
+        }
+        "bytes" => word = keccak256(&parse_hex_value(value)?),
+        "bool" => {
+            let flag = value
+                .as_bool()
+                .ok_or_else(|| SCypherError::crypto("Expected boolean value".to_string()))?;
+            word[31] = flag as u8;
+        }
+        "address" => {
+            let address = parse_hex_value(value)?;
+            if address.len() != 20 {
+                return Err(SCypherError::crypto("Address must be 20 bytes".to_string()));
+            }
+            word[12..].copy_from_slice(&address);
+        }
+        t if t.starts_with("bytes") => {
+            let bytes = parse_hex_value(value)?;
+            if bytes.len() > 32 {
+                return Err(SCypherError::crypto(format!("Value too long for {}", t)));
+            }
+            word[..bytes.len()].copy_from_slice(&bytes);
+        }
+        t if t.starts_with("uint") => word = parse_integer(value, false)?,
+        t if t.starts_with("int") => word = parse_integer(value, true)?,
+        other => return Err(SCypherError::crypto(format!("Unsupported EIP-712 type: {}", other))),
+    }
+
+    Ok(word)
+}
+
+/// encodeData: typeHash || valores codificados de cada campo
+fn encode_data(types: &Value, type_name: &str, data: &Value) -> Result<Vec<u8>> {
+    let fields = struct_fields(types, type_name)
+        .ok_or_else(|| SCypherError::crypto(format!("Unknown EIP-712 type: {}", type_name)))?;
+
+    let mut encoded = keccak256(encode_type(types, type_name)?.as_bytes()).to_vec();
+    for field in fields {
+        let name = field["name"].as_str().unwrap_or("");
+        let field_type = field["type"].as_str().unwrap_or("");
+        let value = data
+            .get(name)
+            .ok_or_else(|| SCypherError::crypto(format!("Missing EIP-712 field: {}.{}", type_name, name)))?;
+        encoded.extend_from_slice(&encode_value(types, field_type, value)?);
+    }
+    Ok(encoded)
+}
+
+/// Hash EIP-712: keccak256(0x1901 || domainSeparator || hashStruct(message))
+fn eip712_hash(typed_data: &Value) -> Result<[u8; 32]> {
+    let types = &typed_data["types"];
+    let primary_type = typed_data["primaryType"]
+        .as_str()
+        .ok_or_else(|| SCypherError::crypto("EIP-712 data is missing primaryType".to_string()))?;
+
+    let domain_separator = keccak256(&encode_data(types, "EIP712Domain", &typed_data["domain"])?);
+    let message_hash = keccak256(&encode_data(types, primary_type, &typed_data["message"])?);
+
+    let mut payload = vec![0x19, 0x01];
+    payload.extend_from_slice(&domain_separator);
+    payload.extend_from_slice(&message_hash);
+    Ok(keccak256(&payload))
+}
+
+// =============================================================================
+// API PÚBLICA
+// =============================================================================
+
+/// Derivar la clave secreta y dirección EVM en una ruta
+fn derive_evm_secret(seed_phrase: &str, passphrase: Option<&str>, path: &str) -> Result<([u8; 32], String)> {
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+    let key = crate::addresses::derive_key_at_path(&master_key, path)?;
+    let secret: [u8; 32] = key.private_key().to_bytes().into();
+    Ok((secret, crate::addresses::evm_address_from_key(&key)?))
+}
+
+/// Firmar una transacción EVM sin firmar (hex RLP) con la clave de `path`
+pub fn sign_transaction(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    path: &str,
+    unsigned_tx_hex: &str,
+) -> Result<EvmSignature> {
+    let unsigned_tx = hex::decode(unsigned_tx_hex.trim().trim_start_matches("0x"))
+        .map_err(|_| SCypherError::crypto("Unsigned transaction is not valid hex".to_string()))?;
+
+    let (mut secret, address) = derive_evm_secret(seed_phrase, passphrase, path)?;
+    let result = sign_transaction_with_secret(&secret, &unsigned_tx);
+    secret.zeroize();
+    let (hash, signature, raw) = result?;
+
+    Ok(EvmSignature {
+        address,
+        path: path.to_string(),
+        hash: format!("0x{}", hex::encode(hash)),
+        signature: format!("0x{}", hex::encode(signature)),
+        raw_transaction: Some(format!("0x{}", hex::encode(raw))),
+    })
+}
+
+/// Firmar datos tipados EIP-712 (JSON) con la clave de `path`
+pub fn sign_typed_data(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    path: &str,
+    typed_data_json: &str,
+) -> Result<EvmSignature> {
+    let typed_data: Value = serde_json::from_str(typed_data_json)
+        .map_err(|e| SCypherError::crypto(format!("Invalid EIP-712 JSON: {}", e)))?;
+    let hash = eip712_hash(&typed_data)?;
+
+    let (mut secret, address) = derive_evm_secret(seed_phrase, passphrase, path)?;
+    let result = sign_hash(&secret, &hash);
+    secret.zeroize();
+    let (recovery_id, r, s) = result?;
+
+    let mut signature = [r, s].concat();
+    signature.push(27 + recovery_id);
+
+    Ok(EvmSignature {
+        address,
+        path: path.to_string(),
+        hash: format!("0x{}", hex::encode(hash)),
+        signature: format!("0x{}", hex::encode(signature)),
+        raw_transaction: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rlp_roundtrip() {
+        let item = Rlp::List(vec![
+            Rlp::Bytes(vec![]),
+            Rlp::Bytes(vec![0x7f]),
+            Rlp::Bytes(vec![0xaa; 60]),
+            Rlp::List(vec![Rlp::Bytes(b"dog".to_vec())]),
+        ]);
+        let encoded = rlp_encode(&item);
+        assert_eq!(rlp_decode_item(&encoded).unwrap(), (item, encoded.len()));
+        assert_eq!(rlp_encode(&Rlp::Bytes(b"dog".to_vec())), vec![0x83, b'd', b'o', b'g']);
+    }
+
+    #[test]
+    fn test_eip155_official_vector() {
+        // Ejemplo de la especificación EIP-155 (clave 0x4646...46, chainId 1)
+        let secret = [0x46u8; 32];
+        let unsigned = hex::decode(
+            "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080",
+        ).unwrap();
+
+        let (_, _, raw) = sign_transaction_with_secret(&secret, &unsigned).unwrap();
+        assert_eq!(
+            hex::encode(raw),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn test_eip712_official_mail_example() {
+        let typed_data: Value = serde_json::from_str(r#"{
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"}
+                ],
+                "Person": [
+                    {"name": "name", "type": "string"},
+                    {"name": "wallet", "type": "address"}
+                ],
+                "Mail": [
+                    {"name": "from", "type": "Person"},
+                    {"name": "to", "type": "Person"},
+                    {"name": "contents", "type": "string"}
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+                "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+                "contents": "Hello, Bob!"
+            }
+        }"#).unwrap();
+
+        let hash = eip712_hash(&typed_data).unwrap();
+        assert_eq!(hex::encode(hash), "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+        // Clave del ejemplo: keccak256("cow")
+        let (recovery_id, r, s) = sign_hash(&keccak256(b"cow"), &hash).unwrap();
+        assert_eq!(27 + recovery_id, 28);
+        assert_eq!(hex::encode(r), "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d");
+        assert_eq!(hex::encode(s), "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562");
+    }
+
+    #[test]
+    fn test_typed_transaction_and_derivation() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // EIP-1559: 0x02 || rlp([chainId, nonce, maxPriority, maxFee, gas, to, value, data, accessList])
+        let fields = Rlp::List(vec![
+            Rlp::Bytes(vec![0x01]),
+            Rlp::Bytes(vec![]),
+            Rlp::Bytes(vec![0x3b, 0x9a, 0xca, 0x00]),
+            Rlp::Bytes(vec![0x04, 0xa8, 0x17, 0xc8, 0x00]),
+            Rlp::Bytes(vec![0x52, 0x08]),
+            Rlp::Bytes(vec![0x35; 20]),
+            Rlp::Bytes(vec![0x01]),
+            Rlp::Bytes(vec![]),
+            Rlp::List(vec![]),
+        ]);
+        let mut unsigned = vec![0x02];
+        unsigned.extend(rlp_encode(&fields));
+
+        let signed = sign_transaction(phrase, None, "m/44'/60'/0'/0/0", &hex::encode(&unsigned)).unwrap();
+        assert_eq!(signed.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        let raw = hex::decode(signed.raw_transaction.unwrap().trim_start_matches("0x")).unwrap();
+        assert_eq!(raw[0], 0x02);
+        assert_eq!(rlp_decode_list(&raw[1..]).unwrap().len(), 12);
+
+        assert!(sign_transaction(phrase, None, "m/44'/60'/0'/0/0", "0x05").is_err());
+    }
+}
@@ -0,0 +1,291 @@
+// =============================================================================
+// AUTOVERIFICACIÓN DE INTEGRIDAD DEL EJECUTABLE
+// Calcula el SHA256 del ejecutable en uso y de la lista BIP39 incluida y los
+// compara con un manifiesto firmado (ed25519) que acompaña a la release
+// (`<ejecutable>.integrity.json`). La clave pública de release se fija al
+// compilar con SCYPHER_RELEASE_PUBLIC_KEY; sin ella la build es de desarrollo
+// y solo se puede comprobar la lista de palabras.
+// =============================================================================
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+
+/// Identificador de formato del manifiesto
+pub const MANIFEST_FORMAT: &str = "scypher-integrity";
+
+/// Versión actual del formato del manifiesto
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Sufijo añadido a la ruta del ejecutable
+const MANIFEST_SUFFIX: &str = ".integrity.json";
+
+/// Clave pública ed25519 de release (hex), fijada al compilar
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("SCYPHER_RELEASE_PUBLIC_KEY");
+
+/// SHA256 de la lista BIP39 inglesa oficial (una palabra por línea)
+const BIP39_ENGLISH_SHA256: &str = "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda";
+
+/// Nombres de los recursos en el manifiesto
+const RESOURCE_EXECUTABLE: &str = "executable";
+const RESOURCE_WORDLIST: &str = "bip39_wordlist";
+
+/// Manifiesto firmado de la release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityManifest {
+    pub format: String,
+    pub version: u32,
+    pub app_version: String,
+    pub files: BTreeMap<String, String>,  // recurso -> SHA256 (hex)
+    pub signature: String,                // ed25519 (hex) sobre `signed_message`
+}
+
+/// Recurso cuyo hash no coincide con el esperado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityMismatch {
+    pub resource: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Resultado de la verificación
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub status: String,  // "verified", "tampered", "invalid_signature", "manifest_missing" o "unsigned_build"
+    pub executable_path: String,
+    pub executable_sha256: String,
+    pub wordlist_sha256: String,
+    pub signature_valid: Option<bool>,
+    pub mismatches: Vec<IntegrityMismatch>,
+}
+
+/// SHA256 de la lista BIP39 incluida en el binario
+pub fn wordlist_sha256() -> String {
+    let mut hasher = Sha256::new();
+    for word in crate::bip39::wordlist::BIP39_WORDLIST.iter() {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// SHA256 de un archivo leído por bloques
+fn file_sha256(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| SCypherError::file(format!("Cannot open {}: {}", path.display(), e)))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| SCypherError::file(format!("Cannot read {}: {}", path.display(), e)))?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Ruta del manifiesto de un ejecutable
+pub fn manifest_path(executable: &Path) -> PathBuf {
+    let mut path = executable.as_os_str().to_owned();
+    path.push(MANIFEST_SUFFIX);
+    PathBuf::from(path)
+}
+
+/// Bytes firmados: cabecera, versión de la app y una línea por recurso (orden fijo)
+pub fn signed_message(manifest: &IntegrityManifest) -> Vec<u8> {
+    let mut message = format!("{}-v{}\n{}\n", manifest.format, manifest.version, manifest.app_version);
+    for (resource, hash) in &manifest.files {
+        message.push_str(&format!("{} {}\n", resource, hash.to_lowercase()));
+    }
+    message.into_bytes()
+}
+
+/// Verificar la firma del manifiesto con una clave pública
+pub fn verify_manifest(manifest: &IntegrityManifest, public_key: &[u8; 32]) -> Result<()> {
+    if manifest.format != MANIFEST_FORMAT || manifest.version > MANIFEST_VERSION {
+        return Err(SCypherError::file(format!(
+            "Unsupported integrity manifest: {} v{}",
+            manifest.format, manifest.version
+        )));
+    }
+
+    let key = VerifyingKey::from_bytes(public_key)
+        .map_err(|e| SCypherError::crypto(format!("Invalid release public key: {}", e)))?;
+    let signature: [u8; 64] = hex::decode(manifest.signature.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| SCypherError::crypto("Invalid manifest signature encoding".to_string()))?;
+
+    key.verify(&signed_message(manifest), &Signature::from_bytes(&signature))
+        .map_err(|_| SCypherError::crypto("Integrity manifest signature is invalid".to_string()))
+}
+
+/// Recursos cuyo hash difiere del manifiesto (o que el manifiesto no cubre)
+fn find_mismatches(manifest: &IntegrityManifest, actual: &BTreeMap<String, String>) -> Vec<IntegrityMismatch> {
+    actual
+        .iter()
+        .filter_map(|(resource, actual_hash)| {
+            let expected = manifest.files.get(resource).map(|h| h.to_lowercase());
+            if expected.as_deref() == Some(actual_hash.as_str()) {
+                None
+            } else {
+                Some(IntegrityMismatch {
+                    resource: resource.clone(),
+                    expected: expected.unwrap_or_else(|| "(not listed)".to_string()),
+                    actual: actual_hash.clone(),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Decidir el estado a partir de los hashes, el manifiesto y la clave
+fn evaluate(
+    executable_path: &Path,
+    actual: BTreeMap<String, String>,
+    manifest: Option<&IntegrityManifest>,
+    public_key: Option<&[u8; 32]>,
+) -> IntegrityReport {
+    let wordlist_hash = actual.get(RESOURCE_WORDLIST).cloned().unwrap_or_default();
+    let mut mismatches = Vec::new();
+
+    // La lista de palabras se comprueba siempre contra el hash oficial
+    if wordlist_hash != BIP39_ENGLISH_SHA256 {
+        mismatches.push(IntegrityMismatch {
+            resource: RESOURCE_WORDLIST.to_string(),
+            expected: BIP39_ENGLISH_SHA256.to_string(),
+            actual: wordlist_hash.clone(),
+        });
+    }
+
+    let (status, signature_valid) = match (public_key, manifest) {
+        (None, _) => ("unsigned_build", None),
+        (Some(_), None) => ("manifest_missing", None),
+        (Some(key), Some(manifest)) => match verify_manifest(manifest, key) {
+            Err(_) => ("invalid_signature", Some(false)),
+            Ok(()) => {
+                for mismatch in find_mismatches(manifest, &actual) {
+                    if !mismatches.iter().any(|m| m.resource == mismatch.resource) {
+                        mismatches.push(mismatch);
+                    }
+                }
+                ("verified", Some(true))
+            }
+        },
+    };
+
+    let status = if !mismatches.is_empty() { "tampered" } else { status };
+
+    IntegrityReport {
+        status: status.to_string(),
+        executable_path: executable_path.display().to_string(),
+        executable_sha256: actual.get(RESOURCE_EXECUTABLE).cloned().unwrap_or_default(),
+        wordlist_sha256: wordlist_hash,
+        signature_valid,
+        mismatches,
+    }
+}
+
+fn release_public_key() -> Result<Option<[u8; 32]>> {
+    match RELEASE_PUBLIC_KEY {
+        None => Ok(None),
+        Some(key_hex) => hex::decode(key_hex.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Some)
+            .ok_or_else(|| SCypherError::crypto("Embedded release public key is malformed".to_string())),
+    }
+}
+
+/// Verificar el ejecutable en uso y sus recursos contra el manifiesto de la release
+pub fn verify_integrity() -> Result<IntegrityReport> {
+    let executable = std::env::current_exe()
+        .map_err(|e| SCypherError::file(format!("Cannot locate running executable: {}", e)))?;
+
+    let mut actual = BTreeMap::new();
+    actual.insert(RESOURCE_EXECUTABLE.to_string(), file_sha256(&executable)?);
+    actual.insert(RESOURCE_WORDLIST.to_string(), wordlist_sha256());
+
+    let manifest_file = manifest_path(&executable);
+    let manifest = if manifest_file.exists() {
+        let content = fs::read_to_string(&manifest_file)
+            .map_err(|e| SCypherError::file(format!("Cannot read integrity manifest: {}", e)))?;
+        Some(
+            serde_json::from_str::<IntegrityManifest>(&content)
+                .map_err(|e| SCypherError::file(format!("Invalid integrity manifest: {}", e)))?,
+        )
+    } else {
+        None
+    };
+
+    let public_key = release_public_key()?;
+    Ok(evaluate(&executable, actual, manifest.as_ref(), public_key.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_manifest(key: &SigningKey, files: &BTreeMap<String, String>) -> IntegrityManifest {
+        let mut manifest = IntegrityManifest {
+            format: MANIFEST_FORMAT.to_string(),
+            version: MANIFEST_VERSION,
+            app_version: "0.1.0".to_string(),
+            files: files.clone(),
+            signature: String::new(),
+        };
+        manifest.signature = hex::encode(key.sign(&signed_message(&manifest)).to_bytes());
+        manifest
+    }
+
+    fn actual_hashes(executable_hash: &str) -> BTreeMap<String, String> {
+        let mut actual = BTreeMap::new();
+        actual.insert(RESOURCE_EXECUTABLE.to_string(), executable_hash.to_string());
+        actual.insert(RESOURCE_WORDLIST.to_string(), wordlist_sha256());
+        actual
+    }
+
+    #[test]
+    fn test_embedded_wordlist_hash() {
+        assert_eq!(wordlist_sha256(), BIP39_ENGLISH_SHA256);
+        assert_eq!(
+            manifest_path(Path::new("/opt/scypher/scypher-gui")),
+            PathBuf::from("/opt/scypher/scypher-gui.integrity.json")
+        );
+    }
+
+    #[test]
+    fn test_signed_manifest_verification() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = key.verifying_key().to_bytes();
+        let exe = Path::new("scypher-gui");
+        let manifest = signed_manifest(&key, &actual_hashes(&"ab".repeat(32)));
+
+        let report = evaluate(exe, actual_hashes(&"ab".repeat(32)), Some(&manifest), Some(&public_key));
+        assert_eq!(report.status, "verified");
+        assert_eq!(report.signature_valid, Some(true));
+
+        // Ejecutable modificado
+        let report = evaluate(exe, actual_hashes(&"cd".repeat(32)), Some(&manifest), Some(&public_key));
+        assert_eq!(report.status, "tampered");
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].resource, RESOURCE_EXECUTABLE);
+
+        // Manifiesto alterado después de firmarlo
+        let mut forged = manifest.clone();
+        forged.files.insert(RESOURCE_EXECUTABLE.to_string(), "cd".repeat(32));
+        let report = evaluate(exe, actual_hashes(&"cd".repeat(32)), Some(&forged), Some(&public_key));
+        assert_eq!(report.status, "invalid_signature");
+
+        let other_key = SigningKey::from_bytes(&[9u8; 32]).verifying_key().to_bytes();
+        assert!(verify_manifest(&manifest, &other_key).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_build() {
+        let report = evaluate(Path::new("scypher-gui"), actual_hashes("00"), None, None);
+        assert_eq!(report.status, "unsigned_build");
+        assert!(report.signature_valid.is_none());
+        assert!(report.mismatches.is_empty());
+    }
+}
@@ -0,0 +1,478 @@
+// =============================================================================
+// SERVIDOR IPC LOCAL (sin HTTP ni TCP)
+// Expone validar/transformar/derivar a otras herramientas offline del mismo
+// equipo mediante un socket Unix (tubería con nombre en Windows). Protocolo:
+// cada mensaje es un prefijo de 4 bytes big-endian con la longitud y un JSON.
+// Solo se atienden las operaciones de la lista permitida (por defecto solo
+// validar). No hay transporte TCP: la garantía offline no depende de un
+// cortafuegos. Se activa con `--ipc <ruta> [--ipc-allow validate,derive,...]`.
+// =============================================================================
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::addresses::NetworkConfig;
+use crate::error::{SCypherError, Result};
+use crate::pipeline::TransformParams;
+
+/// Tamaño máximo de un mensaje (una petición de derivación cabe de sobra)
+pub const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// Operaciones que puede exponer el servidor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpcOperation {
+    Validate,
+    Transform,
+    Derive,
+}
+
+impl IpcOperation {
+    /// Nombre en el informe de diagnóstico
+    fn diagnostics_name(self) -> &'static str {
+        match self {
+            Self::Validate => "ipc_validate",
+            Self::Transform => "ipc_transform",
+            Self::Derive => "ipc_derive",
+        }
+    }
+}
+
+impl std::str::FromStr for IpcOperation {
+    type Err = SCypherError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim() {
+            "validate" => Ok(Self::Validate),
+            "transform" => Ok(Self::Transform),
+            "derive" => Ok(Self::Derive),
+            other => Err(SCypherError::file(format!("Unknown IPC operation: {}", other))),
+        }
+    }
+}
+
+/// Configuración del servidor
+#[derive(Debug, Clone)]
+pub struct IpcConfig {
+    pub endpoint: String,             // Ruta del socket o nombre de la tubería
+    pub allowed: Vec<IpcOperation>,
+}
+
+/// Petición: `{"id": 1, "operation": "validate", "params": {...}}`
+#[derive(Debug, Deserialize)]
+struct IpcRequest {
+    #[serde(default)]
+    id: Option<u64>,
+    operation: IpcOperation,
+    #[serde(default)]
+    params: serde_json::Value,
+}
+
+/// Respuesta: `result` si `ok`, `error` si no
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IpcResponse {
+    pub id: Option<u64>,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<IpcError>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IpcError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+struct ValidateParams {
+    phrase: String,
+}
+
+#[derive(Deserialize)]
+struct TransformRequest {
+    phrase: String,
+    password: String,
+    #[serde(default)]
+    second_password: Option<String>,
+    #[serde(flatten)]
+    params: TransformParams,
+}
+
+impl Drop for TransformRequest {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.password.zeroize();
+        if let Some(second) = self.second_password.as_mut() {
+            second.zeroize();
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeriveRequest {
+    phrase: String,
+    #[serde(default)]
+    passphrase: Option<String>,
+    network_configs: HashMap<String, NetworkConfig>,
+}
+
+impl Drop for DeriveRequest {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        if let Some(passphrase) = self.passphrase.as_mut() {
+            passphrase.zeroize();
+        }
+    }
+}
+
+/// Leer `--ipc <ruta>` y `--ipc-allow <lista>` de los argumentos
+///
+/// None si no se pidió el modo IPC.
+pub fn config_from_args(args: &[String]) -> Result<Option<IpcConfig>> {
+    let value_of = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .map(|i| args.get(i + 1).cloned().ok_or_else(|| SCypherError::file(format!("{} requires a value", flag))))
+            .transpose()
+    };
+
+    let Some(endpoint) = value_of("--ipc")? else {
+        return Ok(None);
+    };
+    let allowed = match value_of("--ipc-allow")? {
+        Some(list) => list.split(',').map(str::parse).collect::<Result<Vec<_>>>()?,
+        None => vec![IpcOperation::Validate],
+    };
+
+    Ok(Some(IpcConfig { endpoint, allowed }))
+}
+
+/// Leer un mensaje; None si el cliente cerró la conexión
+pub fn read_frame<R: Read>(reader: &mut R) -> Result<Option<Vec<u8>>> {
+    let mut header = [0u8; 4];
+    match reader.read_exact(&mut header) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(SCypherError::file(format!("Cannot read IPC message: {}", e))),
+    }
+
+    let length = u32::from_be_bytes(header) as usize;
+    if length > MAX_MESSAGE_SIZE {
+        return Err(SCypherError::file(format!("IPC message too large: {} bytes (max {})", length, MAX_MESSAGE_SIZE)));
+    }
+
+    let mut payload = vec![0u8; length];
+    reader
+        .read_exact(&mut payload)
+        .map_err(|e| SCypherError::file(format!("Cannot read IPC message: {}", e)))?;
+    Ok(Some(payload))
+}
+
+/// Escribir un mensaje con su prefijo de longitud
+pub fn write_frame<W: Write>(writer: &mut W, payload: &[u8]) -> Result<()> {
+    if payload.len() > MAX_MESSAGE_SIZE {
+        return Err(SCypherError::file(format!("IPC message too large: {} bytes (max {})", payload.len(), MAX_MESSAGE_SIZE)));
+    }
+    writer
+        .write_all(&(payload.len() as u32).to_be_bytes())
+        .and_then(|()| writer.write_all(payload))
+        .and_then(|()| writer.flush())
+        .map_err(|e| SCypherError::file(format!("Cannot write IPC message: {}", e)))
+}
+
+fn error_response(id: Option<u64>, code: &str, message: String) -> IpcResponse {
+    IpcResponse {
+        id,
+        ok: false,
+        result: None,
+        error: Some(IpcError { code: code.to_string(), message }),
+    }
+}
+
+fn parse_params<T: serde::de::DeserializeOwned>(params: serde_json::Value) -> Result<T> {
+    serde_json::from_value(params).map_err(|e| SCypherError::file(format!("Invalid IPC parameters: {}", e)))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<serde_json::Value> {
+    serde_json::to_value(value).map_err(|e| SCypherError::file(format!("Cannot encode IPC result: {}", e)))
+}
+
+/// Ejecutar una operación ya permitida
+fn dispatch(operation: IpcOperation, params: serde_json::Value) -> Result<serde_json::Value> {
+    match operation {
+        IpcOperation::Validate => {
+            let request: ValidateParams = parse_params(params)?;
+            let phrase = crate::bip39::normalize_seed_input(&request.phrase);
+            let validation = crate::bip39::validate_seed_phrase_complete(&phrase)
+                .and_then(|()| crate::security::training::ensure_demo_phrase(&phrase));
+            Ok(serde_json::json!({
+                "valid": validation.is_ok(),
+                "word_count": phrase.split_whitespace().count(),
+                "error_code": validation.err().map(|e| e.code()),
+            }))
+        }
+        IpcOperation::Transform => {
+            crate::security::network_guard::ensure_offline()?;
+            let mut request: TransformRequest = parse_params(params)?;
+            let phrase = crate::bip39::normalize_seed_input(&request.phrase);
+            crate::security::training::ensure_demo_phrase(&phrase)?;
+
+            let (output, _) = crate::crypto_worker::transform(crate::crypto_worker::PhraseRequest {
+                phrase,
+                password: std::mem::take(&mut request.password),
+                second_password: request.second_password.take(),
+                params: request.params.clone(),
+                verify: false,
+            })?;
+            crate::security::training::record_output(&output.phrase);
+            Ok(serde_json::json!({
+                "phrase": output.phrase,
+                "checksum_corrected": output.checksum_corrected,
+            }))
+        }
+        IpcOperation::Derive => {
+            let request: DeriveRequest = parse_params(params)?;
+            let phrase = crate::bip39::normalize_seed_input(&request.phrase);
+            crate::security::training::ensure_demo_phrase(&phrase)?;
+            let addresses = crate::addresses::derive_addresses_with_config(
+                &phrase, request.passphrase.as_deref(), request.network_configs.clone(),
+            )?;
+            to_json(&addresses)
+        }
+    }
+}
+
+/// Responder a un mensaje aplicando la lista permitida
+pub fn handle_message(payload: &[u8], allowed: &[IpcOperation]) -> IpcResponse {
+    let request: IpcRequest = match serde_json::from_slice(payload) {
+        Ok(request) => request,
+        Err(e) => return error_response(None, "invalid_request", format!("Invalid IPC request: {}", e)),
+    };
+
+    if !allowed.contains(&request.operation) {
+        return error_response(
+            request.id,
+            "operation_not_allowed",
+            format!("Operation {:?} is not enabled (use --ipc-allow)", request.operation),
+        );
+    }
+
+    let started = std::time::Instant::now();
+    let result = dispatch(request.operation, request.params);
+    crate::diagnostics::record_operation(request.operation.diagnostics_name(), None, started, result.as_ref().err().map(SCypherError::code));
+
+    match result {
+        Ok(result) => IpcResponse { id: request.id, ok: true, result: Some(result), error: None },
+        Err(e) => error_response(request.id, e.code(), e.to_string()),
+    }
+}
+
+/// Atender una conexión hasta que el cliente la cierre
+pub fn serve_connection<S: Read + Write>(stream: &mut S, allowed: &[IpcOperation]) -> Result<()> {
+    loop {
+        let payload = match read_frame(stream) {
+            Ok(Some(payload)) => payload,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                // Mensaje demasiado grande o cortado: avisar y cerrar
+                let response = error_response(None, "invalid_frame", e.to_string());
+                let _ = write_frame(stream, &serde_json::to_vec(&response).unwrap_or_default());
+                return Err(e);
+            }
+        };
+
+        let response = handle_message(&payload, allowed);
+        let mut encoded = serde_json::to_vec(&response)
+            .map_err(|e| SCypherError::file(format!("Cannot encode IPC response: {}", e)))?;
+        let written = write_frame(stream, &encoded);
+        encoded.zeroize();
+        written?;
+    }
+}
+
+/// Arrancar el servidor (bloquea; atiende las conexiones de una en una)
+pub fn run_ipc_server(config: &IpcConfig) -> Result<()> {
+    if config.allowed.is_empty() {
+        return Err(SCypherError::file("IPC server needs at least one allowed operation".to_string()));
+    }
+    tracing::info!(endpoint = %config.endpoint, allowed = ?config.allowed, "Servidor IPC iniciado");
+    serve_platform(config)
+}
+
+/// Crear el socket con permisos 0600 desde el primer momento
+///
+/// Con umask 0177 durante `bind` no hay ningún instante en que otro usuario
+/// local pueda conectarse; si el sistema no respeta la máscara, se falla cerrado.
+#[cfg(unix)]
+fn bind_private_socket(endpoint: &str) -> Result<std::os::unix::net::UnixListener> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let previous_umask = unsafe { libc::umask(0o177) };
+    let bound = std::os::unix::net::UnixListener::bind(endpoint);
+    unsafe { libc::umask(previous_umask) };
+    let listener = bound.map_err(|e| SCypherError::file(format!("Cannot bind IPC socket: {}", e)))?;
+
+    let mode = std::fs::metadata(endpoint)
+        .map(|metadata| metadata.permissions().mode())
+        .map_err(|e| SCypherError::file(format!("Cannot check socket permissions: {}", e)))?;
+    if mode & 0o077 != 0 {
+        drop(listener);
+        let _ = std::fs::remove_file(endpoint);
+        return Err(SCypherError::file(format!("IPC socket was created with permissions {:o}", mode & 0o777)));
+    }
+    Ok(listener)
+}
+
+#[cfg(unix)]
+fn serve_platform(config: &IpcConfig) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    // Quitar un socket que quedó de una ejecución anterior (nunca otro tipo de archivo)
+    if let Ok(metadata) = std::fs::symlink_metadata(&config.endpoint) {
+        if !metadata.file_type().is_socket() {
+            return Err(SCypherError::file(format!("{} exists and is not a socket", config.endpoint)));
+        }
+        std::fs::remove_file(&config.endpoint)
+            .map_err(|e| SCypherError::file(format!("Cannot remove stale socket: {}", e)))?;
+    }
+
+    let listener = bind_private_socket(&config.endpoint)?;
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(mut stream) => {
+                if let Err(e) = serve_connection(&mut stream, &config.allowed) {
+                    tracing::warn!(code = e.code(), "Conexión IPC cerrada con error");
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "Fallo al aceptar conexión IPC"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn serve_platform(config: &IpcConfig) -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::io::FromRawHandle;
+    use winapi::shared::winerror::ERROR_PIPE_CONNECTED;
+    use winapi::um::handleapi::INVALID_HANDLE_VALUE;
+    use winapi::um::namedpipeapi::{ConnectNamedPipe, CreateNamedPipeW};
+    use winapi::um::winbase::{
+        PIPE_ACCESS_DUPLEX, PIPE_READMODE_BYTE, PIPE_REJECT_REMOTE_CLIENTS, PIPE_TYPE_BYTE, PIPE_WAIT,
+    };
+
+    // Se acepta el nombre corto ("scypher") o la ruta completa (\\.\pipe\scypher)
+    let name = if config.endpoint.starts_with(r"\\.\pipe\") {
+        config.endpoint.clone()
+    } else {
+        format!(r"\\.\pipe\{}", config.endpoint)
+    };
+    let wide: Vec<u16> = OsStr::new(&name).encode_wide().chain(std::iter::once(0)).collect();
+
+    loop {
+        // Una instancia por conexión; PIPE_REJECT_REMOTE_CLIENTS impide el acceso por SMB
+        let handle = unsafe {
+            CreateNamedPipeW(
+                wide.as_ptr(),
+                PIPE_ACCESS_DUPLEX,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                1,
+                MAX_MESSAGE_SIZE as u32,
+                MAX_MESSAGE_SIZE as u32,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if handle == INVALID_HANDLE_VALUE {
+            return Err(SCypherError::file(format!("Cannot create named pipe: {}", std::io::Error::last_os_error())));
+        }
+
+        let connected = unsafe { ConnectNamedPipe(handle, std::ptr::null_mut()) } != 0
+            || std::io::Error::last_os_error().raw_os_error() == Some(ERROR_PIPE_CONNECTED as i32);
+
+        // El File cierra la instancia al salir del ámbito
+        let mut pipe = unsafe { std::fs::File::from_raw_handle(handle as _) };
+        if connected {
+            if let Err(e) = serve_connection(&mut pipe, &config.allowed) {
+                tracing::warn!(code = e.code(), "Conexión IPC cerrada con error");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn request(client: &mut impl Write, json: serde_json::Value) {
+        write_frame(client, &serde_json::to_vec(&json).unwrap()).unwrap();
+    }
+
+    fn response(client: &mut impl Read) -> IpcResponse {
+        serde_json::from_slice(&read_frame(client).unwrap().unwrap()).unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ipc_allowlist_over_socket() {
+        let (mut client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+        let worker = std::thread::spawn(move || serve_connection(&mut server, &[IpcOperation::Validate]));
+
+        request(&mut client, serde_json::json!({"id": 1, "operation": "validate", "params": {"phrase": PHRASE}}));
+        let reply = response(&mut client);
+        assert!(reply.ok);
+        assert_eq!(reply.id, Some(1));
+        assert_eq!(reply.result.unwrap()["valid"], true);
+
+        // Operación fuera de la lista permitida
+        request(&mut client, serde_json::json!({"id": 2, "operation": "transform", "params": {"phrase": PHRASE}}));
+        let reply = response(&mut client);
+        assert!(!reply.ok);
+        assert_eq!(reply.error.unwrap().code, "operation_not_allowed");
+
+        drop(client);
+        assert!(worker.join().unwrap().is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_socket_created_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let endpoint = std::env::temp_dir().join(format!("scypher_ipc_{}.sock", std::process::id()));
+        let endpoint = endpoint.to_str().unwrap();
+        let _ = std::fs::remove_file(endpoint);
+
+        let listener = bind_private_socket(endpoint).unwrap();
+        assert_eq!(std::fs::metadata(endpoint).unwrap().permissions().mode() & 0o777, 0o600);
+        drop(listener);
+        std::fs::remove_file(endpoint).unwrap();
+    }
+
+    #[test]
+    fn test_ipc_frames_and_args() {
+        // Prefijo demasiado grande: se rechaza sin leer el cuerpo
+        let oversized = ((MAX_MESSAGE_SIZE + 1) as u32).to_be_bytes();
+        assert!(read_frame(&mut std::io::Cursor::new(oversized.to_vec())).is_err());
+        assert!(read_frame(&mut std::io::Cursor::new(Vec::new())).unwrap().is_none());
+
+        let reply = handle_message(b"not json", &[IpcOperation::Validate]);
+        assert_eq!(reply.error.unwrap().code, "invalid_request");
+
+        let args: Vec<String> = ["scypher-gui", "--ipc", "/tmp/scypher.sock", "--ipc-allow", "validate,derive"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let config = config_from_args(&args).unwrap().unwrap();
+        assert_eq!(config.allowed, vec![IpcOperation::Validate, IpcOperation::Derive]);
+        assert!(config_from_args(&args[..1]).unwrap().is_none());
+        assert!(config_from_args(&["--ipc".to_string()]).is_err());
+    }
+}
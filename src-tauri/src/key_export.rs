@@ -0,0 +1,539 @@
+// =============================================================================
+// EXPORTACIÓN DE CLAVES PRIVADAS
+// Cifradas:
+//   Bitcoin: BIP38 (sin EC multiply, clave comprimida, ruta BIP44 legacy)
+//   EVM (Ethereum, BSC, Polygon): keystore JSON v3 (scrypt + AES-128-CTR)
+// En claro (opt-in, doble confirmación + auditoría de seguridad):
+//   Bitcoin/Litecoin/Dogecoin: WIF, EVM: hex, Solana: base58 (keypair Phantom)
+// Claves raíz BIP32: huella y xpub siempre; xprv con las mismas condiciones
+// =============================================================================
+
+use std::str::FromStr;
+use aes::cipher::{BlockEncrypt, KeyInit, KeyIvInit, StreamCipher};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Longitud mínima de la contraseña de exportación (igual que la CLI)
+const MIN_EXPORT_PASSWORD_LENGTH: usize = 8;
+
+/// Parámetros scrypt fijados por BIP38 (N = 16384, r = 8, p = 8)
+const BIP38_SCRYPT_LOG_N: u8 = 14;
+const BIP38_SCRYPT_P: u32 = 8;
+
+/// Parámetros scrypt estándar de keystore v3 (N = 262144, r = 8, p = 1)
+const KEYSTORE_SCRYPT_LOG_N: u8 = 18;
+
+/// Máximo de índices exportables por solicitud
+const MAX_EXPORT_INDICES: usize = 20;
+
+/// Clave privada cifrada lista para exportar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedKeyExport {
+    pub network: String,
+    pub index: u32,
+    pub path: String,
+    pub address: String,
+    pub format: String, // "bip38" o "keystore-v3"
+    pub encrypted_key: String,
+}
+
+/// Validar contraseña y confirmación (mismas reglas que la entrada segura de la CLI)
+fn validate_export_password(password: &str, password_confirm: &str) -> Result<()> {
+    if !crate::security::utils::constant_time_eq(password.as_bytes(), password_confirm.as_bytes()) {
+        return Err(SCypherError::PasswordMismatch);
+    }
+    if password.len() < MIN_EXPORT_PASSWORD_LENGTH {
+        return Err(SCypherError::InvalidPassword);
+    }
+    Ok(())
+}
+
+/// Ejecutar scrypt con los parámetros dados
+fn scrypt_derive(password: &[u8], salt: &[u8], log_n: u8, p: u32, length: usize) -> Result<Vec<u8>> {
+    let params = scrypt::Params::new(log_n, 8, p, length)
+        .map_err(|e| SCypherError::crypto(format!("Invalid scrypt parameters: {}", e)))?;
+
+    let mut output = vec![0u8; length];
+    scrypt::scrypt(password, salt, &params, &mut output)
+        .map_err(|e| SCypherError::crypto(format!("scrypt derivation failed: {}", e)))?;
+
+    Ok(output)
+}
+
+/// Cifrar una clave privada Bitcoin según BIP38 (formato comprimido)
+pub fn bip38_encrypt(secret: &[u8; 32], address: &str, password: &str) -> Result<String> {
+    // addresshash = primeros 4 bytes de SHA256(SHA256(dirección))
+    let address_hash = Sha256::digest(Sha256::digest(address.as_bytes()));
+    let salt = &address_hash[0..4];
+
+    let mut derived = scrypt_derive(password.as_bytes(), salt, BIP38_SCRYPT_LOG_N, BIP38_SCRYPT_P, 64)?;
+    let (derived_half1, derived_half2) = derived.split_at(32);
+
+    let cipher = aes::Aes256::new_from_slice(derived_half2)
+        .map_err(|e| SCypherError::crypto(format!("Invalid AES key: {}", e)))?;
+
+    let mut block1 = [0u8; 16];
+    let mut block2 = [0u8; 16];
+    for i in 0..16 {
+        block1[i] = secret[i] ^ derived_half1[i];
+        block2[i] = secret[i + 16] ^ derived_half1[i + 16];
+    }
+
+    cipher.encrypt_block((&mut block1).into());
+    cipher.encrypt_block((&mut block2).into());
+    derived.zeroize();
+
+    // 0x01 0x42 | flag (0xE0: sin EC multiply, comprimida) | addresshash | encryptedhalf1 | encryptedhalf2
+    let mut payload = Vec::with_capacity(39);
+    payload.extend_from_slice(&[0x01, 0x42, 0xE0]);
+    payload.extend_from_slice(salt);
+    payload.extend_from_slice(&block1);
+    payload.extend_from_slice(&block2);
+
+    Ok(bitcoin::base58::encode_check(&payload))
+}
+
+/// Generar un identificador UUID v4 para el keystore
+fn random_uuid_v4() -> String {
+    let mut bytes = crate::security::utils::secure_random_bytes(16);
+    bytes[6] = (bytes[6] & 0x0F) | 0x40;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    let hex = hex::encode(&bytes);
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+/// Cifrar una clave privada EVM como keystore JSON v3
+fn keystore_v3_encrypt_with_params(
+    secret: &[u8; 32],
+    address: &str,
+    password: &str,
+    log_n: u8,
+) -> Result<String> {
+    let salt = crate::security::utils::secure_random_bytes(32);
+    let iv = crate::security::utils::secure_random_bytes(16);
+
+    let mut derived = scrypt_derive(password.as_bytes(), &salt, log_n, 1, 32)?;
+
+    let mut ciphertext = secret.to_vec();
+    let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new_from_slices(&derived[0..16], &iv)
+        .map_err(|e| SCypherError::crypto(format!("Invalid AES-CTR parameters: {}", e)))?;
+    cipher.apply_keystream(&mut ciphertext);
+
+    // mac = keccak256(derived[16..32] || ciphertext)
+    let mut hasher = Keccak::v256();
+    hasher.update(&derived[16..32]);
+    hasher.update(&ciphertext);
+    let mut mac = [0u8; 32];
+    hasher.finalize(&mut mac);
+    derived.zeroize();
+
+    let keystore = serde_json::json!({
+        "version": 3,
+        "id": random_uuid_v4(),
+        "address": address.trim_start_matches("0x").to_lowercase(),
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": hex::encode(&iv) },
+            "ciphertext": hex::encode(&ciphertext),
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 1u32 << log_n,
+                "r": 8,
+                "p": 1,
+                "salt": hex::encode(&salt),
+            },
+            "mac": hex::encode(mac),
+        }
+    });
+
+    Ok(keystore.to_string())
+}
+
+/// Cifrar una clave privada EVM como keystore JSON v3 (parámetros estándar)
+pub fn keystore_v3_encrypt(secret: &[u8; 32], address: &str, password: &str) -> Result<String> {
+    keystore_v3_encrypt_with_params(secret, address, password, KEYSTORE_SCRYPT_LOG_N)
+}
+
+/// Exportar claves privadas cifradas para los índices seleccionados
+pub fn export_encrypted_keys(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    network: &str,
+    indices: &[u32],
+    password: &str,
+    password_confirm: &str,
+) -> Result<Vec<EncryptedKeyExport>> {
+    validate_export_password(password, password_confirm)?;
+
+    if indices.is_empty() || indices.len() > MAX_EXPORT_INDICES {
+        return Err(SCypherError::crypto(format!(
+            "Select between 1 and {} derivation indices",
+            MAX_EXPORT_INDICES
+        )));
+    }
+
+    let effective_passphrase = if crate::addresses::network_supports_passphrase(network) {
+        passphrase
+    } else {
+        None
+    };
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, effective_passphrase)?;
+
+    let mut exports = Vec::with_capacity(indices.len());
+    for &index in indices {
+        let export = match network {
+            "bitcoin" => {
+                let path = format!("m/44'/0'/0'/0/{}", index);
+                let key = crate::addresses::derive_key_at_path(&master_key, &path)?;
+                let mut secret: [u8; 32] = key.private_key().to_bytes().into();
+
+                let secp = bitcoin::secp256k1::Secp256k1::new();
+                let secret_key = bitcoin::secp256k1::SecretKey::from_slice(&secret)
+                    .map_err(|e| SCypherError::crypto(format!("Invalid private key: {}", e)))?;
+                let private_key = bitcoin::PrivateKey::new(secret_key, bitcoin::Network::Bitcoin);
+                let address = bitcoin::Address::p2pkh(&private_key.public_key(&secp), bitcoin::Network::Bitcoin)
+                    .to_string();
+
+                let encrypted = bip38_encrypt(&secret, &address, password);
+                secret.zeroize();
+
+                EncryptedKeyExport {
+                    network: network.to_string(),
+                    index,
+                    path,
+                    address,
+                    format: "bip38".to_string(),
+                    encrypted_key: encrypted?,
+                }
+            }
+            "ethereum" | "bsc" | "polygon" => {
+                let path = format!("m/44'/60'/0'/0/{}", index);
+                let key = crate::addresses::derive_key_at_path(&master_key, &path)?;
+                let mut secret: [u8; 32] = key.private_key().to_bytes().into();
+                let address = crate::addresses::evm_address_from_key(&key)?;
+
+                let encrypted = keystore_v3_encrypt(&secret, &address, password);
+                secret.zeroize();
+
+                EncryptedKeyExport {
+                    network: network.to_string(),
+                    index,
+                    path,
+                    address,
+                    format: "keystore-v3".to_string(),
+                    encrypted_key: encrypted?,
+                }
+            }
+            _ => {
+                return Err(SCypherError::crypto(format!(
+                    "Encrypted key export is not supported for network: {}",
+                    network
+                )))
+            }
+        };
+        exports.push(export);
+    }
+
+    Ok(exports)
+}
+
+// =============================================================================
+// EXPORTACIÓN EN CLARO (WIF / HEX / BASE58)
+// =============================================================================
+
+/// Clave privada en claro lista para importar en otra wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateKeyExport {
+    pub network: String,
+    pub index: u32,
+    pub path: String,
+    pub address: String,
+    pub format: String, // "wif", "hex" o "base58"
+    pub private_key: String,
+}
+
+impl Drop for PrivateKeyExport {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Confirmaciones explícitas requeridas para exportar claves en claro
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ExportConfirmation {
+    pub acknowledge_risk: bool,
+    pub confirm_export: bool,
+}
+
+/// Bloquear la exportación si falta alguna confirmación o la auditoría falla
+pub(crate) fn check_export_gate(confirmation: &ExportConfirmation) -> Result<()> {
+    if !(confirmation.acknowledge_risk && confirmation.confirm_export) {
+        return Err(SCypherError::crypto(
+            "Private key export requires both risk acknowledgement and explicit confirmation".to_string(),
+        ));
+    }
+
+    let audit = crate::security::security_audit();
+    if audit.has_critical_issues() {
+        return Err(SCypherError::crypto(format!(
+            "Private key export blocked by security audit: {}",
+            audit.critical_issues().join("; ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Derivar claves privadas en claro sin comprobar confirmaciones
+fn derive_private_keys(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    network: &str,
+    indices: &[u32],
+) -> Result<Vec<PrivateKeyExport>> {
+    if indices.is_empty() || indices.len() > MAX_EXPORT_INDICES {
+        return Err(SCypherError::crypto(format!(
+            "Select between 1 and {} derivation indices",
+            MAX_EXPORT_INDICES
+        )));
+    }
+
+    let mut exports = Vec::with_capacity(indices.len());
+
+    // Solana usa su propia derivación Ed25519 (sin passphrase, como Phantom)
+    if network == "solana" {
+        for &index in indices {
+            let signing_key = crate::addresses::solana_signing_key_at_index(seed_phrase, index)?;
+            let mut keypair = signing_key.to_keypair_bytes();
+
+            exports.push(PrivateKeyExport {
+                network: network.to_string(),
+                index,
+                path: crate::addresses::solana_derivation_path(index),
+                address: bs58::encode(signing_key.verifying_key().as_bytes()).into_string(),
+                format: "base58".to_string(),
+                private_key: bs58::encode(&keypair).into_string(),
+            });
+            keypair.zeroize();
+        }
+        return Ok(exports);
+    }
+
+    let effective_passphrase = if crate::addresses::network_supports_passphrase(network) {
+        passphrase
+    } else {
+        None
+    };
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, effective_passphrase)?;
+
+    for &index in indices {
+        let utxo = crate::addresses::NetworkId::from_str(network)
+            .ok()
+            .and_then(|id| crate::addresses::utxo_params(id).map(|params| (id, params)));
+
+        let export = if let Some((network_id, params)) = utxo {
+            let path = format!("m/44'/{}'/0'/0/{}", params.coin_type, index);
+            let key = crate::addresses::derive_key_at_path(&master_key, &path)?;
+            let mut secret: [u8; 32] = key.private_key().to_bytes().into();
+            let wif = crate::addresses::encode_wif(&secret, network_id);
+            secret.zeroize();
+
+            PrivateKeyExport {
+                network: network.to_string(),
+                index,
+                path,
+                address: crate::addresses::p2pkh_address(&key.public_key().to_bytes(), params.p2pkh),
+                format: "wif".to_string(),
+                private_key: wif?,
+            }
+        } else if matches!(network, "ethereum" | "bsc" | "polygon") {
+            let path = format!("m/44'/60'/0'/0/{}", index);
+            let key = crate::addresses::derive_key_at_path(&master_key, &path)?;
+            let mut secret: [u8; 32] = key.private_key().to_bytes().into();
+
+            let export = PrivateKeyExport {
+                network: network.to_string(),
+                index,
+                path,
+                address: crate::addresses::evm_address_from_key(&key)?,
+                format: "hex".to_string(),
+                private_key: format!("0x{}", hex::encode(secret)),
+            };
+            secret.zeroize();
+            export
+        } else {
+            return Err(SCypherError::crypto(format!(
+                "Private key export is not supported for network: {}",
+                network
+            )));
+        };
+        exports.push(export);
+    }
+
+    Ok(exports)
+}
+
+/// Exportar claves privadas en claro tras doble confirmación y auditoría
+pub fn export_private_keys(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    network: &str,
+    indices: &[u32],
+    confirmation: &ExportConfirmation,
+) -> Result<Vec<PrivateKeyExport>> {
+    check_export_gate(confirmation)?;
+    derive_private_keys(seed_phrase, passphrase, network, indices)
+}
+
+/// Claves raíz BIP32 (ruta m) de la frase
+///
+/// La huella es la que piden los coordinadores PSBT (Sparrow, Specter) al
+/// importar la wallet de solo lectura generada a partir de los descriptores.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MasterKeys {
+    pub fingerprint: String,    // 8 caracteres hex en minúsculas
+    pub xpub: String,
+    pub xprv: Option<String>,   // Solo si se pidió y pasó las confirmaciones
+}
+
+impl Drop for MasterKeys {
+    fn drop(&mut self) {
+        if let Some(xprv) = self.xprv.as_mut() {
+            xprv.zeroize();
+        }
+    }
+}
+
+/// Huella, xpub raíz y, con `confirmation`, xprv raíz
+pub fn master_keys(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    confirmation: Option<&ExportConfirmation>,
+) -> Result<MasterKeys> {
+    use bip32::Prefix;
+
+    if let Some(confirmation) = confirmation {
+        check_export_gate(confirmation)?;
+    }
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+    let public_key = master_key.public_key();
+    Ok(MasterKeys {
+        fingerprint: hex::encode(public_key.fingerprint()),
+        xpub: public_key.to_string(Prefix::XPUB),
+        xprv: confirmation.map(|_| master_key.to_string(Prefix::XPRV).as_str().to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bip38_official_vector_compressed() {
+        // Vector oficial BIP38: "No compression, no EC multiply" (variante comprimida)
+        let secret: [u8; 32] = hex::decode("CBF4B9F70470856BB4F40F80B87EDB90865997FFEE6DF315AB166D713AF433A5")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        let secret_key = bitcoin::secp256k1::SecretKey::from_slice(&secret).unwrap();
+        let private_key = bitcoin::PrivateKey::new(secret_key, bitcoin::Network::Bitcoin);
+        let address = bitcoin::Address::p2pkh(&private_key.public_key(&secp), bitcoin::Network::Bitcoin);
+
+        let encrypted = bip38_encrypt(&secret, &address.to_string(), "TestingOneTwoThree").unwrap();
+        assert_eq!(encrypted, "6PYNKZ1EAgYgmQfmNVamxyXVWHzK5s6DGhwP4J5o44cvXdoY7sRzhtpUeo");
+    }
+
+    #[test]
+    fn test_keystore_v3_decrypts_back() {
+        let secret = [0x42u8; 32];
+        let json = keystore_v3_encrypt_with_params(&secret, "0xABCDEF", "password123", 10).unwrap();
+        let keystore: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(keystore["version"], 3);
+        assert_eq!(keystore["address"], "abcdef");
+
+        let crypto = &keystore["crypto"];
+        let salt = hex::decode(crypto["kdfparams"]["salt"].as_str().unwrap()).unwrap();
+        let iv = hex::decode(crypto["cipherparams"]["iv"].as_str().unwrap()).unwrap();
+        let mut data = hex::decode(crypto["ciphertext"].as_str().unwrap()).unwrap();
+
+        let derived = scrypt_derive(b"password123", &salt, 10, 1, 32).unwrap();
+
+        let mut hasher = Keccak::v256();
+        hasher.update(&derived[16..32]);
+        hasher.update(&data);
+        let mut mac = [0u8; 32];
+        hasher.finalize(&mut mac);
+        assert_eq!(hex::encode(mac), crypto["mac"].as_str().unwrap());
+
+        let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new_from_slices(&derived[0..16], &iv).unwrap();
+        cipher.apply_keystream(&mut data);
+        assert_eq!(data, secret);
+    }
+
+    #[test]
+    fn test_password_confirmation_required() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(matches!(
+            export_encrypted_keys(phrase, None, "bitcoin", &[0], "password123", "password124"),
+            Err(SCypherError::PasswordMismatch)
+        ));
+        assert!(matches!(
+            export_encrypted_keys(phrase, None, "bitcoin", &[0], "short", "short"),
+            Err(SCypherError::InvalidPassword)
+        ));
+        assert!(export_encrypted_keys(phrase, None, "cardano", &[0], "password123", "password123").is_err());
+    }
+
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_plain_export_known_vectors() {
+        let bitcoin = derive_private_keys(TEST_MNEMONIC, None, "bitcoin", &[0]).unwrap();
+        assert_eq!(bitcoin[0].address, "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        assert_eq!(bitcoin[0].private_key, "L4p2b9VAf8k5aUahF1JCJUzZkgNEAqLfq8DDdQiyAprQAKSbu8hf");
+
+        let ethereum = derive_private_keys(TEST_MNEMONIC, None, "ethereum", &[0]).unwrap();
+        assert_eq!(ethereum[0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(
+            ethereum[0].private_key,
+            "0x1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727"
+        );
+
+        // El keypair Solana (64 bytes) termina con la clave pública de la dirección
+        let solana = derive_private_keys(TEST_MNEMONIC, None, "solana", &[0]).unwrap();
+        let keypair = bs58::decode(&solana[0].private_key).into_vec().unwrap();
+        assert_eq!(keypair.len(), 64);
+        assert_eq!(bs58::encode(&keypair[32..]).into_string(), solana[0].address);
+    }
+
+    #[test]
+    fn test_plain_export_requires_double_confirmation() {
+        let partial = ExportConfirmation { acknowledge_risk: true, confirm_export: false };
+        assert!(export_private_keys(TEST_MNEMONIC, None, "bitcoin", &[0], &partial).is_err());
+        assert!(export_private_keys(TEST_MNEMONIC, None, "bitcoin", &[0], &ExportConfirmation::default()).is_err());
+        assert!(derive_private_keys(TEST_MNEMONIC, None, "cardano", &[0]).is_err());
+    }
+
+    #[test]
+    fn test_master_keys_known_vector() {
+        let keys = master_keys(TEST_MNEMONIC, None, None).unwrap();
+        assert_eq!(keys.fingerprint, "73c5da0a");
+        assert_eq!(keys.xpub, "xpub661MyMwAqRbcFkPHucMnrGNzDwb6teAX1RbKQmqtEF8kK3Z7LZ59qafCjB9eCRLiTVG3uxBxgKvRgbubRhqSKXnGGb1aoaqLrpMBDrVxga8");
+        assert!(keys.xprv.is_none());
+
+        let confirmed = ExportConfirmation { acknowledge_risk: true, confirm_export: true };
+        let keys = master_keys(TEST_MNEMONIC, None, Some(&confirmed)).unwrap();
+        assert_eq!(
+            keys.xprv.as_deref(),
+            Some("xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVpxR86QEC8w35uxmGoggxtQTPvfUu")
+        );
+        assert!(master_keys(TEST_MNEMONIC, None, Some(&ExportConfirmation::default())).is_err());
+    }
+}
@@ -30,18 +30,41 @@
 //! assert_eq!(seed, decrypted);
 //! # Ok::<(), SCypherError>(())
 //! ```
+//!
+//! ## API con tipos
+//! `SeedPhrase`, `TransformedPhrase` y `Password` se validan al construirse y
+//! se borran de memoria al liberarse; el compilador impide confundirlos.
+//! ```rust,no_run
+//! use scypher_rust::{Password, SCypherBuilder, SeedPhrase, SCypherError};
+//!
+//! let seed = SeedPhrase::new("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")?;
+//! let password = Password::new("my_secure_password")?;
+//! let builder = SCypherBuilder::new();
+//!
+//! let encrypted = builder.encrypt(&seed, &password)?;
+//! let decrypted = builder.decrypt(&encrypted, &password)?;
+//!
+//! assert_eq!(seed.as_str(), decrypted.as_str());
+//! # Ok::<(), SCypherError>(())
+//! ```
 
 // Módulos públicos
 pub mod error;
 pub mod crypto;
 pub mod bip39;
 pub mod security;
+pub mod logging;
+pub mod evm_registry;
+pub mod derivation;
+pub mod addresses;
+pub mod types;
 
 // Re-exportaciones públicas para facilitar el uso
 pub use error::{SCypherError, Result};
 pub use crypto::transform_seed;
 pub use bip39::{validate_seed_phrase_complete as validate_seed_phrase, verify_checksum};
 pub use security::{SecureString, SecureBytes};
+pub use types::{SeedPhrase, TransformedPhrase, Password};
 
 /// Versión de la biblioteca
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -68,7 +91,10 @@ pub fn scypher_transform(
 pub struct SCypherConfig {
     pub iterations: u32,
     pub memory_cost: u32,
+    pub parallelism: u32,
     pub verify_checksum: bool,
+    pub checksum_policy: crypto::ChecksumPolicy,
+    pub compatibility: crypto::compat::CompatibilityMode,
 }
 
 impl Default for SCypherConfig {
@@ -76,7 +102,10 @@ impl Default for SCypherConfig {
         Self {
             iterations: 5,
             memory_cost: 131072, // 128MB
+            parallelism: crypto::keystream::DEFAULT_PARALLELISM,
             verify_checksum: true,
+            checksum_policy: crypto::ChecksumPolicy::Fix,
+            compatibility: crypto::compat::CompatibilityMode::Current,
         }
     }
 }
@@ -84,12 +113,14 @@ impl Default for SCypherConfig {
 /// Builder para configuración avanzada
 pub struct SCypherBuilder {
     config: SCypherConfig,
+    second_password: Option<String>, // Control dual (crypto::dual)
 }
 
 impl SCypherBuilder {
     pub fn new() -> Self {
         Self {
             config: SCypherConfig::default(),
+            second_password: None,
         }
     }
 
@@ -103,22 +134,87 @@ impl SCypherBuilder {
         self
     }
 
+    /// Carriles Argon2id (1..=MAX_PARALLELISM); cambia el resultado
+    pub fn parallelism(mut self, parallelism: u32) -> Self {
+        self.config.parallelism = parallelism;
+        self
+    }
+
     pub fn verify_checksum(mut self, verify: bool) -> Self {
         self.config.verify_checksum = verify;
         self
     }
 
+    /// Tratamiento del checksum de entrada (`PassThrough` requiere `verify_checksum(false)`)
+    pub fn checksum_policy(mut self, policy: crypto::ChecksumPolicy) -> Self {
+        self.config.checksum_policy = policy;
+        self
+    }
+
+    /// Derivación del keystream; `BashV2` recupera frases del script Bash (solo usa `iterations`)
+    pub fn compatibility_mode(mut self, mode: crypto::compat::CompatibilityMode) -> Self {
+        self.config.compatibility = mode;
+        self
+    }
+
+    /// Control dual: el keystream combina `password` y esta segunda contraseña
+    pub fn second_password(mut self, password: &str) -> Self {
+        self.second_password = Some(password.to_string());
+        self
+    }
+
     pub fn transform(&self, seed_phrase: &str, password: &str) -> Result<String> {
         if self.config.verify_checksum {
             bip39::validate_seed_phrase_complete(seed_phrase)?;
         }
 
-        transform_seed(
+        if let Some(second_password) = &self.second_password {
+            if self.config.compatibility == crypto::compat::CompatibilityMode::BashV2 {
+                return Err(SCypherError::crypto("Dual control is not available in Bash v2 compatibility mode".to_string()));
+            }
+            return crypto::dual::transform_seed_dual(
+                seed_phrase,
+                password,
+                second_password,
+                self.config.iterations,
+                self.config.memory_cost,
+                self.config.parallelism,
+                self.config.checksum_policy,
+            )
+            .map(|result| result.phrase);
+        }
+
+        if self.config.compatibility == crypto::compat::CompatibilityMode::BashV2 {
+            return crypto::compat::transform_bash_v2(
+                seed_phrase,
+                password,
+                self.config.iterations,
+                self.config.checksum_policy,
+            )
+            .map(|result| result.phrase);
+        }
+
+        crypto::transform_seed_with_policy(
             seed_phrase,
             password,
             self.config.iterations,
             self.config.memory_cost,
+            self.config.parallelism,
+            self.config.checksum_policy,
         )
+        .map(|result| result.phrase)
+    }
+
+    /// Cifrar una frase semilla
+    pub fn encrypt(&self, seed_phrase: &SeedPhrase, password: &Password) -> Result<TransformedPhrase> {
+        self.transform(seed_phrase.as_str(), password.as_str())
+            .map(TransformedPhrase::from_transform)
+    }
+
+    /// Descifrar una frase cifrada con la misma configuración
+    pub fn decrypt(&self, transformed: &TransformedPhrase, password: &Password) -> Result<SeedPhrase> {
+        self.transform(transformed.as_str(), password.as_str())
+            .map(SeedPhrase::from_transform)
     }
 }
 
@@ -128,6 +224,14 @@ impl Default for SCypherBuilder {
     }
 }
 
+impl Drop for SCypherBuilder {
+    fn drop(&mut self) {
+        if let Some(password) = self.second_password.as_mut() {
+            zeroize::Zeroize::zeroize(password);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,7 +247,10 @@ mod tests {
         let config = SCypherConfig::default();
         assert_eq!(config.iterations, 5);
         assert_eq!(config.memory_cost, 131072);
+        assert_eq!(config.parallelism, 1);
         assert!(config.verify_checksum);
+        assert_eq!(config.checksum_policy, crypto::ChecksumPolicy::Fix);
+        assert_eq!(config.compatibility, crypto::compat::CompatibilityMode::Current);
     }
 
     #[test]
@@ -151,10 +258,27 @@ mod tests {
         let builder = SCypherBuilder::new()
             .iterations(10)
             .memory_cost(262144)
-            .verify_checksum(false);
+            .parallelism(4)
+            .verify_checksum(false)
+            .checksum_policy(crypto::ChecksumPolicy::PassThrough);
 
         assert_eq!(builder.config.iterations, 10);
         assert_eq!(builder.config.memory_cost, 262144);
+        assert_eq!(builder.config.parallelism, 4);
         assert!(!builder.config.verify_checksum);
+        assert_eq!(builder.config.checksum_policy, crypto::ChecksumPolicy::PassThrough);
+    }
+
+    #[test]
+    fn test_builder_dual_control() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let dual = SCypherBuilder::new().iterations(1).memory_cost(8192).second_password("officer_two");
+
+        let encrypted = dual.transform(phrase, "officer_one").unwrap();
+        assert_eq!(dual.transform(&encrypted, "officer_one").unwrap(), phrase);
+
+        let single = SCypherBuilder::new().iterations(1).memory_cost(8192);
+        assert_ne!(single.transform(phrase, "officer_one").unwrap(), encrypted);
+        assert!(dual.compatibility_mode(crypto::compat::CompatibilityMode::BashV2).transform(phrase, "officer_one").is_err());
     }
 }
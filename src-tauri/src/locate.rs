@@ -0,0 +1,319 @@
+// =============================================================================
+// BÚSQUEDA INVERSA DE DIRECCIONES
+// Dada una dirección, busca en qué ruta de la frase está: redes, esquemas de
+// derivación (BIP44/49/84/86, Solana de Phantom y Ledger), cuentas, cadena
+// de cambio e índices dentro de los límites que fije el usuario. Solo se
+// prueban las redes cuyo formato acepta la dirección. El trabajo se reparte
+// entre los núcleos, se puede cancelar y avisa del progreso con los eventos de
+// `scan_progress`.
+// =============================================================================
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use crate::addresses::{NetworkId, ProfileAddressKind};
+use crate::error::{SCypherError, Result};
+use crate::scan_progress::{ScanEvent, ScanStatus, ScanTracker};
+
+/// Nombre del escaneo en los eventos de progreso
+pub const LOCATE_SCAN: &str = "locate";
+
+/// Límites máximos del espacio de búsqueda
+pub const MAX_ACCOUNTS: u32 = 100;
+pub const MAX_INDICES: u32 = 10_000;
+
+/// Rutas como máximo en una búsqueda (acota memoria y tiempo)
+pub const MAX_PATHS: usize = 1_000_000;
+
+/// Cancelación de la búsqueda en curso (la GUI solo lanza una a la vez)
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Señal de cancelación compartida con el comando
+pub fn cancel_flag() -> &'static AtomicBool {
+    &CANCEL_REQUESTED
+}
+
+/// Pedir que se detenga la búsqueda en curso
+pub fn cancel_search() {
+    CANCEL_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+/// Esquema de derivación: `{a}` cuenta, `{c}` cadena (0 recepción, 1 cambio), `{i}` índice
+struct Scheme {
+    network: NetworkId,
+    label: &'static str,
+    template: &'static str,
+    kind: ProfileAddressKind,
+}
+
+const SCHEMES: &[Scheme] = &[
+    Scheme { network: NetworkId::Bitcoin, label: "Native SegWit (BIP84)", template: "m/84'/0'/{a}'/{c}/{i}", kind: ProfileAddressKind::BitcoinNativeSegwit },
+    Scheme { network: NetworkId::Bitcoin, label: "Taproot (BIP86)", template: "m/86'/0'/{a}'/{c}/{i}", kind: ProfileAddressKind::BitcoinTaproot },
+    Scheme { network: NetworkId::Bitcoin, label: "Nested SegWit (BIP49)", template: "m/49'/0'/{a}'/{c}/{i}", kind: ProfileAddressKind::BitcoinNestedSegwit },
+    Scheme { network: NetworkId::Bitcoin, label: "Legacy (BIP44)", template: "m/44'/0'/{a}'/{c}/{i}", kind: ProfileAddressKind::BitcoinLegacy },
+    Scheme { network: NetworkId::Ethereum, label: "EVM (BIP44)", template: "m/44'/60'/{a}'/{c}/{i}", kind: ProfileAddressKind::Evm },
+    Scheme { network: NetworkId::Litecoin, label: "Native SegWit (BIP84)", template: "m/84'/2'/{a}'/{c}/{i}", kind: ProfileAddressKind::LitecoinNativeSegwit },
+    Scheme { network: NetworkId::Litecoin, label: "Legacy (BIP44)", template: "m/44'/2'/{a}'/{c}/{i}", kind: ProfileAddressKind::LitecoinLegacy },
+    Scheme { network: NetworkId::Dogecoin, label: "Legacy (BIP44)", template: "m/44'/3'/{a}'/{c}/{i}", kind: ProfileAddressKind::Dogecoin },
+    Scheme { network: NetworkId::Tron, label: "TRON (BIP44)", template: "m/44'/195'/{a}'/{c}/{i}", kind: ProfileAddressKind::Tron },
+    Scheme { network: NetworkId::Solana, label: "Phantom / Solflare", template: "m/44'/501'/{a}'/0'", kind: ProfileAddressKind::Solana },
+    Scheme { network: NetworkId::Solana, label: "Ledger", template: "m/44'/501'/{a}'", kind: ProfileAddressKind::Solana },
+];
+
+/// Límites de la búsqueda
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchSpace {
+    #[serde(default)]
+    pub networks: Vec<NetworkId>,   // Vacío: todas las que acepten la dirección
+    pub accounts: u32,              // Cuentas 0..accounts
+    pub indices: u32,               // Índices 0..indices
+    #[serde(default = "default_include_change")]
+    pub include_change: bool,       // Probar también la cadena de cambio (1)
+}
+
+fn default_include_change() -> bool {
+    true
+}
+
+/// Ruta donde apareció la dirección
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocatedAddress {
+    pub network: NetworkId,
+    pub scheme: String,
+    pub path: String,
+    pub account: u32,
+    pub change: u32,
+    pub index: u32,
+    pub address: String,
+    pub passphrase_applied: bool,
+}
+
+/// Una ruta concreta por probar
+struct Candidate {
+    scheme: &'static Scheme,
+    account: u32,
+    change: u32,
+    index: u32,
+}
+
+impl Candidate {
+    fn path(&self) -> String {
+        self.scheme
+            .template
+            .replace("{a}", &self.account.to_string())
+            .replace("{c}", &self.change.to_string())
+            .replace("{i}", &self.index.to_string())
+    }
+}
+
+/// Forma canónica de la dirección en cada red cuyo formato la acepta
+fn matching_networks(address: &str, space: &SearchSpace) -> Vec<(NetworkId, String)> {
+    let mut networks: Vec<NetworkId> = SCHEMES.iter().map(|scheme| scheme.network).collect();
+    networks.dedup();
+
+    networks
+        .into_iter()
+        .filter(|network| space.networks.is_empty() || space.networks.contains(network))
+        .filter(|network| crate::addresses::network_compiled(*network))
+        .filter_map(|network| {
+            let validation = crate::addresses::validate_address(network.as_str(), address).ok()?;
+            validation.normalized.filter(|_| validation.valid).map(|normalized| (network, normalized))
+        })
+        .collect()
+}
+
+/// Todas las rutas que cubre el espacio de búsqueda para esas redes
+fn candidates(networks: &[(NetworkId, String)], space: &SearchSpace) -> Result<Vec<Candidate>> {
+    let schemes: Vec<(&'static Scheme, u32, u32)> = SCHEMES
+        .iter()
+        .filter(|scheme| networks.iter().any(|(network, _)| *network == scheme.network))
+        .map(|scheme| {
+            let changes = if scheme.template.contains("{c}") && space.include_change { 2 } else { 1 };
+            let indices = if scheme.template.contains("{i}") { space.indices } else { 1 };
+            (scheme, changes, indices)
+        })
+        .collect();
+
+    let total: usize = schemes
+        .iter()
+        .map(|(_, changes, indices)| space.accounts as usize * *changes as usize * *indices as usize)
+        .sum();
+    if total > MAX_PATHS {
+        return Err(SCypherError::crypto(format!(
+            "Search space too large: {} paths (maximum {}); narrow networks, accounts or indices",
+            total, MAX_PATHS
+        )));
+    }
+
+    let mut candidates = Vec::with_capacity(total);
+    for (scheme, changes, indices) in schemes {
+        for account in 0..space.accounts {
+            for change in 0..changes {
+                for index in 0..indices {
+                    candidates.push(Candidate { scheme, account, change, index });
+                }
+            }
+        }
+    }
+    Ok(candidates)
+}
+
+/// Buscar la ruta de `address` dentro de la frase
+///
+/// Devuelve None si no aparece en el espacio de búsqueda. `cancel` detiene la
+/// búsqueda en cuanto se activa. Una vez empezada, `events` recibe avisos de
+/// progreso limitados en frecuencia y, al terminar por cualquier motivo, el
+/// resumen.
+pub fn locate_address(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    address: &str,
+    space: &SearchSpace,
+    cancel: &AtomicBool,
+    events: impl Fn(ScanEvent) + Sync,
+) -> Result<Option<LocatedAddress>> {
+    if space.accounts == 0 || space.accounts > MAX_ACCOUNTS || space.indices == 0 || space.indices > MAX_INDICES {
+        return Err(SCypherError::crypto(format!(
+            "Search space must have 1-{} accounts and 1-{} indices",
+            MAX_ACCOUNTS, MAX_INDICES
+        )));
+    }
+
+    let networks = matching_networks(address, space);
+    if networks.is_empty() {
+        return Err(SCypherError::crypto("Address format does not match any searchable network".to_string()));
+    }
+
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+    let candidates = candidates(&networks, space)?;
+    let tracker = ScanTracker::new(LOCATE_SCAN, candidates.len());
+    let next = AtomicUsize::new(0);
+    let found: Mutex<Option<LocatedAddress>> = Mutex::new(None);
+    let failure: Mutex<Option<SCypherError>> = Mutex::new(None);
+    let stop = AtomicBool::new(false);
+
+    let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                if stop.load(Ordering::Relaxed) || cancel.load(Ordering::Relaxed) {
+                    return;
+                }
+                let Some(candidate) = candidates.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                    return;
+                };
+
+                let path = candidate.path();
+                let derived = crate::addresses::profile_address(&master_key, seed_phrase, candidate.scheme.kind, &path);
+                match derived {
+                    Ok(derived) => {
+                        let target = networks.iter().find(|(network, _)| *network == candidate.scheme.network);
+                        if target.is_some_and(|(_, normalized)| *normalized == derived) {
+                            stop.store(true, Ordering::Relaxed);
+                            tracker.record_found();
+                            *found.lock().unwrap_or_else(|e| e.into_inner()) = Some(LocatedAddress {
+                                network: candidate.scheme.network,
+                                scheme: candidate.scheme.label.to_string(),
+                                path,
+                                account: candidate.account,
+                                change: candidate.change,
+                                index: candidate.index,
+                                address: derived,
+                                // Solana no usa passphrase (igual que Phantom)
+                                passphrase_applied: passphrase.is_some_and(|p| !p.is_empty())
+                                    && candidate.scheme.kind != ProfileAddressKind::Solana,
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        stop.store(true, Ordering::Relaxed);
+                        failure.lock().unwrap_or_else(|e| e.into_inner()).get_or_insert(e);
+                    }
+                }
+
+                if let Some(progress) = tracker.record_checked(candidate.scheme.network, candidate.account, candidate.index) {
+                    events(ScanEvent::Progress(progress));
+                }
+            });
+        }
+    });
+
+    // Los hilos ya terminaron: el resumen es el último evento
+    let failure = failure.into_inner().unwrap_or_else(|e| e.into_inner());
+    let found = found.into_inner().unwrap_or_else(|e| e.into_inner());
+    let cancelled = found.is_none() && cancel.load(Ordering::Relaxed);
+    let status = if failure.is_some() {
+        ScanStatus::Failed
+    } else if cancelled {
+        ScanStatus::Cancelled
+    } else {
+        ScanStatus::Completed
+    };
+    events(ScanEvent::Summary(tracker.summary(status)));
+
+    if let Some(e) = failure {
+        return Err(e);
+    }
+    if cancelled {
+        return Err(SCypherError::crypto("Address search cancelled".to_string()));
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn space(accounts: u32, indices: u32) -> SearchSpace {
+        SearchSpace { networks: Vec::new(), accounts, indices, include_change: true }
+    }
+
+    #[test]
+    fn test_locate_known_addresses() {
+        let cancel = AtomicBool::new(false);
+
+        // Primera dirección BIP84 (vector de BIP84)
+        let found = locate_address(PHRASE, None, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", &space(1, 5), &cancel, |_| {})
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.network, NetworkId::Bitcoin);
+        assert_eq!(found.path, "m/84'/0'/0'/0/0");
+
+        // EVM en minúsculas: se compara la forma canónica
+        let found = locate_address(PHRASE, None, "0x9858effd232b4033e47d90003d41ec34ecaeda94", &space(1, 3), &cancel, |_| {})
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.path, "m/44'/60'/0'/0/0");
+        assert_eq!(found.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    }
+
+    #[test]
+    fn test_locate_not_found_and_cancel() {
+        // Dirección válida de otra frase: recorre todo el espacio y termina con el resumen
+        let events = Mutex::new(Vec::new());
+        let result = locate_address(
+            PHRASE, None, "0x0000000000000000000000000000000000000001", &space(1, 2), &AtomicBool::new(false),
+            |event| events.lock().unwrap().push(event),
+        ).unwrap();
+        assert!(result.is_none());
+        let events = events.into_inner().unwrap();
+        let Some(ScanEvent::Summary(summary)) = events.last() else { panic!("missing summary") };
+        assert_eq!((summary.status, summary.checked, summary.total, summary.found), (ScanStatus::Completed, 4, 4, 0));
+        assert!(events[..events.len() - 1].iter().all(|event| matches!(event, ScanEvent::Progress(_))));
+
+        // Cancelada: también hay resumen
+        let summary = Mutex::new(None);
+        let cancelled = locate_address(
+            PHRASE, None, "0x0000000000000000000000000000000000000001", &space(1, 2), &AtomicBool::new(true),
+            |event| if let ScanEvent::Summary(s) = event { *summary.lock().unwrap() = Some(s.status) },
+        );
+        assert!(cancelled.is_err());
+        assert_eq!(summary.into_inner().unwrap(), Some(ScanStatus::Cancelled));
+
+        assert!(locate_address(PHRASE, None, "not an address", &space(1, 2), &AtomicBool::new(false), |_| {}).is_err());
+        assert!(locate_address(PHRASE, None, "0x0000000000000000000000000000000000000001", &space(0, 2), &AtomicBool::new(false), |_| {}).is_err());
+        assert!(locate_address(PHRASE, None, "0x0000000000000000000000000000000000000001", &space(MAX_ACCOUNTS, MAX_INDICES), &AtomicBool::new(false), |_| {}).is_err());
+    }
+}
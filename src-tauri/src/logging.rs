@@ -0,0 +1,213 @@
+// =============================================================================
+// REGISTRO ESTRUCTURADO CON POLÍTICA DE REDACCIÓN
+// Capa `tracing` única para toda la aplicación. El nivel se puede cambiar en
+// tiempo de ejecución (SCYPHER_LOG al arrancar o desde la GUI) y toda línea
+// pasa por `redact_line` antes de escribirse: secuencias hexadecimales de
+// longitud de clave y frases BIP39 completas se sustituyen por [REDACTED].
+// SecureString y SecureBytes no implementan Display ni Debug (comprobado en
+// compilación en `security`), así que no pueden llegar a un evento.
+// =============================================================================
+
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::OnceLock;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{reload, Registry};
+use crate::error::{SCypherError, Result};
+
+/// Variable de entorno con el nivel inicial
+const LOG_ENV_VAR: &str = "SCYPHER_LOG";
+
+/// Nivel por defecto: solo avisos y errores
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::WARN;
+
+/// Texto que sustituye al material redactado
+const REDACTED: &str = "[REDACTED]";
+
+/// Secuencias hexadecimales de esta longitud o más se consideran material de clave (16 bytes)
+const MIN_SECRET_HEX_LEN: usize = 32;
+
+/// Palabras BIP39 consecutivas que se consideran una frase semilla
+const MIN_PHRASE_WORDS: usize = 12;
+
+static LEVEL_HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+/// Envoltorio para registrar que un valor existe sin mostrar su contenido
+#[cfg_attr(not(feature = "network-solana"), allow(dead_code))]
+pub struct Redacted<T>(pub T);
+
+impl<T: AsRef<[u8]>> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} bytes)", REDACTED, self.0.as_ref().len())
+    }
+}
+
+impl<T: AsRef<[u8]>> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Direcciones EVM (0x + 40 hex) son públicas y no se redactan
+fn is_evm_address(line: &str, start: usize, len: usize) -> bool {
+    len == 40 && line[..start].ends_with("0x")
+}
+
+fn redact_hex(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut run_start: Option<usize> = None;
+
+    let flush = |output: &mut String, start: usize, end: usize| {
+        let len = end - start;
+        if len >= MIN_SECRET_HEX_LEN && !is_evm_address(line, start, len) {
+            output.push_str(REDACTED);
+        } else {
+            output.push_str(&line[start..end]);
+        }
+    };
+
+    for (i, c) in line.char_indices() {
+        if c.is_ascii_hexdigit() {
+            run_start.get_or_insert(i);
+        } else {
+            if let Some(start) = run_start.take() {
+                flush(&mut output, start, i);
+            }
+            output.push(c);
+        }
+    }
+    if let Some(start) = run_start {
+        flush(&mut output, start, line.len());
+    }
+    output
+}
+
+fn is_bip39_word(token: &str) -> bool {
+    let word = token.trim_matches(|c: char| !c.is_ascii_alphabetic()).to_lowercase();
+    crate::bip39::wordlist::BIP39_WORDLIST.binary_search(&word.as_str()).is_ok()
+}
+
+fn redact_phrases(line: &str) -> String {
+    let tokens: Vec<&str> = line.split(' ').collect();
+    let mut output: Vec<&str> = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let run = tokens[i..].iter().take_while(|t| is_bip39_word(t)).count();
+        if run >= MIN_PHRASE_WORDS {
+            output.push(REDACTED);
+            i += run;
+        } else {
+            output.push(tokens[i]);
+            i += 1;
+        }
+    }
+    output.join(" ")
+}
+
+/// Aplicar la política de redacción a una línea de registro
+pub fn redact_line(line: &str) -> String {
+    redact_phrases(&redact_hex(line))
+}
+
+/// Salida de registro (stderr) que redacta cada escritura
+pub struct RedactingWriter;
+
+impl Write for RedactingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let redacted = redact_line(&String::from_utf8_lossy(buf));
+        io::stderr().write_all(redacted.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for RedactingWriter {
+    type Writer = RedactingWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter> {
+    level
+        .trim()
+        .parse::<LevelFilter>()
+        .map_err(|_| SCypherError::crypto(format!(
+            "Invalid log level '{}'. Use off, error, warn, info, debug or trace",
+            level
+        )))
+}
+
+/// Instalar el suscriptor global (una sola vez, al arrancar)
+pub fn init_logging() {
+    let initial = std::env::var(LOG_ENV_VAR)
+        .ok()
+        .and_then(|level| parse_level(&level).ok())
+        .unwrap_or(DEFAULT_LEVEL);
+
+    let (filter, handle) = reload::Layer::new(initial);
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(RedactingWriter)
+        .with_target(true);
+
+    if tracing_subscriber::registry().with(filter).with(fmt_layer).try_init().is_ok() {
+        let _ = LEVEL_HANDLE.set(handle);
+    }
+}
+
+/// Cambiar el nivel de registro en tiempo de ejecución
+pub fn set_log_level(level: &str) -> Result<String> {
+    let filter = parse_level(level)?;
+    let handle = LEVEL_HANDLE
+        .get()
+        .ok_or_else(|| SCypherError::crypto("Logging is not initialized".to_string()))?;
+
+    handle
+        .reload(filter)
+        .map_err(|e| SCypherError::crypto(format!("Cannot change log level: {}", e)))?;
+    Ok(filter.to_string())
+}
+
+/// Nivel de registro actual
+pub fn current_log_level() -> String {
+    LEVEL_HANDLE
+        .get()
+        .and_then(|handle| handle.clone_current())
+        .unwrap_or(LevelFilter::OFF)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_key_material() {
+        let entropy = "00000000000000000000000000000000";
+        assert_eq!(redact_line(&format!("entropy={}", entropy)), "entropy=[REDACTED]");
+
+        // Direcciones EVM, índices y hashes cortos se mantienen
+        let line = "index=0 address=0x9858EfFD232B4033E47d90003D41EC34EcaEda94 id=0x8000002c";
+        assert_eq!(redact_line(line), line);
+
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(redact_line(&format!("mnemonic: {} parsed", phrase)), "mnemonic: [REDACTED] parsed");
+        assert_eq!(redact_line("derive: bitcoin wallet"), "derive: bitcoin wallet");
+    }
+
+    #[test]
+    fn test_redacted_wrapper_and_levels() {
+        assert_eq!(Redacted([1u8; 16]).to_string(), "[REDACTED] (16 bytes)");
+        assert_eq!(format!("{:?}", Redacted(b"secret")), "[REDACTED] (6 bytes)");
+
+        assert_eq!(parse_level("DEBUG").unwrap(), LevelFilter::DEBUG);
+        assert!(parse_level("verbose").is_err());
+    }
+}
@@ -3,6 +3,33 @@
 
 mod commands;
 mod addresses;
+mod paper;
+mod stego;
+mod key_export;
+mod signing;
+mod psbt_signer;
+mod evm_signer;
+mod evm_registry;
+mod printing;
+mod airgap;
+mod seedqr;
+mod metadata;
+mod integrity;
+mod pipeline;
+mod watch_export;
+mod bip47;
+mod nostr;
+mod diagnostics;
+mod ipc;
+mod build_info;
+mod locate;
+mod scan_progress;
+mod audit_log;
+mod logging;
+mod crypto_worker;
+mod dev_identity;
+mod backup_health;
+mod derivation;
 
 // Importar tus módulos existentes
 mod crypto;
@@ -16,10 +43,83 @@ pub use error::{SCypherError, Result};
 pub use crypto::transform_seed;
 
 fn main() {
+    // Proceso aislado de cifrado: sin registro, hilos ni limpieza global
+    if std::env::args().nth(1).as_deref() == Some(crypto_worker::WORKER_FLAG) {
+        std::process::exit(crypto_worker::run_worker());
+    }
+
+    // Registro estructurado con redacción (nivel inicial desde SCYPHER_LOG)
+    logging::init_logging();
+
     // Configurar limpieza de seguridad
     security::setup_security_cleanup();
 
+    // Avisar si el equipo tiene conexión de red al arrancar
+    security::network_guard::warn_if_online();
+
+    // Modo formación (solo vectores de prueba) si se arranca con SCYPHER_TRAINING=1
+    security::training::init_from_env();
+
+    // Sesión sin historial ni escrituras de configuración con SCYPHER_INCOGNITO=1
+    security::incognito::init_from_env();
+
+    // Aleatoriedad reproducible (SCYPHER_TEST_SEED) solo en builds de prueba
+    #[cfg(feature = "test-determinism")]
+    security::rng::init_from_env();
+
+    // Interfaz de terminal opcional para equipos sin entorno gráfico
+    #[cfg(feature = "tui")]
+    if std::env::args().any(|arg| arg == "--tui") {
+        if let Err(e) = cli::tui::run_tui() {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let args: Vec<String> = std::env::args().collect();
+
+    // Menú de línea de comandos (frase y contraseña por la terminal)
+    if args.iter().any(|arg| arg == "--cli") {
+        let output_file = args
+            .iter()
+            .position(|arg| arg == "-o" || arg == "--output")
+            .and_then(|index| args.get(index + 1));
+        if let Err(e) = cli::run_cli_session(output_file) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Servidor IPC local opcional (socket Unix / tubería con nombre, nunca TCP)
+    match ipc::config_from_args(&args) {
+        Ok(Some(config)) => {
+            if let Err(e) = ipc::run_ipc_server(&config) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
     tauri::Builder::default()
+        .setup(|app| {
+            // Resultados retenidos con caducidad: borrado y aviso a la GUI
+            commands::start_result_expiry_watcher(app.handle());
+            Ok(())
+        })
+        // Cerrar la ventana borra los secretos antes de destruir la vista web
+        .on_window_event(|event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event.event() {
+                security::secure_cleanup();
+            }
+        })
         .invoke_handler(tauri::generate_handler![
             commands::validate_seed_phrase,
             commands::transform_seed_phrase,
@@ -34,7 +134,129 @@ fn main() {
             commands::derive_addresses,
             commands::validate_network,
             commands::get_supported_networks,
+            commands::remember_session_password,
+            commands::recall_session_password,
+            commands::forget_session_password,
+            commands::is_keychain_available,
+            commands::enable_capture_protection,
+            commands::disable_capture_protection,
+            commands::get_capture_protection_status,
+            commands::start_scrambled_keypad,
+            commands::resolve_scrambled_selection,
+            commands::end_scrambled_keypad,
+            commands::export_paper_backup,
+            commands::embed_in_image,
+            commands::extract_from_image,
+            commands::split_password_shares,
+            commands::combine_password_shares,
+            commands::export_encrypted_keys,
+            commands::export_private_keys,
+            commands::sign_message,
+            commands::verify_message,
+            commands::sign_psbt,
+            commands::sign_evm_transaction,
+            commands::sign_eip712_typed_data,
+            commands::generate_seed_from_manual_entropy,
+            commands::manual_entropy_requirements,
+            commands::analyze_entropy,
+            commands::export_verification_worksheet,
+            commands::print_result,
+            commands::encode_ur,
+            commands::decode_ur,
+            commands::encode_bbqr,
+            commands::decode_bbqr,
+            commands::export_seedqr,
+            commands::import_seedqr,
+            commands::get_wallet_profiles,
+            commands::derive_addresses_with_profile,
+            commands::get_evm_chain_registry,
+            commands::clear_kdf_cache,
+            commands::load_metadata,
+            commands::detect_format,
+            commands::run_security_audit,
+            commands::apply_remediation,
+            commands::get_network_status,
+            commands::set_network_policy,
+            commands::verify_integrity,
+            commands::get_log_level,
+            commands::set_log_level,
+            commands::get_available_parallelism,
+            commands::check_kdf_feasibility,
+            commands::validate_address,
+            commands::export_addresses,
+            commands::get_training_status,
+            commands::set_training_mode,
+            commands::transform_entropy,
+            commands::vault_add_profile,
+            commands::vault_select_profile,
+            commands::vault_remove_profile,
+            commands::vault_list_profiles,
+            commands::vault_clear,
+            commands::vault_transform,
+            commands::vault_derive_addresses,
+            commands::fetch_result,
+            commands::consume_result,
+            commands::verify_expected_fingerprint,
+            commands::get_attempt_guard_status,
+            commands::set_attempt_guard,
+            commands::create_hidden_container,
+            commands::transform_and_derive,
+            commands::export_watch_bundle,
+            commands::derive_payment_code,
+            commands::derive_nostr_identity,
+            commands::export_cross_check_checklist,
+            commands::export_transcription_aid,
+            commands::import_transcription_aid,
+            commands::export_diagnostics,
+            commands::get_build_info,
+            commands::analyze_seed_phrase,
+            commands::extract_mnemonic_from_text,
+            commands::locate_address,
+            commands::cancel_locate_address,
+            commands::validate_seed_file,
+            commands::estimate_kdf_time,
+            commands::enroll_fido2_pepper,
+            commands::set_heap_scrub_on_exit,
+            commands::enable_audit_log,
+            commands::get_audit_log_status,
+            commands::verify_log_integrity,
+            commands::split_seed_xor,
+            commands::combine_seed_xor,
+            commands::register_custom_wordlist,
+            commands::clear_custom_wordlist,
+            commands::get_custom_wordlist,
+            commands::encode_with_custom_wordlist,
+            commands::decode_custom_wordlist_phrase,
+            commands::get_environment_policy,
+            commands::set_environment_policy,
+            commands::set_crypto_worker,
+            commands::request_consent,
+            commands::copy_to_clipboard,
+            commands::test_trng,
+            commands::redact_result,
+            commands::vault_redact_selected,
+            commands::export_phonetic_readout,
+            commands::compare_phrases_secure,
+            commands::get_master_keys,
+            commands::enable_incognito_mode,
+            commands::get_incognito_status,
+            commands::predict_contract_addresses,
+            commands::derive_ssh_key,
+            commands::derive_gpg_key,
+            commands::get_backup_health,
+            commands::track_backup,
+            commands::untrack_backup,
+            commands::set_backup_reminder_interval,
+            commands::verify_backup,
+            commands::inspect_wif_key,
+            commands::security_audit_text,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|_app, event| {
+            // Salida por cualquier vía (menú, atajo del sistema, última ventana)
+            if let tauri::RunEvent::Exit = event {
+                security::secure_cleanup();
+            }
+        });
 }
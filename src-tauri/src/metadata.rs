@@ -0,0 +1,196 @@
+// =============================================================================
+// METADATOS NO SECRETOS JUNTO A LOS RESULTADOS GUARDADOS
+// Archivo JSON opcional (`<resultado>.meta.json`) con el algoritmo y los
+// parámetros del KDF, la versión y una pista elegida por el usuario, para que
+// al abrir el resultado se puedan rellenar los parámetros de descifrado.
+// Nunca contiene la contraseña, la frase ni nada derivado de ellas.
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Identificador de formato del archivo de metadatos
+pub const METADATA_FORMAT: &str = "scypher-metadata";
+
+/// Versión actual del formato
+pub const METADATA_VERSION: u32 = 1;
+
+/// Sufijo añadido al nombre del archivo de resultado
+const SIDECAR_SUFFIX: &str = ".meta.json";
+
+/// Longitud máxima de la pista
+const MAX_HINT_LENGTH: usize = 200;
+
+/// Parámetros del KDF usados en la transformación
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub algorithm: String,   // "argon2id"
+    pub iterations: u32,
+    pub memory_cost: u32,    // KB
+    pub parallelism: u32,
+}
+
+/// Contenido del archivo de metadatos
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultMetadata {
+    pub format: String,
+    pub version: u32,
+    #[serde(default = "default_format_version")]
+    pub format_version: u32, // Versión del formato de cifrado (crypto::format)
+    pub app_version: String,
+    pub kdf: KdfParams,
+    pub word_count: Option<usize>,
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub dual_control: bool,  // Se necesitan las dos contraseñas (crypto::dual)
+    #[serde(default)]
+    pub wordlist_hash: Option<String>, // SHA256 de la lista propia con la que se escribió
+    pub created_at: u64,     // Segundos Unix
+}
+
+fn default_format_version() -> u32 {
+    crate::crypto::format::FormatVersion::V1.number()
+}
+
+/// Construir los metadatos de un resultado (valida parámetros y pista)
+pub fn build_metadata(
+    iterations: u32,
+    memory_cost: u32,
+    parallelism: u32,
+    word_count: Option<usize>,
+    hint: Option<String>,
+    dual_control: bool,
+    wordlist_hash: Option<String>,
+) -> Result<ResultMetadata> {
+    crate::crypto::keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    let hint = hint.map(|h| h.trim().to_string()).filter(|h| !h.is_empty());
+    if let Some(hint) = &hint {
+        if hint.chars().count() > MAX_HINT_LENGTH {
+            return Err(SCypherError::file(format!(
+                "Password hint is too long (max {} characters)",
+                MAX_HINT_LENGTH
+            )));
+        }
+    }
+
+    let created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    Ok(ResultMetadata {
+        format: METADATA_FORMAT.to_string(),
+        version: METADATA_VERSION,
+        format_version: crate::crypto::format::CURRENT_FORMAT.number(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        kdf: KdfParams {
+            algorithm: "argon2id".to_string(),
+            iterations,
+            memory_cost,
+            parallelism,
+        },
+        word_count,
+        hint,
+        dual_control,
+        wordlist_hash,
+        created_at,
+    })
+}
+
+/// Ruta del archivo de metadatos de un resultado
+pub fn sidecar_path(result_path: &str) -> PathBuf {
+    if result_path.ends_with(SIDECAR_SUFFIX) {
+        PathBuf::from(result_path)
+    } else {
+        PathBuf::from(format!("{}{}", result_path, SIDECAR_SUFFIX))
+    }
+}
+
+/// Escribir el archivo de metadatos junto al resultado
+pub fn write_sidecar(result_path: &str, metadata: &ResultMetadata) -> Result<PathBuf> {
+    let path = sidecar_path(result_path);
+    let json = serde_json::to_string_pretty(metadata)
+        .map_err(|e| SCypherError::file(format!("Cannot encode metadata: {}", e)))?;
+
+    fs::write(&path, json)
+        .map_err(|e| SCypherError::file(format!("Cannot write metadata file: {}", e)))?;
+    Ok(path)
+}
+
+/// Leer los metadatos de un resultado (acepta la ruta del resultado o del sidecar)
+///
+/// Devuelve None si no hay archivo de metadatos.
+pub fn load_metadata(path: &str) -> Result<Option<ResultMetadata>> {
+    let sidecar = sidecar_path(path);
+    if !Path::new(&sidecar).exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&sidecar)
+        .map_err(|e| SCypherError::file(format!("Cannot read metadata file: {}", e)))?;
+    let metadata: ResultMetadata = serde_json::from_str(&content)
+        .map_err(|e| SCypherError::file(format!("Invalid metadata file: {}", e)))?;
+
+    if metadata.format != METADATA_FORMAT {
+        return Err(SCypherError::file(format!("Unknown metadata format: {}", metadata.format)));
+    }
+    if metadata.version > METADATA_VERSION {
+        return Err(SCypherError::file(format!(
+            "Metadata version {} is newer than supported ({})",
+            metadata.version, METADATA_VERSION
+        )));
+    }
+    crate::crypto::keystream::validate_argon2_params(
+        metadata.kdf.iterations,
+        metadata.kdf.memory_cost,
+        metadata.kdf.parallelism,
+    )?;
+
+    Ok(Some(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidecar_roundtrip() {
+        let result_file = std::env::temp_dir().join("scypher_metadata_test.txt");
+        let result_path = result_file.to_str().unwrap();
+        let _ = fs::remove_file(sidecar_path(result_path));
+
+        assert!(load_metadata(result_path).unwrap().is_none());
+
+        let metadata = build_metadata(5, 131072, 2, Some(12), Some("  first pet + year  ".to_string()), true, Some("ab".repeat(32))).unwrap();
+        let written = write_sidecar(result_path, &metadata).unwrap();
+        assert!(written.to_str().unwrap().ends_with(".txt.meta.json"));
+
+        // Se puede abrir tanto por el resultado como por el propio sidecar
+        for path in [result_path, written.to_str().unwrap()] {
+            let loaded = load_metadata(path).unwrap().unwrap();
+            assert_eq!(loaded.kdf, metadata.kdf);
+            assert_eq!(loaded.kdf.parallelism, 2);
+            assert_eq!(loaded.format_version, 1);
+            assert_eq!(loaded.hint.as_deref(), Some("first pet + year"));
+            assert!(loaded.dual_control);
+            assert_eq!(loaded.wordlist_hash, metadata.wordlist_hash);
+        }
+
+        // Metadatos anteriores al control dual
+        fs::write(&written, serde_json::to_string(&metadata).unwrap().replace(r#""dual_control":true,"#, "")).unwrap();
+        assert!(!load_metadata(result_path).unwrap().unwrap().dual_control);
+
+        let _ = fs::remove_file(written);
+    }
+
+    #[test]
+    fn test_rejects_invalid_metadata() {
+        assert!(build_metadata(0, 131072, 1, None, None, false, None).is_err());
+        assert!(build_metadata(5, 131072, 0, None, None, false, None).is_err());
+        assert!(build_metadata(5, 131072, 1, None, Some("x".repeat(MAX_HINT_LENGTH + 1)), false, None).is_err());
+        assert!(build_metadata(5, 131072, 1, None, Some("   ".to_string()), false, None).unwrap().hint.is_none());
+    }
+}
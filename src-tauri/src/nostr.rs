@@ -0,0 +1,129 @@
+// =============================================================================
+// IDENTIDAD NOSTR (NIP-06)
+// Deriva el par de claves Nostr de la misma frase semilla (m/44'/1237'/n'/0/0)
+// y lo codifica en bech32 (NIP-19): `npub` para la clave pública x-only y
+// `nsec` para la privada. La `nsec` solo se entrega tras la misma doble
+// confirmación y auditoría que la exportación de claves privadas.
+// =============================================================================
+
+use bech32::{ToBase32, Variant};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use crate::key_export::ExportConfirmation;
+
+/// Coin type de Nostr (SLIP-44)
+const NOSTR_COIN_TYPE: u32 = 1237;
+
+/// Identidad Nostr derivada
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NostrIdentity {
+    pub path: String,
+    pub public_key: String,      // Hex x-only (formato de los eventos)
+    pub npub: String,
+    pub nsec: Option<String>,    // Solo con confirmación
+}
+
+impl Drop for NostrIdentity {
+    fn drop(&mut self) {
+        if let Some(nsec) = self.nsec.as_mut() {
+            nsec.zeroize();
+        }
+    }
+}
+
+fn encode_nip19(hrp: &str, data: &[u8]) -> Result<String> {
+    bech32::encode(hrp, data.to_base32(), Variant::Bech32)
+        .map_err(|e| SCypherError::crypto(format!("Bech32 encoding failed: {}", e)))
+}
+
+/// Derivar la identidad sin comprobar confirmaciones
+fn derive_identity(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    account: u32,
+    include_nsec: bool,
+) -> Result<NostrIdentity> {
+    if account >= 0x8000_0000 {
+        return Err(SCypherError::crypto(format!("Invalid account index: {}", account)));
+    }
+
+    let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+    let path = format!("m/44'/{}'/{}'/0/0", NOSTR_COIN_TYPE, account);
+    let key = crate::addresses::derive_key_at_path(&master_key, &path)?;
+
+    // Clave pública x-only (BIP340): sin el byte de paridad
+    let public_key = key.public_key().to_bytes();
+    let x_only = &public_key[1..];
+
+    let nsec = if include_nsec {
+        let mut secret: [u8; 32] = key.private_key().to_bytes().into();
+        let nsec = encode_nip19("nsec", &secret);
+        secret.zeroize();
+        Some(nsec?)
+    } else {
+        None
+    };
+
+    Ok(NostrIdentity {
+        path,
+        public_key: hex::encode(x_only),
+        npub: encode_nip19("npub", x_only)?,
+        nsec,
+    })
+}
+
+/// Derivar la identidad Nostr de la cuenta `account`
+///
+/// Con `confirmation` se incluye la `nsec` si pasa la comprobación de exportación.
+pub fn derive_nostr_identity(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    account: u32,
+    confirmation: Option<&ExportConfirmation>,
+) -> Result<NostrIdentity> {
+    if let Some(confirmation) = confirmation {
+        crate::key_export::check_export_gate(confirmation)?;
+    }
+    derive_identity(seed_phrase, passphrase, account, confirmation.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nip06_vectors() {
+        // Vectores de NIP-06
+        let identity = derive_identity(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            None,
+            0,
+            false,
+        )
+        .unwrap();
+        assert_eq!(identity.path, "m/44'/1237'/0'/0/0");
+        assert_eq!(identity.public_key, "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917");
+        assert_eq!(identity.npub, "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu");
+        assert!(identity.nsec.is_none());
+
+        let phrase = "what bleak badge arrange retreat wolf trade produce cricket blur garlic valid proud rude strong \
+                      choose busy staff weather area salt hollow arm fade";
+        let identity = derive_identity(phrase, None, 0, true).unwrap();
+        assert_eq!(identity.npub, "npub16sdj9zv4f8sl85e45vgq9n7nsgt5qphpvmf7vk8r5hhvmdjxx4es8rq74h");
+        assert_eq!(identity.nsec.as_deref(), Some("nsec1c9wh8xy5eqdzln7n5t0ctgxjcrdug73gp5yj0x03gntn67h83twssdfhel"));
+    }
+
+    #[test]
+    fn test_nsec_requires_confirmation() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let unconfirmed = ExportConfirmation { acknowledge_risk: true, confirm_export: false };
+        assert!(derive_nostr_identity(phrase, None, 0, Some(&unconfirmed)).is_err());
+
+        // Otra cuenta, otra identidad
+        let first = derive_nostr_identity(phrase, None, 0, None).unwrap();
+        let second = derive_nostr_identity(phrase, None, 1, None).unwrap();
+        assert_ne!(first.npub, second.npub);
+        assert!(first.npub.starts_with("npub1"));
+    }
+}
@@ -0,0 +1,376 @@
+// =============================================================================
+// RESPALDO EN PAPEL (PDF IMPRIMIBLE)
+// Genera localmente un PDF con la frase transformada, parámetros KDF, fecha,
+// código QR e instrucciones de recuperación. No usa red ni recursos externos.
+// =============================================================================
+
+use printpdf::{
+    BuiltinFont, IndirectFontRef, Mm, OffsetDateTime, PdfDocument, PdfLayerReference, Rect,
+};
+use qrcode::{Color as QrColor, EcLevel, QrCode};
+use crate::cli::output::format::{format_phrase, PhraseLayout};
+use crate::error::{SCypherError, Result};
+
+// Dimensiones A4 en milímetros
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+
+// Rejilla de palabras: 3 columnas, hasta 8 filas (24 palabras)
+const GRID_COLUMNS: usize = 3;
+const GRID_TOP: f32 = 232.0;
+const GRID_ROW_HEIGHT: f32 = 8.0;
+const GRID_COLUMN_WIDTH: f32 = 58.0;
+
+// Frase como texto (disposiciones en línea, rejilla de 4 o separador propio)
+const PHRASE_FONT_SIZE: f32 = 11.0;
+const PHRASE_LINE_CHARS: usize = 72;
+
+// Tamaño del código QR impreso
+const QR_SIZE: f32 = 60.0;
+
+/// Marcador para posiciones que están en la otra página
+const MISSING_WORD: &str = "________";
+
+/// Una página del respaldo: etiqueta y palabras visibles por posición
+struct BackupPage {
+    label: String,
+    words: Vec<Option<String>>,
+}
+
+/// Fuentes usadas en el documento
+struct Fonts {
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    mono: IndirectFontRef,
+}
+
+/// Dividir la frase en dos páginas complementarias
+/// Página A: posiciones impares (1, 3, 5...), Página B: posiciones pares
+fn split_into_pages(words: &[&str]) -> (Vec<Option<String>>, Vec<Option<String>>) {
+    let page_a = words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| if i % 2 == 0 { Some(w.to_string()) } else { None })
+        .collect();
+    let page_b = words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| if i % 2 == 1 { Some(w.to_string()) } else { None })
+        .collect();
+    (page_a, page_b)
+}
+
+/// Contenido codificado en el QR de una página (conserva las posiciones)
+fn qr_payload(words: &[Option<String>]) -> String {
+    words
+        .iter()
+        .map(|w| w.as_deref().unwrap_or("_"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Líneas de texto de una página con la disposición elegida
+///
+/// Las disposiciones en una sola línea se parten en palabras completas para
+/// que quepan en el ancho de la página; el separador queda al final de línea.
+fn layout_lines(words: &[Option<String>], layout: &PhraseLayout) -> Result<Vec<String>> {
+    layout.validate()?;
+    let words: Vec<&str> = words.iter().map(|w| w.as_deref().unwrap_or(MISSING_WORD)).collect();
+
+    let separator = match layout.separator() {
+        Some(separator) => separator,
+        None => return Ok(format_phrase(&words.join(" "), layout)?.lines().map(String::from).collect()),
+    };
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for (i, word) in words.iter().enumerate() {
+        let piece = if i + 1 < words.len() { format!("{}{}", word, separator) } else { word.to_string() };
+        if !line.is_empty() && line.chars().count() + piece.trim_end().chars().count() > PHRASE_LINE_CHARS {
+            lines.push(line.trim_end().to_string());
+            line.clear();
+        }
+        line.push_str(&piece);
+    }
+    if !line.is_empty() {
+        lines.push(line.trim_end().to_string());
+    }
+    Ok(lines)
+}
+
+/// Fecha de creación legible en UTC
+fn format_creation_date(date: &OffsetDateTime) -> String {
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        date.year(),
+        date.month() as u8,
+        date.day(),
+        date.hour(),
+        date.minute()
+    )
+}
+
+/// Generar el PDF del respaldo en memoria
+///
+/// Sin `layout` (o con `Numbered`) las palabras van en la rejilla numerada de
+/// 3 columnas; el resto de disposiciones se imprimen como texto.
+pub fn render_paper_backup(
+    phrase: &str,
+    iterations: u32,
+    memory_cost: u32,
+    split: bool,
+    layout: Option<&PhraseLayout>,
+) -> Result<Vec<u8>> {
+    crate::bip39::validate_seed_phrase_complete(phrase)?;
+
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+
+    let pages = if split {
+        let (page_a, page_b) = split_into_pages(&words);
+        vec![
+            BackupPage { label: "Page A of 2 (odd positions)".to_string(), words: page_a },
+            BackupPage { label: "Page B of 2 (even positions)".to_string(), words: page_b },
+        ]
+    } else {
+        vec![BackupPage {
+            label: "Complete backup".to_string(),
+            words: words.iter().map(|w| Some(w.to_string())).collect(),
+        }]
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let created = format_creation_date(&now);
+
+    let (doc, first_page, first_layer) =
+        PdfDocument::new("SCypher Paper Backup", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Backup");
+    let doc = doc
+        .with_creator("SCypher")
+        .with_producer("SCypher")
+        .with_creation_date(now)
+        .with_mod_date(now);
+
+    let font_error = |e: printpdf::Error| SCypherError::file(format!("Cannot load PDF font: {}", e));
+    let fonts = Fonts {
+        regular: doc.add_builtin_font(BuiltinFont::Helvetica).map_err(font_error)?,
+        bold: doc.add_builtin_font(BuiltinFont::HelveticaBold).map_err(font_error)?,
+        mono: doc.add_builtin_font(BuiltinFont::Courier).map_err(font_error)?,
+    };
+
+    for (index, page) in pages.iter().enumerate() {
+        let layer = if index == 0 {
+            doc.get_page(first_page).get_layer(first_layer)
+        } else {
+            let (page_index, layer_index) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Backup");
+            doc.get_page(page_index).get_layer(layer_index)
+        };
+
+        draw_page(&layer, &fonts, page, iterations, memory_cost, &created, split, layout)?;
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| SCypherError::file(format!("Cannot generate PDF: {}", e)))
+}
+
+/// Dibujar una página completa del respaldo
+#[allow(clippy::too_many_arguments)]
+fn draw_page(
+    layer: &PdfLayerReference,
+    fonts: &Fonts,
+    page: &BackupPage,
+    iterations: u32,
+    memory_cost: u32,
+    created: &str,
+    split: bool,
+    layout: Option<&PhraseLayout>,
+) -> Result<()> {
+    // Encabezado
+    layer.use_text("SCypher Paper Backup", 20.0, Mm(MARGIN), Mm(272.0), &fonts.bold);
+    layer.use_text(page.label.as_str(), 11.0, Mm(MARGIN), Mm(264.0), &fonts.regular);
+
+    // Parámetros de derivación y fecha
+    layer.use_text(
+        format!("KDF: Argon2id  |  Iterations: {}  |  Memory: {} KiB", iterations, memory_cost),
+        10.0,
+        Mm(MARGIN),
+        Mm(254.0),
+        &fonts.regular,
+    );
+    layer.use_text(format!("Created: {}", created), 10.0, Mm(MARGIN), Mm(248.0), &fonts.regular);
+
+    let rows = match layout {
+        Some(layout) if *layout != PhraseLayout::Numbered => {
+            // Frase como texto con la disposición elegida
+            let lines = layout_lines(&page.words, layout)?;
+            for (row, line) in lines.iter().enumerate() {
+                let y = GRID_TOP - row as f32 * GRID_ROW_HEIGHT;
+                layer.use_text(line.as_str(), PHRASE_FONT_SIZE, Mm(MARGIN), Mm(y), &fonts.mono);
+            }
+            lines.len()
+        }
+        _ => {
+            // Rejilla numerada de palabras
+            let rows = page.words.len().div_ceil(GRID_COLUMNS);
+            for (position, word) in page.words.iter().enumerate() {
+                let column = position / rows;
+                let row = position % rows;
+                let x = MARGIN + column as f32 * GRID_COLUMN_WIDTH;
+                let y = GRID_TOP - row as f32 * GRID_ROW_HEIGHT;
+                let text = format!("{:>2}. {}", position + 1, word.as_deref().unwrap_or(MISSING_WORD));
+                layer.use_text(text, 12.0, Mm(x), Mm(y), &fonts.mono);
+            }
+            rows
+        }
+    };
+
+    // Código QR con las palabras de esta página
+    let qr_top = GRID_TOP - rows as f32 * GRID_ROW_HEIGHT - 6.0;
+    draw_qr_code(layer, &qr_payload(&page.words), MARGIN, qr_top - QR_SIZE)?;
+
+    // Instrucciones de recuperación junto al QR
+    let mut instructions = vec![
+        "Recovery instructions:".to_string(),
+        "1. Open SCypher and enter the phrase above in order.".to_string(),
+        "2. Enter your password with the same KDF parameters.".to_string(),
+        "3. The XOR transformation restores the original seed.".to_string(),
+    ];
+    if split {
+        instructions.push("4. Combine page A (odd) and page B (even) first.".to_string());
+    }
+    instructions.push("Without the password this phrase cannot be reversed.".to_string());
+    instructions.push("Store this page offline, away from the password.".to_string());
+
+    let text_x = MARGIN + QR_SIZE + 8.0;
+    for (line_index, line) in instructions.iter().enumerate() {
+        let font = if line_index == 0 { &fonts.bold } else { &fonts.regular };
+        let y = qr_top - 6.0 - line_index as f32 * 6.0;
+        layer.use_text(line.as_str(), 9.0, Mm(text_x), Mm(y), font);
+    }
+
+    Ok(())
+}
+
+/// Dibujar un código QR como rectángulos rellenos
+fn draw_qr_code(layer: &PdfLayerReference, payload: &str, x: f32, y: f32) -> Result<()> {
+    let code = QrCode::with_error_correction_level(payload.as_bytes(), EcLevel::M)
+        .map_err(|e| SCypherError::file(format!("Cannot generate QR code: {}", e)))?;
+
+    let width = code.width();
+    let module = QR_SIZE / width as f32;
+    let colors = code.to_colors();
+
+    for row in 0..width {
+        for column in 0..width {
+            if colors[row * width + column] != QrColor::Dark {
+                continue;
+            }
+            // El origen del PDF está abajo a la izquierda: invertir filas
+            let llx = x + column as f32 * module;
+            let lly = y + (width - row - 1) as f32 * module;
+            layer.add_rect(Rect::new(Mm(llx), Mm(lly), Mm(llx + module), Mm(lly + module)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Generar el PDF y guardarlo en disco con permisos restrictivos
+pub fn export_paper_backup(
+    phrase: &str,
+    iterations: u32,
+    memory_cost: u32,
+    path: &str,
+    split: bool,
+    layout: Option<&PhraseLayout>,
+) -> Result<()> {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    let pdf = render_paper_backup(phrase, iterations, memory_cost, split, layout)?;
+
+    // El archivo nace con permisos restrictivos: nunca existe legible por otros
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options
+        .open(path)
+        .map_err(|e| SCypherError::file(format!("Cannot create file: {}", e)))?;
+    file.write_all(&pdf)
+        .map_err(|e| SCypherError::file(format!("Cannot write file: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_split_pages_are_complementary() {
+        let words: Vec<&str> = PHRASE.split_whitespace().collect();
+        let (page_a, page_b) = split_into_pages(&words);
+
+        for (i, word) in words.iter().enumerate() {
+            // Cada posición aparece exactamente en una página
+            assert!(page_a[i].is_some() != page_b[i].is_some());
+            let present = page_a[i].as_ref().or(page_b[i].as_ref()).unwrap();
+            assert_eq!(present, word);
+        }
+        assert!(qr_payload(&page_b).starts_with("_ abandon"));
+    }
+
+    #[test]
+    fn test_render_produces_pdf() {
+        let single = render_paper_backup(PHRASE, 5, 131072, false, None).unwrap();
+        assert!(single.starts_with(b"%PDF"));
+
+        let split = render_paper_backup(PHRASE, 5, 131072, true, None).unwrap();
+        assert!(split.starts_with(b"%PDF"));
+
+        let custom = PhraseLayout::Custom(" / ".to_string());
+        let text = render_paper_backup(PHRASE, 5, 131072, true, Some(&custom)).unwrap();
+        assert!(text.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_layout_lines_fit_page() {
+        let words: Vec<Option<String>> = PHRASE.split_whitespace().map(|w| Some(w.to_string())).collect();
+        let words: Vec<Option<String>> = words.iter().chain(words.iter()).cloned().collect(); // 24 palabras
+
+        for layout in [PhraseLayout::SingleLine, PhraseLayout::Grid, PhraseLayout::Custom(" - ".to_string())] {
+            let lines = layout_lines(&words, &layout).unwrap();
+            assert!(lines.iter().all(|line| line.chars().count() <= PHRASE_LINE_CHARS), "{:?}", layout);
+
+            let parsed = crate::cli::output::format::parse_formatted_phrase(&lines.join("\n"));
+            assert_eq!(parsed.split_whitespace().count(), 24);
+        }
+        assert_eq!(layout_lines(&words, &PhraseLayout::Grid).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_export_creates_private_file() {
+        let path = std::env::temp_dir().join(format!("scypher_paper_{}.pdf", std::process::id()));
+        let path_str = path.to_str().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        export_paper_backup(PHRASE, 5, 131072, path_str, false, None).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        // No se sobrescribe un archivo existente
+        assert!(export_paper_backup(PHRASE, 5, 131072, path_str, false, None).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_phrase_rejected() {
+        assert!(render_paper_backup("not a valid phrase", 5, 131072, false, None).is_err());
+    }
+}
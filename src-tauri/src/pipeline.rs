@@ -0,0 +1,203 @@
+// =============================================================================
+// TRANSFORMACIÓN Y DERIVACIÓN EN UNA SOLA LLAMADA
+// Transforma la frase y deriva las direcciones del resultado dentro del mismo
+// trabajo del backend. La frase transformada no pasa por JavaScript salvo que
+// se pida expresamente; para comprobar que se descifró la wallet correcta
+// basta con las direcciones y las huellas BIP32 de entrada y salida.
+// =============================================================================
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::addresses::{AddressSet, NetworkConfig};
+use crate::crypto::compat::CompatibilityMode;
+use crate::crypto::sanity::TransformMode;
+use crate::crypto::{ChecksumPolicy, PolicyTransform};
+use crate::security::hardware_pepper::PepperSource;
+use crate::error::{SCypherError, Result};
+
+/// Parámetros de la transformación (los mismos que `transform_seed_phrase`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformParams {
+    pub iterations: u32,
+    pub memory_cost: u32,
+    #[serde(default)]
+    pub parallelism: Option<u32>, // Por defecto DEFAULT_PARALLELISM
+    #[serde(default)]
+    pub mode: TransformMode,
+    #[serde(default)]
+    pub checksum_policy: ChecksumPolicy,
+    #[serde(default)]
+    pub compatibility: CompatibilityMode,
+    #[serde(default)]
+    pub hardware_pepper: Option<PepperSource>, // Secreto Argon2id de un token hardware
+}
+
+/// Resultado combinado
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelineResult {
+    pub addresses: AddressSet,
+    pub input_fingerprint: Option<String>, // None si la entrada no es una frase BIP39 válida
+    pub output_fingerprint: String,
+    pub checksum_corrected: bool,
+    pub result: Option<String>,            // Solo si se pidió la frase transformada
+}
+
+/// Aplicar la transformación según modo, compatibilidad y política de checksum
+///
+/// Con `second_password` se usa el control dual (solo formato v1). `pepper`
+/// es el secreto ya obtenido del token (`hardware_pepper`), lo que permite
+/// verificar la ida y vuelta sin volver a pedirlo.
+pub fn transform_phrase_with_pepper(
+    phrase: &str,
+    password: &str,
+    second_password: Option<&str>,
+    params: &TransformParams,
+    pepper: Option<&[u8]>,
+) -> Result<PolicyTransform> {
+    let parallelism = params.parallelism.unwrap_or(crate::crypto::keystream::DEFAULT_PARALLELISM);
+    let policy = params.checksum_policy;
+
+    if pepper.is_some() && (second_password.is_some() || params.compatibility == CompatibilityMode::BashV2) {
+        return Err(SCypherError::crypto(
+            "A hardware token pepper is not available with dual control or Bash v2 compatibility".to_string(),
+        ));
+    }
+
+    // Protección opcional contra intentos repetidos. El XOR es simétrico:
+    // cifrar una frase ya transformada también la recupera, así que el
+    // límite se aplica en todos los modos y no solo al descifrar.
+    crate::security::attempt_guard::ensure_attempt_allowed()?;
+
+    if let Some(second_password) = second_password {
+        if params.compatibility == CompatibilityMode::BashV2 {
+            return Err(SCypherError::crypto("Dual control is not available in Bash v2 compatibility mode".to_string()));
+        }
+        if params.mode != TransformMode::Decrypt {
+            crate::crypto::keystream::validate_encrypt_parallelism(parallelism)?;
+        }
+        return crate::crypto::dual::transform_seed_dual(
+            phrase, password, second_password, params.iterations, params.memory_cost, parallelism, policy,
+        );
+    }
+
+    match params.mode {
+        // Frases del script Bash v2.0: keystream SHAKE-256
+        _ if params.compatibility == CompatibilityMode::BashV2 => {
+            crate::crypto::compat::transform_bash_v2(phrase, password, params.iterations, policy)
+        }
+        TransformMode::Decrypt if pepper.is_some() => {
+            // El pepper solo existe en el formato v1 (frase sin sobre)
+            if crate::crypto::format::detect_format(phrase, None).format_version != crate::crypto::format::FormatVersion::V1.number() {
+                return Err(SCypherError::crypto("A hardware token pepper only applies to plain phrases (format v1)".to_string()));
+            }
+            crate::crypto::transform_seed_with_pepper(
+                phrase, password, pepper, params.iterations, params.memory_cost, parallelism, policy,
+            )
+        }
+        TransformMode::Decrypt => {
+            crate::crypto::format::decode_with_policy(
+                phrase, password, params.iterations, params.memory_cost, parallelism, None, policy,
+            )
+        }
+        _ => {
+            crate::crypto::keystream::validate_encrypt_parallelism(parallelism)?;
+            crate::crypto::transform_seed_with_pepper(
+                phrase, password, pepper, params.iterations, params.memory_cost, parallelism, policy,
+            )
+        }
+    }
+}
+
+/// Derivar las direcciones de una frase ya transformada
+///
+/// La transformación se hace antes (en el proceso aislado de cifrado); las
+/// huellas se calculan con la misma passphrase que la derivación.
+pub fn derive_transformed(
+    phrase: &str,
+    output: PolicyTransform,
+    passphrase: Option<&str>,
+    network_configs: HashMap<String, NetworkConfig>,
+    include_result: bool,
+) -> Result<PipelineResult> {
+    let addresses = crate::addresses::derive_addresses_with_config(&output.phrase, passphrase, network_configs)?;
+
+    Ok(PipelineResult {
+        addresses,
+        input_fingerprint: crate::addresses::master_fingerprint(phrase, passphrase).ok(),
+        output_fingerprint: crate::addresses::master_fingerprint(&output.phrase, passphrase)?,
+        checksum_corrected: output.checksum_corrected,
+        result: include_result.then(|| output.phrase.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::addresses::NetworkId;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn params(mode: TransformMode) -> TransformParams {
+        TransformParams {
+            iterations: 1,
+            memory_cost: 8192,
+            parallelism: None,
+            mode,
+            checksum_policy: ChecksumPolicy::default(),
+            compatibility: CompatibilityMode::default(),
+            hardware_pepper: None,
+        }
+    }
+
+    fn bitcoin_config() -> HashMap<String, NetworkConfig> {
+        let config: NetworkConfig = serde_json::from_str(r#"{"count": 1, "use_passphrase": false}"#).unwrap();
+        HashMap::from([("bitcoin".to_string(), config)])
+    }
+
+    /// Transformación en este proceso seguida de la derivación
+    fn transform_and_derive(phrase: &str, mode: TransformMode, include_result: bool) -> PipelineResult {
+        let output = transform_phrase_with_pepper(phrase, "pipeline_password", None, &params(mode), None).unwrap();
+        derive_transformed(phrase, output, None, bitcoin_config(), include_result).unwrap()
+    }
+
+    #[test]
+    fn test_transform_and_derive() {
+        let result = transform_and_derive(PHRASE, TransformMode::Encrypt, false);
+        assert!(result.result.is_none());
+        assert_eq!(result.input_fingerprint.as_deref(), Some("73c5da0a"));
+        assert!(!result.addresses[NetworkId::Bitcoin].is_empty());
+
+        // Las direcciones y la huella son las de la frase transformada
+        let transformed = transform_phrase_with_pepper(PHRASE, "pipeline_password", None, &params(TransformMode::Encrypt), None).unwrap().phrase;
+        assert_eq!(result.output_fingerprint, crate::addresses::master_fingerprint(&transformed, None).unwrap());
+        let expected = crate::addresses::derive_addresses_with_config(&transformed, None, bitcoin_config()).unwrap();
+        assert_eq!(result.addresses[NetworkId::Bitcoin][0].address, expected[NetworkId::Bitcoin][0].address);
+    }
+
+    #[test]
+    fn test_transform_and_derive_roundtrip() {
+        let encrypted = transform_and_derive(PHRASE, TransformMode::Encrypt, true);
+        let encrypted_phrase = encrypted.result.unwrap();
+
+        let decrypted = transform_and_derive(&encrypted_phrase, TransformMode::Decrypt, true);
+        assert_eq!(decrypted.result.as_deref(), Some(PHRASE));
+        assert_eq!(decrypted.output_fingerprint, "73c5da0a");
+        assert_eq!(decrypted.input_fingerprint.as_deref(), Some(encrypted.output_fingerprint.as_str()));
+    }
+
+    #[test]
+    fn test_pepper_roundtrip() {
+        let pepper = [5u8; 32];
+        let encrypted = transform_phrase_with_pepper(PHRASE, "pipeline_password", None, &params(TransformMode::Encrypt), Some(&pepper)).unwrap().phrase;
+        let plain = transform_phrase_with_pepper(PHRASE, "pipeline_password", None, &params(TransformMode::Encrypt), None).unwrap().phrase;
+        assert_ne!(encrypted, plain);
+
+        // Sin el pepper no se recupera la frase
+        let decrypted = transform_phrase_with_pepper(&encrypted, "pipeline_password", None, &params(TransformMode::Decrypt), Some(&pepper)).unwrap();
+        assert_eq!(decrypted.phrase, PHRASE);
+        let without = transform_phrase_with_pepper(&encrypted, "pipeline_password", None, &params(TransformMode::Decrypt), None).unwrap();
+        assert_ne!(without.phrase, PHRASE);
+
+        assert!(transform_phrase_with_pepper(PHRASE, "a", Some("b"), &params(TransformMode::Encrypt), Some(&pepper)).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+// =============================================================================
+// IMPRESIÓN DIRECTA A IMPRESORA LOCAL
+// Envía el resultado formateado al sistema de impresión sin crear archivos
+// temporales propios:
+// - Linux/macOS: CUPS, pasando el texto por stdin a `lp`
+// - Windows: spooler nativo (OpenPrinter/WritePrinter, tipo de datos TEXT)
+// El trabajo sí pasa por la cola del sistema operativo, que lo elimina al
+// terminar salvo que el administrador tenga activada la retención de trabajos.
+// =============================================================================
+
+use crate::error::{SCypherError, Result};
+
+/// Validar el nombre de impresora (evita inyección de opciones en `lp`)
+fn validate_printer_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 127
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@' | ' '));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(SCypherError::crypto(format!("Invalid printer name: {}", name)))
+    }
+}
+
+/// Documento a imprimir a partir de un resultado
+pub fn format_print_document(result: &str) -> String {
+    let mut document = String::new();
+    if let Some(mark) = crate::security::training::watermark() {
+        document.push_str(&format!("*** {} - TRAINING MODE - NOT A REAL SEED ***\n", mark));
+    }
+    document.push_str("SCypher - Transformed seed phrase\n");
+    document.push_str(&crate::cli::output::format::separator_line(48));
+    document.push_str("\n\n");
+    document.push_str(&crate::cli::output::format::format_seed_phrase(result));
+    document.push_str("\n\n");
+    document.push_str("Same password and Argon2id parameters are required to reverse it.\n");
+    document
+}
+
+/// Enviar texto a la impresora (predeterminada si `printer` es None)
+pub fn print_text(text: &str, printer: Option<&str>) -> Result<()> {
+    if let Some(name) = printer {
+        validate_printer_name(name)?;
+    }
+    spool_text(text, printer)
+}
+
+#[cfg(unix)]
+fn spool_text(text: &str, printer: Option<&str>) -> Result<()> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut command = Command::new("lp");
+    crate::security::environment::harden_command(&mut command);
+    command.args(["-s", "-t", "SCypher"]);
+    if let Some(name) = printer {
+        command.args(["-d", name]);
+    }
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SCypherError::crypto(format!("Cannot start CUPS 'lp' command: {}", e)))?;
+
+    // El texto solo viaja por la tubería; stdin se cierra al salir del bloque
+    {
+        let stdin = child
+            .stdin
+            .as_mut()
+            .ok_or_else(|| SCypherError::crypto("Cannot open printer pipe".to_string()))?;
+        stdin.write_all(text.as_bytes())?;
+    }
+    drop(child.stdin.take());
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(SCypherError::crypto(format!(
+            "Printing failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn spool_text(text: &str, printer: Option<&str>) -> Result<()> {
+    use std::ptr;
+    use winapi::um::winnt::HANDLE;
+    use winapi::um::winspool::{
+        ClosePrinter, EndDocPrinter, EndPagePrinter, GetDefaultPrinterW, OpenPrinterW,
+        StartDocPrinterW, StartPagePrinter, WritePrinter, DOC_INFO_1W,
+    };
+
+    fn wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    fn last_error(call: &str) -> SCypherError {
+        SCypherError::crypto(format!("{} failed: {}", call, std::io::Error::last_os_error()))
+    }
+
+    let mut printer_name = match printer {
+        Some(name) => wide(name),
+        None => {
+            let mut length: u32 = 0;
+            unsafe { GetDefaultPrinterW(ptr::null_mut(), &mut length) };
+            if length == 0 {
+                return Err(SCypherError::crypto("No default printer configured".to_string()));
+            }
+            let mut buffer = vec![0u16; length as usize];
+            if unsafe { GetDefaultPrinterW(buffer.as_mut_ptr(), &mut length) } == 0 {
+                return Err(last_error("GetDefaultPrinter"));
+            }
+            buffer
+        }
+    };
+
+    // El procesador WinPrint interpreta TEXT con saltos de línea CRLF
+    let data = text.replace('\n', "\r\n").into_bytes();
+    let mut doc_name = wide("SCypher");
+    let mut datatype = wide("TEXT");
+
+    unsafe {
+        let mut handle: HANDLE = ptr::null_mut();
+        if OpenPrinterW(printer_name.as_mut_ptr(), &mut handle, ptr::null_mut()) == 0 {
+            return Err(last_error("OpenPrinter"));
+        }
+
+        let mut doc_info = DOC_INFO_1W {
+            pDocName: doc_name.as_mut_ptr(),
+            pOutputFile: ptr::null_mut(),
+            pDatatype: datatype.as_mut_ptr(),
+        };
+
+        let result = if StartDocPrinterW(handle, 1, &mut doc_info as *mut DOC_INFO_1W as *mut u8) == 0 {
+            Err(last_error("StartDocPrinter"))
+        } else {
+            let mut written: u32 = 0;
+            let page_ok = StartPagePrinter(handle) != 0
+                && WritePrinter(handle, data.as_ptr() as *mut _, data.len() as u32, &mut written) != 0
+                && written as usize == data.len();
+            let page_error = if page_ok { None } else { Some(last_error("WritePrinter")) };
+            EndPagePrinter(handle);
+            EndDocPrinter(handle);
+            page_error.map_or(Ok(()), Err)
+        };
+
+        ClosePrinter(handle);
+        result
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn spool_text(_text: &str, _printer: Option<&str>) -> Result<()> {
+    Err(SCypherError::UnsupportedPlatform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_printer_name_validation() {
+        assert!(validate_printer_name("HP_LaserJet").is_ok());
+        assert!(validate_printer_name("office printer@host.local").is_ok());
+        assert!(validate_printer_name("-oraw").is_err());
+        assert!(validate_printer_name("bad;name").is_err());
+        assert!(validate_printer_name("").is_err());
+    }
+
+    #[test]
+    fn test_print_document_uses_seed_format() {
+        let document = format_print_document("abandon ability able about");
+        assert!(document.contains(" 1. abandon"));
+        assert!(document.contains(" 4. about"));
+    }
+}
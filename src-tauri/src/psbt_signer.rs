@@ -0,0 +1,172 @@
+// =============================================================================
+// FIRMANTE PSBT OFFLINE (BIP174)
+// Convierte la herramienta en un firmante mínimo air-gapped para flujos
+// Sparrow/Electrum: recibe una PSBT en base64, identifica las entradas cuyas
+// derivaciones BIP32 pertenecen a la semilla, las firma y devuelve la PSBT.
+// =============================================================================
+
+use base64::Engine;
+use bitcoin::bip32::ExtendedPrivKey;
+use bitcoin::psbt::Psbt;
+use bitcoin::Network;
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Salida de la transacción, para revisión antes de firmar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PsbtOutputSummary {
+    pub address: Option<String>,
+    pub amount_sats: u64,
+}
+
+/// Resultado de firmar una PSBT
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PsbtSignResult {
+    pub psbt: String,                   // PSBT actualizada en base64
+    pub total_inputs: usize,
+    pub signed_inputs: Vec<usize>,      // Entradas firmadas con claves de esta semilla
+    pub outputs: Vec<PsbtOutputSummary>,
+    pub fee_sats: Option<u64>,          // Solo si todas las entradas incluyen su UTXO
+}
+
+/// Decodificar una PSBT en base64
+fn decode_psbt(psbt_base64: &str) -> Result<Psbt> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(psbt_base64.trim())
+        .map_err(|_| SCypherError::crypto("PSBT is not valid base64".to_string()))?;
+
+    Psbt::deserialize(&bytes).map_err(|e| SCypherError::crypto(format!("Invalid PSBT: {}", e)))
+}
+
+/// Resumir las salidas de la transacción
+fn summarize_outputs(psbt: &Psbt, network: Network) -> Vec<PsbtOutputSummary> {
+    psbt.unsigned_tx
+        .output
+        .iter()
+        .map(|output| PsbtOutputSummary {
+            address: bitcoin::Address::from_script(&output.script_pubkey, network)
+                .ok()
+                .map(|address| address.to_string()),
+            amount_sats: output.value,
+        })
+        .collect()
+}
+
+/// Firmar todas las entradas de la PSBT controladas por la semilla
+pub fn sign_psbt(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    psbt_base64: &str,
+    testnet: bool,
+) -> Result<PsbtSignResult> {
+    use bip39_crate::{Language, Mnemonic};
+
+    let network = if testnet { Network::Testnet } else { Network::Bitcoin };
+    let mut psbt = decode_psbt(psbt_base64)?;
+
+    let mnemonic = Mnemonic::parse_in_normalized(Language::English, seed_phrase)
+        .map_err(|e| SCypherError::crypto(format!("Invalid mnemonic: {}", e)))?;
+    let seed = mnemonic.to_seed(passphrase.unwrap_or(""));
+
+    let master_key = ExtendedPrivKey::new_master(network, &seed)
+        .map_err(|e| SCypherError::crypto(format!("Master key derivation failed: {}", e)))?;
+
+    // Las entradas se identifican por la huella de la master key en bip32_derivation
+    let secp = bitcoin::secp256k1::Secp256k1::new();
+    let signing_keys = match psbt.sign(&master_key, &secp) {
+        Ok(keys) => keys,
+        Err((_, errors)) => {
+            let details: Vec<String> = errors
+                .iter()
+                .map(|(index, error)| format!("input #{}: {}", index, error))
+                .collect();
+            return Err(SCypherError::crypto(format!("PSBT signing failed ({})", details.join("; "))));
+        }
+    };
+
+    let signed_inputs: Vec<usize> = signing_keys
+        .iter()
+        .filter(|(_, keys)| !keys.is_empty())
+        .map(|(index, _)| *index)
+        .collect();
+
+    if signed_inputs.is_empty() {
+        return Err(SCypherError::crypto(
+            "No inputs in this PSBT belong to the provided seed phrase".to_string(),
+        ));
+    }
+
+    Ok(PsbtSignResult {
+        psbt: base64::engine::general_purpose::STANDARD.encode(psbt.serialize()),
+        total_inputs: psbt.inputs.len(),
+        signed_inputs,
+        outputs: summarize_outputs(&psbt, network),
+        fee_sats: psbt.fee().ok().map(|fee| fee.to_sat()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::bip32::DerivationPath;
+    use bitcoin::{absolute, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
+    use std::str::FromStr;
+
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// PSBT que gasta un UTXO P2WPKH de m/84'/0'/0'/0/0 (como la exportaría Sparrow)
+    fn sample_psbt(fingerprint_seed: &str) -> String {
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        let mnemonic = bip39_crate::Mnemonic::parse_in_normalized(bip39_crate::Language::English, fingerprint_seed).unwrap();
+        let master = ExtendedPrivKey::new_master(Network::Bitcoin, &mnemonic.to_seed("")).unwrap();
+        let path = DerivationPath::from_str("m/84'/0'/0'/0/0").unwrap();
+        let child = master.derive_priv(&secp, &path).unwrap();
+        let public_key = bitcoin::PublicKey::new(child.private_key.public_key(&secp));
+
+        let spent_script = ScriptBuf::new_v0_p2wpkh(&public_key.wpubkey_hash().unwrap());
+
+        let tx = Transaction {
+            version: 2,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::from_str(
+                    "0000000000000000000000000000000000000000000000000000000000000001:0",
+                ).unwrap(),
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut { value: 90_000, script_pubkey: spent_script.clone() }],
+        };
+
+        let mut psbt = Psbt::from_unsigned_tx(tx).unwrap();
+        psbt.inputs[0].witness_utxo = Some(TxOut { value: 100_000, script_pubkey: spent_script });
+        psbt.inputs[0]
+            .bip32_derivation
+            .insert(public_key.inner, (master.fingerprint(&secp), path));
+
+        base64::engine::general_purpose::STANDARD.encode(psbt.serialize())
+    }
+
+    #[test]
+    fn test_sign_owned_input() {
+        let result = sign_psbt(TEST_MNEMONIC, None, &sample_psbt(TEST_MNEMONIC), false).unwrap();
+
+        assert_eq!(result.signed_inputs, vec![0]);
+        assert_eq!(result.fee_sats, Some(10_000));
+        assert_eq!(
+            result.outputs[0].address.as_deref(),
+            Some("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu")
+        );
+
+        let signed = decode_psbt(&result.psbt).unwrap();
+        assert_eq!(signed.inputs[0].partial_sigs.len(), 1);
+    }
+
+    #[test]
+    fn test_foreign_psbt_not_signed() {
+        // Con passphrase la huella de la master key cambia: ninguna entrada coincide
+        assert!(sign_psbt(TEST_MNEMONIC, Some("other"), &sample_psbt(TEST_MNEMONIC), false).is_err());
+        assert!(sign_psbt(TEST_MNEMONIC, None, "not-base64!", false).is_err());
+    }
+}
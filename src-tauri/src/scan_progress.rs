@@ -0,0 +1,201 @@
+// =============================================================================
+// EVENTOS DE PROGRESO DE LOS ESCANEOS DE DIRECCIONES
+// Esquema común para las búsquedas largas (búsqueda inversa de direcciones y
+// futuros escaneos por gap limit): `scan-progress` con la red, cuenta e índice
+// que se está probando, rutas comprobadas, coincidencias y tiempo estimado,
+// emitido como mucho cada `PROGRESS_INTERVAL`; y `scan-summary` una sola vez
+// al final, también si se cancela o falla. El resumen se emite después de que
+// todos los hilos terminen: tras él no llega ningún `scan-progress` de ese
+// escaneo, así que la GUI puede cerrar el panel al recibirlo.
+// =============================================================================
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::addresses::NetworkId;
+
+/// Evento de progreso
+pub const SCAN_PROGRESS_EVENT: &str = "scan-progress";
+
+/// Evento final con el resumen
+pub const SCAN_SUMMARY_EVENT: &str = "scan-summary";
+
+/// Intervalo mínimo entre dos eventos de progreso
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Progreso de un escaneo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanProgress {
+    pub scan: String,            // "locate"...
+    pub network: NetworkId,
+    pub account: u32,
+    pub index: u32,
+    pub checked: usize,
+    pub total: usize,
+    pub found: usize,
+    pub elapsed_ms: u64,
+    pub eta_ms: Option<u64>,     // None hasta tener una medida
+}
+
+/// Cómo terminó el escaneo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanStatus {
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// Resumen final de un escaneo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanSummary {
+    pub scan: String,
+    pub status: ScanStatus,
+    pub checked: usize,
+    pub total: usize,
+    pub found: usize,
+    pub elapsed_ms: u64,
+}
+
+/// Evento para la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ScanEvent {
+    Progress(ScanProgress),
+    Summary(ScanSummary),
+}
+
+impl ScanEvent {
+    /// Nombre del evento de Tauri
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Progress(_) => SCAN_PROGRESS_EVENT,
+            Self::Summary(_) => SCAN_SUMMARY_EVENT,
+        }
+    }
+}
+
+/// Contadores de un escaneo compartidos entre hilos, con el límite de frecuencia
+pub struct ScanTracker {
+    scan: &'static str,
+    total: usize,
+    started: Instant,
+    interval: Duration,
+    checked: AtomicUsize,
+    found: AtomicUsize,
+    last_emit: Mutex<Option<Instant>>,
+}
+
+impl ScanTracker {
+    pub fn new(scan: &'static str, total: usize) -> Self {
+        Self::with_interval(scan, total, PROGRESS_INTERVAL)
+    }
+
+    fn with_interval(scan: &'static str, total: usize, interval: Duration) -> Self {
+        Self {
+            scan,
+            total,
+            started: Instant::now(),
+            interval,
+            checked: AtomicUsize::new(0),
+            found: AtomicUsize::new(0),
+            last_emit: Mutex::new(None),
+        }
+    }
+
+    /// Anotar una coincidencia
+    pub fn record_found(&self) {
+        self.found.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Anotar una ruta comprobada; devuelve el progreso si toca avisar
+    ///
+    /// Se avisa en la primera ruta, en la última y como mucho una vez por
+    /// intervalo. Si otro hilo está avisando, este no espera: se salta el aviso.
+    pub fn record_checked(&self, network: NetworkId, account: u32, index: u32) -> Option<ScanProgress> {
+        let checked = self.checked.fetch_add(1, Ordering::Relaxed) + 1;
+        let now = Instant::now();
+        let due = {
+            let mut last = match self.last_emit.try_lock() {
+                Ok(last) => last,
+                Err(_) if checked == self.total => self.last_emit.lock().unwrap_or_else(|e| e.into_inner()),
+                Err(_) => return None,
+            };
+            let due = checked == self.total || last.is_none_or(|at| now.duration_since(at) >= self.interval);
+            if due {
+                *last = Some(now);
+            }
+            due
+        };
+        if !due {
+            return None;
+        }
+
+        let elapsed = now.duration_since(self.started);
+        let remaining = self.total.saturating_sub(checked);
+        let eta_ms = (checked > 0 && !elapsed.is_zero())
+            .then(|| (elapsed.as_millis() * remaining as u128 / checked as u128) as u64);
+        Some(ScanProgress {
+            scan: self.scan.to_string(),
+            network,
+            account,
+            index,
+            checked,
+            total: self.total,
+            found: self.found.load(Ordering::Relaxed),
+            elapsed_ms: elapsed.as_millis() as u64,
+            eta_ms,
+        })
+    }
+
+    /// Resumen final (llamar cuando ya no quedan hilos trabajando)
+    pub fn summary(&self, status: ScanStatus) -> ScanSummary {
+        ScanSummary {
+            scan: self.scan.to_string(),
+            status,
+            checked: self.checked.load(Ordering::Relaxed),
+            total: self.total,
+            found: self.found.load(Ordering::Relaxed),
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_is_throttled() {
+        let tracker = ScanTracker::with_interval("locate", 100, Duration::from_secs(3600));
+        let emitted: Vec<ScanProgress> = (0..100)
+            .filter_map(|index| tracker.record_checked(NetworkId::Bitcoin, 0, index))
+            .collect();
+
+        // Primera y última ruta; nada intermedio dentro del intervalo
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(emitted[0].checked, 1);
+        assert_eq!(emitted[1].checked, 100);
+        assert_eq!(emitted[1].index, 99);
+        assert_eq!(emitted[1].eta_ms.unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn test_events_serialize_with_their_names() {
+        let tracker = ScanTracker::new("locate", 10);
+        tracker.record_found();
+        let progress = ScanEvent::Progress(tracker.record_checked(NetworkId::Ethereum, 2, 7).unwrap());
+        let summary = ScanEvent::Summary(tracker.summary(ScanStatus::Cancelled));
+
+        assert_eq!(progress.name(), SCAN_PROGRESS_EVENT);
+        assert_eq!(summary.name(), SCAN_SUMMARY_EVENT);
+        let progress = serde_json::to_value(&progress).unwrap();
+        assert_eq!(progress["network"], "ethereum");
+        assert_eq!(progress["account"], 2);
+        assert_eq!(progress["found"], 1);
+        let summary = serde_json::to_value(&summary).unwrap();
+        assert_eq!(summary["status"], "cancelled");
+        assert_eq!(summary["checked"], 1);
+    }
+}
@@ -0,0 +1,255 @@
+//! Límite de intentos de descifrado (opcional)
+//!
+//! Con el cifrado XOR cualquier contraseña "descifra" a una frase válida; el
+//! único fallo detectable es que la huella esperada no coincida. Si el
+//! usuario activa esta protección, cada fallo cuenta y, superados los
+//! intentos libres, la sesión impone una espera exponencial antes del
+//! siguiente descifrado o comprobación. No impide un ataque fuera de la
+//! aplicación, pero hace menos útil la aplicación instalada a quien se lleve
+//! el portátil junto con la frase cifrada.
+
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Espera máxima configurable (24 horas)
+const MAX_DELAY_LIMIT_SECS: u64 = 86_400;
+
+/// Configuración de la protección
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttemptGuardConfig {
+    pub enabled: bool,
+    pub free_attempts: u32,    // Fallos permitidos sin espera
+    pub base_delay_secs: u64,  // Primera espera; se duplica en cada fallo
+    pub max_delay_secs: u64,
+}
+
+impl Default for AttemptGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            free_attempts: 3,
+            base_delay_secs: 2,
+            max_delay_secs: 900,
+        }
+    }
+}
+
+impl AttemptGuardConfig {
+    /// ¿Permite más intentos o esperas más cortas que `current`?
+    fn is_weaker_than(&self, current: &AttemptGuardConfig) -> bool {
+        current.enabled
+            && (!self.enabled
+                || self.free_attempts > current.free_attempts
+                || self.base_delay_secs < current.base_delay_secs
+                || self.max_delay_secs < current.max_delay_secs)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.base_delay_secs == 0
+            || self.max_delay_secs < self.base_delay_secs
+            || self.max_delay_secs > MAX_DELAY_LIMIT_SECS
+        {
+            return Err(SCypherError::crypto(format!(
+                "Attempt delays must satisfy 1 <= base <= max <= {} seconds",
+                MAX_DELAY_LIMIT_SECS
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Estado reportado a la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttemptGuardStatus {
+    pub config: AttemptGuardConfig,
+    pub failed_attempts: u32,
+    pub retry_after_secs: u64,  // 0 si se puede intentar ya
+}
+
+#[derive(Default)]
+struct AttemptGuard {
+    config: AttemptGuardConfig,
+    failures: u32,
+    locked_until: Option<Instant>,
+}
+
+impl AttemptGuard {
+    fn retry_after(&self, now: Instant) -> u64 {
+        self.locked_until
+            .filter(|until| *until > now)
+            .map(|until| (until - now).as_secs_f64().ceil() as u64)
+            .unwrap_or(0)
+    }
+
+    fn check(&self, now: Instant) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+        match self.retry_after(now) {
+            0 => Ok(()),
+            retry_after_secs => Err(SCypherError::TooManyAttempts { retry_after_secs }),
+        }
+    }
+
+    /// Espera tras el fallo número `failures`: base * 2^(fallos - libres - 1)
+    fn delay_for(&self, failures: u32) -> Duration {
+        let over = failures.saturating_sub(self.config.free_attempts);
+        if over == 0 {
+            return Duration::ZERO;
+        }
+        let factor = 1u64.checked_shl(over - 1).unwrap_or(u64::MAX);
+        Duration::from_secs(self.config.base_delay_secs.saturating_mul(factor).min(self.config.max_delay_secs))
+    }
+
+    fn record(&mut self, success: bool, now: Instant) {
+        if !self.config.enabled {
+            return;
+        }
+        if success {
+            self.failures = 0;
+            self.locked_until = None;
+            return;
+        }
+        self.failures = self.failures.saturating_add(1);
+        let delay = self.delay_for(self.failures);
+        self.locked_until = (!delay.is_zero()).then(|| now + delay);
+    }
+
+    /// Cambiar la configuración conservando los fallos y la espera en curso
+    ///
+    /// Durante una espera no se puede relajar ni desactivar la protección:
+    /// si no, bastaría con reconfigurarla entre intento e intento.
+    fn reconfigure(&mut self, config: AttemptGuardConfig, now: Instant) -> Result<()> {
+        config.validate()?;
+        let retry_after_secs = self.retry_after(now);
+        if retry_after_secs > 0 && config.is_weaker_than(&self.config) {
+            return Err(SCypherError::TooManyAttempts { retry_after_secs });
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    fn status(&self, now: Instant) -> AttemptGuardStatus {
+        AttemptGuardStatus {
+            config: self.config.clone(),
+            failed_attempts: self.failures,
+            retry_after_secs: if self.config.enabled { self.retry_after(now) } else { 0 },
+        }
+    }
+}
+
+static ATTEMPT_GUARD: OnceLock<Mutex<AttemptGuard>> = OnceLock::new();
+
+fn guard_slot() -> &'static Mutex<AttemptGuard> {
+    ATTEMPT_GUARD.get_or_init(|| Mutex::new(AttemptGuard::default()))
+}
+
+/// Cambiar la configuración (no reinicia el contador de fallos)
+pub fn configure(config: AttemptGuardConfig) -> Result<AttemptGuardStatus> {
+    let mut guard = guard_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    guard.reconfigure(config, now)?;
+    Ok(guard.status(now))
+}
+
+/// Configuración y estado actuales
+pub fn status() -> AttemptGuardStatus {
+    guard_slot().lock().unwrap_or_else(|e| e.into_inner()).status(Instant::now())
+}
+
+/// Rechazar el intento si la sesión está en espera
+pub fn ensure_attempt_allowed() -> Result<()> {
+    guard_slot().lock().unwrap_or_else(|e| e.into_inner()).check(Instant::now())
+}
+
+/// Registrar el resultado de una comprobación (un acierto reinicia el contador)
+pub fn record_attempt(success: bool) {
+    guard_slot().lock().unwrap_or_else(|e| e.into_inner()).record(success, Instant::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled_guard() -> AttemptGuard {
+        AttemptGuard {
+            config: AttemptGuardConfig { enabled: true, ..AttemptGuardConfig::default() },
+            ..AttemptGuard::default()
+        }
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let mut guard = enabled_guard();
+        let now = Instant::now();
+
+        // Tres fallos libres
+        for _ in 0..3 {
+            guard.record(false, now);
+            assert!(guard.check(now).is_ok());
+        }
+
+        guard.record(false, now);
+        assert!(matches!(guard.check(now), Err(SCypherError::TooManyAttempts { retry_after_secs: 2 })));
+        assert!(guard.check(now + Duration::from_secs(2)).is_ok());
+
+        guard.record(false, now);
+        assert_eq!(guard.retry_after(now), 4);
+
+        // Tope máximo y reinicio tras un acierto
+        for _ in 0..20 {
+            guard.record(false, now);
+        }
+        assert_eq!(guard.retry_after(now), 900);
+        guard.record(true, now);
+        assert!(guard.check(now).is_ok());
+        assert_eq!(guard.failures, 0);
+    }
+
+    #[test]
+    fn test_disabled_guard_and_config_validation() {
+        let mut guard = AttemptGuard::default();
+        let now = Instant::now();
+        for _ in 0..10 {
+            guard.record(false, now);
+        }
+        assert!(guard.check(now).is_ok());
+        assert_eq!(guard.status(now).failed_attempts, 0);
+
+        let invalid = AttemptGuardConfig { base_delay_secs: 0, ..AttemptGuardConfig::default() };
+        assert!(invalid.validate().is_err());
+        let invalid = AttemptGuardConfig { max_delay_secs: MAX_DELAY_LIMIT_SECS + 1, ..AttemptGuardConfig::default() };
+        assert!(invalid.validate().is_err());
+        assert!(AttemptGuardConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_reconfigure_keeps_lockout() {
+        let mut guard = enabled_guard();
+        let now = Instant::now();
+        for _ in 0..5 {
+            guard.record(false, now);
+        }
+        assert_eq!(guard.retry_after(now), 4);
+
+        // Ni desactivar ni relajar mientras dura la espera
+        let disabled = AttemptGuardConfig { enabled: false, ..guard.config.clone() };
+        assert!(matches!(guard.reconfigure(disabled, now), Err(SCypherError::TooManyAttempts { .. })));
+        let more_attempts = AttemptGuardConfig { free_attempts: 50, ..guard.config.clone() };
+        assert!(guard.reconfigure(more_attempts, now).is_err());
+
+        // Endurecer sí, y los fallos se conservan
+        let stricter = AttemptGuardConfig { max_delay_secs: 3600, ..guard.config.clone() };
+        guard.reconfigure(stricter, now).unwrap();
+        assert_eq!(guard.failures, 5);
+        assert!(guard.check(now).is_err());
+
+        // Pasada la espera se puede relajar, sin borrar el contador
+        let later = now + Duration::from_secs(4);
+        let disabled = AttemptGuardConfig { enabled: false, ..guard.config.clone() };
+        guard.reconfigure(disabled, later).unwrap();
+        assert_eq!(guard.failures, 5);
+    }
+}
@@ -0,0 +1,116 @@
+//! Protección contra capturas de pantalla y grabación
+//!
+//! Mientras una frase semilla está visible, la ventana se excluye de
+//! capturas y de la compartición de pantalla:
+//! - Windows: `SetWindowDisplayAffinity(WDA_EXCLUDEFROMCAPTURE)`
+//! - macOS: `NSWindow.sharingType = NSWindowSharingNone`
+//!
+//! En otras plataformas no existe un mecanismo equivalente y se informa
+//! como no soportado.
+
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Estado global de la protección (la GUI tiene una sola ventana principal)
+static CAPTURE_PROTECTION_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Estado de la protección de captura reportado a la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureProtectionStatus {
+    pub supported: bool,
+    pub active: bool,
+}
+
+/// Verificar si la plataforma actual soporta protección de captura
+pub fn is_capture_protection_supported() -> bool {
+    cfg!(any(target_os = "windows", target_os = "macos"))
+}
+
+/// Obtener el estado actual de la protección
+pub fn capture_protection_status() -> CaptureProtectionStatus {
+    CaptureProtectionStatus {
+        supported: is_capture_protection_supported(),
+        active: CAPTURE_PROTECTION_ACTIVE.load(Ordering::SeqCst),
+    }
+}
+
+/// Activar o desactivar la protección sobre una ventana nativa
+///
+/// `native_window` es el HWND en Windows o el puntero `NSWindow*` en macOS.
+pub fn set_capture_protection(native_window: *mut c_void, enabled: bool) -> Result<CaptureProtectionStatus> {
+    if !is_capture_protection_supported() {
+        return Err(SCypherError::UnsupportedPlatform);
+    }
+
+    if native_window.is_null() {
+        return Err(SCypherError::WindowError("Invalid native window handle".to_string()));
+    }
+
+    apply_platform_protection(native_window, enabled)?;
+    CAPTURE_PROTECTION_ACTIVE.store(enabled, Ordering::SeqCst);
+
+    Ok(capture_protection_status())
+}
+
+#[cfg(target_os = "windows")]
+fn apply_platform_protection(native_window: *mut c_void, enabled: bool) -> Result<()> {
+    use winapi::shared::windef::HWND;
+    use winapi::um::winuser::SetWindowDisplayAffinity;
+
+    // Constantes de Windows 10 2004+ (no expuestas por winapi 0.3)
+    const WDA_NONE: u32 = 0x0000_0000;
+    const WDA_EXCLUDEFROMCAPTURE: u32 = 0x0000_0011;
+
+    let affinity = if enabled { WDA_EXCLUDEFROMCAPTURE } else { WDA_NONE };
+
+    unsafe {
+        if SetWindowDisplayAffinity(native_window as HWND, affinity) == 0 {
+            return Err(SCypherError::WindowError(format!(
+                "SetWindowDisplayAffinity failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn apply_platform_protection(native_window: *mut c_void, enabled: bool) -> Result<()> {
+    use objc::runtime::Object;
+    use objc::{msg_send, sel, sel_impl};
+
+    // NSWindowSharingNone = 0, NSWindowSharingReadOnly = 1
+    let sharing_type: u64 = if enabled { 0 } else { 1 };
+
+    unsafe {
+        let ns_window = native_window as *mut Object;
+        let _: () = msg_send![ns_window, setSharingType: sharing_type];
+    }
+
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn apply_platform_protection(_native_window: *mut c_void, _enabled: bool) -> Result<()> {
+    Err(SCypherError::UnsupportedPlatform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_null_handle_rejected() {
+        assert!(set_capture_protection(std::ptr::null_mut(), true).is_err());
+        assert!(!capture_protection_status().active);
+    }
+
+    #[test]
+    fn test_status_reports_support() {
+        let status = capture_protection_status();
+        assert_eq!(status.supported, is_capture_protection_supported());
+    }
+}
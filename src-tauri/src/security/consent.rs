@@ -0,0 +1,125 @@
+//! Consentimiento por acción para los comandos sensibles
+//!
+//! Exportar claves privadas, escribir un resultado en disco, imprimirlo o
+//! copiarlo al portapapeles exige un token de consentimiento reciente. El token lo emite
+//! el backend después de que el usuario confirme en un diálogo nativo (no en
+//! la vista web), vale para una sola acción concreta, se consume al usarse y
+//! caduca a los pocos segundos. Así un script inyectado en la vista web no
+//! puede exportar nada aunque controle el estado de la interfaz.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Vigencia de un token de consentimiento
+pub const CONSENT_TTL: Duration = Duration::from_secs(60);
+
+/// Acciones que requieren consentimiento
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentAction {
+    ExportPrivateKeys,
+    ExportEncryptedKeys,
+    WriteFile,
+    CopyToClipboard,
+    Print,
+    DisableCryptoWorker,
+}
+
+impl ConsentAction {
+    /// Texto del diálogo de confirmación
+    pub fn prompt(self) -> &'static str {
+        match self {
+            Self::ExportPrivateKeys => "Export private keys in plain text? Anyone who sees them controls the funds.",
+            Self::ExportEncryptedKeys => "Export encrypted private keys for the selected addresses?",
+            Self::WriteFile => "Write the result to a file on disk?",
+            Self::CopyToClipboard => "Copy the result to the clipboard? Other applications can read the clipboard.",
+            Self::Print => "Send the result to the printer? Printers and print spoolers may keep a copy.",
+            Self::DisableCryptoWorker => "Run encryption inside the main process? The keystream will no longer be isolated in a sandboxed worker.",
+        }
+    }
+}
+
+/// Token emitido tras la confirmación del usuario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsentGrant {
+    pub token: String,
+    pub action: ConsentAction,
+    pub expires_in_secs: u64,
+}
+
+static GRANTS: OnceLock<Mutex<HashMap<String, (ConsentAction, Instant)>>> = OnceLock::new();
+
+fn grants() -> &'static Mutex<HashMap<String, (ConsentAction, Instant)>> {
+    GRANTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn issue_at(action: ConsentAction, now: Instant) -> ConsentGrant {
+    let token = hex::encode(crate::security::utils::secure_random_bytes(16));
+    let mut grants = grants().lock().unwrap_or_else(|e| e.into_inner());
+    grants.retain(|_, (_, expires)| *expires > now);
+    grants.insert(token.clone(), (action, now + CONSENT_TTL));
+    ConsentGrant { token, action, expires_in_secs: CONSENT_TTL.as_secs() }
+}
+
+fn consume_at(token: Option<&str>, action: ConsentAction, now: Instant) -> Result<()> {
+    let token = token.ok_or_else(|| SCypherError::ConsentRequired(format!("{:?} requires user consent", action)))?;
+    // El token se retira aunque no sirva: no se puede reintentar con otra acción
+    let grant = grants().lock().unwrap_or_else(|e| e.into_inner()).remove(token);
+    match grant {
+        Some((granted, expires)) if granted == action && expires > now => Ok(()),
+        Some((granted, _)) if granted != action => Err(SCypherError::ConsentRequired(format!(
+            "Consent was given for {:?}, not {:?}",
+            granted, action
+        ))),
+        Some(_) => Err(SCypherError::ConsentRequired("Consent expired; confirm the action again".to_string())),
+        None => Err(SCypherError::ConsentRequired("Unknown or already used consent token".to_string())),
+    }
+}
+
+/// Emitir un token para `action` (llamar solo después de que el usuario confirme)
+pub fn issue_consent(action: ConsentAction) -> ConsentGrant {
+    issue_at(action, Instant::now())
+}
+
+/// Consumir el token de `action`; falla si falta, es de otra acción o caducó
+pub fn consume_consent(token: Option<&str>, action: ConsentAction) -> Result<()> {
+    consume_at(token, action, Instant::now())
+}
+
+/// Retirar todos los tokens pendientes (limpieza de la sesión)
+pub fn clear_consents() {
+    grants().lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent_is_single_use_and_bound_to_action() {
+        let grant = issue_consent(ConsentAction::WriteFile);
+        assert_eq!(grant.expires_in_secs, CONSENT_TTL.as_secs());
+        assert!(consume_consent(Some(&grant.token), ConsentAction::WriteFile).is_ok());
+        assert!(consume_consent(Some(&grant.token), ConsentAction::WriteFile).is_err());
+
+        // Un token de otra acción no sirve y queda retirado
+        let grant = issue_consent(ConsentAction::CopyToClipboard);
+        let error = consume_consent(Some(&grant.token), ConsentAction::ExportPrivateKeys).unwrap_err();
+        assert_eq!(error.code(), "consent_required");
+        assert!(consume_consent(Some(&grant.token), ConsentAction::CopyToClipboard).is_err());
+
+        assert!(consume_consent(None, ConsentAction::WriteFile).is_err());
+    }
+
+    #[test]
+    fn test_consent_expires() {
+        let issued = Instant::now();
+        let grant = issue_at(ConsentAction::ExportEncryptedKeys, issued);
+        let later = issued + CONSENT_TTL + Duration::from_secs(1);
+        let error = consume_at(Some(&grant.token), ConsentAction::ExportEncryptedKeys, later).unwrap_err();
+        assert!(error.to_string().contains("expired"));
+    }
+}
@@ -2,8 +2,16 @@
 //!
 //! Este módulo maneja la configuración segura del entorno donde se ejecuta
 //! SCypher, incluyendo variables de entorno y configuraciones del sistema.
+//!
+//! El entorno del proceso no se modifica nunca: `set_var`/`remove_var` con
+//! hilos en marcha (Tauri, tokio) es una carrera con cualquier `getenv`. El
+//! endurecimiento se aplica solo a los procesos hijo (`harden_command`), según
+//! la `EnvironmentPolicy` configurada.
 
 use std::collections::HashMap;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
 use crate::error::{SCypherError, Result};
 
 /// Lista de variables de entorno potencialmente peligrosas
@@ -31,10 +39,97 @@ const SENSITIVE_ENV_VARS: &[&str] = &[
     "RECOVERY_PHRASE",
 ];
 
+/// PATH mínimo de las herramientas externas en Unix
+#[cfg(unix)]
+const SECURE_CHILD_PATH: &str = "/usr/local/bin:/usr/bin:/bin";
+
+/// Variables que se conservan en el entorno limpio de los procesos hijo
+const CHILD_ENV_PASSTHROUGH: &[&str] = &[
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "DISPLAY",
+    "WAYLAND_DISPLAY",
+    "XDG_RUNTIME_DIR",
+    "CUPS_SERVER",
+    "SYSTEMROOT",
+    "WINDIR",
+];
+
+/// Política de entorno para los procesos hijo (impresora, tokens, etc.)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentPolicy {
+    pub clean_child_environment: bool,  // Entorno mínimo en lugar del heredado
+    pub minimal_path: bool,             // PATH fijo del sistema (solo Unix)
+    pub c_locale: bool,                 // LC_ALL=C y LANG=C para salida predecible
+}
+
+impl Default for EnvironmentPolicy {
+    fn default() -> Self {
+        Self {
+            clean_child_environment: true,
+            minimal_path: true,
+            c_locale: true,
+        }
+    }
+}
+
+static ENVIRONMENT_POLICY: OnceLock<Mutex<EnvironmentPolicy>> = OnceLock::new();
+
+fn policy_slot() -> &'static Mutex<EnvironmentPolicy> {
+    ENVIRONMENT_POLICY.get_or_init(|| Mutex::new(EnvironmentPolicy::default()))
+}
+
+/// Cambiar la política de entorno de los procesos hijo
+pub fn configure_environment_policy(policy: EnvironmentPolicy) -> EnvironmentPolicy {
+    *policy_slot().lock().unwrap_or_else(|e| e.into_inner()) = policy.clone();
+    policy
+}
+
+/// Política de entorno actual
+pub fn environment_policy() -> EnvironmentPolicy {
+    policy_slot().lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Aplicar la política a un proceso hijo antes de lanzarlo
+///
+/// Las variables sensibles no se heredan nunca, sea cual sea la política.
+pub fn harden_command(command: &mut Command) -> &mut Command {
+    apply_policy(command, &environment_policy())
+}
+
+fn apply_policy<'a>(command: &'a mut Command, policy: &EnvironmentPolicy) -> &'a mut Command {
+    if policy.clean_child_environment {
+        command.env_clear();
+        for &var in CHILD_ENV_PASSTHROUGH {
+            if let Some(value) = std::env::var_os(var) {
+                command.env(var, value);
+            }
+        }
+        if let Some(path) = std::env::var_os("PATH") {
+            command.env("PATH", path);
+        }
+    } else {
+        for &var in SENSITIVE_ENV_VARS.iter().chain(DANGEROUS_ENV_VARS) {
+            command.env_remove(var);
+        }
+    }
+
+    #[cfg(unix)]
+    if policy.minimal_path {
+        command.env("PATH", SECURE_CHILD_PATH);
+    }
+
+    if policy.c_locale {
+        command.env("LC_ALL", "C").env("LANG", "C");
+    }
+
+    command
+}
+
 /// Configurar entorno seguro para la ejecución
 pub fn setup_secure_environment() -> Result<()> {
     validate_environment_safety()?;
-    clean_sensitive_variables();
     configure_secure_umask();
     validate_execution_context()?;
 
@@ -63,6 +158,14 @@ pub fn validate_environment_safety() -> Result<()> {
         critical_issues.push("Debugger or profiler detected".to_string());
     }
 
+    // Secretos en el entorno: no se borran (ver módulo), solo se avisa
+    for var in sensitive_variables_present() {
+        warnings.push(format!("Sensitive environment variable set: {} (it is not passed to child processes)", var));
+    }
+    for var in insecure_temp_variables() {
+        warnings.push(format!("{} points to an insecure temporary directory", var));
+    }
+
     // Verificar PATH seguro
     if let Ok(path) = std::env::var("PATH") {
         if path.contains(".") || path.contains("..") {
@@ -86,22 +189,21 @@ pub fn validate_environment_safety() -> Result<()> {
     Ok(())
 }
 
-/// Limpiar variables de entorno sensibles
-pub fn clean_sensitive_variables() {
-    for &var in SENSITIVE_ENV_VARS {
-        std::env::remove_var(var);
-    }
+/// Variables sensibles presentes en el entorno del proceso
+pub fn sensitive_variables_present() -> Vec<&'static str> {
+    SENSITIVE_ENV_VARS
+        .iter()
+        .copied()
+        .filter(|var| std::env::var_os(var).is_some())
+        .collect()
+}
 
-    // También limpiar variables temporales comunes
-    let temp_vars = ["TMPDIR", "TEMP", "TMP"];
-    for &var in &temp_vars {
-        if let Ok(value) = std::env::var(var) {
-            // Verificar si la ubicación temporal es segura
-            if !is_secure_temp_dir(&value) {
-                std::env::remove_var(var);
-            }
-        }
-    }
+/// Variables de directorio temporal que apuntan a un lugar inseguro
+pub fn insecure_temp_variables() -> Vec<&'static str> {
+    ["TMPDIR", "TEMP", "TMP"]
+        .into_iter()
+        .filter(|var| std::env::var(var).map(|value| !is_secure_temp_dir(&value)).unwrap_or(false))
+        .collect()
 }
 
 /// Configurar umask segura
@@ -302,21 +404,6 @@ pub fn get_environment_info() -> HashMap<String, String> {
     info
 }
 
-/// Configurar entorno limpio antes de operaciones sensibles
-pub fn setup_clean_environment() -> Result<()> {
-    // Limpiar variables sensibles
-    clean_sensitive_variables();
-
-    // Configurar variables mínimas necesarias
-    std::env::set_var("LC_ALL", "C");
-    std::env::set_var("LANG", "C");
-
-    // Limpiar PATH a mínimo necesario
-    let secure_path = "/usr/local/bin:/usr/bin:/bin";
-    std::env::set_var("PATH", secure_path);
-
-    Ok(())
-}
 
 #[cfg(test)]
 mod tests {
@@ -329,15 +416,29 @@ mod tests {
     }
 
     #[test]
-    fn test_clean_sensitive_variables() {
-        // Configurar variable sensible para test
-        std::env::set_var("SCYPHER_PASSWORD", "test_password");
-
-        // Limpiar
-        clean_sensitive_variables();
-
-        // Verificar que se limpió
-        assert!(std::env::var("SCYPHER_PASSWORD").is_err());
+    fn test_harden_command() {
+        let vars = |command: &Command| -> HashMap<String, Option<String>> {
+            command
+                .get_envs()
+                .map(|(key, value)| (key.to_string_lossy().into_owned(), value.map(|v| v.to_string_lossy().into_owned())))
+                .collect()
+        };
+
+        let mut command = Command::new("true");
+        harden_command(&mut command);
+        let envs = vars(&command);
+        assert_eq!(envs.get("LC_ALL"), Some(&Some("C".to_string())));
+        assert!(!envs.contains_key("SCYPHER_PASSWORD"));
+        #[cfg(unix)]
+        assert_eq!(envs.get("PATH"), Some(&Some(SECURE_CHILD_PATH.to_string())));
+
+        // Sin entorno limpio las variables sensibles se quitan una a una
+        let mut command = Command::new("true");
+        let policy = EnvironmentPolicy { clean_child_environment: false, minimal_path: false, c_locale: false };
+        apply_policy(&mut command, &policy);
+        let envs = vars(&command);
+        assert_eq!(envs.get("SCYPHER_PASSWORD"), Some(&None));
+        assert!(!envs.contains_key("LC_ALL"));
     }
 
     #[test]
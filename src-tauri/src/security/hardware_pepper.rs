@@ -0,0 +1,220 @@
+//! Secreto de Argon2id (pepper) obtenido de un token hardware
+//!
+//! Con un pepper la contraseña ya no basta: el keystream solo se reproduce
+//! con el mismo token conectado. El secreto nunca se guarda; se pide al token
+//! en cada operación con una entrada fija, de modo que la respuesta es
+//! siempre la misma para ese token.
+//!
+//! Fuentes admitidas (herramientas de línea de órdenes del fabricante, como
+//! la impresión con `lp`):
+//! - FIDO2/CTAP2 con la extensión hmac-secret: `fido2-cred` y `fido2-assert`
+//!   de libfido2. Primero se registra una credencial (`enroll_fido2_credential`)
+//!   cuyo identificador se guarda junto a los parámetros; no es secreto.
+//! - YubiKey en modo challenge-response HMAC-SHA1: `ykchalresp` de
+//!   yubikey-personalization, slot 1 o 2.
+//!
+//! Las entradas viajan por stdin o son constantes; la respuesta del token
+//! solo existe en memoria bloqueada (`SecureBytes`).
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+use crate::security::SecureBytes;
+
+/// Relying party por defecto de las credenciales FIDO2
+pub const DEFAULT_RP_ID: &str = "scypher.local";
+
+/// Entradas fijas: la respuesta del token depende solo del token y la credencial
+const FIDO2_CLIENT_DATA: &[u8] = b"SCYPHER_FIDO2_CLIENT_DATA_V1";
+const PEPPER_CHALLENGE: &[u8] = b"SCYPHER_HARDWARE_PEPPER_V1";
+
+/// Token del que se obtiene el pepper
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PepperSource {
+    /// Llave FIDO2 con hmac-secret (dispositivo según `fido2-token -L`)
+    Fido2 {
+        device: String,
+        credential_id: String,  // Base64, devuelto por `enroll_fido2_credential`
+        #[serde(default = "default_rp_id")]
+        rp_id: String,
+    },
+    /// YubiKey con challenge-response HMAC-SHA1 configurado en el slot
+    Yubikey {
+        #[serde(default = "default_slot")]
+        slot: u8,
+    },
+}
+
+fn default_rp_id() -> String {
+    DEFAULT_RP_ID.to_string()
+}
+
+fn default_slot() -> u8 {
+    2
+}
+
+fn token_error(message: impl Into<String>) -> SCypherError {
+    SCypherError::HardwareTokenError(message.into())
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(data)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Rechazar rutas de dispositivo que `fido2-*` interpretaría como opciones
+fn validate_device(device: &str) -> Result<()> {
+    if device.is_empty() || device.starts_with('-') || device.chars().any(char::is_control) {
+        return Err(token_error(format!("Invalid FIDO2 device: {}", device)));
+    }
+    Ok(())
+}
+
+/// Ejecutar una herramienta del token y devolver su salida estándar
+fn run_tool(program: &str, args: &[&str], input: Option<&str>) -> Result<String> {
+    let mut child = crate::security::environment::harden_command(&mut Command::new(program))
+        .args(args)
+        .stdin(if input.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| token_error(format!("Cannot start '{}' (is it installed?): {}", program, e)))?;
+
+    if let (Some(input), Some(stdin)) = (input, child.stdin.as_mut()) {
+        stdin.write_all(input.as_bytes())?;
+    }
+    drop(child.stdin.take());
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(token_error(format!(
+            "'{}' failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    String::from_utf8(output.stdout).map_err(|_| token_error(format!("'{}' returned invalid output", program)))
+}
+
+/// Identificador de credencial en la salida de `fido2-cred -M` (quinta línea)
+fn parse_fido2_cred_output(output: &str) -> Result<String> {
+    let credential_id = output
+        .lines()
+        .nth(4)
+        .map(str::trim)
+        .filter(|line| base64::engine::general_purpose::STANDARD.decode(line).is_ok_and(|id| !id.is_empty()))
+        .ok_or_else(|| token_error("Unexpected fido2-cred output"))?;
+    Ok(credential_id.to_string())
+}
+
+/// Secreto hmac-secret en la salida de `fido2-assert -G -h` (última línea)
+fn parse_fido2_assert_output(output: &str) -> Result<Vec<u8>> {
+    output
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .and_then(|line| base64::engine::general_purpose::STANDARD.decode(line).ok())
+        .filter(|secret| secret.len() == 32)
+        .ok_or_else(|| token_error("The FIDO2 token did not return an hmac-secret"))
+}
+
+/// Respuesta HMAC-SHA1 en hexadecimal de `ykchalresp -x`
+fn parse_ykchalresp_output(output: &str) -> Result<Vec<u8>> {
+    hex::decode(output.trim())
+        .ok()
+        .filter(|response| response.len() == 20)
+        .ok_or_else(|| token_error("The YubiKey did not return an HMAC-SHA1 response"))
+}
+
+/// Registrar en la llave FIDO2 una credencial con hmac-secret
+///
+/// Devuelve el identificador de credencial (base64) que hay que conservar
+/// para volver a obtener el mismo pepper.
+pub fn enroll_fido2_credential(device: &str, rp_id: Option<&str>) -> Result<String> {
+    validate_device(device)?;
+    let input = format!(
+        "{}\n{}\nscypher\n{}\n",
+        base64_encode(&sha256(FIDO2_CLIENT_DATA)),
+        rp_id.unwrap_or(DEFAULT_RP_ID),
+        base64_encode(&sha256(b"scypher-user")),
+    );
+
+    tracing::info!("Registrando credencial FIDO2 con hmac-secret (toque la llave)");
+    parse_fido2_cred_output(&run_tool("fido2-cred", &["-M", "-h", device], Some(&input))?)
+}
+
+/// Obtener el pepper del token (puede pedir que se toque la llave)
+pub fn acquire_pepper(source: &PepperSource) -> Result<SecureBytes> {
+    let mut pepper = match source {
+        PepperSource::Fido2 { device, credential_id, rp_id } => {
+            validate_device(device)?;
+            let input = format!(
+                "{}\n{}\n{}\n{}\n",
+                base64_encode(&sha256(FIDO2_CLIENT_DATA)),
+                rp_id,
+                credential_id.trim(),
+                base64_encode(&sha256(PEPPER_CHALLENGE)),
+            );
+            tracing::info!("Solicitando hmac-secret a la llave FIDO2 (toque la llave)");
+            parse_fido2_assert_output(&run_tool("fido2-assert", &["-G", "-h", device], Some(&input))?)?
+        }
+        PepperSource::Yubikey { slot } => {
+            let slot_flag = match slot {
+                1 => "-1",
+                2 => "-2",
+                _ => return Err(token_error(format!("Invalid YubiKey slot: {} (expected 1 or 2)", slot))),
+            };
+            let challenge = hex::encode(sha256(PEPPER_CHALLENGE));
+            tracing::info!("Solicitando challenge-response a la YubiKey");
+            parse_ykchalresp_output(&run_tool("ykchalresp", &[slot_flag, "-x", &challenge], None)?)?
+        }
+    };
+
+    let secure = SecureBytes::from_slice(&pepper);
+    pepper.zeroize();
+    secure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tool_output() {
+        let cred = "Y2xpZW50\nscypher.local\npacked\nYXV0aGRhdGE=\nY3JlZGVudGlhbC1pZA==\nc2ln\n";
+        assert_eq!(parse_fido2_cred_output(cred).unwrap(), "Y3JlZGVudGlhbC1pZA==");
+        assert!(parse_fido2_cred_output("Y2xpZW50\nscypher.local\n").is_err());
+
+        let secret = base64_encode(&[9u8; 32]);
+        let assert = format!("Y2xpZW50\nscypher.local\nYXV0aGRhdGE=\nc2ln\n{}\n", secret);
+        assert_eq!(parse_fido2_assert_output(&assert).unwrap(), vec![9u8; 32]);
+        assert!(parse_fido2_assert_output("Y2xpZW50\nscypher.local\nYXV0aGRhdGE=\nc2ln\n").is_err());
+
+        let response = parse_ykchalresp_output("8a1f2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c\n").unwrap();
+        assert_eq!(response.len(), 20);
+        assert!(parse_ykchalresp_output("not hex").is_err());
+    }
+
+    #[test]
+    fn test_source_validation() {
+        let source: PepperSource = serde_json::from_str(r#"{"kind": "yubikey"}"#).unwrap();
+        assert_eq!(source, PepperSource::Yubikey { slot: 2 });
+        let source: PepperSource =
+            serde_json::from_str(r#"{"kind": "fido2", "device": "/dev/hidraw0", "credential_id": "AA=="}"#).unwrap();
+        assert!(matches!(source, PepperSource::Fido2 { ref rp_id, .. } if rp_id == DEFAULT_RP_ID));
+
+        // Nada se ejecuta con entradas inválidas
+        assert!(validate_device("-h").is_err());
+        assert!(enroll_fido2_credential("", None).is_err());
+        let error = acquire_pepper(&PepperSource::Yubikey { slot: 3 }).err().unwrap();
+        assert_eq!(error.code(), "hardware_token_error");
+    }
+}
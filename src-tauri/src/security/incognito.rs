@@ -0,0 +1,100 @@
+//! Modo incógnito: sesión sin historial
+//!
+//! Para equipos que el usuario no controla del todo. Mientras está activo no
+//! queda rastro de la sesión fuera de la memoria del proceso:
+//! - No se registran operaciones (ni el historial de diagnóstico ni el
+//!   registro de auditoría en disco).
+//! - La caché de keystreams de Argon2id no guarda nada.
+//! - No se escribe configuración: ni el llavero del sistema ni la creación
+//!   del registro de auditoría.
+//! - No se puede exportar el informe de diagnóstico.
+//!
+//! Se activa al arrancar con `SCYPHER_INCOGNITO=1` o desde la GUI antes de
+//! empezar a trabajar, y dura hasta cerrar la aplicación: no se puede
+//! desactivar a mitad de sesión. Las comprobaciones están en cada punto de
+//! escritura, no en la GUI.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Variable de entorno que activa el modo al arrancar
+const INCOGNITO_ENV_VAR: &str = "SCYPHER_INCOGNITO";
+
+/// Funciones que el modo desactiva (para la GUI)
+const DISABLED_FEATURES: [&str; 4] = ["operation_journal", "kdf_cache", "config_writes", "diagnostics_export"];
+
+static INCOGNITO: AtomicBool = AtomicBool::new(false);
+
+/// Estado del modo incógnito
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncognitoStatus {
+    pub enabled: bool,
+    pub disabled_features: Vec<String>,
+}
+
+/// Activar el modo si `SCYPHER_INCOGNITO` está definido (1, true, yes u on)
+pub fn init_from_env() {
+    let enabled = std::env::var(INCOGNITO_ENV_VAR)
+        .map(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    if enabled {
+        enable_incognito();
+    }
+}
+
+/// Activar el modo para el resto de la sesión (vacía la caché de keystreams)
+pub fn enable_incognito() -> IncognitoStatus {
+    if !INCOGNITO.swap(true, Ordering::Relaxed) {
+        tracing::info!("Modo incógnito activado para esta sesión");
+    }
+    crate::crypto::keystream::clear_kdf_cache();
+    incognito_status()
+}
+
+/// ¿Está activo el modo incógnito?
+pub fn is_incognito() -> bool {
+    INCOGNITO.load(Ordering::Relaxed)
+}
+
+/// Estado actual
+pub fn incognito_status() -> IncognitoStatus {
+    let enabled = is_incognito();
+    IncognitoStatus {
+        enabled,
+        disabled_features: if enabled { DISABLED_FEATURES.iter().map(|f| f.to_string()).collect() } else { Vec::new() },
+    }
+}
+
+fn check_persistence(enabled: bool, what: &str) -> Result<()> {
+    if enabled {
+        return Err(SCypherError::file(format!("Incognito mode is active: {} is disabled for this session", what)));
+    }
+    Ok(())
+}
+
+/// Rechazar escrituras persistentes (`what`: qué se iba a guardar) en modo incógnito
+pub fn ensure_persistence_allowed(what: &str) -> Result<()> {
+    check_persistence(is_incognito(), what)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persistence_checks() {
+        assert!(check_persistence(false, "writing the audit log").is_ok());
+        let error = check_persistence(true, "writing the audit log").unwrap_err();
+        assert!(error.to_string().contains("Incognito mode"));
+    }
+
+    #[test]
+    fn test_status_reports_disabled_features() {
+        // No se activa en los tests: el modo es global y no se puede desactivar
+        let status = incognito_status();
+        assert_eq!(status.enabled, is_incognito());
+        assert_eq!(status.disabled_features.is_empty(), !status.enabled);
+        assert!(DISABLED_FEATURES.contains(&"kdf_cache"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Integración opcional con el llavero del sistema operativo
+//!
+//! Permite "recordar la contraseña durante esta sesión" guardándola en el
+//! almacén seguro del sistema (macOS Keychain, Windows Credential Manager,
+//! libsecret/Secret Service en Linux) en lugar de escribirla en disco.
+//! Cada proceso usa una cuenta propia que se elimina en `secure_cleanup`.
+
+use std::sync::OnceLock;
+use crate::error::{SCypherError, Result};
+use crate::security::SecureString;
+
+/// Nombre del servicio bajo el que se registran las entradas
+const KEYCHAIN_SERVICE: &str = "com.scypher.app";
+
+/// Identificador único de la sesión actual (una cuenta por proceso)
+static SESSION_ACCOUNT: OnceLock<String> = OnceLock::new();
+
+/// Obtener el nombre de cuenta de la sesión actual
+fn session_account() -> &'static str {
+    SESSION_ACCOUNT.get_or_init(|| {
+        let nonce = crate::security::utils::secure_random_bytes(8);
+        format!("session-{}-{}", std::process::id(), hex::encode(nonce))
+    })
+}
+
+/// Abrir la entrada del llavero asociada a la sesión
+fn session_entry() -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, session_account())
+        .map_err(|e| SCypherError::KeychainError(format!("Cannot open keychain entry: {}", e)))
+}
+
+/// Verificar si el llavero del sistema está disponible
+pub fn is_keychain_available() -> bool {
+    match session_entry() {
+        Ok(entry) => matches!(entry.get_password(), Ok(_) | Err(keyring::Error::NoEntry)),
+        Err(_) => false,
+    }
+}
+
+/// Guardar el secreto de desbloqueo de la sesión en el llavero
+pub fn store_session_secret(secret: &SecureString) -> Result<()> {
+    if secret.is_empty() {
+        return Err(SCypherError::InvalidPassword);
+    }
+    crate::security::incognito::ensure_persistence_allowed("storing secrets in the system keychain")?;
+
+    session_entry()?
+        .set_password(secret.as_str())
+        .map_err(|e| SCypherError::KeychainError(format!("Cannot store session secret: {}", e)))
+}
+
+/// Recuperar el secreto de la sesión, si fue guardado previamente
+pub fn retrieve_session_secret() -> Result<Option<SecureString>> {
+    match session_entry()?.get_password() {
+        Ok(password) => Ok(Some(SecureString::from(password))),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(SCypherError::KeychainError(format!("Cannot read session secret: {}", e))),
+    }
+}
+
+/// Eliminar el secreto de la sesión del llavero
+pub fn clear_session_secret() -> Result<()> {
+    // Si nunca se creó la cuenta de sesión no hay nada que borrar
+    if SESSION_ACCOUNT.get().is_none() {
+        return Ok(());
+    }
+
+    match session_entry()?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(SCypherError::KeychainError(format!("Cannot delete session secret: {}", e))),
+    }
+}
+
+/// Cuenta fija del registro de auditoría (persiste entre sesiones)
+const AUDIT_LOG_ACCOUNT: &str = "audit-log";
+
+fn audit_log_entry() -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, AUDIT_LOG_ACCOUNT)
+        .map_err(|e| SCypherError::KeychainError(format!("Cannot open keychain entry: {}", e)))
+}
+
+/// Guardar la clave y la cabeza de la cadena del registro de auditoría
+pub fn store_audit_log_secret(secret: &SecureString) -> Result<()> {
+    crate::security::incognito::ensure_persistence_allowed("storing secrets in the system keychain")?;
+    audit_log_entry()?
+        .set_password(secret.as_str())
+        .map_err(|e| SCypherError::KeychainError(format!("Cannot store audit log key: {}", e)))
+}
+
+/// Recuperar el secreto del registro de auditoría, si existe
+pub fn retrieve_audit_log_secret() -> Result<Option<SecureString>> {
+    match audit_log_entry()?.get_password() {
+        Ok(secret) => Ok(Some(SecureString::from(secret))),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(SCypherError::KeychainError(format!("Cannot read audit log key: {}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_account_is_stable() {
+        let first = session_account();
+        let second = session_account();
+        assert_eq!(first, second);
+        assert!(first.starts_with("session-"));
+    }
+
+    #[test]
+    fn test_store_rejects_empty_secret() {
+        let empty = SecureString::new("");
+        assert!(matches!(store_session_secret(&empty), Err(SCypherError::InvalidPassword)));
+    }
+}
@@ -0,0 +1,123 @@
+//! Teclado aleatorizado para selección de palabras BIP39
+//!
+//! Para reducir la exposición a keyloggers, la GUI puede mostrar la lista
+//! BIP39 en una permutación aleatoria por sesión. El usuario selecciona
+//! palabras con el ratón y el frontend envía solo los índices permutados;
+//! el backend los traduce a palabras reales usando la permutación privada.
+
+use std::sync::{Mutex, OnceLock};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+use crate::bip39::wordlist::BIP39_WORDLIST;
+use crate::error::{SCypherError, Result};
+use crate::security::utils::{constant_time_eq, secure_random_bytes};
+
+/// Sesión activa del teclado (permutación + nonce)
+struct KeypadSession {
+    nonce: Vec<u8>,
+    permutation: Vec<u16>, // posición mostrada -> índice BIP39 real
+}
+
+impl Drop for KeypadSession {
+    fn drop(&mut self) {
+        self.nonce.zeroize();
+        self.permutation.zeroize();
+    }
+}
+
+static KEYPAD_SESSION: OnceLock<Mutex<Option<KeypadSession>>> = OnceLock::new();
+
+fn session_slot() -> &'static Mutex<Option<KeypadSession>> {
+    KEYPAD_SESSION.get_or_init(|| Mutex::new(None))
+}
+
+/// Datos que recibe el frontend para dibujar el teclado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrambledKeypad {
+    pub nonce: String,
+    pub words: Vec<String>,
+}
+
+/// Iniciar una nueva sesión de teclado con una permutación aleatoria
+/// Cualquier sesión anterior queda invalidada
+pub fn start_keypad_session() -> ScrambledKeypad {
+    let mut permutation: Vec<u16> = (0..BIP39_WORDLIST.len() as u16).collect();
+    permutation.shuffle(&mut crate::security::rng::SourceRng);
+
+    let nonce = secure_random_bytes(16);
+
+    let keypad = ScrambledKeypad {
+        nonce: hex::encode(&nonce),
+        words: permutation
+            .iter()
+            .map(|&index| BIP39_WORDLIST[index as usize].to_string())
+            .collect(),
+    };
+
+    let mut slot = session_slot().lock().unwrap_or_else(|e| e.into_inner());
+    *slot = Some(KeypadSession { nonce, permutation });
+
+    keypad
+}
+
+/// Traducir índices permutados a la frase semilla real
+pub fn resolve_selection(nonce: &str, permuted_indices: &[usize]) -> Result<String> {
+    let slot = session_slot().lock().unwrap_or_else(|e| e.into_inner());
+    let session = slot
+        .as_ref()
+        .ok_or_else(|| SCypherError::crypto("No active keypad session".to_string()))?;
+
+    let provided_nonce = hex::decode(nonce)
+        .map_err(|_| SCypherError::crypto("Invalid keypad session nonce".to_string()))?;
+
+    if !constant_time_eq(&provided_nonce, &session.nonce) {
+        return Err(SCypherError::crypto("Keypad session expired or invalid".to_string()));
+    }
+
+    if permuted_indices.is_empty() {
+        return Err(SCypherError::InvalidSeedPhrase);
+    }
+
+    let mut words = Vec::with_capacity(permuted_indices.len());
+    for &position in permuted_indices {
+        let index = session
+            .permutation
+            .get(position)
+            .ok_or(SCypherError::InvalidSeedPhrase)?;
+        words.push(BIP39_WORDLIST[*index as usize]);
+    }
+
+    Ok(words.join(" "))
+}
+
+/// Finalizar la sesión del teclado y limpiar la permutación
+pub fn end_keypad_session() {
+    let mut slot = session_slot().lock().unwrap_or_else(|e| e.into_inner());
+    *slot = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keypad_roundtrip() {
+        let keypad = start_keypad_session();
+        assert_eq!(keypad.words.len(), 2048);
+
+        // Buscar las posiciones mostradas de "abandon" y "about"
+        let abandon = keypad.words.iter().position(|w| w == "abandon").unwrap();
+        let about = keypad.words.iter().position(|w| w == "about").unwrap();
+
+        let phrase = resolve_selection(&keypad.nonce, &[abandon, abandon, about]).unwrap();
+        assert_eq!(phrase, "abandon abandon about");
+
+        // Nonce incorrecto e índices fuera de rango deben fallar
+        assert!(resolve_selection("00", &[abandon]).is_err());
+        assert!(resolve_selection(&keypad.nonce, &[5000]).is_err());
+
+        end_keypad_session();
+        assert!(resolve_selection(&keypad.nonce, &[abandon]).is_err());
+    }
+}
@@ -3,8 +3,25 @@
 //! Este módulo proporciona utilidades para el manejo seguro de memoria,
 //! incluyendo limpieza de datos sensibles y verificaciones de integridad.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use zeroize::Zeroize;
 
+/// mlockall tuvo éxito: toda reserva futura cuenta contra RLIMIT_MEMLOCK
+static PROCESS_MEMORY_LOCKED: AtomicBool = AtomicBool::new(false);
+
+/// Sobrescribir el heap libre al salir (desactivado por defecto)
+static HEAP_SCRUB_ON_EXIT: AtomicBool = AtomicBool::new(false);
+
+/// Variable de entorno que activa la sobrescritura del heap al salir
+const HEAP_SCRUB_ENV_VAR: &str = "SCYPHER_SCRUB_HEAP";
+
+/// Máximo sobrescrito al salir (256MB) y valor sin información del sistema (64MB)
+const HEAP_SCRUB_LIMIT: usize = 256 * 1024 * 1024;
+const HEAP_SCRUB_DEFAULT: usize = 64 * 1024 * 1024;
+
+/// Bloques por debajo del umbral de mmap: salen de las arenas del heap
+const HEAP_SCRUB_CHUNK: usize = 64 * 1024;
+
 /// Limpiar buffer de memoria de forma segura
 /// Sobrescribe con datos aleatorios antes de poner en ceros
 pub fn secure_clear(buffer: &mut [u8]) {
@@ -241,6 +258,7 @@ pub fn disable_swap_for_process() -> Result<(), std::io::Error> {
                 return Err(std::io::Error::last_os_error());
             }
         }
+        PROCESS_MEMORY_LOCKED.store(true, Ordering::Relaxed);
     }
 
     #[cfg(not(target_os = "linux"))]
@@ -251,6 +269,66 @@ pub fn disable_swap_for_process() -> Result<(), std::io::Error> {
     Ok(())
 }
 
+/// Activar o desactivar la sobrescritura del heap en la limpieza final
+pub fn set_heap_scrub_on_exit(enabled: bool) {
+    HEAP_SCRUB_ON_EXIT.store(enabled, Ordering::Relaxed);
+}
+
+/// Sobrescritura activada por la GUI o por `SCYPHER_SCRUB_HEAP` (1, true, yes u on)
+pub fn heap_scrub_on_exit() -> bool {
+    HEAP_SCRUB_ON_EXIT.load(Ordering::Relaxed)
+        || std::env::var(HEAP_SCRUB_ENV_VAR)
+            .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+            .unwrap_or(false)
+}
+
+/// Pico de memoria residente del proceso en bytes (`VmHWM`)
+#[cfg(target_os = "linux")]
+fn peak_resident_bytes() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb: usize = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn peak_resident_bytes() -> Option<usize> {
+    None
+}
+
+/// Sobrescribir la memoria libre del heap hasta el pico de uso del proceso
+///
+/// Reserva bloques pequeños (el asignador reutiliza los huecos liberados,
+/// donde pueden quedar copias de secretos), los escribe y los pone a cero
+/// antes de soltarlos. Las reservas grandes de Argon2id van por mmap y el
+/// sistema las devuelve a cero, así que basta con cubrir el heap hasta un
+/// límite. Devuelve los bytes sobrescritos.
+pub fn scrub_heap() -> usize {
+    scrub_heap_bytes(peak_resident_bytes().unwrap_or(HEAP_SCRUB_DEFAULT).min(HEAP_SCRUB_LIMIT))
+}
+
+fn scrub_heap_bytes(target: usize) -> usize {
+    let mut chunks: Vec<Vec<u8>> = Vec::with_capacity(target / HEAP_SCRUB_CHUNK + 1);
+    let mut total = 0;
+    while total < target {
+        let mut chunk = vec![0xA5u8; HEAP_SCRUB_CHUNK];
+        chunk.zeroize();
+        chunks.push(chunk);
+        total += HEAP_SCRUB_CHUNK;
+    }
+    total
+}
+
+/// Indica si toda la memoria del proceso está bloqueada (mlockall)
+pub fn process_memory_locked() -> bool {
+    PROCESS_MEMORY_LOCKED.load(Ordering::Relaxed)
+}
+
 /// Verificar límites de memoria bloqueada
 pub fn check_memory_lock_limits() -> (usize, usize) {
     #[cfg(unix)]
@@ -390,4 +468,14 @@ mod tests {
         assert_eq!(buffer.as_slice(), data);
         assert_eq!(buffer.len(), data.len());
     }
+
+    #[test]
+    fn test_scrub_heap_bytes() {
+        // Se redondea al tamaño de bloque
+        assert_eq!(scrub_heap_bytes(0), 0);
+        assert_eq!(scrub_heap_bytes(HEAP_SCRUB_CHUNK + 1), 2 * HEAP_SCRUB_CHUNK);
+
+        #[cfg(target_os = "linux")]
+        assert!(peak_resident_bytes().unwrap() > 0);
+    }
 }
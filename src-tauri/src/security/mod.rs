@@ -3,10 +3,27 @@
 pub mod memory;
 pub mod process;
 pub mod environment;
+pub mod keychain;
+pub mod capture;
+pub mod keypad;
+pub mod network_guard;
+pub mod training;
+pub mod vault;
+pub mod result_store;
+pub mod attempt_guard;
+pub mod hardware_pepper;
+pub mod rng;
+pub mod sandbox;
+pub mod consent;
+pub mod trng;
+pub mod incognito;
 
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+use serde::Serialize;
 use zeroize::Zeroize;
-use crate::error::Result;
+use crate::error::{SCypherError, Result};
 
 // Flag global para rastrear si la limpieza está configurada
 static CLEANUP_CONFIGURED: AtomicBool = AtomicBool::new(false);
@@ -24,9 +41,36 @@ pub fn setup_security_cleanup() {
         std::process::exit(130); // 128 + 2 (SIGINT)
     });
 
+    // Un pánico en cualquier hilo se trata como fallo de la sesión
+    install_panic_cleanup_hook();
+
     CLEANUP_CONFIGURED.store(true, Ordering::Relaxed);
 }
 
+/// Espera máxima de la limpieza dentro del hook de pánico
+const PANIC_CLEANUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Borrar los secretos antes de que el hook original informe del pánico
+///
+/// La limpieza corre en otro hilo con un tiempo máximo: el hilo que entra en
+/// pánico puede tener tomado el Mutex de alguno de los almacenes.
+fn install_panic_cleanup_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let (done, finished) = mpsc::channel();
+        let spawned = std::thread::Builder::new()
+            .name("panic-cleanup".to_string())
+            .spawn(move || {
+                secure_cleanup();
+                let _ = done.send(());
+            });
+        if spawned.is_ok() {
+            let _ = finished.recv_timeout(PANIC_CLEANUP_TIMEOUT);
+        }
+        previous(info);
+    }));
+}
+
 /// Configurar protecciones completas de seguridad
 pub fn setup_comprehensive_security() -> Result<()> {
     // Configurar protecciones de entorno
@@ -51,8 +95,19 @@ pub fn setup_comprehensive_security() -> Result<()> {
 
 /// Limpieza segura de memoria al final de la aplicación
 pub fn secure_cleanup() {
-    // Limpiar variables de entorno sensibles si las hay
-    clear_environment_variables();
+    // Eliminar el secreto de sesión del llavero del sistema
+    let _ = keychain::clear_session_secret();
+
+    // Borrar las frases de la bóveda de la sesión
+    vault::clear_vault();
+
+    // Borrar los resultados pendientes de recoger y los consentimientos sin usar
+    result_store::clear_results();
+    consent::clear_consents();
+
+    // Borrar los keystreams en caché y la sesión del teclado aleatorio
+    crate::crypto::keystream::clear_kdf_cache();
+    keypad::end_keypad_session();
 
     // Limpiar información del proceso
     process::cleanup_process_info();
@@ -61,26 +116,15 @@ pub fn secure_cleanup() {
     let mut dummy_buffer = vec![0u8; 4096];
     dummy_buffer.zeroize();
 
+    // Sobrescribir el heap libre hasta el pico de uso (opcional)
+    if memory::heap_scrub_on_exit() {
+        memory::scrub_heap();
+    }
+
     // Nota: En Rust, la limpieza automática de memoria es más segura
     // que en otros lenguajes debido al ownership system
 }
 
-/// Limpiar variables de entorno que podrían contener datos sensibles
-fn clear_environment_variables() {
-    // Variables que podrían contener información sensible
-    let sensitive_vars = [
-        "SCYPHER_PASSWORD",
-        "SCYPHER_SEED",
-        "TMPDIR",
-        "TEMP",
-        "TMP",
-    ];
-
-    for var in &sensitive_vars {
-        std::env::remove_var(var);
-    }
-}
-
 /// Wrapper seguro para strings sensibles
 /// Implementa Drop para limpieza automática
 pub struct SecureString {
@@ -189,6 +233,27 @@ impl SecureBytes {
     }
 }
 
+/// Garantía en compilación: SecureString y SecureBytes no pueden formatearse
+///
+/// Si alguien añade `Display` o `Debug` a estos tipos, la llamada a
+/// `assert_not_formattable` pasa a ser ambigua y el crate deja de compilar,
+/// así que nunca pueden acabar en un registro ni en un mensaje de error.
+const _: fn() = || {
+    trait NotFormattable<A> {
+        fn assert_not_formattable() {}
+    }
+    impl<T: ?Sized> NotFormattable<()> for T {}
+
+    struct ImplementsDisplay;
+    impl<T: ?Sized + std::fmt::Display> NotFormattable<ImplementsDisplay> for T {}
+
+    struct ImplementsDebug;
+    impl<T: ?Sized + std::fmt::Debug> NotFormattable<ImplementsDebug> for T {}
+
+    let _ = <SecureString as NotFormattable<_>>::assert_not_formattable;
+    let _ = <SecureBytes as NotFormattable<_>>::assert_not_formattable;
+};
+
 /// Utilidades para operaciones seguras en memoria
 pub mod utils {
     use super::*;
@@ -209,10 +274,8 @@ pub mod utils {
 
     /// Generar bytes aleatorios seguros
     pub fn secure_random_bytes(len: usize) -> Vec<u8> {
-        use rand::RngCore;
-        let mut rng = rand::thread_rng();
         let mut bytes = vec![0u8; len];
-        rng.fill_bytes(&mut bytes);
+        super::rng::fill_random(&mut bytes);
         bytes
     }
 
@@ -249,106 +312,174 @@ pub fn security_audit() -> SecurityAuditReport {
 
     // Auditar entorno
     if let Err(e) = environment::validate_environment_safety() {
-        report.add_critical_issue(format!("Environment validation failed: {}", e));
+        report.add(AuditSeverity::Critical, "environment_unsafe", format!("Environment validation failed: {}", e), None);
     }
 
     // Verificar integridad del proceso
     if !process::check_process_integrity() {
-        report.add_critical_issue("Process integrity check failed - debugger detected".to_string());
+        report.add(
+            AuditSeverity::Critical,
+            "debugger_detected",
+            "Process integrity check failed - debugger detected".to_string(),
+            None,
+        );
+    }
+
+    // Verificar core dumps
+    if process::core_dumps_enabled() {
+        report.add(
+            AuditSeverity::Warning,
+            "core_dumps_enabled",
+            "Core dumps are enabled - a crash could write secrets to disk".to_string(),
+            Some(REMEDIATION_DISABLE_CORE_DUMPS),
+        );
     }
 
     // Verificar límites de memoria
     let (current_limit, _max_limit) = memory::check_memory_lock_limits();
     if current_limit == 0 {
-        report.add_warning("No memory locking limits configured".to_string());
+        report.add(
+            AuditSeverity::Warning,
+            "memlock_unconfigured",
+            "No memory locking limits configured".to_string(),
+            Some(REMEDIATION_RAISE_MEMLOCK),
+        );
     } else if current_limit < 64 * 1024 * 1024 {
-        report.add_warning(format!("Low memory lock limit: {} bytes", current_limit));
+        report.add(
+            AuditSeverity::Warning,
+            "memlock_low",
+            format!("Low memory lock limit: {} bytes", current_limit),
+            Some(REMEDIATION_RAISE_MEMLOCK),
+        );
     }
 
     // Verificar información del entorno
     let env_info = environment::get_environment_info();
     if env_info.get("container").unwrap_or(&"false".to_string()) == "true" {
-        report.add_info("Running in containerized environment".to_string());
+        report.add(AuditSeverity::Info, "container", "Running in containerized environment".to_string(), None);
     }
 
     if env_info.get("development").unwrap_or(&"false".to_string()) == "true" {
-        report.add_warning("Running in development environment".to_string());
+        report.add(
+            AuditSeverity::Warning,
+            "development_environment",
+            "Running in development environment".to_string(),
+            None,
+        );
     }
 
     report
 }
 
+/// Acción correctiva: deshabilitar core dumps
+pub const REMEDIATION_DISABLE_CORE_DUMPS: &str = "disable_core_dumps";
+
+/// Acción correctiva: aumentar el límite de memoria bloqueada
+pub const REMEDIATION_RAISE_MEMLOCK: &str = "raise_memlock";
+
+/// Aplicar una acción correctiva y repetir la auditoría
+pub fn apply_remediation(code: &str) -> Result<SecurityAuditReport> {
+    match code {
+        REMEDIATION_DISABLE_CORE_DUMPS => process::disable_core_dumps()?,
+        REMEDIATION_RAISE_MEMLOCK => memory::configure_memory_limits()?,
+        _ => return Err(SCypherError::crypto(format!("Unknown remediation: {}", code))),
+    }
+
+    Ok(security_audit())
+}
+
+/// Gravedad de un hallazgo de la auditoría
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditSeverity {
+    Critical,
+    Warning,
+    Info,
+}
+
+/// Hallazgo con código legible por máquina y acción correctiva opcional
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditFinding {
+    pub severity: AuditSeverity,
+    pub code: String,
+    pub message: String,
+    pub remediation: Option<String>,
+}
+
 /// Reporte de auditoría de seguridad
+#[derive(Debug, Clone, Serialize)]
 pub struct SecurityAuditReport {
-    critical_issues: Vec<String>,
-    warnings: Vec<String>,
-    info: Vec<String>,
+    findings: Vec<AuditFinding>,
 }
 
 impl SecurityAuditReport {
     fn new() -> Self {
-        Self {
-            critical_issues: Vec::new(),
-            warnings: Vec::new(),
-            info: Vec::new(),
-        }
-    }
-
-    fn add_critical_issue(&mut self, issue: String) {
-        self.critical_issues.push(issue);
+        Self { findings: Vec::new() }
     }
 
-    fn add_warning(&mut self, warning: String) {
-        self.warnings.push(warning);
+    fn add(&mut self, severity: AuditSeverity, code: &str, message: String, remediation: Option<&str>) {
+        self.findings.push(AuditFinding {
+            severity,
+            code: code.to_string(),
+            message,
+            remediation: remediation.map(str::to_string),
+        });
     }
 
-    fn add_info(&mut self, info: String) {
-        self.info.push(info);
+    fn messages(&self, severity: AuditSeverity) -> Vec<String> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .map(|finding| finding.message.clone())
+            .collect()
     }
 
     /// Verificar si hay problemas críticos
     pub fn has_critical_issues(&self) -> bool {
-        !self.critical_issues.is_empty()
+        self.findings.iter().any(|finding| finding.severity == AuditSeverity::Critical)
     }
 
     /// Obtener todos los problemas críticos
-    pub fn critical_issues(&self) -> &[String] {
-        &self.critical_issues
+    pub fn critical_issues(&self) -> Vec<String> {
+        self.messages(AuditSeverity::Critical)
     }
 
     /// Obtener todas las advertencias
-    pub fn warnings(&self) -> &[String] {
-        &self.warnings
+    pub fn warnings(&self) -> Vec<String> {
+        self.messages(AuditSeverity::Warning)
     }
 
     /// Obtener toda la información
-    pub fn info(&self) -> &[String] {
-        &self.info
+    pub fn info(&self) -> Vec<String> {
+        self.messages(AuditSeverity::Info)
     }
 
     /// Generar reporte legible
     pub fn generate_report(&self) -> String {
         let mut report = String::new();
 
-        if !self.critical_issues.is_empty() {
+        let critical_issues = self.critical_issues();
+        if !critical_issues.is_empty() {
             report.push_str("CRITICAL ISSUES:\n");
-            for issue in &self.critical_issues {
+            for issue in &critical_issues {
                 report.push_str(&format!("  ❌ {}\n", issue));
             }
             report.push('\n');
         }
 
-        if !self.warnings.is_empty() {
+        let warnings = self.warnings();
+        if !warnings.is_empty() {
             report.push_str("WARNINGS:\n");
-            for warning in &self.warnings {
+            for warning in &warnings {
                 report.push_str(&format!("  ⚠️  {}\n", warning));
             }
             report.push('\n');
         }
 
-        if !self.info.is_empty() {
+        let info = self.info();
+        if !info.is_empty() {
             report.push_str("INFORMATION:\n");
-            for info in &self.info {
+            for info in &info {
                 report.push_str(&format!("  ℹ️  {}\n", info));
             }
         }
@@ -406,6 +537,22 @@ mod tests {
     fn test_security_audit() {
         let report = security_audit();
         // No debería causar panic
-        let _report_text = report.generate_report();
+        let report_text = report.generate_report();
+        assert_eq!(report.has_critical_issues(), report_text.contains("CRITICAL ISSUES:"));
+
+        // Toda acción correctiva sugerida debe ser aplicable
+        for finding in &report.findings {
+            if let Some(code) = &finding.remediation {
+                assert!([REMEDIATION_DISABLE_CORE_DUMPS, REMEDIATION_RAISE_MEMLOCK].contains(&code.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_remediation() {
+        let report = apply_remediation(REMEDIATION_DISABLE_CORE_DUMPS).unwrap();
+        assert!(report.findings.iter().all(|finding| finding.code != "core_dumps_enabled"));
+
+        assert!(apply_remediation("format_disk").is_err());
     }
 }
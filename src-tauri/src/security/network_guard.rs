@@ -0,0 +1,160 @@
+//! Detección del estado de red y política "solo sin conexión"
+//!
+//! SCypher está pensado para equipos desconectados. Este módulo detecta
+//! interfaces activas y rutas por defecto al arrancar y antes de operaciones
+//! sensibles; si la política está activada, las transformaciones se rechazan
+//! mientras haya conexión salvo que el usuario lo autorice explícitamente.
+//!
+//! - Linux: `/sys/class/net/*/operstate` y `/proc/net/route`
+//! - Otras plataformas: consulta de ruta con un socket UDP "conectado"
+//!   (no envía ningún paquete; solo pregunta al sistema si hay ruta)
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Rechazar operaciones sensibles con conexión activa
+static ENFORCE_OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// El usuario autorizó operar con conexión a pesar de la política
+static ONLINE_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+/// Estado de red reportado a la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkStatus {
+    pub online: bool,
+    pub active_interfaces: Vec<String>,  // Interfaces levantadas (sin loopback)
+    pub default_route: bool,
+    pub method: String,                  // "procfs" o "route_probe"
+    pub enforce_offline: bool,
+    pub online_override: bool,
+}
+
+/// Interfaces con ruta por defecto en el formato de `/proc/net/route`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn default_route_interfaces(route_table: &str) -> Vec<String> {
+    route_table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [iface, destination, ..] if *destination == "00000000" && *iface != "lo" => {
+                    Some(iface.to_string())
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn probe() -> (Vec<String>, bool, &'static str) {
+    let mut interfaces = Vec::new();
+    if let Ok(entries) = std::fs::read_dir("/sys/class/net") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name == "lo" {
+                continue;
+            }
+            let state = std::fs::read_to_string(entry.path().join("operstate")).unwrap_or_default();
+            if state.trim() == "up" {
+                interfaces.push(name);
+            }
+        }
+    }
+    interfaces.sort();
+
+    let routes = std::fs::read_to_string("/proc/net/route").unwrap_or_default();
+    let default_route = !default_route_interfaces(&routes).is_empty();
+
+    (interfaces, default_route, "procfs")
+}
+
+#[cfg(not(target_os = "linux"))]
+fn probe() -> (Vec<String>, bool, &'static str) {
+    // connect() en UDP solo resuelve la ruta; no se envían datos
+    let default_route = std::net::UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("192.0.2.1:9")?; // TEST-NET-1 (RFC 5737)
+            socket.local_addr()
+        })
+        .map(|addr| !addr.ip().is_unspecified() && !addr.ip().is_loopback())
+        .unwrap_or(false);
+
+    (Vec::new(), default_route, "route_probe")
+}
+
+/// Detectar el estado de red actual
+pub fn network_status() -> NetworkStatus {
+    let (active_interfaces, default_route, method) = probe();
+
+    NetworkStatus {
+        online: default_route,
+        active_interfaces,
+        default_route,
+        method: method.to_string(),
+        enforce_offline: ENFORCE_OFFLINE.load(Ordering::Relaxed),
+        online_override: ONLINE_OVERRIDE.load(Ordering::Relaxed),
+    }
+}
+
+/// Configurar la política: exigir modo sin conexión y/o autorizar la excepción
+pub fn set_network_policy(enforce_offline: bool, online_override: bool) {
+    ENFORCE_OFFLINE.store(enforce_offline, Ordering::Relaxed);
+    ONLINE_OVERRIDE.store(online_override, Ordering::Relaxed);
+}
+
+fn check_policy(online: bool, enforce_offline: bool, online_override: bool) -> Result<()> {
+    if online && enforce_offline && !online_override {
+        return Err(SCypherError::crypto(
+            "Network connection detected. Disconnect from all networks or explicitly allow online use".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Comprobar la política antes de una operación sensible
+pub fn ensure_offline() -> Result<()> {
+    let status = network_status();
+    check_policy(status.online, status.enforce_offline, status.online_override)
+}
+
+/// Aviso al arrancar si el equipo está conectado
+pub fn warn_if_online() {
+    let status = network_status();
+    if status.online {
+        eprintln!(
+            "Warning: network connection detected ({}). SCypher is intended for offline use",
+            if status.active_interfaces.is_empty() {
+                "default route".to_string()
+            } else {
+                status.active_interfaces.join(", ")
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_route_parsing() {
+        let table = "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\n\
+                     wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\n\
+                     wlan0\t0001A8C0\t00000000\t0001\t0\t0\t600\t00FFFFFF\n";
+        assert_eq!(default_route_interfaces(table), vec!["wlan0".to_string()]);
+
+        let offline = "Iface\tDestination\tGateway\n";
+        assert!(default_route_interfaces(offline).is_empty());
+    }
+
+    #[test]
+    fn test_policy() {
+        assert!(check_policy(false, true, false).is_ok());
+        assert!(check_policy(true, false, false).is_ok());
+        assert!(check_policy(true, true, false).is_err());
+        assert!(check_policy(true, true, true).is_ok());
+    }
+}
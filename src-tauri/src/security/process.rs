@@ -52,6 +52,27 @@ pub fn disable_core_dumps() -> Result<()> {
     Ok(())
 }
 
+/// Verificar si el proceso puede generar core dumps
+pub fn core_dumps_enabled() -> bool {
+    #[cfg(unix)]
+    {
+        use libc::{getrlimit, rlimit, RLIMIT_CORE};
+
+        let mut rlim = rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+
+        unsafe {
+            if getrlimit(RLIMIT_CORE, &mut rlim) == 0 {
+                return rlim.rlim_cur > 0;
+            }
+        }
+    }
+
+    false
+}
+
 /// Configurar protecciones anti-debugging básicas
 pub fn setup_anti_debugging() -> Result<()> {
     #[cfg(target_os = "linux")]
@@ -183,11 +204,6 @@ pub fn cleanup_process_info() {
             }
         }
     }
-
-    // Limpiar variables de entorno sensibles
-    std::env::remove_var("SCYPHER_PASSWORD");
-    std::env::remove_var("SCYPHER_SEED");
-    std::env::remove_var("RUST_LOG");
 }
 
 #[cfg(test)]
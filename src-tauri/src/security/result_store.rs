@@ -0,0 +1,182 @@
+//! Almacén de resultados con caducidad
+//!
+//! En lugar de devolver la frase transformada en la respuesta (donde queda
+//! en el estado de la GUI indefinidamente), el backend la guarda en memoria
+//! bloqueada y entrega un token de un solo uso. La GUI la recoge una vez con
+//! `fetch_result`; después solo queda el token hasta que caduca o se llama a
+//! `consume_result`, y entonces se avisa a la GUI para que borre la pantalla.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+use crate::security::SecureBytes;
+
+/// Tiempo de visualización por defecto (segundos)
+pub const DEFAULT_DISPLAY_TIMEOUT_SECS: u64 = 60;
+
+/// Límites del tiempo de visualización configurable
+pub const MIN_DISPLAY_TIMEOUT_SECS: u64 = 5;
+pub const MAX_DISPLAY_TIMEOUT_SECS: u64 = 3600;
+
+/// Evento que recibe la GUI cuando un resultado caduca
+pub const RESULT_EXPIRED_EVENT: &str = "result-expired";
+
+/// Resultado guardado (la frase se borra al recogerla)
+struct StoredResult {
+    data: Option<SecureBytes>,
+    expires_at: Instant,
+}
+
+/// Token entregado a la GUI en lugar del resultado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultTicket {
+    pub token: String,
+    pub expires_in_secs: u64,
+}
+
+static RESULT_STORE: OnceLock<Mutex<HashMap<String, StoredResult>>> = OnceLock::new();
+
+fn store_slot() -> &'static Mutex<HashMap<String, StoredResult>> {
+    RESULT_STORE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Tiempo de visualización dentro de los límites
+pub fn clamp_timeout(display_timeout_secs: Option<u64>) -> u64 {
+    display_timeout_secs
+        .unwrap_or(DEFAULT_DISPLAY_TIMEOUT_SECS)
+        .clamp(MIN_DISPLAY_TIMEOUT_SECS, MAX_DISPLAY_TIMEOUT_SECS)
+}
+
+fn insert(
+    store: &mut HashMap<String, StoredResult>,
+    result: &str,
+    timeout_secs: u64,
+    now: Instant,
+) -> Result<ResultTicket> {
+    let token = hex::encode(crate::security::utils::secure_random_bytes(16));
+    store.insert(token.clone(), StoredResult {
+        data: Some(SecureBytes::from_slice(result.as_bytes())?),
+        expires_at: now + Duration::from_secs(timeout_secs),
+    });
+    Ok(ResultTicket { token, expires_in_secs: timeout_secs })
+}
+
+fn take(store: &mut HashMap<String, StoredResult>, token: &str, now: Instant) -> Result<String> {
+    let expired = || SCypherError::crypto("Result token is invalid, expired or already used".to_string());
+    let entry = store.get_mut(token).ok_or_else(expired)?;
+    if entry.expires_at <= now {
+        return Err(expired());
+    }
+
+    // Un solo uso: la copia del backend se borra al entregarla
+    let data = entry.data.take().ok_or_else(expired)?;
+    String::from_utf8(data.as_slice().to_vec())
+        .map_err(|_| SCypherError::crypto("Stored result is corrupted".to_string()))
+}
+
+fn peek<T>(
+    store: &HashMap<String, StoredResult>,
+    token: &str,
+    now: Instant,
+    inspect: impl FnOnce(&str) -> Result<T>,
+) -> Result<T> {
+    let unavailable = || SCypherError::crypto("Result token is invalid, expired or already fetched".to_string());
+    let entry = store.get(token).filter(|entry| entry.expires_at > now).ok_or_else(unavailable)?;
+    let data = entry.data.as_ref().ok_or_else(unavailable)?;
+    let text = std::str::from_utf8(data.as_slice())
+        .map_err(|_| SCypherError::crypto("Stored result is corrupted".to_string()))?;
+    inspect(text)
+}
+
+fn expire(store: &mut HashMap<String, StoredResult>, now: Instant) -> Vec<String> {
+    let expired: Vec<String> = store
+        .iter()
+        .filter(|(_, entry)| entry.expires_at <= now)
+        .map(|(token, _)| token.clone())
+        .collect();
+    for token in &expired {
+        store.remove(token);
+    }
+    expired
+}
+
+/// Guardar un resultado y devolver su token
+pub fn store_result(result: &str, display_timeout_secs: Option<u64>) -> Result<ResultTicket> {
+    let mut store = store_slot().lock().unwrap_or_else(|e| e.into_inner());
+    insert(&mut store, result, clamp_timeout(display_timeout_secs), Instant::now())
+}
+
+/// Recoger el resultado (solo una vez)
+pub fn fetch_result(token: &str) -> Result<String> {
+    let mut store = store_slot().lock().unwrap_or_else(|e| e.into_inner());
+    take(&mut store, token, Instant::now())
+}
+
+/// Examinar el resultado sin entregarlo ni consumirlo (p. ej. para una vista enmascarada)
+pub fn inspect_result<T>(token: &str, inspect: impl FnOnce(&str) -> Result<T>) -> Result<T> {
+    let store = store_slot().lock().unwrap_or_else(|e| e.into_inner());
+    peek(&store, token, Instant::now(), inspect)
+}
+
+/// Descartar un resultado antes de que caduque; true si existía
+pub fn consume_result(token: &str) -> bool {
+    let mut store = store_slot().lock().unwrap_or_else(|e| e.into_inner());
+    store.remove(token).is_some()
+}
+
+/// Borrar los resultados caducados y devolver sus tokens (para avisar a la GUI)
+pub fn expire_results() -> Vec<String> {
+    let mut store = store_slot().lock().unwrap_or_else(|e| e.into_inner());
+    expire(&mut store, Instant::now())
+}
+
+/// Borrar todos los resultados pendientes
+pub fn clear_results() {
+    if let Some(store) = RESULT_STORE.get() {
+        store.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_result_is_fetched_once() {
+        let mut store = HashMap::new();
+        let now = Instant::now();
+        let ticket = insert(&mut store, PHRASE, 60, now).unwrap();
+        assert_eq!(ticket.token.len(), 32);
+
+        // Examinarlo no lo consume
+        assert_eq!(peek(&store, &ticket.token, now, |text| Ok(text.len())).unwrap(), PHRASE.len());
+        assert_eq!(take(&mut store, &ticket.token, now).unwrap(), PHRASE);
+        assert!(peek(&store, &ticket.token, now, |_| Ok(())).is_err());
+        assert!(take(&mut store, &ticket.token, now).is_err());
+        assert!(take(&mut store, "unknown", now).is_err());
+
+        // El token sigue vivo hasta caducar para poder avisar a la GUI
+        assert!(store.contains_key(&ticket.token));
+    }
+
+    #[test]
+    fn test_results_expire() {
+        let mut store = HashMap::new();
+        let now = Instant::now();
+        let short = insert(&mut store, PHRASE, 5, now).unwrap();
+        let long = insert(&mut store, PHRASE, 60, now).unwrap();
+
+        let later = now + Duration::from_secs(10);
+        assert!(take(&mut store, &short.token, later).is_err());
+        assert_eq!(expire(&mut store, later), vec![short.token]);
+        assert!(store.contains_key(&long.token));
+
+        assert_eq!(clamp_timeout(None), DEFAULT_DISPLAY_TIMEOUT_SECS);
+        assert_eq!(clamp_timeout(Some(1)), MIN_DISPLAY_TIMEOUT_SECS);
+        assert_eq!(clamp_timeout(Some(u64::MAX)), MAX_DISPLAY_TIMEOUT_SECS);
+    }
+}
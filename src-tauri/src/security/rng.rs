@@ -0,0 +1,200 @@
+//! Origen de los bytes aleatorios de la aplicación
+//!
+//! Toda la aleatoriedad que acaba en un resultado (frases generadas, partes
+//! de Shamir y XOR, ranuras del contenedor oculto, teclado aleatorio) pasa
+//! por `fill_random`. En una build normal siempre es el generador del sistema
+//! operativo y no hay forma de cambiarlo.
+//!
+//! Con la feature `test-determinism` se puede inyectar otro `EntropySource`,
+//! por ejemplo `DeterministicEntropy` con una semilla fija (también desde
+//! `SCYPHER_TEST_SEED`), para que los tests de integración y los auditores
+//! reproduzcan un flujo completo byte a byte. Esa build no debe usarse nunca
+//! con frases reales: `build_info` la anuncia en sus features.
+
+use rand::{CryptoRng, RngCore};
+
+/// Fuente de bytes aleatorios
+pub trait EntropySource: Send + Sync {
+    /// Rellenar `dest` con bytes aleatorios
+    fn fill_bytes(&self, dest: &mut [u8]);
+
+    /// Nombre para diagnóstico
+    fn name(&self) -> &'static str;
+}
+
+/// Generador del sistema operativo (getrandom)
+pub struct OsEntropy;
+
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        rand::rngs::OsRng.fill_bytes(dest);
+    }
+
+    fn name(&self) -> &'static str {
+        "os"
+    }
+}
+
+/// Rellenar con bytes de la fuente activa
+pub fn fill_random(dest: &mut [u8]) {
+    #[cfg(feature = "test-determinism")]
+    if let Some(source) = injected::active().as_ref() {
+        source.fill_bytes(dest);
+        return;
+    }
+    OsEntropy.fill_bytes(dest);
+}
+
+/// Nombre de la fuente activa
+pub fn active_source_name() -> &'static str {
+    #[cfg(feature = "test-determinism")]
+    if let Some(source) = injected::active().as_ref() {
+        return source.name();
+    }
+    OsEntropy.name()
+}
+
+/// Adaptador `RngCore` sobre la fuente activa (barajar, muestrear)
+pub struct SourceRng;
+
+impl RngCore for SourceRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        fill_random(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        fill_random(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_random(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> std::result::Result<(), rand::Error> {
+        fill_random(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SourceRng {}
+
+#[cfg(feature = "test-determinism")]
+pub use injected::{reset_entropy_source, set_entropy_source, use_deterministic_entropy, init_from_env, DeterministicEntropy};
+
+#[cfg(feature = "test-determinism")]
+mod injected {
+    use std::sync::{Mutex, OnceLock, RwLock, RwLockReadGuard};
+    use sha2::{Digest, Sha256};
+    use zeroize::Zeroize;
+    use super::EntropySource;
+
+    /// Variable de entorno con la semilla (hex) de la fuente determinista
+    const TEST_SEED_ENV_VAR: &str = "SCYPHER_TEST_SEED";
+
+    static INJECTED: OnceLock<RwLock<Option<Box<dyn EntropySource>>>> = OnceLock::new();
+
+    fn slot() -> &'static RwLock<Option<Box<dyn EntropySource>>> {
+        INJECTED.get_or_init(|| RwLock::new(None))
+    }
+
+    pub(super) fn active() -> RwLockReadGuard<'static, Option<Box<dyn EntropySource>>> {
+        slot().read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Flujo SHA256(semilla || contador): reproducible, no apto para secretos reales
+    pub struct DeterministicEntropy {
+        state: Mutex<(Vec<u8>, u64)>,
+    }
+
+    impl DeterministicEntropy {
+        pub fn new(seed: &[u8]) -> Self {
+            Self { state: Mutex::new((seed.to_vec(), 0)) }
+        }
+    }
+
+    impl Drop for DeterministicEntropy {
+        fn drop(&mut self) {
+            if let Ok(state) = self.state.get_mut() {
+                state.0.zeroize();
+            }
+        }
+    }
+
+    impl EntropySource for DeterministicEntropy {
+        fn fill_bytes(&self, dest: &mut [u8]) {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            for chunk in dest.chunks_mut(32) {
+                let mut hasher = Sha256::new();
+                hasher.update(&state.0);
+                hasher.update(state.1.to_be_bytes());
+                state.1 += 1;
+                chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            "deterministic"
+        }
+    }
+
+    /// Sustituir la fuente de aleatoriedad
+    pub fn set_entropy_source(source: Box<dyn EntropySource>) {
+        tracing::warn!("Fuente de aleatoriedad sustituida ({}): solo para pruebas", source.name());
+        *slot().write().unwrap_or_else(|e| e.into_inner()) = Some(source);
+    }
+
+    /// Usar la fuente determinista con una semilla fija
+    pub fn use_deterministic_entropy(seed: &[u8]) {
+        set_entropy_source(Box::new(DeterministicEntropy::new(seed)));
+    }
+
+    /// Volver al generador del sistema operativo
+    pub fn reset_entropy_source() {
+        *slot().write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Activar la fuente determinista si `SCYPHER_TEST_SEED` tiene una semilla en hex
+    pub fn init_from_env() {
+        if let Some(seed) = std::env::var(TEST_SEED_ENV_VAR).ok().and_then(|value| hex::decode(value.trim()).ok()) {
+            use_deterministic_entropy(&seed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_rng_fills() {
+        let mut first = [0u8; 48];
+        let mut second = [0u8; 48];
+        fill_random(&mut first);
+        SourceRng.fill_bytes(&mut second);
+        assert_ne!(first, [0u8; 48]);
+        assert_ne!(first, second);
+    }
+
+    #[cfg(feature = "test-determinism")]
+    #[test]
+    fn test_deterministic_source_is_reproducible() {
+        let mut first = [0u8; 40];
+        let mut second = [0u8; 40];
+        DeterministicEntropy::new(b"seed").fill_bytes(&mut first);
+        DeterministicEntropy::new(b"seed").fill_bytes(&mut second);
+        assert_eq!(first, second);
+
+        // La secuencia avanza: dos lecturas seguidas no se repiten
+        let source = DeterministicEntropy::new(b"seed");
+        let mut a = [0u8; 16];
+        let mut b = [0u8; 16];
+        source.fill_bytes(&mut a);
+        source.fill_bytes(&mut b);
+        assert_ne!(a, b);
+        assert_eq!(a[..], first[..16]);
+    }
+}
@@ -0,0 +1,266 @@
+//! Restricciones del proceso aislado de cifrado
+//!
+//! El proceso hijo que ejecuta Argon2id (`--crypto-worker`) se encierra
+//! antes de tocar ningún secreto:
+//! - cierra los descriptores heredados (salvo stdin/stdout/stderr),
+//! - deja de ser volcable ni depurable por otros procesos del usuario,
+//! - en Linux activa `no_new_privs` y un filtro seccomp que devuelve EPERM
+//!   a red, ejecución de programas, ptrace y lectura de memoria ajena,
+//! - en Windows se mete en un job object sin procesos hijo ni acceso a la
+//!   interfaz (portapapeles, escritorio).
+//!
+//! Es una lista de llamadas prohibidas, no permitidas: el proceso necesita
+//! memoria, hilos y la tubería, y el resto se deja para no romperse con
+//! cambios de la biblioteca estándar.
+
+use crate::error::Result;
+#[cfg(not(unix))]
+use crate::error::SCypherError;
+
+/// Aplicar todas las restricciones al proceso actual
+///
+/// Debe llamarse desde el hilo principal antes de crear otros hilos: el
+/// filtro seccomp solo lo heredan los hilos creados después.
+pub fn enter_worker_sandbox() -> Result<()> {
+    close_inherited_handles();
+    restrict_process()
+}
+
+/// Cerrar los descriptores heredados que no sean los estándar
+#[cfg(unix)]
+fn close_inherited_handles() {
+    let directory = if cfg!(target_os = "linux") { "/proc/self/fd" } else { "/dev/fd" };
+    let descriptors: Vec<libc::c_int> = std::fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                .filter(|fd| *fd > 2)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // El descriptor del propio directorio ya está cerrado: su close da EBADF
+    for fd in descriptors {
+        unsafe {
+            libc::close(fd);
+        }
+    }
+}
+
+/// En Windows solo se heredan las tuberías estándar que pasa el padre
+#[cfg(not(unix))]
+fn close_inherited_handles() {}
+
+#[cfg(target_os = "linux")]
+fn restrict_process() -> Result<()> {
+    // Sin volcados de memoria ni ptrace desde otros procesos del usuario
+    unsafe {
+        libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0);
+    }
+    seccomp::install_syscall_filter()
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn restrict_process() -> Result<()> {
+    crate::security::process::disable_core_dumps()
+}
+
+#[cfg(windows)]
+fn restrict_process() -> Result<()> {
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::jobapi2::{AssignProcessToJobObject, CreateJobObjectW, SetInformationJobObject};
+    use winapi::um::processthreadsapi::GetCurrentProcess;
+    use winapi::um::winnt::{
+        JobObjectBasicUIRestrictions, JobObjectExtendedLimitInformation, JOBOBJECT_BASIC_UI_RESTRICTIONS,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_ACTIVE_PROCESS,
+        JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_UILIMIT_DESKTOP, JOB_OBJECT_UILIMIT_DISPLAYSETTINGS, JOB_OBJECT_UILIMIT_EXITWINDOWS,
+        JOB_OBJECT_UILIMIT_GLOBALATOMS, JOB_OBJECT_UILIMIT_HANDLES, JOB_OBJECT_UILIMIT_READCLIPBOARD,
+        JOB_OBJECT_UILIMIT_SYSTEMPARAMETERS, JOB_OBJECT_UILIMIT_WRITECLIPBOARD,
+    };
+
+    let error = |what: &str| SCypherError::WorkerError(format!("{}: {}", what, std::io::Error::last_os_error()));
+
+    unsafe {
+        let job = CreateJobObjectW(std::ptr::null_mut(), std::ptr::null());
+        if job.is_null() {
+            return Err(error("Cannot create job object"));
+        }
+
+        // Un único proceso (no puede lanzar otros) que muere con el job
+        let mut limits: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+        limits.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_ACTIVE_PROCESS
+            | JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION
+            | JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+        limits.BasicLimitInformation.ActiveProcessLimit = 1;
+
+        let mut ui = JOBOBJECT_BASIC_UI_RESTRICTIONS {
+            UIRestrictionsClass: JOB_OBJECT_UILIMIT_DESKTOP
+                | JOB_OBJECT_UILIMIT_DISPLAYSETTINGS
+                | JOB_OBJECT_UILIMIT_EXITWINDOWS
+                | JOB_OBJECT_UILIMIT_GLOBALATOMS
+                | JOB_OBJECT_UILIMIT_HANDLES
+                | JOB_OBJECT_UILIMIT_READCLIPBOARD
+                | JOB_OBJECT_UILIMIT_SYSTEMPARAMETERS
+                | JOB_OBJECT_UILIMIT_WRITECLIPBOARD,
+        };
+
+        let configured = SetInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            &mut limits as *mut _ as *mut _,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        ) != 0
+            && SetInformationJobObject(
+                job,
+                JobObjectBasicUIRestrictions,
+                &mut ui as *mut _ as *mut _,
+                std::mem::size_of::<JOBOBJECT_BASIC_UI_RESTRICTIONS>() as u32,
+            ) != 0;
+        if !configured {
+            let e = error("Cannot configure job object");
+            CloseHandle(job);
+            return Err(e);
+        }
+
+        // El handle se mantiene abierto: cerrarlo terminaría el proceso
+        if AssignProcessToJobObject(job, GetCurrentProcess()) == 0 {
+            let e = error("Cannot assign worker to job object");
+            CloseHandle(job);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn restrict_process() -> Result<()> {
+    Err(SCypherError::UnsupportedPlatform)
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) mod seccomp {
+    use crate::error::{SCypherError, Result};
+
+    /// Arquitectura que el filtro acepta (AUDIT_ARCH_*); otra ABI mata el proceso
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xC000_003E;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xC000_00B7;
+
+    /// Bit de las llamadas de la ABI x32 en x86_64
+    #[cfg(target_arch = "x86_64")]
+    const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+    /// Desplazamientos en `struct seccomp_data`
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    const OFFSET_NR: u32 = 0;
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    const OFFSET_ARCH: u32 = 4;
+
+    /// Llamadas al sistema que el proceso aislado no necesita nunca
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn denied_syscalls() -> Vec<libc::c_long> {
+        let mut denied = vec![
+            libc::SYS_socket,
+            libc::SYS_socketpair,
+            libc::SYS_connect,
+            libc::SYS_bind,
+            libc::SYS_listen,
+            libc::SYS_accept,
+            libc::SYS_accept4,
+            libc::SYS_execve,
+            libc::SYS_execveat,
+            libc::SYS_ptrace,
+            libc::SYS_process_vm_readv,
+            libc::SYS_process_vm_writev,
+        ];
+        #[cfg(target_arch = "x86_64")]
+        denied.extend([libc::SYS_fork, libc::SYS_vfork]);
+        denied
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn statement(code: u32, k: u32) -> libc::sock_filter {
+        libc::sock_filter { code: code as u16, jt: 0, jf: 0, k }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn jump(code: u32, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+        libc::sock_filter { code: code as u16, jt, jf, k }
+    }
+
+    /// Programa BPF: comprobar la arquitectura y devolver EPERM a las llamadas prohibidas
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn build_filter(arch: u32) -> Vec<libc::sock_filter> {
+        use libc::{BPF_ABS, BPF_JEQ, BPF_JMP, BPF_K, BPF_LD, BPF_RET, BPF_W};
+        let deny = libc::SECCOMP_RET_ERRNO | (libc::EPERM as u32 & libc::SECCOMP_RET_DATA);
+
+        let mut program = vec![
+            statement(BPF_LD | BPF_W | BPF_ABS, OFFSET_ARCH),
+            jump(BPF_JMP | BPF_JEQ | BPF_K, arch, 1, 0),
+            statement(BPF_RET | BPF_K, libc::SECCOMP_RET_KILL_PROCESS),
+            statement(BPF_LD | BPF_W | BPF_ABS, OFFSET_NR),
+        ];
+        #[cfg(target_arch = "x86_64")]
+        program.extend([
+            jump(BPF_JMP | libc::BPF_JGE | BPF_K, X32_SYSCALL_BIT, 0, 1),
+            statement(BPF_RET | BPF_K, deny),
+        ]);
+        for syscall in denied_syscalls() {
+            program.push(jump(BPF_JMP | BPF_JEQ | BPF_K, syscall as u32, 0, 1));
+            program.push(statement(BPF_RET | BPF_K, deny));
+        }
+        program.push(statement(BPF_RET | BPF_K, libc::SECCOMP_RET_ALLOW));
+        program
+    }
+
+    /// Instalar el filtro en el hilo actual (y en los que cree después)
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    pub fn install_syscall_filter() -> Result<()> {
+        let mut program = build_filter(AUDIT_ARCH);
+        let fprog = libc::sock_fprog { len: program.len() as libc::c_ushort, filter: program.as_mut_ptr() };
+        let installed = unsafe {
+            libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) == 0
+                && libc::prctl(libc::PR_SET_SECCOMP, libc::SECCOMP_MODE_FILTER, &fprog as *const libc::sock_fprog) == 0
+        };
+        if !installed {
+            return Err(SCypherError::WorkerError(format!(
+                "Cannot install seccomp filter: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sin filtro para otras arquitecturas: el proceso aislado no arranca
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn install_syscall_filter() -> Result<()> {
+        Err(SCypherError::UnsupportedPlatform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    #[test]
+    fn test_seccomp_filter_denies_network_in_thread() {
+        // El filtro es por hilo: se prueba en un hilo propio sin afectar al resto
+        let result = std::thread::spawn(|| {
+            super::seccomp::install_syscall_filter().unwrap();
+            let socket = std::net::UdpSocket::bind("127.0.0.1:0");
+            let spawn = std::process::Command::new("true").status();
+            let allocation = vec![7u8; 1 << 20];
+            (socket.map_err(|e| e.raw_os_error()), spawn.is_err(), allocation.len())
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(result.0.unwrap_err(), Some(libc::EPERM));
+        assert!(result.1);
+        assert_eq!(result.2, 1 << 20);
+
+        // Este hilo sigue sin restricciones
+        assert!(std::net::UdpSocket::bind("127.0.0.1:0").is_ok());
+    }
+}
@@ -0,0 +1,188 @@
+//! Modo formación para demostraciones
+//!
+//! Pensado para enseñar el flujo con la pantalla proyectada sin arriesgar
+//! frases reales. Mientras está activo:
+//! - Solo se aceptan los vectores de prueba oficiales de BIP39 y las frases
+//!   que la propia sesión ha producido a partir de ellos (para poder
+//!   demostrar el descifrado).
+//! - Los resultados se marcan como TEST.
+//! - No se pueden exportar frases a archivos.
+//!
+//! Se activa desde la GUI o arrancando con `SCYPHER_TRAINING=1`.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{SCypherError, Result};
+
+/// Variable de entorno que activa el modo al arrancar
+const TRAINING_ENV_VAR: &str = "SCYPHER_TRAINING";
+
+/// Marca que acompaña a todo resultado en modo formación
+pub const TRAINING_WATERMARK: &str = "TEST";
+
+/// Vectores de prueba oficiales BIP39 (Trezor) de 12 y 24 palabras
+const TEST_VECTORS: [&str; 8] = [
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+     abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+    "legal winner thank year wave sausage worth useful legal winner thank year \
+     wave sausage worth useful legal winner thank year wave sausage worth title",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd \
+     amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+];
+
+static TRAINING_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Hashes de las frases producidas en esta sesión a partir de vectores de prueba
+static SESSION_OUTPUTS: OnceLock<Mutex<HashSet<[u8; 32]>>> = OnceLock::new();
+
+/// Estado del modo formación reportado a la GUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingStatus {
+    pub enabled: bool,
+    pub watermark: String,
+    pub test_vectors: Vec<String>,
+}
+
+fn session_outputs() -> &'static Mutex<HashSet<[u8; 32]>> {
+    SESSION_OUTPUTS.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Forma canónica para comparar frases (espacios y abreviaturas)
+fn canonical(phrase: &str) -> String {
+    crate::bip39::wordlist::expand_abbreviations(phrase)
+        .unwrap_or_else(|_| phrase.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn phrase_hash(phrase: &str) -> [u8; 32] {
+    Sha256::digest(canonical(phrase).as_bytes()).into()
+}
+
+/// Activar el modo si `SCYPHER_TRAINING` está definido (1, true, yes u on)
+pub fn init_from_env() {
+    let enabled = std::env::var(TRAINING_ENV_VAR)
+        .map(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    if enabled {
+        set_training_mode(true);
+    }
+}
+
+/// Activar o desactivar el modo formación (al salir se olvidan las frases de la sesión)
+pub fn set_training_mode(enabled: bool) {
+    TRAINING_MODE.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        if let Ok(mut outputs) = session_outputs().lock() {
+            outputs.clear();
+        }
+    }
+}
+
+/// ¿Está activo el modo formación?
+pub fn is_training_mode() -> bool {
+    TRAINING_MODE.load(Ordering::Relaxed)
+}
+
+/// Estado actual y frases de ejemplo disponibles
+pub fn training_status() -> TrainingStatus {
+    TrainingStatus {
+        enabled: is_training_mode(),
+        watermark: TRAINING_WATERMARK.to_string(),
+        test_vectors: TEST_VECTORS.iter().map(|v| canonical(v)).collect(),
+    }
+}
+
+/// Vector de prueba oficial o resultado ya producido en la sesión
+fn is_demo_phrase(phrase: &str, session_outputs: &HashSet<[u8; 32]>) -> bool {
+    let phrase = canonical(phrase);
+    TEST_VECTORS.iter().any(|vector| canonical(vector) == phrase)
+        || session_outputs.contains(&phrase_hash(&phrase))
+}
+
+fn check_demo_phrase(enabled: bool, phrase: &str, session_outputs: &HashSet<[u8; 32]>) -> Result<()> {
+    if enabled && !is_demo_phrase(phrase, session_outputs) {
+        return Err(SCypherError::crypto(
+            "Training mode is active: only the built-in BIP39 test vectors can be used".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rechazar frases reales mientras el modo formación está activo
+pub fn ensure_demo_phrase(phrase: &str) -> Result<()> {
+    if !is_training_mode() {
+        return Ok(());
+    }
+    let outputs = session_outputs()
+        .lock()
+        .map_err(|_| SCypherError::crypto("Training session state is unavailable".to_string()))?;
+    check_demo_phrase(true, phrase, &outputs)
+}
+
+/// Recordar un resultado de la sesión para poder usarlo como entrada (descifrado)
+pub fn record_output(phrase: &str) {
+    if !is_training_mode() {
+        return;
+    }
+    if let Ok(mut outputs) = session_outputs().lock() {
+        outputs.insert(phrase_hash(phrase));
+    }
+}
+
+fn check_export(enabled: bool) -> Result<()> {
+    if enabled {
+        return Err(SCypherError::file(
+            "Training mode is active: exporting phrases to files is disabled".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rechazar la exportación de frases a archivos en modo formación
+pub fn ensure_export_allowed() -> Result<()> {
+    check_export(is_training_mode())
+}
+
+/// Marca para los resultados (None fuera del modo formación)
+pub fn watermark() -> Option<&'static str> {
+    is_training_mode().then_some(TRAINING_WATERMARK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_vectors_are_valid() {
+        for vector in TEST_VECTORS {
+            assert!(crate::bip39::validate_seed_phrase_complete(vector).is_ok(), "{}", vector);
+        }
+    }
+
+    #[test]
+    fn test_training_mode_restrictions() {
+        let real = "legal winner thank year wave sausage worth useful legal winner thank year";
+        let output = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo";
+        let mut outputs = HashSet::new();
+
+        assert!(check_demo_phrase(true, TEST_VECTORS[0], &outputs).is_ok());
+        assert!(check_demo_phrase(true, "aban aban aban aban aban aban aban aban aban aban aban abou", &outputs).is_ok());
+        assert!(check_demo_phrase(true, real, &outputs).is_err());
+        assert!(check_demo_phrase(false, real, &outputs).is_ok());
+
+        // Un resultado de la sesión se puede volver a introducir para descifrar
+        assert!(check_demo_phrase(true, output, &outputs).is_err());
+        outputs.insert(phrase_hash(output));
+        assert!(check_demo_phrase(true, output, &outputs).is_ok());
+
+        assert!(check_export(true).is_err());
+        assert!(check_export(false).is_ok());
+    }
+}
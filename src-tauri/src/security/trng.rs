@@ -0,0 +1,281 @@
+//! Entropía de un generador hardware por puerto serie (TRNG)
+//!
+//! Opcionalmente, al generar una frase se leen muestras de un TRNG conectado
+//! por USB/serie (OneRNG, Infinite Noise con su controlador de puerto serie,
+//! etc.). Antes de usarlas se pasan los dos tests de salud continuos de
+//! NIST SP 800-90B (4.4):
+//! - Repetition Count Test: ninguna muestra se repite C veces seguidas,
+//!   con C = 1 + ceil(20 / H).
+//! - Adaptive Proportion Test: en cada ventana de 512 muestras, la primera
+//!   no aparece C o más veces, con C el valor crítico binomial para p = 2^-H.
+//!
+//! H es la min-entropía por byte que se le supone al dispositivo; α = 2^-20.
+//! Si pasan, las muestras se condicionan con SHA-256 y se combinan (XOR) con
+//! la entropía del sistema operativo: el resultado no es más débil que el RNG
+//! del sistema aunque el TRNG esté comprometido.
+
+use std::io::{Read, Write};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+/// Muestras leídas por generación (dos ventanas del test de proporción)
+pub const TRNG_SAMPLE_BYTES: usize = 1024;
+
+/// Ventana del Adaptive Proportion Test para muestras no binarias
+const APT_WINDOW: usize = 512;
+
+/// Probabilidad de falso positivo de los tests (α = 2^-20)
+const ALPHA_EXPONENT: f64 = 20.0;
+
+/// Min-entropía supuesta por defecto (bits por byte)
+const DEFAULT_MIN_ENTROPY: f64 = 4.0;
+
+/// Inicialización que necesita el dispositivo antes de emitir datos
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrngInit {
+    #[default]
+    None,
+    OneRng,  // "cmd0" (ruido de avalancha con blanqueo) y "cmdO" (activar salida)
+}
+
+/// Dispositivo TRNG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrngSource {
+    pub device: String,  // /dev/ttyACM0, COM3...
+    #[serde(default)]
+    pub init: TrngInit,
+    #[serde(default = "default_min_entropy")]
+    pub min_entropy_per_byte: f64,
+}
+
+fn default_min_entropy() -> f64 {
+    DEFAULT_MIN_ENTROPY
+}
+
+/// Resultado de los tests de salud
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrngHealthReport {
+    pub samples: usize,
+    pub min_entropy_per_byte: f64,
+    pub repetition_cutoff: usize,
+    pub longest_run: usize,
+    pub proportion_cutoff: usize,
+    pub highest_proportion: usize,  // Máximo de apariciones de la primera muestra en una ventana
+    pub passed: bool,
+}
+
+fn trng_error(message: impl Into<String>) -> SCypherError {
+    SCypherError::HardwareTokenError(message.into())
+}
+
+/// Valor de corte del Repetition Count Test
+fn repetition_cutoff(min_entropy: f64) -> usize {
+    1 + (ALPHA_EXPONENT / min_entropy).ceil() as usize
+}
+
+/// Valor de corte del Adaptive Proportion Test: 1 + CRITBINOM(W, 2^-H, 1 - α)
+fn proportion_cutoff(min_entropy: f64) -> usize {
+    let p = 2f64.powf(-min_entropy);
+    let target = 1.0 - 2f64.powf(-ALPHA_EXPONENT);
+
+    let mut probability = (1.0 - p).powi(APT_WINDOW as i32);
+    let mut cumulative = probability;
+    let mut k = 0;
+    while cumulative < target && k < APT_WINDOW {
+        probability *= (APT_WINDOW - k) as f64 / (k + 1) as f64 * p / (1.0 - p);
+        k += 1;
+        cumulative += probability;
+    }
+    1 + k
+}
+
+/// Aplicar los tests de salud a un bloque de muestras
+pub fn health_test(samples: &[u8], min_entropy: f64) -> Result<TrngHealthReport> {
+    if !(0.5..=8.0).contains(&min_entropy) {
+        return Err(trng_error("Assumed min-entropy must be between 0.5 and 8 bits per byte"));
+    }
+    if samples.len() < APT_WINDOW {
+        return Err(trng_error(format!("At least {} TRNG samples are needed", APT_WINDOW)));
+    }
+
+    let repetition_cutoff = repetition_cutoff(min_entropy);
+    let proportion_cutoff = proportion_cutoff(min_entropy);
+
+    let mut longest_run = 1;
+    let mut run = 1;
+    for pair in samples.windows(2) {
+        run = if pair[0] == pair[1] { run + 1 } else { 1 };
+        longest_run = longest_run.max(run);
+    }
+
+    let highest_proportion = samples
+        .chunks_exact(APT_WINDOW)
+        .map(|window| window.iter().filter(|&&sample| sample == window[0]).count())
+        .max()
+        .unwrap_or(0);
+
+    Ok(TrngHealthReport {
+        samples: samples.len(),
+        min_entropy_per_byte: min_entropy,
+        repetition_cutoff,
+        longest_run,
+        proportion_cutoff,
+        highest_proportion,
+        passed: longest_run < repetition_cutoff && highest_proportion < proportion_cutoff,
+    })
+}
+
+/// Poner el puerto en modo crudo con un plazo de lectura de 2 segundos
+#[cfg(unix)]
+fn configure_port(file: &std::fs::File) -> Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let fd = file.as_raw_fd();
+    unsafe {
+        let mut settings: libc::termios = std::mem::zeroed();
+        // Un archivo o pipe (pruebas, /dev/hwrng) no es una terminal: se lee tal cual
+        if libc::tcgetattr(fd, &mut settings) != 0 {
+            return Ok(());
+        }
+        libc::cfmakeraw(&mut settings);
+        settings.c_cc[libc::VMIN] = 0;
+        settings.c_cc[libc::VTIME] = 20;
+        if libc::tcsetattr(fd, libc::TCSANOW, &settings) != 0 {
+            return Err(trng_error(format!("Cannot configure serial port: {}", std::io::Error::last_os_error())));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn configure_port(_file: &std::fs::File) -> Result<()> {
+    Ok(())
+}
+
+/// Leer exactamente `count` muestras; una lectura vacía (plazo agotado) es un error
+fn read_samples<R: Read>(reader: &mut R, count: usize) -> Result<Vec<u8>> {
+    let mut samples = vec![0u8; count];
+    let mut filled = 0;
+    while filled < count {
+        match reader.read(&mut samples[filled..]) {
+            Ok(0) => {
+                samples.zeroize();
+                return Err(trng_error(format!("TRNG stopped sending data after {} of {} bytes", filled, count)));
+            }
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => {
+                samples.zeroize();
+                return Err(trng_error(format!("Cannot read TRNG: {}", e)));
+            }
+        }
+    }
+    Ok(samples)
+}
+
+/// Leer muestras del dispositivo
+fn read_device(source: &TrngSource, count: usize) -> Result<Vec<u8>> {
+    let mut port = std::fs::OpenOptions::new()
+        .read(true)
+        .write(source.init != TrngInit::None)
+        .open(&source.device)
+        .map_err(|e| trng_error(format!("Cannot open TRNG device {}: {}", source.device, e)))?;
+    configure_port(&port)?;
+
+    if source.init == TrngInit::OneRng {
+        port.write_all(b"cmd0\ncmdO\n")
+            .map_err(|e| trng_error(format!("Cannot initialise OneRNG: {}", e)))?;
+    }
+    let samples = read_samples(&mut port, count);
+    if source.init == TrngInit::OneRng {
+        let _ = port.write_all(b"cmdo\n");
+    }
+    samples
+}
+
+/// Leer el TRNG y pasar los tests de salud sin generar nada
+pub fn check_trng(source: &TrngSource) -> Result<TrngHealthReport> {
+    let mut samples = read_device(source, TRNG_SAMPLE_BYTES)?;
+    let report = health_test(&samples, source.min_entropy_per_byte);
+    samples.zeroize();
+    report
+}
+
+/// Condicionar muestras sanas y combinarlas (XOR) con `entropy`
+fn mix_samples(entropy: &mut [u8], samples: &[u8], min_entropy: f64) -> Result<TrngHealthReport> {
+    let report = health_test(samples, min_entropy)?;
+    if !report.passed {
+        return Err(trng_error(format!(
+            "TRNG health test failed (longest run {} of {}, highest proportion {} of {})",
+            report.longest_run, report.repetition_cutoff, report.highest_proportion, report.proportion_cutoff
+        )));
+    }
+
+    let mut conditioned: [u8; 32] = Sha256::new()
+        .chain_update(b"SCYPHER_TRNG_V1")
+        .chain_update(samples)
+        .finalize()
+        .into();
+    for (byte, extra) in entropy.iter_mut().zip(conditioned.iter()) {
+        *byte ^= extra;
+    }
+    conditioned.zeroize();
+    Ok(report)
+}
+
+/// Añadir la entropía del TRNG a `entropy` (como mucho 32 bytes)
+pub fn mix_trng_entropy(entropy: &mut [u8], source: &TrngSource) -> Result<TrngHealthReport> {
+    if entropy.len() > 32 {
+        return Err(trng_error("TRNG mixing supports up to 32 bytes of entropy"));
+    }
+    let mut samples = read_device(source, TRNG_SAMPLE_BYTES)?;
+    let report = mix_samples(entropy, &samples, source.min_entropy_per_byte);
+    samples.zeroize();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cutoffs_match_sp800_90b() {
+        // Valores de la tabla de SP 800-90B para ventanas de 512 muestras
+        assert_eq!(repetition_cutoff(1.0), 21);
+        assert_eq!(repetition_cutoff(8.0), 4);
+        assert_eq!(proportion_cutoff(0.5), 410);
+        assert_eq!(proportion_cutoff(1.0), 311);
+        assert_eq!(proportion_cutoff(8.0), 13);
+    }
+
+    #[test]
+    fn test_health_tests_and_mixing() {
+        let mut random = vec![0u8; TRNG_SAMPLE_BYTES];
+        crate::security::rng::fill_random(&mut random);
+        let mut stuck = random.clone();
+        stuck[100..110].fill(0xAA);
+
+        let report = health_test(&random, 4.0).unwrap();
+        assert!(report.passed);
+        assert!(!health_test(&stuck, 4.0).unwrap().passed);
+        assert!(health_test(&random[..100], 4.0).is_err());
+
+        // Mezcla: cambia la entropía; muestras defectuosas no se usan
+        let mut entropy = [0u8; 16];
+        mix_samples(&mut entropy, &random, 4.0).unwrap();
+        assert_ne!(entropy, [0u8; 16]);
+        let before = entropy;
+        assert!(mix_samples(&mut entropy, &stuck, 4.0).is_err());
+        assert_eq!(entropy, before);
+
+        // Un dispositivo que deja de enviar datos
+        let file = std::env::temp_dir().join(format!("scypher-trng-{}", std::process::id()));
+        std::fs::write(&file, &random[..600]).unwrap();
+        let source = TrngSource { device: file.to_string_lossy().into_owned(), init: TrngInit::None, min_entropy_per_byte: 4.0 };
+        assert!(check_trng(&source).unwrap_err().to_string().contains("stopped"));
+        std::fs::remove_file(&file).unwrap();
+    }
+}
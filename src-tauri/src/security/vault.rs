@@ -0,0 +1,216 @@
+//! Bóveda de sesión con varias frases semilla etiquetadas
+//!
+//! Quien gestiona varias wallets (personal, empresa...) puede cargar cada
+//! frase una vez y elegir con qué perfil trabajar, sin volver a pegarla en
+//! cada operación. Las frases viven en memoria bloqueada (`SecureBytes`),
+//! nunca se escriben en disco y se borran al cerrar la aplicación
+//! (`secure_cleanup`). La GUI solo recibe etiquetas y número de palabras.
+
+use std::sync::{Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+use crate::security::SecureBytes;
+
+/// Perfiles máximos en una sesión
+pub const MAX_PROFILES: usize = 16;
+
+/// Longitud máxima de una etiqueta (caracteres)
+const MAX_LABEL_CHARS: usize = 64;
+
+/// Perfil guardado: etiqueta y frase en memoria bloqueada
+struct VaultProfile {
+    label: String,
+    phrase: SecureBytes,
+    word_count: usize,
+}
+
+/// Contenido de la bóveda
+#[derive(Default)]
+struct Vault {
+    profiles: Vec<VaultProfile>,
+    selected: Option<String>,
+}
+
+/// Resumen de un perfil para la GUI (sin la frase)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultProfileSummary {
+    pub label: String,
+    pub word_count: usize,
+    pub selected: bool,
+    pub memory_locked: bool,
+}
+
+static VAULT: OnceLock<Mutex<Vault>> = OnceLock::new();
+
+fn vault_slot() -> &'static Mutex<Vault> {
+    VAULT.get_or_init(|| Mutex::new(Vault::default()))
+}
+
+fn validate_label(label: &str) -> Result<String> {
+    let label = label.trim();
+    if label.is_empty() || label.chars().count() > MAX_LABEL_CHARS {
+        return Err(SCypherError::crypto(format!(
+            "Profile label must be between 1 and {} characters",
+            MAX_LABEL_CHARS
+        )));
+    }
+    Ok(label.to_string())
+}
+
+fn not_found(label: &str) -> SCypherError {
+    SCypherError::crypto(format!("No vault profile named '{}'", label))
+}
+
+impl Vault {
+    fn position(&self, label: &str) -> Option<usize> {
+        self.profiles
+            .iter()
+            .position(|profile| profile.label.eq_ignore_ascii_case(label.trim()))
+    }
+
+    fn add(&mut self, label: &str, phrase: &str) -> Result<()> {
+        let label = validate_label(label)?;
+        if self.position(&label).is_some() {
+            return Err(SCypherError::crypto(format!("A vault profile named '{}' already exists", label)));
+        }
+        if self.profiles.len() >= MAX_PROFILES {
+            return Err(SCypherError::crypto(format!("The vault holds at most {} profiles", MAX_PROFILES)));
+        }
+
+        // Se guarda la forma canónica (abreviaturas expandidas)
+        let phrase = crate::bip39::wordlist::expand_abbreviations(phrase)?;
+        crate::bip39::validate_seed_phrase(&phrase)?;
+        let word_count = phrase.split_whitespace().count();
+
+        // El primer perfil queda seleccionado
+        if self.selected.is_none() {
+            self.selected = Some(label.clone());
+        }
+        self.profiles.push(VaultProfile {
+            label,
+            phrase: SecureBytes::new(phrase.into_bytes())?,
+            word_count,
+        });
+        Ok(())
+    }
+
+    fn select(&mut self, label: &str) -> Result<()> {
+        let index = self.position(label).ok_or_else(|| not_found(label))?;
+        self.selected = Some(self.profiles[index].label.clone());
+        Ok(())
+    }
+
+    fn remove(&mut self, label: &str) -> Result<()> {
+        let index = self.position(label).ok_or_else(|| not_found(label))?;
+        let removed = self.profiles.remove(index);
+        if self.selected.as_deref() == Some(removed.label.as_str()) {
+            self.selected = None;
+        }
+        Ok(())
+    }
+
+    fn selected_phrase(&self) -> Result<String> {
+        let label = self
+            .selected
+            .as_deref()
+            .ok_or_else(|| SCypherError::crypto("No vault profile is selected".to_string()))?;
+        let index = self.position(label).ok_or_else(|| not_found(label))?;
+
+        String::from_utf8(self.profiles[index].phrase.as_slice().to_vec())
+            .map_err(|_| SCypherError::crypto("Vault profile is corrupted".to_string()))
+    }
+
+    fn summaries(&self) -> Vec<VaultProfileSummary> {
+        self.profiles
+            .iter()
+            .map(|profile| VaultProfileSummary {
+                label: profile.label.clone(),
+                word_count: profile.word_count,
+                selected: self.selected.as_deref() == Some(profile.label.as_str()),
+                memory_locked: profile.phrase.is_memory_locked(),
+            })
+            .collect()
+    }
+}
+
+/// Añadir un perfil (el primero queda seleccionado)
+pub fn add_profile(label: &str, phrase: &str) -> Result<Vec<VaultProfileSummary>> {
+    let mut vault = vault_slot().lock().unwrap_or_else(|e| e.into_inner());
+    vault.add(label, phrase)?;
+    Ok(vault.summaries())
+}
+
+/// Elegir el perfil con el que trabajan las operaciones de la bóveda
+pub fn select_profile(label: &str) -> Result<Vec<VaultProfileSummary>> {
+    let mut vault = vault_slot().lock().unwrap_or_else(|e| e.into_inner());
+    vault.select(label)?;
+    Ok(vault.summaries())
+}
+
+/// Eliminar un perfil (su frase se borra de memoria)
+pub fn remove_profile(label: &str) -> Result<Vec<VaultProfileSummary>> {
+    let mut vault = vault_slot().lock().unwrap_or_else(|e| e.into_inner());
+    vault.remove(label)?;
+    Ok(vault.summaries())
+}
+
+/// Perfiles de la sesión
+pub fn list_profiles() -> Vec<VaultProfileSummary> {
+    vault_slot().lock().unwrap_or_else(|e| e.into_inner()).summaries()
+}
+
+/// Copia de la frase del perfil seleccionado para una operación
+///
+/// El llamador debe descartarla en cuanto termine (las funciones de
+/// transformación y derivación ya trabajan sobre `&str`).
+pub fn selected_phrase() -> Result<String> {
+    vault_slot().lock().unwrap_or_else(|e| e.into_inner()).selected_phrase()
+}
+
+/// Vaciar la bóveda
+pub fn clear_vault() {
+    if let Some(vault) = VAULT.get() {
+        *vault.lock().unwrap_or_else(|e| e.into_inner()) = Vault::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERSONAL: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const BUSINESS: &str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    #[test]
+    fn test_vault_profiles() {
+        let mut vault = Vault::default();
+        vault.add("Personal", "aban aban aban aban aban aban aban aban aban aban aban abou").unwrap();
+        vault.add("Business", BUSINESS).unwrap();
+
+        // El primero queda seleccionado y la frase se guarda expandida
+        assert_eq!(vault.selected_phrase().unwrap(), PERSONAL);
+        vault.select("business").unwrap();
+        assert_eq!(vault.selected_phrase().unwrap(), BUSINESS);
+
+        let summaries = vault.summaries();
+        assert_eq!(summaries.len(), 2);
+        assert!(summaries[1].selected && !summaries[0].selected);
+        assert_eq!(summaries[0].word_count, 12);
+
+        vault.remove("Business").unwrap();
+        assert!(vault.selected_phrase().is_err());
+        assert!(vault.select("Business").is_err());
+    }
+
+    #[test]
+    fn test_vault_rejects_invalid_input() {
+        let mut vault = Vault::default();
+        vault.add("Personal", PERSONAL).unwrap();
+
+        assert!(vault.add("personal", BUSINESS).is_err());
+        assert!(vault.add("  ", BUSINESS).is_err());
+        assert!(vault.add("Other", "not a seed phrase").is_err());
+        assert!(vault.add(&"x".repeat(MAX_LABEL_CHARS + 1), BUSINESS).is_err());
+        assert_eq!(vault.profiles.len(), 1);
+    }
+}
@@ -0,0 +1,190 @@
+// =============================================================================
+// SEEDQR Y COMPACTSEEDQR (FORMATO SEEDSIGNER)
+// Permite mover la frase original o la transformada entre firmadores DIY
+// (SeedSigner, Keystone, Krux, Jade) usando sus formatos estándar:
+// - SeedQR estándar: índice BIP39 de cada palabra con 4 dígitos (modo numérico)
+// - CompactSeedQR: la entropía en bruto, sin checksum (modo byte; solo 12 y 24 palabras)
+// =============================================================================
+
+use base64::Engine;
+use qrcode::{Color as QrColor, EcLevel, QrCode};
+use serde::{Deserialize, Serialize};
+use crate::error::{SCypherError, Result};
+
+/// Píxeles por módulo en la imagen PNG del QR
+const PNG_MODULE_SIZE: usize = 10;
+
+/// Zona de silencio alrededor del QR (en módulos)
+const PNG_QUIET_ZONE: usize = 4;
+
+/// Formatos SeedQR soportados
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeedQrFormat {
+    Standard,
+    Compact,
+}
+
+/// QR listo para mostrar o imprimir
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedQrExport {
+    pub format: SeedQrFormat,
+    pub payload: String,        // Dígitos (estándar) o entropía en hex (compacto)
+    pub modules: usize,         // 21x21, 25x25, 29x29...
+    pub png_base64: String,
+}
+
+/// SeedQR estándar: cuatro dígitos por palabra
+pub fn standard_payload(phrase: &str) -> Result<String> {
+    crate::bip39::validate_seed_phrase_complete(phrase)?;
+
+    phrase
+        .split_whitespace()
+        .map(|word| {
+            crate::bip39::word_to_index(word)
+                .map(|index| format!("{:04}", index))
+                .ok_or_else(|| SCypherError::InvalidBip39Word(word.to_string()))
+        })
+        .collect()
+}
+
+/// CompactSeedQR: 16 bytes (12 palabras) o 32 bytes (24 palabras) de entropía
+pub fn compact_payload(phrase: &str) -> Result<Vec<u8>> {
+    crate::bip39::validate_seed_phrase_complete(phrase)?;
+
+    let word_count = phrase.split_whitespace().count();
+    if word_count != 12 && word_count != 24 {
+        return Err(SCypherError::crypto(
+            "CompactSeedQR only supports 12 and 24 word phrases".to_string(),
+        ));
+    }
+    crate::bip39::phrase_to_entropy(phrase)
+}
+
+/// Convertir un QR en PNG en escala de grises
+fn render_png(code: &QrCode) -> Result<Vec<u8>> {
+    let width = code.width();
+    let colors = code.to_colors();
+    let size = (width + 2 * PNG_QUIET_ZONE) * PNG_MODULE_SIZE;
+
+    let mut pixels = vec![255u8; size * size];
+    for row in 0..width {
+        for column in 0..width {
+            if colors[row * width + column] != QrColor::Dark {
+                continue;
+            }
+            let top = (row + PNG_QUIET_ZONE) * PNG_MODULE_SIZE;
+            let left = (column + PNG_QUIET_ZONE) * PNG_MODULE_SIZE;
+            for y in top..top + PNG_MODULE_SIZE {
+                pixels[y * size + left..y * size + left + PNG_MODULE_SIZE].fill(0);
+            }
+        }
+    }
+
+    let mut output = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut output, size as u32, size as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| SCypherError::file(format!("Cannot encode PNG image: {}", e)))?;
+        writer
+            .write_image_data(&pixels)
+            .map_err(|e| SCypherError::file(format!("Cannot encode PNG image: {}", e)))?;
+    }
+    Ok(output)
+}
+
+/// Generar el SeedQR de una frase (ECC nivel L, como SeedSigner)
+pub fn export_seedqr(phrase: &str, format: SeedQrFormat) -> Result<SeedQrExport> {
+    let (code, payload) = match format {
+        SeedQrFormat::Standard => {
+            let digits = standard_payload(phrase)?;
+            (QrCode::with_error_correction_level(digits.as_bytes(), EcLevel::L), digits)
+        }
+        SeedQrFormat::Compact => {
+            let entropy = compact_payload(phrase)?;
+            (QrCode::with_error_correction_level(&entropy, EcLevel::L), hex::encode(&entropy))
+        }
+    };
+    let code = code.map_err(|e| SCypherError::file(format!("Cannot generate QR code: {}", e)))?;
+
+    Ok(SeedQrExport {
+        format,
+        payload,
+        modules: code.width(),
+        png_base64: base64::engine::general_purpose::STANDARD.encode(render_png(&code)?),
+    })
+}
+
+/// Reconstruir la frase desde datos escaneados: dígitos SeedQR o entropía compacta en hex
+pub fn import_seedqr(scanned: &str) -> Result<String> {
+    let data = scanned.trim();
+
+    // SeedQR estándar: 4 dígitos por palabra
+    if !data.is_empty() && data.bytes().all(|b| b.is_ascii_digit()) && [48, 60, 72, 84, 96].contains(&data.len()) {
+        let words: Vec<&'static str> = data
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                let index: usize = std::str::from_utf8(chunk).unwrap_or("").parse().unwrap_or(usize::MAX);
+                crate::bip39::index_to_word(index)
+                    .ok_or_else(|| SCypherError::crypto(format!("Invalid SeedQR word index: {}", index)))
+            })
+            .collect::<Result<_>>()?;
+
+        let phrase = words.join(" ");
+        crate::bip39::validate_seed_phrase_complete(&phrase)?;
+        return Ok(phrase);
+    }
+
+    // CompactSeedQR: bytes del QR binario, en hex
+    let entropy = hex::decode(data.trim_start_matches("0x"))
+        .map_err(|_| SCypherError::crypto("Unrecognized SeedQR data".to_string()))?;
+    if entropy.len() != 16 && entropy.len() != 32 {
+        return Err(SCypherError::crypto(format!(
+            "CompactSeedQR must contain 16 or 32 bytes, found {}",
+            entropy.len()
+        )));
+    }
+    crate::bip39::entropy_to_phrase(&entropy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_standard_seedqr_roundtrip() {
+        let export = export_seedqr(PHRASE, SeedQrFormat::Standard).unwrap();
+        assert_eq!(export.payload, format!("{}0003", "0000".repeat(11)));
+        assert_eq!(export.modules, 25);
+        assert_eq!(import_seedqr(&export.payload).unwrap(), PHRASE);
+    }
+
+    #[test]
+    fn test_compact_seedqr_roundtrip() {
+        let export = export_seedqr(PHRASE, SeedQrFormat::Compact).unwrap();
+        assert_eq!(export.payload, "00".repeat(16));
+        assert_eq!(export.modules, 21);
+        assert_eq!(import_seedqr(&export.payload).unwrap(), PHRASE);
+
+        let png = base64::engine::general_purpose::STANDARD.decode(export.png_base64).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+    }
+
+    #[test]
+    fn test_rejects_invalid_seedqr() {
+        // Checksum incorrecto (índice 0 en la última palabra)
+        assert!(import_seedqr(&"0000".repeat(12)).is_err());
+        assert!(import_seedqr("2048").is_err());
+        assert!(import_seedqr(&"00".repeat(20)).is_err());
+
+        let fifteen = "abandon ".repeat(14) + "address";
+        assert!(compact_payload(&fifteen).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+// =============================================================================
+// FIRMA DE MENSAJES CON CLAVES DERIVADAS
+// Permite demostrar la propiedad de una dirección sin conexión:
+// - Bitcoin: formato signmessage (BIP137, firma recuperable en base64)
+// - EVM (Ethereum, BSC, Polygon): EIP-191 personal_sign (r || s || v en hex)
+// - Solana: Ed25519 sobre los bytes del mensaje (firma en base58)
+// =============================================================================
+
+use base64::Engine;
+use bitcoin::hashes::Hash;
+use ed25519_dalek::{Signer, Verifier};
+use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Keccak};
+use crate::error::{SCypherError, Result};
+
+/// Resultado de una firma
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedMessage {
+    pub network: String,
+    pub path: String,
+    pub address: String,
+    pub message: String,
+    pub signature: String,
+}
+
+/// Cabecera BIP137 según el tipo de dirección (clave comprimida)
+fn bip137_header_base(path: &str) -> u8 {
+    if path.starts_with("m/49'") {
+        35 // P2SH-P2WPKH
+    } else if path.starts_with("m/84'") {
+        39 // P2WPKH
+    } else {
+        31 // P2PKH comprimida
+    }
+}
+
+/// Dirección Bitcoin correspondiente a la cabecera BIP137
+fn bitcoin_address_for_header(public_key: &bitcoin::PublicKey, header_base: u8) -> Result<String> {
+    use bitcoin::Network;
+
+    let address = match header_base {
+        35 => bitcoin::Address::p2shwpkh(public_key, Network::Bitcoin)
+            .map_err(|e| SCypherError::crypto(format!("P2SH-P2WPKH address creation failed: {}", e)))?,
+        39 => bitcoin::Address::p2wpkh(public_key, Network::Bitcoin)
+            .map_err(|e| SCypherError::crypto(format!("P2WPKH address creation failed: {}", e)))?,
+        _ => bitcoin::Address::p2pkh(public_key, Network::Bitcoin),
+    };
+
+    Ok(address.to_string())
+}
+
+/// Hash EIP-191: keccak256("\x19Ethereum Signed Message:\n" || len || mensaje)
+fn eip191_hash(message: &str) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes());
+    hasher.update(message.as_bytes());
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Dirección EVM (EIP-55) de una clave pública secp256k1
+fn evm_address_from_public_key(public_key: &secp256k1::PublicKey) -> String {
+    let uncompressed = public_key.serialize_uncompressed();
+    let mut hasher = Keccak::v256();
+    hasher.update(&uncompressed[1..]);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    format!("0x{}", hex::encode(&hash[12..]))
+}
+
+/// Firmar un mensaje con la clave derivada en `path`
+pub fn sign_message(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    network: &str,
+    path: &str,
+    message: &str,
+) -> Result<SignedMessage> {
+    let (address, signature) = match network {
+        "bitcoin" => {
+            let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+            let key = crate::addresses::derive_key_at_path(&master_key, path)?;
+
+            let secp = secp256k1::Secp256k1::new();
+            let secret_key = secp256k1::SecretKey::from_slice(&key.private_key().to_bytes())
+                .map_err(|e| SCypherError::crypto(format!("Invalid private key: {}", e)))?;
+
+            let digest = bitcoin::sign_message::signed_msg_hash(message);
+            let msg = secp256k1::Message::from_slice(&digest.to_byte_array())
+                .map_err(|e| SCypherError::crypto(format!("Invalid message hash: {}", e)))?;
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&msg, &secret_key)
+                .serialize_compact();
+
+            let header_base = bip137_header_base(path);
+            let mut serialized = Vec::with_capacity(65);
+            serialized.push(header_base + recovery_id.to_i32() as u8);
+            serialized.extend_from_slice(&compact);
+
+            let public_key = bitcoin::PublicKey::new(secp256k1::PublicKey::from_secret_key(&secp, &secret_key));
+            (
+                bitcoin_address_for_header(&public_key, header_base)?,
+                base64::engine::general_purpose::STANDARD.encode(serialized),
+            )
+        }
+        "ethereum" | "bsc" | "polygon" => {
+            let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+            let key = crate::addresses::derive_key_at_path(&master_key, path)?;
+
+            let secp = secp256k1::Secp256k1::new();
+            let secret_key = secp256k1::SecretKey::from_slice(&key.private_key().to_bytes())
+                .map_err(|e| SCypherError::crypto(format!("Invalid private key: {}", e)))?;
+
+            let msg = secp256k1::Message::from_slice(&eip191_hash(message))
+                .map_err(|e| SCypherError::crypto(format!("Invalid message hash: {}", e)))?;
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&msg, &secret_key)
+                .serialize_compact();
+
+            let mut serialized = compact.to_vec();
+            serialized.push(27 + recovery_id.to_i32() as u8);
+
+            (
+                crate::addresses::evm_address_from_key(&key)?,
+                format!("0x{}", hex::encode(serialized)),
+            )
+        }
+        "solana" => {
+            // Solana no usa passphrase (igual que Phantom)
+            let signing_key = crate::addresses::solana_signing_key_at_path(seed_phrase, path)?;
+            let signature = signing_key.sign(message.as_bytes());
+            (
+                bs58::encode(signing_key.verifying_key().as_bytes()).into_string(),
+                bs58::encode(signature.to_bytes()).into_string(),
+            )
+        }
+        _ => {
+            return Err(SCypherError::crypto(format!(
+                "Message signing is not supported for network: {}",
+                network
+            )))
+        }
+    };
+
+    Ok(SignedMessage {
+        network: network.to_string(),
+        path: path.to_string(),
+        address,
+        message: message.to_string(),
+        signature,
+    })
+}
+
+/// Verificar que `signature` fue producida por la clave de `address`
+pub fn verify_message(network: &str, address: &str, message: &str, signature: &str) -> Result<bool> {
+    match network {
+        "bitcoin" => {
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(signature.trim())
+                .map_err(|_| SCypherError::crypto("Signature is not valid base64".to_string()))?;
+            if bytes.len() != 65 || !(27..=42).contains(&bytes[0]) {
+                return Err(SCypherError::crypto("Invalid Bitcoin message signature".to_string()));
+            }
+
+            let header = bytes[0];
+            let recovery_id = secp256k1::ecdsa::RecoveryId::from_i32(((header - 27) % 4) as i32)
+                .map_err(|e| SCypherError::crypto(format!("Invalid recovery id: {}", e)))?;
+            let recoverable = secp256k1::ecdsa::RecoverableSignature::from_compact(&bytes[1..], recovery_id)
+                .map_err(|e| SCypherError::crypto(format!("Invalid signature: {}", e)))?;
+
+            let digest = bitcoin::sign_message::signed_msg_hash(message);
+            let msg = secp256k1::Message::from_slice(&digest.to_byte_array())
+                .map_err(|e| SCypherError::crypto(format!("Invalid message hash: {}", e)))?;
+
+            let secp = secp256k1::Secp256k1::verification_only();
+            let recovered = match secp.recover_ecdsa(&msg, &recoverable) {
+                Ok(public_key) => public_key,
+                Err(_) => return Ok(false),
+            };
+
+            // Cabeceras 27-30 indican clave no comprimida (formato legacy)
+            let mut public_key = bitcoin::PublicKey::new(recovered);
+            public_key.compressed = header >= 31;
+
+            let header_base = match header {
+                35..=38 => 35,
+                39..=42 => 39,
+                _ => 31,
+            };
+            let expected = bitcoin_address_for_header(&public_key, header_base)?;
+
+            Ok(expected == address.trim() || expected == address.trim().to_lowercase())
+        }
+        "ethereum" | "bsc" | "polygon" => {
+            let bytes = hex::decode(signature.trim().trim_start_matches("0x"))
+                .map_err(|_| SCypherError::crypto("Signature is not valid hex".to_string()))?;
+            if bytes.len() != 65 {
+                return Err(SCypherError::crypto("EVM signature must be 65 bytes".to_string()));
+            }
+
+            let v = bytes[64];
+            let recovery_id = secp256k1::ecdsa::RecoveryId::from_i32(if v >= 27 { v - 27 } else { v } as i32)
+                .map_err(|e| SCypherError::crypto(format!("Invalid recovery id: {}", e)))?;
+            let recoverable = secp256k1::ecdsa::RecoverableSignature::from_compact(&bytes[..64], recovery_id)
+                .map_err(|e| SCypherError::crypto(format!("Invalid signature: {}", e)))?;
+
+            let msg = secp256k1::Message::from_slice(&eip191_hash(message))
+                .map_err(|e| SCypherError::crypto(format!("Invalid message hash: {}", e)))?;
+
+            let secp = secp256k1::Secp256k1::verification_only();
+            match secp.recover_ecdsa(&msg, &recoverable) {
+                Ok(public_key) => Ok(evm_address_from_public_key(&public_key) == address.trim().to_lowercase()),
+                Err(_) => Ok(false),
+            }
+        }
+        "solana" => {
+            let public_key: [u8; 32] = bs58::decode(address.trim())
+                .into_vec()
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| SCypherError::crypto("Invalid Solana address".to_string()))?;
+            let signature: [u8; 64] = bs58::decode(signature.trim())
+                .into_vec()
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| SCypherError::crypto("Invalid Solana signature".to_string()))?;
+
+            let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&public_key)
+                .map_err(|e| SCypherError::crypto(format!("Invalid Solana public key: {}", e)))?;
+
+            Ok(verifying_key
+                .verify(message.as_bytes(), &ed25519_dalek::Signature::from_bytes(&signature))
+                .is_ok())
+        }
+        _ => Err(SCypherError::crypto(format!(
+            "Message verification is not supported for network: {}",
+            network
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_bitcoin_sign_and_verify() {
+        for (path, expected) in [
+            ("m/44'/0'/0'/0/0", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"),
+            ("m/84'/0'/0'/0/0", "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"),
+        ] {
+            let signed = sign_message(TEST_MNEMONIC, None, "bitcoin", path, "hello").unwrap();
+            assert_eq!(signed.address, expected);
+            assert!(verify_message("bitcoin", expected, "hello", &signed.signature).unwrap());
+            assert!(!verify_message("bitcoin", expected, "hello!", &signed.signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_evm_sign_and_verify() {
+        let signed = sign_message(TEST_MNEMONIC, None, "ethereum", "m/44'/60'/0'/0/0", "hello").unwrap();
+        assert_eq!(signed.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert!(verify_message("ethereum", &signed.address, "hello", &signed.signature).unwrap());
+        assert!(!verify_message("ethereum", &signed.address, "other", &signed.signature).unwrap());
+    }
+
+    #[test]
+    fn test_solana_sign_and_verify() {
+        let signed = sign_message(TEST_MNEMONIC, None, "solana", "m/44'/501'/0'/0'", "hello").unwrap();
+        assert!(verify_message("solana", &signed.address, "hello", &signed.signature).unwrap());
+        assert!(!verify_message("solana", &signed.address, "other", &signed.signature).unwrap());
+    }
+
+    #[test]
+    fn test_unsupported_network() {
+        assert!(sign_message(TEST_MNEMONIC, None, "cardano", "m/0", "hello").is_err());
+    }
+}
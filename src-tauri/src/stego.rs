@@ -0,0 +1,367 @@
+// =============================================================================
+// EXPORTACIÓN ESTEGANOGRÁFICA
+// Oculta la entropía transformada (más un MAC) en los bits menos
+// significativos de una imagen PNG, para respaldos que no parezcan
+// material criptográfico. La imagen resultante siempre se guarda como PNG
+// (sin pérdida); cualquier recompresión con pérdida destruye los datos.
+// La cabecera se enmascara con bytes derivados de la contraseña: sin ella
+// los LSB no contienen ninguna marca fija que delate el respaldo.
+// =============================================================================
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+use crate::error::{SCypherError, Result};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Identificador del contenedor oculto (solo se guarda enmascarado)
+const STEGO_MAGIC: &[u8; 4] = b"SCST";
+const STEGO_VERSION: u8 = 2;
+
+/// Longitud del MAC (HMAC-SHA256)
+const MAC_LENGTH: usize = 32;
+
+/// Cabecera: magic (4) + versión (1) + longitud de entropía (1)
+const HEADER_LENGTH: usize = 6;
+
+/// Longitud de la clave Argon2id usada para derivar la clave del MAC
+/// (distinta de cualquier longitud de entropía BIP39 para separar dominios)
+const MAC_SEED_LENGTH: usize = 64;
+
+/// Imagen decodificada normalizada a 8 bits por canal
+struct DecodedImage {
+    width: u32,
+    height: u32,
+    color_type: png::ColorType,
+    pixels: Vec<u8>,
+}
+
+/// Claves derivadas de la contraseña (en el proceso aislado de cifrado)
+#[derive(Default, Serialize, Deserialize)]
+pub struct StegoKeys {
+    mac_key: Vec<u8>,
+    header_mask: [u8; HEADER_LENGTH],
+}
+
+impl Drop for StegoKeys {
+    fn drop(&mut self) {
+        self.mac_key.zeroize();
+        self.header_mask.zeroize();
+    }
+}
+
+/// Aplicar (o quitar) la máscara de la cabecera
+fn mask_header(header: &mut [u8], mask: &[u8; HEADER_LENGTH]) {
+    for (byte, mask) in header.iter_mut().zip(mask) {
+        *byte ^= mask;
+    }
+}
+
+/// Derivar la clave del MAC y la máscara de la cabecera a partir de la contraseña
+pub(crate) fn derive_keys(password: &str, iterations: u32, memory_cost: u32) -> Result<StegoKeys> {
+    let parallelism = crate::crypto::keystream::DEFAULT_PARALLELISM;
+    crate::crypto::keystream::validate_argon2_params(iterations, memory_cost, parallelism)?;
+
+    let mut seed = crate::crypto::keystream::derive_keystream(
+        password,
+        MAC_SEED_LENGTH,
+        iterations,
+        memory_cost,
+        parallelism,
+    )?;
+
+    let mac_key = Sha256::new()
+        .chain_update(b"SCYPHER_STEGO_MAC_V1")
+        .chain_update(&seed)
+        .finalize()
+        .to_vec();
+    let mut mask_hash = Sha256::new()
+        .chain_update(b"SCYPHER_STEGO_HEADER_V1")
+        .chain_update(&seed)
+        .finalize();
+    seed.zeroize();
+
+    let mut header_mask = [0u8; HEADER_LENGTH];
+    header_mask.copy_from_slice(&mask_hash[..HEADER_LENGTH]);
+    mask_hash.zeroize();
+
+    Ok(StegoKeys { mac_key, header_mask })
+}
+
+/// Calcular el MAC sobre cabecera + entropía
+fn compute_mac(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = HmacSha256::new_from_slice(key)
+        .map_err(|e| SCypherError::crypto(format!("Invalid MAC key: {}", e)))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Canales utilizables para ocultar datos (se omite el canal alfa)
+fn is_data_channel(color_type: png::ColorType, byte_index: usize) -> bool {
+    match color_type {
+        png::ColorType::Rgba => byte_index % 4 != 3,
+        png::ColorType::GrayscaleAlpha => byte_index % 2 != 1,
+        _ => true,
+    }
+}
+
+/// Decodificar un PNG normalizando a 8 bits por canal
+fn decode_png(image: &[u8]) -> Result<DecodedImage> {
+    let mut decoder = png::Decoder::new(image);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder
+        .read_info()
+        .map_err(|e| SCypherError::file(format!("Cannot decode PNG image: {}", e)))?;
+
+    let mut pixels = vec![0u8; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut pixels)
+        .map_err(|e| SCypherError::file(format!("Cannot decode PNG image: {}", e)))?;
+    pixels.truncate(info.line_size * info.height as usize);
+
+    Ok(DecodedImage {
+        width: info.width,
+        height: info.height,
+        color_type: info.color_type,
+        pixels,
+    })
+}
+
+/// Codificar los píxeles como PNG de 8 bits
+fn encode_png(image: &DecodedImage) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut output, image.width, image.height);
+        encoder.set_color(image.color_type);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| SCypherError::file(format!("Cannot encode PNG image: {}", e)))?;
+        writer
+            .write_image_data(&image.pixels)
+            .map_err(|e| SCypherError::file(format!("Cannot encode PNG image: {}", e)))?;
+    }
+    Ok(output)
+}
+
+/// Escribir bits (MSB primero) en los LSB de los canales de datos
+fn write_bits(image: &mut DecodedImage, payload: &[u8]) -> Result<()> {
+    let color_type = image.color_type;
+    let mut slots = image
+        .pixels
+        .iter_mut()
+        .enumerate()
+        .filter(|(index, _)| is_data_channel(color_type, *index))
+        .map(|(_, byte)| byte);
+
+    for byte in payload {
+        for bit in (0..8).rev() {
+            let slot = slots.next().ok_or_else(|| {
+                SCypherError::file("Image is too small to hold the backup".to_string())
+            })?;
+            *slot = (*slot & 0xFE) | ((byte >> bit) & 1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Leer `length` bytes de los LSB de los canales de datos
+fn read_bits(image: &DecodedImage, offset: usize, length: usize) -> Result<Vec<u8>> {
+    let mut slots = image
+        .pixels
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| is_data_channel(image.color_type, *index))
+        .map(|(_, byte)| byte)
+        .skip(offset * 8);
+
+    let mut bytes = Vec::with_capacity(length);
+    for _ in 0..length {
+        let mut byte = 0u8;
+        for _ in 0..8 {
+            let slot = slots
+                .next()
+                .ok_or_else(|| SCypherError::file("No hidden backup found in image".to_string()))?;
+            byte = (byte << 1) | (slot & 1);
+        }
+        bytes.push(byte);
+    }
+
+    Ok(bytes)
+}
+
+/// Ocultar la entropía de la frase transformada dentro de un PNG
+pub fn embed_entropy(cover_png: &[u8], phrase: &str, keys: &StegoKeys) -> Result<Vec<u8>> {
+    crate::bip39::validate_seed_phrase_complete(phrase)?;
+
+    let mut entropy = crate::bip39::conversion::phrase_to_entropy(phrase)?;
+    // El MAC cubre la cabecera en claro; después se enmascara
+    let mut payload = Vec::with_capacity(HEADER_LENGTH + entropy.len() + MAC_LENGTH);
+    payload.extend_from_slice(STEGO_MAGIC);
+    payload.push(STEGO_VERSION);
+    payload.push(entropy.len() as u8);
+    payload.extend_from_slice(&entropy);
+    let mac = compute_mac(&keys.mac_key, &payload)?;
+    payload.extend_from_slice(&mac);
+    mask_header(&mut payload[..HEADER_LENGTH], &keys.header_mask);
+
+    entropy.zeroize();
+
+    let mut image = decode_png(cover_png)?;
+    let result = write_bits(&mut image, &payload);
+    payload.zeroize();
+    result?;
+
+    encode_png(&image)
+}
+
+/// Extraer y verificar la entropía oculta, devolviendo la frase transformada
+pub fn extract_entropy(stego_png: &[u8], keys: &StegoKeys) -> Result<String> {
+    let image = decode_png(stego_png)?;
+
+    // Sin la contraseña correcta no se distingue una imagen limpia de una con datos
+    let mut header = read_bits(&image, 0, HEADER_LENGTH)?;
+    mask_header(&mut header, &keys.header_mask);
+    if &header[0..4] != STEGO_MAGIC {
+        return Err(SCypherError::file(
+            "No hidden backup found in image (or wrong password)".to_string(),
+        ));
+    }
+    if header[4] != STEGO_VERSION {
+        return Err(SCypherError::file(format!("Unsupported hidden backup version: {}", header[4])));
+    }
+
+    let entropy_length = header[5] as usize;
+    crate::crypto::checksum::validate_entropy_length(entropy_length * 8)?;
+
+    let mut body = read_bits(&image, HEADER_LENGTH, entropy_length + MAC_LENGTH)?;
+    let (entropy, stored_mac) = body.split_at(entropy_length);
+
+    let mut authenticated = header.clone();
+    authenticated.extend_from_slice(entropy);
+    let expected_mac = compute_mac(&keys.mac_key, &authenticated)?;
+
+    if !crate::security::utils::constant_time_eq(&expected_mac, stored_mac) {
+        authenticated.zeroize();
+        body.zeroize();
+        return Err(SCypherError::crypto(
+            "Hidden backup authentication failed (wrong password or damaged image)".to_string(),
+        ));
+    }
+
+    let phrase = crate::bip39::conversion::entropy_to_phrase(entropy);
+    authenticated.zeroize();
+    body.zeroize();
+    phrase
+}
+
+/// Leer la imagen de portada, ocultar la frase y guardar el PNG resultante
+pub fn embed_in_image(
+    phrase: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+    cover_path: &str,
+    output_path: &str,
+) -> Result<()> {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+
+    let cover = fs::read(cover_path)
+        .map_err(|e| SCypherError::file(format!("Cannot read image: {}", e)))?;
+    let keys = crate::crypto_worker::derive_stego_keys(password, iterations, memory_cost)?;
+    let stego = embed_entropy(&cover, phrase, &keys)?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options
+        .open(output_path)
+        .map_err(|e| SCypherError::file(format!("Cannot create file: {}", e)))?;
+    file.write_all(&stego)
+        .map_err(|e| SCypherError::file(format!("Cannot write file: {}", e)))?;
+
+    Ok(())
+}
+
+/// Leer un PNG y recuperar la frase transformada oculta
+pub fn extract_from_image(
+    image_path: &str,
+    password: &str,
+    iterations: u32,
+    memory_cost: u32,
+) -> Result<String> {
+    let image = std::fs::read(image_path)
+        .map_err(|e| SCypherError::file(format!("Cannot read image: {}", e)))?;
+    let keys = crate::crypto_worker::derive_stego_keys(password, iterations, memory_cost)?;
+    extract_entropy(&image, &keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn keys(password: &str) -> StegoKeys {
+        derive_keys(password, 1, 8192).unwrap()
+    }
+
+    fn cover_image(width: u32, height: u32, color_type: png::ColorType) -> Vec<u8> {
+        let channels = match color_type {
+            png::ColorType::Rgba => 4,
+            png::ColorType::Rgb => 3,
+            _ => 1,
+        };
+        let image = DecodedImage {
+            width,
+            height,
+            color_type,
+            pixels: (0..width * height * channels).map(|i| (i * 7 % 251) as u8).collect(),
+        };
+        encode_png(&image).unwrap()
+    }
+
+    #[test]
+    fn test_embed_extract_roundtrip() {
+        let cover = cover_image(32, 32, png::ColorType::Rgba);
+        let stego = embed_entropy(&cover, PHRASE, &keys("password123")).unwrap();
+
+        let recovered = extract_entropy(&stego, &keys("password123")).unwrap();
+        assert_eq!(recovered, PHRASE);
+
+        // La marca no aparece en claro en los LSB
+        let hidden = read_bits(&decode_png(&stego).unwrap(), 0, HEADER_LENGTH).unwrap();
+        assert_ne!(&hidden[0..4], STEGO_MAGIC);
+
+        // El canal alfa no se modifica
+        let original = decode_png(&cover).unwrap();
+        let modified = decode_png(&stego).unwrap();
+        for i in (3..original.pixels.len()).step_by(4) {
+            assert_eq!(original.pixels[i], modified.pixels[i]);
+        }
+    }
+
+    #[test]
+    fn test_wrong_password_fails_mac() {
+        let cover = cover_image(32, 32, png::ColorType::Rgb);
+        let stego = embed_entropy(&cover, PHRASE, &keys("password123")).unwrap();
+        assert!(extract_entropy(&stego, &keys("wrong")).is_err());
+    }
+
+    #[test]
+    fn test_small_or_clean_image_rejected() {
+        let tiny = cover_image(4, 4, png::ColorType::Rgb);
+        assert!(embed_entropy(&tiny, PHRASE, &keys("password123")).is_err());
+
+        let clean = cover_image(32, 32, png::ColorType::Rgb);
+        assert!(extract_entropy(&clean, &keys("password123")).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+// =============================================================================
+// TIPOS DE ALTO NIVEL PARA LA API DE LA BIBLIOTECA
+// Frase original, frase transformada y contraseña como tipos distintos: se
+// validan al construirse, se borran de memoria al liberarse y el compilador
+// impide pasar una contraseña donde se espera una frase (o descifrar una
+// frase que no salió del cifrador). No implementan Debug ni Display.
+// =============================================================================
+
+use zeroize::Zeroize;
+use crate::bip39::{self, normalize_seed_input};
+use crate::error::{SCypherError, Result};
+
+/// Normalizar, expandir abreviaturas y comprobar número de palabras y lista
+///
+/// El checksum no se exige aquí: depende de la `ChecksumPolicy` con la que
+/// se cifre (`PassThrough` produce frases sin checksum válido).
+fn normalize_phrase(phrase: &str) -> Result<String> {
+    let mut normalized = normalize_seed_input(phrase);
+    let expanded = bip39::wordlist::expand_abbreviations(&normalized);
+    normalized.zeroize();
+    let mut expanded = expanded?;
+
+    let words: Vec<&str> = expanded.split_whitespace().collect();
+    let checked = bip39::validate_word_count(words.len()).and_then(|_| bip39::validate_words(&words));
+    if let Err(e) = checked {
+        expanded.zeroize();
+        return Err(e);
+    }
+    Ok(expanded)
+}
+
+/// Frase semilla BIP39 en claro
+pub struct SeedPhrase {
+    phrase: String,
+}
+
+impl SeedPhrase {
+    /// Validar y normalizar (acepta abreviaturas de 4 letras)
+    pub fn new(phrase: &str) -> Result<Self> {
+        Ok(Self { phrase: normalize_phrase(phrase)? })
+    }
+
+    /// Frase normalizada (usar con cuidado)
+    pub fn as_str(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Número de palabras
+    pub fn word_count(&self) -> usize {
+        self.phrase.split_whitespace().count()
+    }
+
+    /// ¿Tiene checksum BIP39 válido?
+    pub fn has_valid_checksum(&self) -> bool {
+        bip39::verify_checksum(&self.phrase).unwrap_or(false)
+    }
+
+    pub(crate) fn from_transform(phrase: String) -> Self {
+        Self { phrase }
+    }
+}
+
+impl Drop for SeedPhrase {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+    }
+}
+
+/// Frase cifrada por SCypher (la que se guarda o se transcribe)
+pub struct TransformedPhrase {
+    phrase: String,
+}
+
+impl TransformedPhrase {
+    /// Validar y normalizar una frase cifrada leída del usuario
+    pub fn new(phrase: &str) -> Result<Self> {
+        Ok(Self { phrase: normalize_phrase(phrase)? })
+    }
+
+    /// Frase normalizada
+    pub fn as_str(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Número de palabras
+    pub fn word_count(&self) -> usize {
+        self.phrase.split_whitespace().count()
+    }
+
+    pub(crate) fn from_transform(phrase: String) -> Self {
+        Self { phrase }
+    }
+}
+
+impl Drop for TransformedPhrase {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+    }
+}
+
+/// Contraseña del cifrado
+pub struct Password {
+    password: String,
+}
+
+impl Password {
+    /// La contraseña no puede estar vacía; se usa tal cual, sin normalizar
+    pub fn new(password: &str) -> Result<Self> {
+        if password.is_empty() {
+            return Err(SCypherError::InvalidPassword);
+        }
+        Ok(Self { password: password.to_string() })
+    }
+
+    /// Contraseña en claro (usar con cuidado)
+    pub fn as_str(&self) -> &str {
+        &self.password
+    }
+}
+
+impl Drop for Password {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_on_construction() {
+        let seed = SeedPhrase::new("  Abandon abandon aban abandon abandon abandon abandon abandon abandon abandon abandon ABOUT ").unwrap();
+        assert_eq!(seed.as_str(), "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        assert_eq!(seed.word_count(), 12);
+        assert!(seed.has_valid_checksum());
+
+        // El checksum no se exige al construir
+        let bad_checksum = SeedPhrase::new("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon").unwrap();
+        assert!(!bad_checksum.has_valid_checksum());
+
+        assert!(matches!(SeedPhrase::new("abandon abandon abandon"), Err(SCypherError::InvalidWordCount(3))));
+        assert!(matches!(TransformedPhrase::new("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon zzzzzz"), Err(SCypherError::InvalidWords(_))));
+        assert!(matches!(Password::new(""), Err(SCypherError::InvalidPassword)));
+        assert_eq!(Password::new(" pass ").unwrap().as_str(), " pass ");
+    }
+
+    #[test]
+    fn test_typed_builder_roundtrip() {
+        let seed = SeedPhrase::new("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let password = Password::new("my_secure_password").unwrap();
+        let builder = crate::SCypherBuilder::new().iterations(1).memory_cost(8192);
+
+        let encrypted = builder.encrypt(&seed, &password).unwrap();
+        assert_eq!(encrypted.as_str(), builder.transform(seed.as_str(), password.as_str()).unwrap());
+
+        let reread = TransformedPhrase::new(encrypted.as_str()).unwrap();
+        assert_eq!(builder.decrypt(&reread, &password).unwrap().as_str(), seed.as_str());
+    }
+}
@@ -0,0 +1,304 @@
+// =============================================================================
+// PAQUETE DE SOLO LECTURA (WATCH-ONLY) PARA WALLETS MÓVILES
+// Un único JSON con lo necesario para vigilar la wallet sin claves privadas:
+// - Bitcoin: formato genérico de Coldcard (xfp, xpub por BIP44/49/84/86,
+//   ypub/zpub en `_pub` y descriptores con checksum), que importan Sparrow y
+//   BlueWallet tal cual.
+// - Resto de redes: lista de direcciones en `addresses` (las wallets EVM de
+//   vigilancia importan una dirección); Sparrow y BlueWallet ignoran la clave.
+// El mismo JSON se entrega como QR animado BC-UR (`bytes`) y BBQr (tipo J).
+// =============================================================================
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use bip32::{Prefix, XPrv};
+use serde::{Deserialize, Serialize};
+use crate::addresses::{NetworkConfig, NetworkId, ProfileAddressKind};
+use crate::error::{SCypherError, Result};
+
+/// Tipo UR de los QR animados
+const WATCH_UR_TYPE: &str = "bytes";
+
+/// Tipo BBQr de los QR animados (JSON)
+const WATCH_BBQR_TYPE: char = 'J';
+
+/// Caracteres válidos en un descriptor (BIP380)
+const DESCRIPTOR_INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}\
+                                        IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~\
+                                        ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+
+/// Alfabeto del checksum de descriptor
+const DESCRIPTOR_CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Cuenta Bitcoin en el formato genérico de Coldcard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoinAccountExport {
+    pub name: String,    // "p2pkh", "p2sh-p2wpkh", "p2wpkh", "p2tr"
+    pub deriv: String,   // Ruta de la cuenta (m/84h/0h/0h)
+    pub xpub: String,
+    #[serde(rename = "_pub", default, skip_serializing_if = "Option::is_none")]
+    pub slip132_pub: Option<String>, // ypub/zpub para wallets que no leen descriptores
+    pub desc: String,
+    pub first: String,   // Primera dirección de recepción para comprobar
+}
+
+/// Parte Bitcoin del paquete
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoinWatchExport {
+    pub chain: String,
+    pub xfp: String,     // Huella de la master key en mayúsculas
+    pub account: u32,
+    pub xpub: String,    // xpub raíz (m)
+    pub bip44: BitcoinAccountExport,
+    pub bip49: BitcoinAccountExport,
+    pub bip84: BitcoinAccountExport,
+    pub bip86: BitcoinAccountExport,
+}
+
+/// Contenido del archivo JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchBundleFile {
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub bitcoin: Option<BitcoinWatchExport>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub addresses: BTreeMap<NetworkId, Vec<String>>,
+}
+
+/// Paquete completo: archivo y QR animados
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchBundle {
+    pub bundle: WatchBundleFile,
+    pub json: String,
+    pub ur_frames: Vec<String>,
+    pub bbqr_frames: Vec<String>,
+}
+
+fn descriptor_polymod(c: u64, value: u64) -> u64 {
+    let c0 = c >> 35;
+    let mut c = ((c & 0x7_ffff_ffff) << 5) ^ value;
+    for (bit, generator) in [0xf5dee51989, 0xa9fdca3312, 0x1bab10e32d, 0x3706b1677a, 0x644d626ffd]
+        .into_iter()
+        .enumerate()
+    {
+        if c0 & (1 << bit) != 0 {
+            c ^= generator;
+        }
+    }
+    c
+}
+
+/// Checksum de 8 caracteres de un descriptor (BIP380)
+pub fn descriptor_checksum(descriptor: &str) -> Result<String> {
+    let mut c = 1u64;
+    let mut class = 0u64;
+    let mut class_count = 0;
+    for ch in descriptor.chars() {
+        let position = DESCRIPTOR_INPUT_CHARSET
+            .find(ch)
+            .ok_or_else(|| SCypherError::crypto(format!("Invalid descriptor character: {}", ch)))? as u64;
+        c = descriptor_polymod(c, position & 31);
+        class = class * 3 + (position >> 5);
+        class_count += 1;
+        if class_count == 3 {
+            c = descriptor_polymod(c, class);
+            class = 0;
+            class_count = 0;
+        }
+    }
+    if class_count > 0 {
+        c = descriptor_polymod(c, class);
+    }
+    for _ in 0..8 {
+        c = descriptor_polymod(c, 0);
+    }
+    c ^= 1;
+
+    Ok((0..8)
+        .map(|j| DESCRIPTOR_CHECKSUM_CHARSET[((c >> (5 * (7 - j))) & 31) as usize] as char)
+        .collect())
+}
+
+/// Añadir `#checksum` a un descriptor
+fn with_checksum(descriptor: String) -> Result<String> {
+    let checksum = descriptor_checksum(&descriptor)?;
+    Ok(format!("{}#{}", descriptor, checksum))
+}
+
+/// Exportar una cuenta BIP44/49/84/86
+fn bitcoin_account(
+    master_key: &XPrv,
+    seed_phrase: &str,
+    purpose: u32,
+    account: u32,
+) -> Result<BitcoinAccountExport> {
+    // Nombre, tipo de dirección, prefijo SLIP-132 y envoltorio del descriptor
+    let (name, kind, slip132, (open, close)) = match purpose {
+        44 => ("p2pkh", ProfileAddressKind::BitcoinLegacy, None, ("pkh(", ")")),
+        49 => ("p2sh-p2wpkh", ProfileAddressKind::BitcoinNestedSegwit, Some(Prefix::YPUB), ("sh(wpkh(", "))")),
+        84 => ("p2wpkh", ProfileAddressKind::BitcoinNativeSegwit, Some(Prefix::ZPUB), ("wpkh(", ")")),
+        86 => ("p2tr", ProfileAddressKind::BitcoinTaproot, None, ("tr(", ")")),
+        _ => return Err(SCypherError::crypto(format!("Unsupported purpose: {}", purpose))),
+    };
+
+    let path = format!("m/{}'/0'/{}'", purpose, account);
+    let deriv = path.replace('\'', "h");
+    let account_key = crate::addresses::derive_key_at_path(master_key, &path)?.public_key();
+    let xpub = account_key.to_string(Prefix::XPUB);
+
+    let origin = format!("[{}{}]", hex::encode(master_key.public_key().fingerprint()), &deriv[1..]);
+    let desc = with_checksum(format!("{}{}{}/<0;1>/*{}", open, origin, xpub, close))?;
+    let first = crate::addresses::profile_address(master_key, seed_phrase, kind, &format!("{}/0/0", path))?;
+
+    Ok(BitcoinAccountExport {
+        name: name.to_string(),
+        deriv,
+        slip132_pub: slip132.map(|prefix| account_key.to_string(prefix)),
+        xpub,
+        desc,
+        first,
+    })
+}
+
+/// Construir el paquete para las redes seleccionadas
+///
+/// Bitcoin añade las xpubs de la cuenta `account`; las demás redes, sus
+/// primeras `address_count` direcciones (con passphrase si la red la admite).
+pub fn build_watch_bundle(
+    seed_phrase: &str,
+    passphrase: Option<&str>,
+    networks: &[String],
+    address_count: u32,
+    account: u32,
+) -> Result<WatchBundle> {
+    if networks.is_empty() {
+        return Err(SCypherError::crypto("Select at least one network".to_string()));
+    }
+    if account >= 0x8000_0000 {
+        return Err(SCypherError::crypto(format!("Invalid account index: {}", account)));
+    }
+    let network_ids = networks
+        .iter()
+        .map(|network| NetworkId::from_str(network))
+        .collect::<Result<Vec<_>>>()?;
+
+    let bitcoin = if network_ids.contains(&NetworkId::Bitcoin) {
+        let master_key = crate::addresses::master_key_from_phrase(seed_phrase, passphrase)?;
+        Some(BitcoinWatchExport {
+            chain: "BTC".to_string(),
+            xfp: hex::encode_upper(master_key.public_key().fingerprint()),
+            account,
+            xpub: master_key.public_key().to_string(Prefix::XPUB),
+            bip44: bitcoin_account(&master_key, seed_phrase, 44, account)?,
+            bip49: bitcoin_account(&master_key, seed_phrase, 49, account)?,
+            bip84: bitcoin_account(&master_key, seed_phrase, 84, account)?,
+            bip86: bitcoin_account(&master_key, seed_phrase, 86, account)?,
+        })
+    } else {
+        None
+    };
+
+    let network_configs = networks
+        .iter()
+        .zip(&network_ids)
+        .filter(|(_, id)| **id != NetworkId::Bitcoin)
+        .map(|(network, _)| {
+            (network.clone(), NetworkConfig {
+                count: address_count.clamp(1, 100),
+                use_passphrase: true, // Solo se aplica a redes que lo soporten
+                solana_scheme: crate::addresses::SolanaScheme::default(),
+                evm_scheme: crate::addresses::EvmPathScheme::default(),
+                account: 0,
+            })
+        })
+        .collect();
+    let derived = crate::addresses::derive_addresses_with_config(seed_phrase, passphrase, network_configs)?;
+    let addresses = derived
+        .0
+        .into_iter()
+        .map(|(id, list)| (id, list.into_iter().map(|a| a.address).collect()))
+        .collect();
+
+    let bundle = WatchBundleFile { bitcoin, addresses };
+    let json = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| SCypherError::crypto(format!("Cannot encode watch-only bundle: {}", e)))?;
+    Ok(WatchBundle {
+        ur_frames: crate::airgap::encode_ur(json.as_bytes(), WATCH_UR_TYPE, crate::airgap::DEFAULT_UR_FRAGMENT_LEN)?,
+        bbqr_frames: crate::airgap::encode_bbqr(json.as_bytes(), WATCH_BBQR_TYPE, crate::airgap::DEFAULT_BBQR_PART_CHARS)?,
+        bundle,
+        json,
+    })
+}
+
+/// Guardar el JSON del paquete (permisos 0600 en Unix)
+pub fn write_watch_bundle(bundle: &WatchBundle, path: &str) -> Result<()> {
+    use std::fs;
+
+    fs::write(path, &bundle.json)
+        .map_err(|e| SCypherError::file(format!("Cannot write file: {}", e)))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| SCypherError::file(format!("Cannot set permissions: {}", e)))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_descriptor_checksum_vectors() {
+        // Vectores de BIP380
+        assert_eq!(descriptor_checksum("raw(deadbeef)").unwrap(), "89f8spxm");
+        assert_eq!(
+            descriptor_checksum("pkh([d34db33f/44'/0'/0']xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL/1/*)").unwrap(),
+            "ml40v0wf"
+        );
+        assert!(descriptor_checksum("pkh(\u{e9})").is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_watch_bundle() {
+        let bundle = build_watch_bundle(PHRASE, None, &["bitcoin".to_string()], 1, 0).unwrap();
+        let bitcoin = bundle.bundle.bitcoin.as_ref().unwrap();
+        assert_eq!(bitcoin.xfp, "73C5DA0A");
+
+        // Vectores de BIP44, BIP49, BIP84 y BIP86
+        assert_eq!(bitcoin.bip44.xpub, "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj");
+        assert_eq!(bitcoin.bip44.first, "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        assert_eq!(bitcoin.bip49.slip132_pub.as_deref(), Some("ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP"));
+        assert_eq!(bitcoin.bip49.first, "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf");
+        assert_eq!(bitcoin.bip84.slip132_pub.as_deref(), Some("zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs"));
+        assert_eq!(bitcoin.bip84.first, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(bitcoin.bip86.xpub, "xpub6BgBgsespWvERF3LHQu6CnqdvfEvtMcQjYrcRzx53QJjSxarj2afYWcLteoGVky7D3UKDP9QyrLprQ3VCECoY49yfdDEHGCtMMj92pReUsQ");
+        assert_eq!(bitcoin.bip86.first, "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+
+        let (desc, checksum) = bitcoin.bip84.desc.split_once('#').unwrap();
+        assert!(desc.starts_with("wpkh([73c5da0a/84h/0h/0h]xpub") && desc.ends_with("/<0;1>/*)"));
+        assert_eq!(descriptor_checksum(desc).unwrap(), checksum);
+
+        // El JSON sigue el formato genérico de Coldcard y los QR lo reconstruyen
+        let json: serde_json::Value = serde_json::from_str(&bundle.json).unwrap();
+        assert_eq!(json["bip84"]["_pub"], bitcoin.bip84.slip132_pub.clone().unwrap());
+        assert!(json.get("addresses").is_none());
+        assert_eq!(crate::airgap::decode_ur(&bundle.ur_frames).unwrap().payload_text.unwrap(), bundle.json);
+        assert_eq!(crate::airgap::decode_bbqr(&bundle.bbqr_frames).unwrap().payload_text.unwrap(), bundle.json);
+    }
+
+    #[test]
+    fn test_evm_watch_bundle() {
+        let bundle = build_watch_bundle(PHRASE, None, &["ethereum".to_string()], 2, 0).unwrap();
+        assert!(bundle.bundle.bitcoin.is_none());
+        assert_eq!(bundle.bundle.addresses[&NetworkId::Ethereum][0], "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(bundle.bundle.addresses[&NetworkId::Ethereum].len(), 2);
+
+        assert!(build_watch_bundle(PHRASE, None, &[], 1, 0).is_err());
+        assert!(build_watch_bundle(PHRASE, None, &["unknown".to_string()], 1, 0).is_err());
+    }
+}
@@ -21,6 +21,11 @@ document.addEventListener('DOMContentLoaded', async function() {
         bip39WordList = await invoke('get_bip39_wordlist');
         console.log(`Loaded ${bip39WordList.length} BIP39 words from backend`);
 
+        // Load networks compiled into this build (network-* features)
+        const supportedNetworks = await invoke('get_supported_networks');
+        AppState.supportedNetworks = supportedNetworks.map(network => network.id);
+        console.log(`Loaded ${AppState.supportedNetworks.length} supported networks from backend`);
+
         // Initialize core systems
         await initializeApplication();
 
@@ -322,8 +327,7 @@ function toggleNetworkSelection(network, buttonElement) {
 }
 
 function selectAllNetworks() {
-    const allNetworks = ['bitcoin', 'ethereum', 'ergo', 'tron', 'bsc', 'polygon', 'cardano', 'dogecoin', 'litecoin', 'solana'];
-    AppState.selectedNetworks = [...allNetworks];
+    AppState.selectedNetworks = [...AppState.supportedNetworks];
 
     document.querySelectorAll('.network-button').forEach(button => {
         button.classList.add('selected');
@@ -1277,7 +1281,8 @@ function initializeSecureMode() {
  * Precargar iconos críticos para mejor rendimiento
  */
 async function preloadCriticalIcons() {
-    const criticalNetworks = ['bitcoin', 'ethereum', 'tron', 'bsc'];
+    const criticalNetworks = ['bitcoin', 'ethereum', 'tron', 'bsc']
+        .filter(network => AppState.supportedNetworks.includes(network));
 
     const preloadPromises = criticalNetworks.map(async (network) => {
         const networkInfo = getNetworkInfo(network);
@@ -1302,9 +1307,7 @@ function debugIconLoading() {
     console.log('Cached icons:', Array.from(iconCache.keys()));
 
     // Probar carga de todos los iconos
-    const networks = ['bitcoin', 'ethereum', 'ergo', 'tron', 'bsc', 'polygon', 'cardano', 'dogecoin', 'litecoin', 'solana'];
-
-    networks.forEach(async (network) => {
+    AppState.supportedNetworks.forEach(async (network) => {
         const networkInfo = getNetworkInfo(network);
         try {
             const svgContent = await loadSVGIcon(networkInfo.icon);
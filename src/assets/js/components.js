@@ -909,18 +909,8 @@ async function initializeNetworkGrid() {
     const networkGrid = document.getElementById('networkGrid');
     if (!networkGrid) return;
 
-    const networks = [
-        { id: 'bitcoin', color: '#F7931A' },
-        { id: 'ethereum', color: '#627EEA' },
-        { id: 'ergo', color: '#FF5722' },
-        { id: 'tron', color: '#FF0013' },
-        { id: 'bsc', color: '#F0B90B' },
-        { id: 'polygon', color: '#8247E5' },
-        { id: 'cardano', color: '#0033AD' },
-        { id: 'dogecoin', color: '#C2A633' },
-        { id: 'litecoin', color: '#BFBBBB' },
-        { id: 'solana', color: '#00FFA3' }
-    ];
+    // Solo las redes compiladas en el backend; el color sale de getNetworkInfo
+    const networks = AppState.supportedNetworks.map(id => ({ id, color: getNetworkInfo(id).color }));
 
     // Limpiar grid existente
     networkGrid.innerHTML = '';
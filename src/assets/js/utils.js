@@ -12,6 +12,7 @@ const AppState = {
     selectedSource: 'input',
     bip39Passphrase: '',
     selectedNetworks: [],
+    supportedNetworks: [],  // Redes compiladas en el backend (get_supported_networks)
     addressCount: 1,
     derivationResults: {},
     processing: {
@@ -212,6 +213,36 @@ function getNetworkInfo(network) {
             icon: 'assets/icons/sol.svg',
             color: '#00FFA3',
             fallbackIcon: '◉'
+        },
+        bitcoincash: {
+            name: 'Bitcoin Cash',
+            icon: 'assets/icons/bch.svg',
+            color: '#8DC351',
+            fallbackIcon: 'Ƀ'
+        },
+        stellar: {
+            name: 'Stellar',
+            icon: 'assets/icons/xlm.svg',
+            color: '#7D00FF',
+            fallbackIcon: '✦'
+        },
+        ton: {
+            name: 'TON',
+            icon: 'assets/icons/ton.svg',
+            color: '#0098EA',
+            fallbackIcon: '◇'
+        },
+        liquid: {
+            name: 'Liquid',
+            icon: 'assets/icons/lbtc.svg',
+            color: '#46BEAE',
+            fallbackIcon: '◈'
+        },
+        zcash: {
+            name: 'Zcash (transparent)',
+            icon: 'assets/icons/zec.svg',
+            color: '#F4B728',
+            fallbackIcon: 'ⓩ'
         }
     };
 
@@ -315,9 +346,12 @@ async function handleModernSave() {
         const selectedPath = await invoke('save_file_dialog');
 
         if (selectedPath) {
+            // El backend pide la confirmación y emite un token de un solo uso
+            const consent = await invoke('request_consent', { action: 'write_file' });
             await invoke('save_result_file', {
                 content: resultText,
-                path: selectedPath
+                path: selectedPath,
+                consentToken: consent.token
             });
             showToast(`File saved successfully to ${selectedPath}`, 'success');
         } else {
@@ -374,7 +408,9 @@ function generateTXTContent(results, sourceType) {
 async function copyToClipboard() {
     const resultText = document.getElementById('resultText').textContent;
     try {
-        await navigator.clipboard.writeText(resultText);
+        // La frase se copia desde el backend, con confirmación nativa
+        const consent = await invoke('request_consent', { action: 'copy_to_clipboard' });
+        await invoke('copy_to_clipboard', { text: resultText, consentToken: consent.token });
         showToast('Result copied to clipboard', 'success', 2000);
     } catch (error) {
         console.error('Failed to copy to clipboard:', error);